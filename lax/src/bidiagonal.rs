@@ -0,0 +1,347 @@
+//! Bidiagonalization and singular value decomposition of bidiagonal matrix
+//!
+//! A general `m x n` matrix `A` is reduced into a bidiagonal form `A = Q B P^H`
+//! by Householder reflections, where `B` is upper bidiagonal if `m >= n`,
+//! and lower bidiagonal otherwise.
+//! Then the SVD of `B = U_B S V_B^H` gives the SVD of `A = (Q U_B) S (V_B^H P^H)`.
+//! This is what `*gesvd` does internally.
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgebrd | dgebrd | cgebrd | zgebrd |
+//! | sorgbr | dorgbr | cungbr | zungbr |
+//! | sbdsqr | dbdsqr | cbdsqr | zbdsqr |
+//!
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Result of [BidiagonalWorkImpl::eval]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BidiagonalOwned<T: Scalar> {
+    /// Upper bidiagonal if $m \ge n$, lower bidiagonal otherwise
+    pub uplo: UPLO,
+    /// Diagonal elements of $B$
+    pub d: Vec<T::Real>,
+    /// Off-diagonal elements of $B$
+    pub e: Vec<T::Real>,
+    /// Scalar factors of the elementary reflectors which represent $Q$
+    pub tauq: Vec<T>,
+    /// Scalar factors of the elementary reflectors which represent $P$
+    pub taup: Vec<T>,
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Result of [BidiagonalWorkImpl::calc]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BidiagonalRef<'work, T: Scalar> {
+    /// Upper bidiagonal if $m \ge n$, lower bidiagonal otherwise
+    pub uplo: UPLO,
+    /// Diagonal elements of $B$
+    pub d: &'work [T::Real],
+    /// Off-diagonal elements of $B$
+    pub e: &'work [T::Real],
+    /// Scalar factors of the elementary reflectors which represent $Q$
+    pub tauq: &'work [T],
+    /// Scalar factors of the elementary reflectors which represent $P$
+    pub taup: &'work [T],
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Working memory for bidiagonalization $A = QBP^H$
+///
+/// For C-continuous array, this bidiagonalizes the transposed matrix $A^T$.
+pub struct BidiagonalWork<T: Scalar> {
+    pub m: i32,
+    pub n: i32,
+    pub d: Vec<MaybeUninit<T::Real>>,
+    pub e: Vec<MaybeUninit<T::Real>>,
+    pub tauq: Vec<MaybeUninit<T>>,
+    pub taup: Vec<MaybeUninit<T>>,
+    pub work: Vec<MaybeUninit<T>>,
+}
+
+pub trait BidiagonalWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(l: MatrixLayout) -> Result<Self>;
+    /// Bidiagonalize `a`. The reflectors representing $Q$ and $P$ are stored in `a`.
+    fn calc(&mut self, a: &mut [Self::Elem]) -> Result<BidiagonalRef<'_, Self::Elem>>;
+    fn eval(self, a: &mut [Self::Elem]) -> Result<BidiagonalOwned<Self::Elem>>;
+}
+
+macro_rules! impl_bidiagonal_work {
+    ($s:ty, $gebrd:path) => {
+        impl BidiagonalWorkImpl for BidiagonalWork<$s> {
+            type Elem = $s;
+
+            fn new(layout: MatrixLayout) -> Result<Self> {
+                let m = layout.lda();
+                let n = layout.len();
+                let k = m.min(n) as usize;
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                unsafe {
+                    $gebrd(
+                        &m,
+                        &n,
+                        std::ptr::null_mut(),
+                        &m,
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                Ok(BidiagonalWork {
+                    m,
+                    n,
+                    d: vec_uninit(k),
+                    e: vec_uninit(k.saturating_sub(1)),
+                    tauq: vec_uninit(k),
+                    taup: vec_uninit(k),
+                    work: vec_uninit(lwork),
+                })
+            }
+
+            fn calc(&mut self, a: &mut [Self::Elem]) -> Result<BidiagonalRef<'_, Self::Elem>> {
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                unsafe {
+                    $gebrd(
+                        &self.m,
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.m,
+                        AsPtr::as_mut_ptr(&mut self.d),
+                        AsPtr::as_mut_ptr(&mut self.e),
+                        AsPtr::as_mut_ptr(&mut self.tauq),
+                        AsPtr::as_mut_ptr(&mut self.taup),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(BidiagonalRef {
+                    uplo: if self.m >= self.n {
+                        UPLO::Upper
+                    } else {
+                        UPLO::Lower
+                    },
+                    d: unsafe { self.d.slice_assume_init_ref() },
+                    e: unsafe { self.e.slice_assume_init_ref() },
+                    tauq: unsafe { self.tauq.slice_assume_init_ref() },
+                    taup: unsafe { self.taup.slice_assume_init_ref() },
+                })
+            }
+
+            fn eval(mut self, a: &mut [Self::Elem]) -> Result<BidiagonalOwned<Self::Elem>> {
+                let BidiagonalRef { uplo, .. } = self.calc(a)?;
+                Ok(BidiagonalOwned {
+                    uplo,
+                    d: unsafe { self.d.assume_init() },
+                    e: unsafe { self.e.assume_init() },
+                    tauq: unsafe { self.tauq.assume_init() },
+                    taup: unsafe { self.taup.assume_init() },
+                })
+            }
+        }
+    };
+}
+impl_bidiagonal_work!(c64, lapack_sys::zgebrd_);
+impl_bidiagonal_work!(c32, lapack_sys::cgebrd_);
+impl_bidiagonal_work!(f64, lapack_sys::dgebrd_);
+impl_bidiagonal_work!(f32, lapack_sys::sgebrd_);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Working memory for generating $Q$ or $P^H$ from the output of [BidiagonalWorkImpl]
+///
+/// For an $m \times n$ matrix $A$ bidiagonalized into $A = QBP^H$ with $k = \min(m, n)$,
+///
+/// - [BidiagonalFactor::Q] generates the first $k$ columns of $Q$, i.e. `a` is $m \times k$
+///   and its columns initially store the reflectors of $Q$.
+/// - [BidiagonalFactor::P] generates the first $k$ rows of $P^H$, i.e. `a` is $k \times n$
+///   and its rows initially store the reflectors of $P$.
+///
+/// For C-continuous array, the transposed matrix is generated.
+pub struct GenerateBidiagonalWork<T: Scalar> {
+    pub factor: BidiagonalFactor,
+    pub layout: MatrixLayout,
+    /// The number of columns (for $Q$) or rows (for $P^H$) of the original matrix $A$
+    pub k: i32,
+    pub work: Vec<MaybeUninit<T>>,
+}
+
+pub trait GenerateBidiagonalWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(factor: BidiagonalFactor, layout: MatrixLayout, k: i32) -> Result<Self>;
+    fn calc(&mut self, a: &mut [Self::Elem], tau: &[Self::Elem]) -> Result<()>;
+}
+
+macro_rules! impl_generate_bidiagonal_work {
+    ($s:ty, $gbr:path) => {
+        impl GenerateBidiagonalWorkImpl for GenerateBidiagonalWork<$s> {
+            type Elem = $s;
+
+            fn new(factor: BidiagonalFactor, layout: MatrixLayout, k: i32) -> Result<Self> {
+                let m = layout.lda();
+                let n = layout.len();
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                unsafe {
+                    $gbr(
+                        factor.as_ptr(),
+                        &m,
+                        &n,
+                        &k,
+                        std::ptr::null_mut(),
+                        &m,
+                        std::ptr::null(),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                Ok(GenerateBidiagonalWork {
+                    factor,
+                    layout,
+                    k,
+                    work: vec_uninit(lwork),
+                })
+            }
+
+            fn calc(&mut self, a: &mut [Self::Elem], tau: &[Self::Elem]) -> Result<()> {
+                let m = self.layout.lda();
+                let n = self.layout.len();
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                unsafe {
+                    $gbr(
+                        self.factor.as_ptr(),
+                        &m,
+                        &n,
+                        &self.k,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+impl_generate_bidiagonal_work!(c64, lapack_sys::zungbr_);
+impl_generate_bidiagonal_work!(c32, lapack_sys::cungbr_);
+impl_generate_bidiagonal_work!(f64, lapack_sys::dorgbr_);
+impl_generate_bidiagonal_work!(f32, lapack_sys::sorgbr_);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Compute SVD of a real $n \times n$ bidiagonal matrix $B = U_B \Sigma V_B^H$
+/// using implicit zero-shift QR algorithm
+///
+/// The diagonal elements `d` are overwritten by the singular values in descending order,
+/// and the off-diagonal elements `e` are destroyed.
+/// Optionally, the rotations are accumulated into given matrices as
+///
+/// - `vt` ($n \times \mathrm{ncvt}$) is overwritten by $V_B^H \cdot \mathrm{vt}$
+/// - `u` ($\mathrm{nru} \times n$) is overwritten by $\mathrm{u} \cdot U_B$
+/// - `c` ($n \times \mathrm{ncc}$) is overwritten by $U_B^H \cdot \mathrm{c}$
+///
+/// For C-continuous arrays, they are regarded as transposed matrices in Fortran layout.
+///
+/// If $B$ is computed from $A = QBP^H$ by [BidiagonalWorkImpl],
+/// the SVD of $A$ is obtained by passing $Q$ and $P^H$,
+/// generated from `tauq` and `taup` by [GenerateBidiagonalWorkImpl], as `u` and `vt`.
+pub trait SvdBidiagonalImpl: Scalar {
+    fn svd_bidiagonal(
+        uplo: UPLO,
+        d: &mut [Self::Real],
+        e: &mut [Self::Real],
+        vt: Option<(MatrixLayout, &mut [Self])>,
+        u: Option<(MatrixLayout, &mut [Self])>,
+        c: Option<(MatrixLayout, &mut [Self])>,
+    ) -> Result<()>;
+}
+
+macro_rules! impl_svd_bidiagonal {
+    ($s:ty, $bdsqr:path) => {
+        impl SvdBidiagonalImpl for $s {
+            fn svd_bidiagonal(
+                uplo: UPLO,
+                d: &mut [Self::Real],
+                e: &mut [Self::Real],
+                vt: Option<(MatrixLayout, &mut [Self])>,
+                u: Option<(MatrixLayout, &mut [Self])>,
+                c: Option<(MatrixLayout, &mut [Self])>,
+            ) -> Result<()> {
+                let n = d.len() as i32;
+                assert_eq!(e.len(), d.len().saturating_sub(1));
+                let (ncvt, ldvt, vt) = match vt {
+                    Some((l, vt)) => {
+                        assert_eq!(l.lda(), n.max(1));
+                        (l.len(), l.lda(), vt)
+                    }
+                    None => (0, 1, &mut [] as &mut [Self]),
+                };
+                let (nru, ldu, u) = match u {
+                    Some((l, u)) => {
+                        assert_eq!(l.len(), n);
+                        (l.lda(), l.lda(), u)
+                    }
+                    None => (0, 1, &mut [] as &mut [Self]),
+                };
+                let (ncc, ldc, c) = match c {
+                    Some((l, c)) => {
+                        assert_eq!(l.lda(), n.max(1));
+                        (l.len(), l.lda(), c)
+                    }
+                    None => (0, 1, &mut [] as &mut [Self]),
+                };
+                let mut work: Vec<MaybeUninit<Self::Real>> = vec_uninit(4 * n.max(1) as usize);
+                let mut info = 0;
+                unsafe {
+                    $bdsqr(
+                        uplo.as_ptr(),
+                        &n,
+                        &ncvt,
+                        &nru,
+                        &ncc,
+                        AsPtr::as_mut_ptr(d),
+                        AsPtr::as_mut_ptr(e),
+                        AsPtr::as_mut_ptr(vt),
+                        &ldvt,
+                        AsPtr::as_mut_ptr(u),
+                        &ldu,
+                        AsPtr::as_mut_ptr(c),
+                        &ldc,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+impl_svd_bidiagonal!(c64, lapack_sys::zbdsqr_);
+impl_svd_bidiagonal!(c32, lapack_sys::cbdsqr_);
+impl_svd_bidiagonal!(f64, lapack_sys::dbdsqr_);
+impl_svd_bidiagonal!(f32, lapack_sys::sbdsqr_);
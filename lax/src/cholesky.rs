@@ -43,6 +43,79 @@ impl_cholesky_!(c32, lapack_sys::cpotrf_);
 impl_cholesky_!(f64, lapack_sys::dpotrf_);
 impl_cholesky_!(f32, lapack_sys::spotrf_);
 
+/// Compute Cholesky decomposition with complete pivoting of a positive semi-definite matrix
+///
+/// The factor $U$ of $P^T A P = U^H U$ (or $L$ of $P^T A P = L L^H$) is written into `a`,
+/// and the permutation and the computed rank are returned.
+/// `piv[k]` is the 1-based row of $P$ whose `k`-th column is non-zero.
+/// The pivoting stops when the remaining diagonal element is smaller than `tol`,
+/// and the trailing `(n - rank) x (n - rank)` block of `a` is left unspecified.
+/// A negative `tol` uses `n * eps * max(diag(A))`.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | spstrf | dpstrf | cpstrf | zpstrf |
+///
+pub trait CholeskyPivotedImpl: Scalar {
+    fn cholesky_pivoted(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &mut [Self],
+        tol: Self::Real,
+    ) -> Result<(Pivot, i32)>;
+}
+
+macro_rules! impl_cholesky_pivoted {
+    ($s:ty, $pstrf:path) => {
+        impl CholeskyPivotedImpl for $s {
+            fn cholesky_pivoted(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &mut [Self],
+                tol: Self::Real,
+            ) -> Result<(Pivot, i32)> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                let mut piv = vec_uninit(n as usize);
+                let mut rank = 0;
+                let mut work: Vec<MaybeUninit<Self::Real>> = vec_uninit(2 * n as usize);
+                let mut info = 0;
+                unsafe {
+                    $pstrf(
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut piv),
+                        &mut rank,
+                        &tol,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &mut info,
+                    );
+                }
+                // Positive `info` only means that the matrix is rank deficient
+                if info < 0 {
+                    info.as_lapack_result()?;
+                }
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                let piv = unsafe { piv.assume_init() };
+                Ok((piv, rank))
+            }
+        }
+    };
+}
+impl_cholesky_pivoted!(c64, lapack_sys::zpstrf_);
+impl_cholesky_pivoted!(c32, lapack_sys::cpstrf_);
+impl_cholesky_pivoted!(f64, lapack_sys::dpstrf_);
+impl_cholesky_pivoted!(f32, lapack_sys::spstrf_);
+
 /// Compute inverse matrix using Cholesky factroization result
 ///
 /// LAPACK correspondance
@@ -82,6 +155,51 @@ impl_inv_cholesky!(c32, lapack_sys::cpotri_);
 impl_inv_cholesky!(f64, lapack_sys::dpotri_);
 impl_inv_cholesky!(f32, lapack_sys::spotri_);
 
+#[cfg_attr(doc, katexit::katexit)]
+/// Compute the product of a triangular matrix and its conjugate transpose
+///
+/// For [UPLO::Upper], the upper triangle $U$ is overwritten by the upper triangle of $U U^H$,
+/// and for [UPLO::Lower], the lower triangle $L$ is overwritten by the lower triangle of $L^H L$.
+/// The other triangle is not referenced.
+/// This is the last step of [Lapack::inv_cholesky] after inverting the triangular factor.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | slauum | dlauum | clauum | zlauum |
+///
+pub trait TriangularProductImpl: Scalar {
+    fn triangular_product(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<()>;
+}
+
+macro_rules! impl_triangular_product {
+    ($s:ty, $lauum:path) => {
+        impl TriangularProductImpl for $s {
+            fn triangular_product(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<()> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                let mut info = 0;
+                unsafe {
+                    $lauum(uplo.as_ptr(), &n, AsPtr::as_mut_ptr(a), &l.lda(), &mut info);
+                }
+                info.as_lapack_result()?;
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                Ok(())
+            }
+        }
+    };
+}
+impl_triangular_product!(c64, lapack_sys::zlauum_);
+impl_triangular_product!(c32, lapack_sys::clauum_);
+impl_triangular_product!(f64, lapack_sys::dlauum_);
+impl_triangular_product!(f32, lapack_sys::slauum_);
+
 /// Solve linear equation using Cholesky factroization result
 ///
 /// LAPACK correspondance
@@ -109,9 +227,7 @@ macro_rules! impl_solve_cholesky {
                 let mut info = 0;
                 if matches!(l, MatrixLayout::C { .. }) {
                     uplo = uplo.t();
-                    for val in b.iter_mut() {
-                        *val = val.conj();
-                    }
+                    conj_in_place(b);
                 }
                 unsafe {
                     $trs(
@@ -127,9 +243,7 @@ macro_rules! impl_solve_cholesky {
                 }
                 info.as_lapack_result()?;
                 if matches!(l, MatrixLayout::C { .. }) {
-                    for val in b.iter_mut() {
-                        *val = val.conj();
-                    }
+                    conj_in_place(b);
                 }
                 Ok(())
             }
@@ -140,3 +254,111 @@ impl_solve_cholesky!(c64, lapack_sys::zpotrs_);
 impl_solve_cholesky!(c32, lapack_sys::cpotrs_);
 impl_solve_cholesky!(f64, lapack_sys::dpotrs_);
 impl_solve_cholesky!(f32, lapack_sys::spotrs_);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Compute Cholesky decomposition of a band matrix according to [UPLO]
+///
+/// `ab` is the `n x n` band matrix with `kd` super- (or sub-) diagonals
+/// in the `(kd + 1) x n` column-major band storage,
+/// see [crate::eigh_generalized_banded] for the storage format.
+/// It is overwritten by the factor $U$ or $L$ in the same storage.
+/// Returns [crate::error::Error::LapackComputationalFailure] if the matrix is not positive definite.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | spbtrf | dpbtrf | cpbtrf | zpbtrf |
+///
+pub trait CholeskyBandedImpl: Scalar {
+    fn cholesky_banded(uplo: UPLO, n: i32, kd: i32, ab: &mut [Self]) -> Result<()>;
+}
+
+macro_rules! impl_cholesky_banded {
+    ($s:ty, $pbtrf:path) => {
+        impl CholeskyBandedImpl for $s {
+            fn cholesky_banded(uplo: UPLO, n: i32, kd: i32, ab: &mut [Self]) -> Result<()> {
+                let ldab = kd + 1;
+                assert_eq!(ab.len(), (ldab * n) as usize);
+                let mut info = 0;
+                unsafe {
+                    $pbtrf(
+                        uplo.as_ptr(),
+                        &n,
+                        &kd,
+                        AsPtr::as_mut_ptr(ab),
+                        &ldab,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+impl_cholesky_banded!(c64, lapack_sys::zpbtrf_);
+impl_cholesky_banded!(c32, lapack_sys::cpbtrf_);
+impl_cholesky_banded!(f64, lapack_sys::dpbtrf_);
+impl_cholesky_banded!(f32, lapack_sys::spbtrf_);
+
+/// Solve linear equation using the Cholesky factorization of a band matrix
+///
+/// `ab` is the factor computed by [CholeskyBandedImpl::cholesky_banded] with the same `uplo` and `kd`.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | spbtrs | dpbtrs | cpbtrs | zpbtrs |
+///
+pub trait SolveCholeskyBandedImpl: Scalar {
+    fn solve_cholesky_banded(
+        uplo: UPLO,
+        n: i32,
+        kd: i32,
+        ab: &[Self],
+        b: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_solve_cholesky_banded {
+    ($s:ty, $pbtrs:path) => {
+        impl SolveCholeskyBandedImpl for $s {
+            fn solve_cholesky_banded(
+                uplo: UPLO,
+                n: i32,
+                kd: i32,
+                ab: &[Self],
+                b: &mut [Self],
+            ) -> Result<()> {
+                let ldab = kd + 1;
+                assert_eq!(ab.len(), (ldab * n) as usize);
+                assert_eq!(b.len(), n as usize);
+                let nrhs = 1;
+                let mut info = 0;
+                unsafe {
+                    $pbtrs(
+                        uplo.as_ptr(),
+                        &n,
+                        &kd,
+                        &nrhs,
+                        AsPtr::as_ptr(ab),
+                        &ldab,
+                        AsPtr::as_mut_ptr(b),
+                        &n,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+impl_solve_cholesky_banded!(c64, lapack_sys::zpbtrs_);
+impl_solve_cholesky_banded!(c32, lapack_sys::cpbtrs_);
+impl_solve_cholesky_banded!(f64, lapack_sys::dpbtrs_);
+impl_solve_cholesky_banded!(f32, lapack_sys::spbtrs_);
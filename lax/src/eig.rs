@@ -6,6 +6,7 @@
 //! | f32   | f64   | c32   | c64   |
 //! |:------|:------|:------|:------|
 //! | sgeev | dgeev | cgeev | zgeev |
+//! | sggev | dggev | cggev | zggev |
 //!
 
 use crate::{error::*, layout::MatrixLayout, *};
@@ -391,6 +392,232 @@ macro_rules! impl_eig_work_r {
 impl_eig_work_r!(f32, lapack_sys::sgeev_);
 impl_eig_work_r!(f64, lapack_sys::dgeev_);
 
+/// Owned result of generalized eigenvalue problem by [EigGeneralizedImpl::eig_generalized]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EigGeneralizedOwned<T: Scalar> {
+    /// Numerators of the generalized eigenvalues `alpha[i] / beta[i]`
+    pub alpha: Vec<T::Complex>,
+    /// Denominators of the generalized eigenvalues, which are real and non-negative for real matrices
+    pub beta: Vec<T>,
+    /// Right eigenvectors in column-major order
+    pub vr: Option<Vec<T::Complex>>,
+    /// Left eigenvectors in column-major order
+    pub vl: Option<Vec<T::Complex>>,
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Generalized eigenvalue problem for general matrices
+///
+/// Solve $A v_R = \lambda B v_R$ and $v_L^\dagger A = \lambda v_L^\dagger B$
+/// where the generalized eigenvalues are represented as pairs
+/// $\lambda = \alpha / \beta$, since $\beta$ can be zero for singular $B$.
+/// Each eigenvector is normalized so that its largest component has
+/// $|\mathrm{Re}| + |\mathrm{Im}| = 1$.
+///
+/// `a` and `b` must have the same layout, and they are destroyed.
+/// Row-major matrices are transposed in place before calling LAPACK,
+/// and the eigenvectors are always returned in column-major order.
+pub trait EigGeneralizedImpl: Scalar {
+    fn eig_generalized(
+        calc_vl: bool,
+        calc_vr: bool,
+        l: MatrixLayout,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<EigGeneralizedOwned<Self>>;
+}
+
+macro_rules! impl_eig_generalized_c {
+    ($c:ty, $ggev:path) => {
+        impl EigGeneralizedImpl for $c {
+            fn eig_generalized(
+                calc_vl: bool,
+                calc_vr: bool,
+                l: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<EigGeneralizedOwned<Self>> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                    square_transpose(l, b);
+                }
+                let jobvl = if calc_vl { JobEv::All } else { JobEv::None };
+                let jobvr = if calc_vr { JobEv::All } else { JobEv::None };
+                let mut alpha: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut beta: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut vl = jobvl.then(|| vec_uninit((n * n) as usize));
+                let mut vr = jobvr.then(|| vec_uninit((n * n) as usize));
+                let ldvl = if calc_vl { n.max(1) } else { 1 };
+                let ldvr = if calc_vr { n.max(1) } else { 1 };
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(8 * n as usize);
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [<$c>::zero()];
+                unsafe {
+                    $ggev(
+                        jobvl.as_ptr(),
+                        jobvr.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut alpha),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(vl.as_deref_mut().unwrap_or(&mut [])),
+                        &ldvl,
+                        AsPtr::as_mut_ptr(vr.as_deref_mut().unwrap_or(&mut [])),
+                        &ldvr,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // actual ev
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<$c>> = vec_uninit(lwork);
+                unsafe {
+                    $ggev(
+                        jobvl.as_ptr(),
+                        jobvr.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut alpha),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(vl.as_deref_mut().unwrap_or(&mut [])),
+                        &ldvl,
+                        AsPtr::as_mut_ptr(vr.as_deref_mut().unwrap_or(&mut [])),
+                        &ldvr,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                Ok(EigGeneralizedOwned {
+                    alpha: unsafe { alpha.assume_init() },
+                    beta: unsafe { beta.assume_init() },
+                    vl: vl.map(|v| unsafe { v.assume_init() }),
+                    vr: vr.map(|v| unsafe { v.assume_init() }),
+                })
+            }
+        }
+    };
+}
+impl_eig_generalized_c!(c32, lapack_sys::cggev_);
+impl_eig_generalized_c!(c64, lapack_sys::zggev_);
+
+macro_rules! impl_eig_generalized_r {
+    ($f:ty, $ggev:path) => {
+        impl EigGeneralizedImpl for $f {
+            fn eig_generalized(
+                calc_vl: bool,
+                calc_vr: bool,
+                l: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<EigGeneralizedOwned<Self>> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                    square_transpose(l, b);
+                }
+                let jobvl = if calc_vl { JobEv::All } else { JobEv::None };
+                let jobvr = if calc_vr { JobEv::All } else { JobEv::None };
+                let mut alpha_re: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut alpha_im: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut beta: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut vr_l = jobvl.then(|| vec_uninit((n * n) as usize));
+                let mut vr_r = jobvr.then(|| vec_uninit((n * n) as usize));
+                let ldvl = if calc_vl { n.max(1) } else { 1 };
+                let ldvr = if calc_vr { n.max(1) } else { 1 };
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size: [$f; 1] = [0.0];
+                unsafe {
+                    $ggev(
+                        jobvl.as_ptr(),
+                        jobvr.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut alpha_re),
+                        AsPtr::as_mut_ptr(&mut alpha_im),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(vr_l.as_deref_mut().unwrap_or(&mut [])),
+                        &ldvl,
+                        AsPtr::as_mut_ptr(vr_r.as_deref_mut().unwrap_or(&mut [])),
+                        &ldvr,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // actual ev
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<$f>> = vec_uninit(lwork);
+                unsafe {
+                    $ggev(
+                        jobvl.as_ptr(),
+                        jobvr.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut alpha_re),
+                        AsPtr::as_mut_ptr(&mut alpha_im),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(vr_l.as_deref_mut().unwrap_or(&mut [])),
+                        &ldvl,
+                        AsPtr::as_mut_ptr(vr_r.as_deref_mut().unwrap_or(&mut [])),
+                        &ldvr,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let alpha_re = unsafe { alpha_re.assume_init() };
+                let alpha_im = unsafe { alpha_im.assume_init() };
+                let mut alpha = vec_uninit(n as usize);
+                reconstruct_eigs(&alpha_re, &alpha_im, &mut alpha);
+                // Complex conjugate pairs of eigenvectors are stored in the same way as `*geev`
+                let to_complex = |v: Vec<MaybeUninit<$f>>| {
+                    let v = unsafe { v.assume_init() };
+                    let mut vc = vec_uninit((n * n) as usize);
+                    reconstruct_eigenvectors(false, &alpha_im, &v, &mut vc);
+                    unsafe { vc.assume_init() }
+                };
+                Ok(EigGeneralizedOwned {
+                    alpha: unsafe { alpha.assume_init() },
+                    beta: unsafe { beta.assume_init() },
+                    vl: vr_l.map(to_complex),
+                    vr: vr_r.map(to_complex),
+                })
+            }
+        }
+    };
+}
+impl_eig_generalized_r!(f32, lapack_sys::sggev_);
+impl_eig_generalized_r!(f64, lapack_sys::dggev_);
+
 /// Reconstruct eigenvectors into complex-array
 ///
 /// From LAPACK API https://software.intel.com/en-us/node/469230
@@ -0,0 +1,136 @@
+//! Eigenvalue problem for symmetric/Hermitian band matrices
+//!
+//! The matrix is given in the band storage of LAPACK, see [crate::eigh_generalized_banded].
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32   | f64   | c32   | c64   |
+//! |:------|:------|:------|:------|
+//! | ssbev | dsbev | chbev | zhbev |
+//!
+
+use super::*;
+use crate::error::*;
+use cauchy::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EighBandedOwned<T: Scalar> {
+    /// Eigenvalues in ascending order
+    pub eigs: Vec<T::Real>,
+    /// Orthonormal eigenvectors in column-major order
+    pub z: Option<Vec<T>>,
+}
+
+/// Compute eigenvalues, and eigenvectors if `calc_eigenvec`
+///
+/// `ab` is the `(kd + 1) x n` array of the band storage, and destroyed.
+pub trait EighBandedImpl: Scalar {
+    fn eigh_banded(
+        calc_eigenvec: bool,
+        uplo: UPLO,
+        n: i32,
+        kd: i32,
+        ab: &mut [Self],
+    ) -> Result<EighBandedOwned<Self>>;
+}
+
+macro_rules! impl_eigh_banded_c {
+    ($c:ty, $hbev:path) => {
+        impl EighBandedImpl for $c {
+            fn eigh_banded(
+                calc_eigenvec: bool,
+                uplo: UPLO,
+                n: i32,
+                kd: i32,
+                ab: &mut [Self],
+            ) -> Result<EighBandedOwned<Self>> {
+                let ldab = kd + 1;
+                assert_eq!(ab.len(), (ldab * n) as usize);
+                let jobz = if calc_eigenvec {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let n_ = n as usize;
+                let mut eigs: Vec<MaybeUninit<Self::Real>> = vec_uninit(n_);
+                let mut z: Option<Vec<MaybeUninit<Self>>> = jobz.then(|| vec_uninit(n_ * n_));
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(n_.max(1));
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit((3 * n_).max(3) - 2);
+                let mut info = 0;
+                unsafe {
+                    $hbev(
+                        jobz.as_ptr(),
+                        uplo.as_ptr(),
+                        &n,
+                        &kd,
+                        AsPtr::as_mut_ptr(ab),
+                        &ldab,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(z.as_mut().map(|z| z.as_mut_slice()).unwrap_or(&mut [])),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(EighBandedOwned {
+                    eigs: unsafe { eigs.assume_init() },
+                    z: z.map(|z| unsafe { z.assume_init() }),
+                })
+            }
+        }
+    };
+}
+impl_eigh_banded_c!(c64, lapack_sys::zhbev_);
+impl_eigh_banded_c!(c32, lapack_sys::chbev_);
+
+macro_rules! impl_eigh_banded_r {
+    ($f:ty, $sbev:path) => {
+        impl EighBandedImpl for $f {
+            fn eigh_banded(
+                calc_eigenvec: bool,
+                uplo: UPLO,
+                n: i32,
+                kd: i32,
+                ab: &mut [Self],
+            ) -> Result<EighBandedOwned<Self>> {
+                let ldab = kd + 1;
+                assert_eq!(ab.len(), (ldab * n) as usize);
+                let jobz = if calc_eigenvec {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let n_ = n as usize;
+                let mut eigs: Vec<MaybeUninit<Self::Real>> = vec_uninit(n_);
+                let mut z: Option<Vec<MaybeUninit<Self>>> = jobz.then(|| vec_uninit(n_ * n_));
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit((3 * n_).max(3) - 2);
+                let mut info = 0;
+                unsafe {
+                    $sbev(
+                        jobz.as_ptr(),
+                        uplo.as_ptr(),
+                        &n,
+                        &kd,
+                        AsPtr::as_mut_ptr(ab),
+                        &ldab,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(z.as_mut().map(|z| z.as_mut_slice()).unwrap_or(&mut [])),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(EighBandedOwned {
+                    eigs: unsafe { eigs.assume_init() },
+                    z: z.map(|z| unsafe { z.assume_init() }),
+                })
+            }
+        }
+    };
+}
+impl_eigh_banded_r!(f64, lapack_sys::dsbev_);
+impl_eigh_banded_r!(f32, lapack_sys::ssbev_);
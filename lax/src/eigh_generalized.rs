@@ -3,13 +3,18 @@
 //! LAPACK correspondance
 //! ----------------------
 //!
-//! | f32   | f64   | c32   | c64   |
-//! |:------|:------|:------|:------|
-//! | ssygv | dsygv | chegv | zhegv |
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | ssygv  | dsygv  | chegv  | zhegv  |
+//! | ssygvd | dsygvd | chegvd | zhegvd |
+//! | ssygst | dsygst | chegst | zhegst |
 //!
 
 use super::*;
-use crate::{error::*, layout::MatrixLayout};
+use crate::{
+    error::*,
+    layout::{square_transpose, MatrixLayout},
+};
 use cauchy::*;
 use num_traits::{ToPrimitive, Zero};
 
@@ -214,3 +219,281 @@ macro_rules! impl_eigh_generalized_work_r {
 }
 impl_eigh_generalized_work_r!(f64, lapack_sys::dsygv_);
 impl_eigh_generalized_work_r!(f32, lapack_sys::ssygv_);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Solve a generalized eigenvalue problem for symmetric/Hermitian $A$ and positive definite $B$
+/// by divide and conquer method
+///
+/// Unlike [EighGeneralizedWork], which only solves $Ax = \lambda Bx$,
+/// the type of the problem is specified by `itype`.
+/// `uplo` specifies which triangle of $A$ and $B$ is referenced.
+/// If `calc_eigenvec` is true, `a` is overwritten by the eigenvectors in the layout `l`,
+/// which are normalized as $Z^H B Z = I$ for [GeneralizedEigType::AxLambdaBx] and [GeneralizedEigType::ABxLambdaX],
+/// and $Z^H B^{-1} Z = I$ for [GeneralizedEigType::BAxLambdaX].
+/// `b` is overwritten by its Cholesky factor.
+/// The eigenvalues are returned in ascending order.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | ssygvd | dsygvd | chegvd | zhegvd |
+///
+pub trait EighGeneralizedDcImpl: Scalar {
+    fn eigh_generalized_dc(
+        calc_eigenvec: bool,
+        l: MatrixLayout,
+        itype: GeneralizedEigType,
+        uplo: UPLO,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<Vec<Self::Real>>;
+}
+
+macro_rules! impl_eigh_generalized_dc_c {
+    ($c:ty, $gvd:path) => {
+        impl EighGeneralizedDcImpl for $c {
+            fn eigh_generalized_dc(
+                calc_eigenvec: bool,
+                l: MatrixLayout,
+                itype: GeneralizedEigType,
+                uplo: UPLO,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<Vec<Self::Real>> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                    square_transpose(l, b);
+                }
+                let jobz = if calc_eigenvec {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let mut eigs: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                let mut rwork_size = [Self::Real::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $gvd(
+                        itype.as_ptr(),
+                        jobz.as_ptr(),
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        AsPtr::as_mut_ptr(b),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork_size),
+                        &(-1),
+                        iwork_size.as_mut_ptr(),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc eigenvalues
+                let lwork = work_size[0].to_usize().unwrap();
+                let lrwork = rwork_size[0].to_usize().unwrap();
+                let liwork = iwork_size[0];
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(lrwork);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(liwork as usize);
+                unsafe {
+                    $gvd(
+                        itype.as_ptr(),
+                        jobz.as_ptr(),
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        AsPtr::as_mut_ptr(b),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &(lrwork as i32),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &liwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if calc_eigenvec && matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                Ok(unsafe { eigs.assume_init() })
+            }
+        }
+    };
+}
+impl_eigh_generalized_dc_c!(c64, lapack_sys::zhegvd_);
+impl_eigh_generalized_dc_c!(c32, lapack_sys::chegvd_);
+
+macro_rules! impl_eigh_generalized_dc_r {
+    ($f:ty, $gvd:path) => {
+        impl EighGeneralizedDcImpl for $f {
+            fn eigh_generalized_dc(
+                calc_eigenvec: bool,
+                l: MatrixLayout,
+                itype: GeneralizedEigType,
+                uplo: UPLO,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<Vec<Self::Real>> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                    square_transpose(l, b);
+                }
+                let jobz = if calc_eigenvec {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let mut eigs: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $gvd(
+                        itype.as_ptr(),
+                        jobz.as_ptr(),
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        AsPtr::as_mut_ptr(b),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        iwork_size.as_mut_ptr(),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc eigenvalues
+                let lwork = work_size[0].to_usize().unwrap();
+                let liwork = iwork_size[0];
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(liwork as usize);
+                unsafe {
+                    $gvd(
+                        itype.as_ptr(),
+                        jobz.as_ptr(),
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        AsPtr::as_mut_ptr(b),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &liwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if calc_eigenvec && matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                Ok(unsafe { eigs.assume_init() })
+            }
+        }
+    };
+}
+impl_eigh_generalized_dc_r!(f64, lapack_sys::dsygvd_);
+impl_eigh_generalized_dc_r!(f32, lapack_sys::ssygvd_);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Reduce a generalized eigenvalue problem into the standard one
+/// using the Cholesky factor of $B$ computed by [Lapack::cholesky]
+///
+/// Both `a` and `b` are stored in the layout `l`.
+/// `a` is overwritten by the triangle specified by `uplo` of the Hermitian matrix $C$,
+/// which depends on `itype` and `uplo` used for $B = U^H U$ or $B = L L^H$:
+///
+/// | `itype`                                | [UPLO::Upper]         | [UPLO::Lower]         |
+/// |:---------------------------------------|:----------------------|:----------------------|
+/// | [GeneralizedEigType::AxLambdaBx]       | $U^{-H} A U^{-1}$     | $L^{-1} A L^{-H}$     |
+/// | [GeneralizedEigType::ABxLambdaX]       | $U A U^H$             | $L^H A L$             |
+/// | [GeneralizedEigType::BAxLambdaX]       | $U A U^H$             | $L^H A L$             |
+///
+/// The eigenvalues of $C$ are those of the generalized problem,
+/// and the eigenvectors $y$ of $C$ are transformed into those of the generalized problem by
+/// $x = U^{-1} y$ (or $L^{-H} y$) for the first two types, and $x = U^H y$ (or $L y$) for the last type.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | ssygst | dsygst | chegst | zhegst |
+///
+pub trait ReduceGeneralizedImpl: Scalar {
+    fn reduce_generalized(
+        l: MatrixLayout,
+        itype: GeneralizedEigType,
+        uplo: UPLO,
+        a: &mut [Self],
+        b: &[Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_reduce_generalized {
+    ($s:ty, $gst:path) => {
+        impl ReduceGeneralizedImpl for $s {
+            fn reduce_generalized(
+                l: MatrixLayout,
+                itype: GeneralizedEigType,
+                uplo: UPLO,
+                a: &mut [Self],
+                b: &[Self],
+            ) -> Result<()> {
+                let (n, _) = l.size();
+                // C-continuous Hermitian matrix is F-continuous of its complex conjugate,
+                // and the Cholesky factor of the conjugate is in the opposite triangle.
+                let uplo = match l {
+                    MatrixLayout::C { .. } => uplo.t(),
+                    MatrixLayout::F { .. } => uplo,
+                };
+                let mut info = 0;
+                unsafe {
+                    $gst(
+                        itype.as_ptr(),
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        AsPtr::as_ptr(b) as *mut _,
+                        &n,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+impl_reduce_generalized!(c64, lapack_sys::zhegst_);
+impl_reduce_generalized!(c32, lapack_sys::chegst_);
+impl_reduce_generalized!(f64, lapack_sys::dsygst_);
+impl_reduce_generalized!(f32, lapack_sys::ssygst_);
@@ -0,0 +1,243 @@
+//! Generalized eigenvalue problem for symmetric/Hermitian band matrices
+//!
+//! Both matrices are given in the band storage of LAPACK, i.e. the `(k + 1) x n` column-major array
+//! whose `j`-th column keeps the upper (or lower) part of the `j`-th column of the matrix within the bandwidth `k`:
+//!
+//! - `UPLO::Upper`: `ab[k + i - j + j * (k + 1)] = a[i, j]` for `max(0, j - k) <= i <= j`
+//! - `UPLO::Lower`: `ab[i - j + j * (k + 1)] = a[i, j]` for `j <= i <= min(n - 1, j + k)`
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32   | f64   | c32   | c64   |
+//! |:------|:------|:------|:------|
+//! | ssbgv | dsbgv | chbgv | zhbgv |
+//!
+
+use super::*;
+use crate::error::*;
+use cauchy::*;
+
+pub struct EighGeneralizedBandedWork<T: Scalar> {
+    pub n: i32,
+    /// Bandwidth of `A`
+    pub ka: i32,
+    /// Bandwidth of `B`
+    pub kb: i32,
+    pub jobz: JobEv,
+    pub eigs: Vec<MaybeUninit<T::Real>>,
+    /// Eigenvectors in column-major order
+    pub z: Option<Vec<MaybeUninit<T>>>,
+    pub work: Vec<MaybeUninit<T>>,
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EighGeneralizedBandedOwned<T: Scalar> {
+    /// Eigenvalues in ascending order
+    pub eigs: Vec<T::Real>,
+    /// Eigenvectors in column-major order, normalized as `Z^H B Z = I`
+    pub z: Option<Vec<T>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EighGeneralizedBandedRef<'work, T: Scalar> {
+    /// Eigenvalues in ascending order
+    pub eigs: &'work [T::Real],
+    /// Eigenvectors in column-major order, normalized as `Z^H B Z = I`
+    pub z: Option<&'work [T]>,
+}
+
+pub trait EighGeneralizedBandedWorkImpl: Sized {
+    type Elem: Scalar;
+    /// Allocate working memory for `n x n` matrices `A` and `B` with the bandwidths `ka >= kb`
+    fn new(calc_eigenvectors: bool, n: i32, ka: i32, kb: i32) -> Result<Self>;
+    /// `ab` and `bb` are destroyed, and `bb` is overwritten by the split Cholesky factor of `B`
+    fn calc(
+        &mut self,
+        uplo: UPLO,
+        ab: &mut [Self::Elem],
+        bb: &mut [Self::Elem],
+    ) -> Result<EighGeneralizedBandedRef<'_, Self::Elem>>;
+    fn eval(
+        self,
+        uplo: UPLO,
+        ab: &mut [Self::Elem],
+        bb: &mut [Self::Elem],
+    ) -> Result<EighGeneralizedBandedOwned<Self::Elem>>;
+}
+
+macro_rules! impl_eigh_generalized_banded_work_c {
+    ($c:ty, $hbgv:path) => {
+        impl EighGeneralizedBandedWorkImpl for EighGeneralizedBandedWork<$c> {
+            type Elem = $c;
+
+            fn new(calc_eigenvectors: bool, n: i32, ka: i32, kb: i32) -> Result<Self> {
+                assert!(ka >= kb && kb >= 0, "Bandwidths must satisfy ka >= kb >= 0");
+                let jobz = if calc_eigenvectors {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let n_ = n as usize;
+                Ok(EighGeneralizedBandedWork {
+                    n,
+                    ka,
+                    kb,
+                    jobz,
+                    eigs: vec_uninit(n_),
+                    z: jobz.then(|| vec_uninit(n_ * n_)),
+                    // `*hbgv` does not support workspace query
+                    work: vec_uninit(n_),
+                    rwork: Some(vec_uninit(3 * n_)),
+                })
+            }
+
+            fn calc(
+                &mut self,
+                uplo: UPLO,
+                ab: &mut [Self::Elem],
+                bb: &mut [Self::Elem],
+            ) -> Result<EighGeneralizedBandedRef<'_, Self::Elem>> {
+                let n = self.n as usize;
+                let ldab = self.ka + 1;
+                let ldbb = self.kb + 1;
+                assert_eq!(ab.len(), ldab as usize * n);
+                assert_eq!(bb.len(), ldbb as usize * n);
+                let ldz = std::cmp::max(self.n, 1);
+                let mut info = 0;
+                unsafe {
+                    $hbgv(
+                        self.jobz.as_ptr(),
+                        uplo.as_ptr(),
+                        &self.n,
+                        &self.ka,
+                        &self.kb,
+                        AsPtr::as_mut_ptr(ab),
+                        &ldab,
+                        AsPtr::as_mut_ptr(bb),
+                        &ldbb,
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        AsPtr::as_mut_ptr(
+                            self.z.as_mut().map(|z| z.as_mut_slice()).unwrap_or(&mut []),
+                        ),
+                        &ldz,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(EighGeneralizedBandedRef {
+                    eigs: unsafe { self.eigs.slice_assume_init_ref() },
+                    z: self
+                        .z
+                        .as_ref()
+                        .map(|z| unsafe { z.slice_assume_init_ref() }),
+                })
+            }
+
+            fn eval(
+                mut self,
+                uplo: UPLO,
+                ab: &mut [Self::Elem],
+                bb: &mut [Self::Elem],
+            ) -> Result<EighGeneralizedBandedOwned<Self::Elem>> {
+                let _ = self.calc(uplo, ab, bb)?;
+                Ok(EighGeneralizedBandedOwned {
+                    eigs: unsafe { self.eigs.assume_init() },
+                    z: self.z.map(|z| unsafe { z.assume_init() }),
+                })
+            }
+        }
+    };
+}
+impl_eigh_generalized_banded_work_c!(c64, lapack_sys::zhbgv_);
+impl_eigh_generalized_banded_work_c!(c32, lapack_sys::chbgv_);
+
+macro_rules! impl_eigh_generalized_banded_work_r {
+    ($f:ty, $sbgv:path) => {
+        impl EighGeneralizedBandedWorkImpl for EighGeneralizedBandedWork<$f> {
+            type Elem = $f;
+
+            fn new(calc_eigenvectors: bool, n: i32, ka: i32, kb: i32) -> Result<Self> {
+                assert!(ka >= kb && kb >= 0, "Bandwidths must satisfy ka >= kb >= 0");
+                let jobz = if calc_eigenvectors {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let n_ = n as usize;
+                Ok(EighGeneralizedBandedWork {
+                    n,
+                    ka,
+                    kb,
+                    jobz,
+                    eigs: vec_uninit(n_),
+                    z: jobz.then(|| vec_uninit(n_ * n_)),
+                    // `*sbgv` does not support workspace query
+                    work: vec_uninit(3 * n_),
+                    rwork: None,
+                })
+            }
+
+            fn calc(
+                &mut self,
+                uplo: UPLO,
+                ab: &mut [Self::Elem],
+                bb: &mut [Self::Elem],
+            ) -> Result<EighGeneralizedBandedRef<'_, Self::Elem>> {
+                let n = self.n as usize;
+                let ldab = self.ka + 1;
+                let ldbb = self.kb + 1;
+                assert_eq!(ab.len(), ldab as usize * n);
+                assert_eq!(bb.len(), ldbb as usize * n);
+                let ldz = std::cmp::max(self.n, 1);
+                let mut info = 0;
+                unsafe {
+                    $sbgv(
+                        self.jobz.as_ptr(),
+                        uplo.as_ptr(),
+                        &self.n,
+                        &self.ka,
+                        &self.kb,
+                        AsPtr::as_mut_ptr(ab),
+                        &ldab,
+                        AsPtr::as_mut_ptr(bb),
+                        &ldbb,
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        AsPtr::as_mut_ptr(
+                            self.z.as_mut().map(|z| z.as_mut_slice()).unwrap_or(&mut []),
+                        ),
+                        &ldz,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(EighGeneralizedBandedRef {
+                    eigs: unsafe { self.eigs.slice_assume_init_ref() },
+                    z: self
+                        .z
+                        .as_ref()
+                        .map(|z| unsafe { z.slice_assume_init_ref() }),
+                })
+            }
+
+            fn eval(
+                mut self,
+                uplo: UPLO,
+                ab: &mut [Self::Elem],
+                bb: &mut [Self::Elem],
+            ) -> Result<EighGeneralizedBandedOwned<Self::Elem>> {
+                let _ = self.calc(uplo, ab, bb)?;
+                Ok(EighGeneralizedBandedOwned {
+                    eigs: unsafe { self.eigs.assume_init() },
+                    z: self.z.map(|z| unsafe { z.assume_init() }),
+                })
+            }
+        }
+    };
+}
+impl_eigh_generalized_banded_work_r!(f64, lapack_sys::dsbgv_);
+impl_eigh_generalized_banded_work_r!(f32, lapack_sys::ssbgv_);
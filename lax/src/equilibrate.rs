@@ -0,0 +1,151 @@
+//! Equilibration of badly scaled matrices
+//!
+//! For a general `m x n` matrix $A$, this computes the row and column scale factors $R$ and $C$
+//! so that the largest element of each row and column of $\mathrm{diag}(R) A \mathrm{diag}(C)$
+//! has the absolute value 1.
+//! For a Hermitian (or real symmetric) positive definite matrix,
+//! the symmetric scale factors $S_i = 1 / \sqrt{A_{ii}}$ make the diagonal elements 1.
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgeequ | dgeequ | cgeequ | zgeequ |
+//! | spoequ | dpoequ | cpoequ | zpoequ |
+//!
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::Zero;
+
+/// Result of [EquilibrateImpl::equilibrate]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EquilibrateOwned<A: Scalar> {
+    /// Row scale factors
+    pub r: Vec<A::Real>,
+    /// Column scale factors
+    pub c: Vec<A::Real>,
+    /// Ratio of the smallest to the largest row scale factor
+    pub rowcnd: A::Real,
+    /// Ratio of the smallest to the largest column scale factor
+    pub colcnd: A::Real,
+    /// Absolute value of the largest element of the matrix
+    pub amax: A::Real,
+}
+
+/// Result of [EquilibrateHImpl::equilibrateh]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EquilibrateHOwned<A: Scalar> {
+    /// Scale factors applied to both rows and columns
+    pub s: Vec<A::Real>,
+    /// Ratio of the smallest to the largest scale factor
+    pub scond: A::Real,
+    /// Absolute value of the largest element of the matrix
+    pub amax: A::Real,
+}
+
+/// Compute row and column scale factors of a general matrix
+///
+/// Returns [crate::error::Error::LapackComputationalFailure] if a row or column of $A$ is exactly zero,
+/// where `return_code` is `i <= m` for the `i`-th row and `m + j` for the `j`-th column.
+pub trait EquilibrateImpl: Scalar {
+    fn equilibrate(l: MatrixLayout, a: &[Self]) -> Result<EquilibrateOwned<Self>>;
+}
+
+macro_rules! impl_equilibrate {
+    ($s:ty, $equ:path) => {
+        impl EquilibrateImpl for $s {
+            fn equilibrate(l: MatrixLayout, a: &[Self]) -> Result<EquilibrateOwned<Self>> {
+                // C-continuous matrix is regarded as the transposed F-continuous one
+                let (m, n) = match l {
+                    MatrixLayout::F { col, lda } => (lda, col),
+                    MatrixLayout::C { row, lda } => (lda, row),
+                };
+                let mut r: Vec<MaybeUninit<Self::Real>> = vec_uninit(m as usize);
+                let mut c: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                let mut rowcnd = Self::Real::zero();
+                let mut colcnd = Self::Real::zero();
+                let mut amax = Self::Real::zero();
+                let mut info = 0;
+                unsafe {
+                    $equ(
+                        &m,
+                        &n,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut r),
+                        AsPtr::as_mut_ptr(&mut c),
+                        &mut rowcnd,
+                        &mut colcnd,
+                        &mut amax,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let r = unsafe { r.assume_init() };
+                let c = unsafe { c.assume_init() };
+                Ok(match l {
+                    MatrixLayout::F { .. } => EquilibrateOwned {
+                        r,
+                        c,
+                        rowcnd,
+                        colcnd,
+                        amax,
+                    },
+                    MatrixLayout::C { .. } => EquilibrateOwned {
+                        r: c,
+                        c: r,
+                        rowcnd: colcnd,
+                        colcnd: rowcnd,
+                        amax,
+                    },
+                })
+            }
+        }
+    };
+}
+impl_equilibrate!(c64, lapack_sys::zgeequ_);
+impl_equilibrate!(c32, lapack_sys::cgeequ_);
+impl_equilibrate!(f64, lapack_sys::dgeequ_);
+impl_equilibrate!(f32, lapack_sys::sgeequ_);
+
+/// Compute symmetric scale factors of a Hermitian (or real symmetric) positive definite matrix
+///
+/// Only the diagonal elements of $A$ are referenced.
+/// Returns [crate::error::Error::LapackComputationalFailure] if the `i`-th diagonal element is not positive.
+pub trait EquilibrateHImpl: Scalar {
+    fn equilibrateh(l: MatrixLayout, a: &[Self]) -> Result<EquilibrateHOwned<Self>>;
+}
+
+macro_rules! impl_equilibrateh {
+    ($s:ty, $equ:path) => {
+        impl EquilibrateHImpl for $s {
+            fn equilibrateh(l: MatrixLayout, a: &[Self]) -> Result<EquilibrateHOwned<Self>> {
+                let n = l.len();
+                let mut s: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                let mut scond = Self::Real::zero();
+                let mut amax = Self::Real::zero();
+                let mut info = 0;
+                unsafe {
+                    $equ(
+                        &n,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut s),
+                        &mut scond,
+                        &mut amax,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let s = unsafe { s.assume_init() };
+                Ok(EquilibrateHOwned { s, scond, amax })
+            }
+        }
+    };
+}
+impl_equilibrateh!(c64, lapack_sys::zpoequ_);
+impl_equilibrateh!(c32, lapack_sys::cpoequ_);
+impl_equilibrateh!(f64, lapack_sys::dpoequ_);
+impl_equilibrateh!(f32, lapack_sys::spoequ_);
@@ -62,6 +62,59 @@ impl NormType {
     }
 }
 
+/// Specifies whether a matrix is multiplied from the left or the right
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Side {
+    Left = b'L',
+    Right = b'R',
+}
+
+impl Side {
+    /// To use Fortran LAPACK API in lapack-sys crate
+    pub fn as_ptr(&self) -> *const c_char {
+        self as *const Side as *const c_char
+    }
+}
+
+/// Specifies which orthogonal/unitary factor of bidiagonalization $A = QBP^H$ is used
+#[cfg_attr(doc, katexit::katexit)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum BidiagonalFactor {
+    /// $Q$ determined by `tauq`
+    Q = b'Q',
+    /// $P^H$ determined by `taup`
+    P = b'P',
+}
+
+impl BidiagonalFactor {
+    /// To use Fortran LAPACK API in lapack-sys crate
+    pub fn as_ptr(&self) -> *const c_char {
+        self as *const BidiagonalFactor as *const c_char
+    }
+}
+
+/// Type of generalized eigenvalue problem for symmetric/Hermitian matrices, `ITYPE` in LAPACK
+#[cfg_attr(doc, katexit::katexit)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(i32)]
+pub enum GeneralizedEigType {
+    /// $Ax = \lambda Bx$
+    AxLambdaBx = 1,
+    /// $ABx = \lambda x$
+    ABxLambdaX = 2,
+    /// $BAx = \lambda x$
+    BAxLambdaX = 3,
+}
+
+impl GeneralizedEigType {
+    /// To use Fortran LAPACK API in lapack-sys crate
+    pub fn as_ptr(&self) -> *const i32 {
+        self as *const GeneralizedEigType as *const i32
+    }
+}
+
 /// Flag for calculating eigenvectors or not
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(u8)]
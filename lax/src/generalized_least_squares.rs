@@ -0,0 +1,229 @@
+//! Generalized linear least squares problems
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgglse | dgglse | cgglse | zgglse |
+//! | sggglm | dggglm | cggglm | zggglm |
+//!
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Solve linear equality-constrained least squares problem
+///
+/// $$
+/// \min_x \| b - Ax \|_2 \quad \text{subject to} \quad Cx = d
+/// $$
+///
+/// for a $m \times n$ matrix $A$ and a $p \times n$ matrix $C$ with $p \le n \le m + p$.
+/// Both `a` and `c` must be in column-major order without padding,
+/// and have the same number of columns. Otherwise [Error::InvalidShape] is returned.
+/// All the inputs are destroyed.
+///
+/// The solution is unique if $C$ has full row rank $p$ and $(A^T, C^T)^T$ has full column rank $n$.
+/// Otherwise [Error::LapackComputationalFailure] is returned.
+pub trait LeastSquaresConstrainedImpl: Scalar {
+    fn least_squares_constrained(
+        la: MatrixLayout,
+        lc: MatrixLayout,
+        a: &mut [Self],
+        b: &mut [Self],
+        c: &mut [Self],
+        d: &mut [Self],
+    ) -> Result<Vec<Self>>;
+}
+
+macro_rules! impl_least_squares_constrained {
+    ($s:ty, $gglse:path) => {
+        impl LeastSquaresConstrainedImpl for $s {
+            fn least_squares_constrained(
+                la: MatrixLayout,
+                lc: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+                c: &mut [Self],
+                d: &mut [Self],
+            ) -> Result<Vec<Self>> {
+                let (m, n, p) = match (la, lc) {
+                    (MatrixLayout::F { col: n, lda: m }, MatrixLayout::F { col, lda: p })
+                        if n == col && p <= n && n <= m + p =>
+                    {
+                        (m, n, p)
+                    }
+                    _ => return Err(Error::InvalidShape),
+                };
+                if b.len() != m as usize || d.len() != p as usize {
+                    return Err(Error::InvalidShape);
+                }
+                let mut x: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gglse(
+                        &m,
+                        &n,
+                        &p,
+                        AsPtr::as_mut_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(c),
+                        &p.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        AsPtr::as_mut_ptr(d),
+                        AsPtr::as_mut_ptr(&mut x),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // solve
+                let lwork = work_size[0].to_usize().unwrap().max(1);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gglse(
+                        &m,
+                        &n,
+                        &p,
+                        AsPtr::as_mut_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(c),
+                        &p.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        AsPtr::as_mut_ptr(d),
+                        AsPtr::as_mut_ptr(&mut x),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(unsafe { x.assume_init() })
+            }
+        }
+    };
+}
+impl_least_squares_constrained!(c64, lapack_sys::zgglse_);
+impl_least_squares_constrained!(c32, lapack_sys::cgglse_);
+impl_least_squares_constrained!(f64, lapack_sys::dgglse_);
+impl_least_squares_constrained!(f32, lapack_sys::sgglse_);
+
+/// Solution of the general Gauss-Markov linear model by [GaussMarkovImpl]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GaussMarkovOwned<T> {
+    /// `n` coefficients `x`
+    pub x: Vec<T>,
+    /// `p` noise `y` of the minimum norm
+    pub y: Vec<T>,
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Solve general Gauss-Markov linear model (GLM) problem
+///
+/// $$
+/// \min_{x, y} \| y \|_2 \quad \text{subject to} \quad b = Ax + Wy
+/// $$
+///
+/// for a $m \times n$ matrix $A$ and a $m \times p$ matrix $W$ with $n \le m \le n + p$.
+/// Both `a` and `w` must be in column-major order without padding,
+/// and have the same number of rows. Otherwise [Error::InvalidShape] is returned.
+/// All the inputs are destroyed.
+///
+/// The solution is unique if $A$ has full column rank $n$ and $(A, W)$ has full row rank $m$.
+/// Otherwise [Error::LapackComputationalFailure] is returned.
+pub trait GaussMarkovImpl: Scalar {
+    fn gauss_markov(
+        la: MatrixLayout,
+        lw: MatrixLayout,
+        a: &mut [Self],
+        w: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<GaussMarkovOwned<Self>>;
+}
+
+macro_rules! impl_gauss_markov {
+    ($s:ty, $ggglm:path) => {
+        impl GaussMarkovImpl for $s {
+            fn gauss_markov(
+                la: MatrixLayout,
+                lw: MatrixLayout,
+                a: &mut [Self],
+                w: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<GaussMarkovOwned<Self>> {
+                let (m, n, p) = match (la, lw) {
+                    (MatrixLayout::F { col: n, lda: m }, MatrixLayout::F { col: p, lda })
+                        if m == lda && n <= m && m <= n + p =>
+                    {
+                        (m, n, p)
+                    }
+                    _ => return Err(Error::InvalidShape),
+                };
+                if b.len() != m as usize {
+                    return Err(Error::InvalidShape);
+                }
+                let mut x: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut y: Vec<MaybeUninit<Self>> = vec_uninit(p as usize);
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $ggglm(
+                        &m,
+                        &n,
+                        &p,
+                        AsPtr::as_mut_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(w),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        AsPtr::as_mut_ptr(&mut x),
+                        AsPtr::as_mut_ptr(&mut y),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // solve
+                let lwork = work_size[0].to_usize().unwrap().max(1);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $ggglm(
+                        &m,
+                        &n,
+                        &p,
+                        AsPtr::as_mut_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(w),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        AsPtr::as_mut_ptr(&mut x),
+                        AsPtr::as_mut_ptr(&mut y),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(GaussMarkovOwned {
+                    x: unsafe { x.assume_init() },
+                    y: unsafe { y.assume_init() },
+                })
+            }
+        }
+    };
+}
+impl_gauss_markov!(c64, lapack_sys::zggglm_);
+impl_gauss_markov!(c32, lapack_sys::cggglm_);
+impl_gauss_markov!(f64, lapack_sys::dggglm_);
+impl_gauss_markov!(f32, lapack_sys::sggglm_);
@@ -0,0 +1,326 @@
+//! Generalized singular value decomposition (GSVD) of a matrix pair
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32     | f64     | c32     | c64     |
+//! |:--------|:--------|:--------|:--------|
+//! | sggsvd3 | dggsvd3 | cggsvd3 | zggsvd3 |
+//!
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Result of GSVD
+///
+/// For a $m \times n$ matrix $A$ and a $p \times n$ matrix $B$,
+///
+/// $$
+/// U^H A Q = D_1 \begin{pmatrix} 0 & R \end{pmatrix}, \quad
+/// V^H B Q = D_2 \begin{pmatrix} 0 & R \end{pmatrix}
+/// $$
+///
+/// where $R$ is a $(k + l) \times (k + l)$ nonsingular upper triangular matrix,
+/// and $k + l$ is the effective numerical rank of $(A^T, B^T)^T$.
+/// $D_1$ and $D_2$ are $m \times (k + l)$ and $p \times (k + l)$ "diagonal" matrices
+/// whose non-zero entries are `alpha[k..k+l]` and `beta[k..k+l]`, respectively.
+/// See the document of `*ggsvd3` for the detail of their structure.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GsvdOwned<T: Scalar> {
+    pub k: usize,
+    pub l: usize,
+    /// `n` generalized singular value pairs `(alpha[i], beta[i])` with `alpha[i]^2 + beta[i]^2 = 1`.
+    /// `alpha[..k]` are one, and `alpha[k+l..]` and `beta[k+l..]` are zero.
+    pub alpha: Vec<T::Real>,
+    pub beta: Vec<T::Real>,
+    /// `(k + l) x (k + l)` upper triangular matrix in column-major order
+    pub r: Vec<T>,
+    /// `m x m` unitary matrix in column-major order
+    pub u: Option<Vec<T>>,
+    /// `p x p` unitary matrix in column-major order
+    pub v: Option<Vec<T>>,
+    /// `n x n` unitary matrix in column-major order
+    pub q: Option<Vec<T>>,
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Compute GSVD of $m \times n$ matrix $A$ and $p \times n$ matrix $B$
+///
+/// Both `a` and `b` must be in column-major order without padding,
+/// and have the same number of columns. Otherwise [Error::InvalidShape] is returned.
+/// They are destroyed.
+pub trait GsvdImpl: Scalar {
+    fn gsvd(
+        la: MatrixLayout,
+        lb: MatrixLayout,
+        calc_u: bool,
+        calc_v: bool,
+        calc_q: bool,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<GsvdOwned<Self>>;
+}
+
+/// `(m, n, p)` for the layouts of `A` and `B`
+fn gsvd_size(la: MatrixLayout, lb: MatrixLayout) -> Result<(i32, i32, i32)> {
+    match (la, lb) {
+        (MatrixLayout::F { col: n, lda: m }, MatrixLayout::F { col, lda: p }) if n == col => {
+            Ok((m, n, p))
+        }
+        _ => Err(Error::InvalidShape),
+    }
+}
+
+/// Take the upper triangular matrix `R` from `A` and `B` overwritten by `*ggsvd3`
+fn take_r<T: Scalar>(m: i32, n: i32, p: i32, k: i32, l: i32, a: &[T], b: &[T]) -> Vec<T> {
+    let (m, n, p, k, kl) = (
+        m as usize,
+        n as usize,
+        p as usize,
+        k as usize,
+        (k + l) as usize,
+    );
+    let mut r = vec![T::zero(); kl * kl];
+    for j in 0..kl {
+        // `R` is stored in the last `k + l` columns
+        let c = n - kl + j;
+        for i in 0..=j {
+            r[i + j * kl] = if i < m {
+                a[i + c * m]
+            } else {
+                // The rows of `R` below `m` are stored in `B(m-k..l, ..)` if `m < k + l`
+                b[i - k + c * p]
+            };
+        }
+    }
+    r
+}
+
+macro_rules! impl_gsvd_c {
+    ($s:ty, $ggsvd3:path) => {
+        impl GsvdImpl for $s {
+            fn gsvd(
+                la: MatrixLayout,
+                lb: MatrixLayout,
+                calc_u: bool,
+                calc_v: bool,
+                calc_q: bool,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<GsvdOwned<Self>> {
+                let (m, n, p) = gsvd_size(la, lb)?;
+                let jobu = if calc_u { b'U' } else { b'N' } as std::ffi::c_char;
+                let jobv = if calc_v { b'V' } else { b'N' } as std::ffi::c_char;
+                let jobq = if calc_q { b'Q' } else { b'N' } as std::ffi::c_char;
+                let (m_, n_, p_) = (m as usize, n as usize, p as usize);
+                let mut k = 0;
+                let mut l = 0;
+                let mut alpha: Vec<MaybeUninit<Self::Real>> = vec_uninit(n_);
+                let mut beta: Vec<MaybeUninit<Self::Real>> = vec_uninit(n_);
+                let mut u: Option<Vec<MaybeUninit<Self>>> = calc_u.then(|| vec_uninit(m_ * m_));
+                let mut v: Option<Vec<MaybeUninit<Self>>> = calc_v.then(|| vec_uninit(p_ * p_));
+                let mut q: Option<Vec<MaybeUninit<Self>>> = calc_q.then(|| vec_uninit(n_ * n_));
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(2 * n_);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(n_);
+                let ldu = if calc_u { m.max(1) } else { 1 };
+                let ldv = if calc_v { p.max(1) } else { 1 };
+                let ldq = if calc_q { n.max(1) } else { 1 };
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $ggsvd3(
+                        &jobu,
+                        &jobv,
+                        &jobq,
+                        &m,
+                        &n,
+                        &p,
+                        &mut k,
+                        &mut l,
+                        AsPtr::as_mut_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        &p.max(1),
+                        AsPtr::as_mut_ptr(&mut alpha),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(u.as_mut().map(|u| u.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldu,
+                        AsPtr::as_mut_ptr(v.as_mut().map(|v| v.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldv,
+                        AsPtr::as_mut_ptr(q.as_mut().map(|q| q.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldq,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // do GSVD
+                let lwork = work_size[0].to_usize().unwrap().max(1);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $ggsvd3(
+                        &jobu,
+                        &jobv,
+                        &jobq,
+                        &m,
+                        &n,
+                        &p,
+                        &mut k,
+                        &mut l,
+                        AsPtr::as_mut_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        &p.max(1),
+                        AsPtr::as_mut_ptr(&mut alpha),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(u.as_mut().map(|u| u.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldu,
+                        AsPtr::as_mut_ptr(v.as_mut().map(|v| v.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldv,
+                        AsPtr::as_mut_ptr(q.as_mut().map(|q| q.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldq,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                Ok(GsvdOwned {
+                    k: k as usize,
+                    l: l as usize,
+                    alpha: unsafe { alpha.assume_init() },
+                    beta: unsafe { beta.assume_init() },
+                    r: take_r(m, n, p, k, l, a, b),
+                    u: u.map(|u| unsafe { u.assume_init() }),
+                    v: v.map(|v| unsafe { v.assume_init() }),
+                    q: q.map(|q| unsafe { q.assume_init() }),
+                })
+            }
+        }
+    };
+}
+impl_gsvd_c!(c64, lapack_sys::zggsvd3_);
+impl_gsvd_c!(c32, lapack_sys::cggsvd3_);
+
+macro_rules! impl_gsvd_r {
+    ($s:ty, $ggsvd3:path) => {
+        impl GsvdImpl for $s {
+            fn gsvd(
+                la: MatrixLayout,
+                lb: MatrixLayout,
+                calc_u: bool,
+                calc_v: bool,
+                calc_q: bool,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<GsvdOwned<Self>> {
+                let (m, n, p) = gsvd_size(la, lb)?;
+                let jobu = if calc_u { b'U' } else { b'N' } as std::ffi::c_char;
+                let jobv = if calc_v { b'V' } else { b'N' } as std::ffi::c_char;
+                let jobq = if calc_q { b'Q' } else { b'N' } as std::ffi::c_char;
+                let (m_, n_, p_) = (m as usize, n as usize, p as usize);
+                let mut k = 0;
+                let mut l = 0;
+                let mut alpha: Vec<MaybeUninit<Self>> = vec_uninit(n_);
+                let mut beta: Vec<MaybeUninit<Self>> = vec_uninit(n_);
+                let mut u: Option<Vec<MaybeUninit<Self>>> = calc_u.then(|| vec_uninit(m_ * m_));
+                let mut v: Option<Vec<MaybeUninit<Self>>> = calc_v.then(|| vec_uninit(p_ * p_));
+                let mut q: Option<Vec<MaybeUninit<Self>>> = calc_q.then(|| vec_uninit(n_ * n_));
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(n_);
+                let ldu = if calc_u { m.max(1) } else { 1 };
+                let ldv = if calc_v { p.max(1) } else { 1 };
+                let ldq = if calc_q { n.max(1) } else { 1 };
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $ggsvd3(
+                        &jobu,
+                        &jobv,
+                        &jobq,
+                        &m,
+                        &n,
+                        &p,
+                        &mut k,
+                        &mut l,
+                        AsPtr::as_mut_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        &p.max(1),
+                        AsPtr::as_mut_ptr(&mut alpha),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(u.as_mut().map(|u| u.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldu,
+                        AsPtr::as_mut_ptr(v.as_mut().map(|v| v.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldv,
+                        AsPtr::as_mut_ptr(q.as_mut().map(|q| q.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldq,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // do GSVD
+                let lwork = work_size[0].to_usize().unwrap().max(1);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $ggsvd3(
+                        &jobu,
+                        &jobv,
+                        &jobq,
+                        &m,
+                        &n,
+                        &p,
+                        &mut k,
+                        &mut l,
+                        AsPtr::as_mut_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        &p.max(1),
+                        AsPtr::as_mut_ptr(&mut alpha),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(u.as_mut().map(|u| u.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldu,
+                        AsPtr::as_mut_ptr(v.as_mut().map(|v| v.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldv,
+                        AsPtr::as_mut_ptr(q.as_mut().map(|q| q.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldq,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                Ok(GsvdOwned {
+                    k: k as usize,
+                    l: l as usize,
+                    alpha: unsafe { alpha.assume_init() },
+                    beta: unsafe { beta.assume_init() },
+                    r: take_r(m, n, p, k, l, a, b),
+                    u: u.map(|u| unsafe { u.assume_init() }),
+                    v: v.map(|v| unsafe { v.assume_init() }),
+                    q: q.map(|q| unsafe { q.assume_init() }),
+                })
+            }
+        }
+    };
+}
+impl_gsvd_r!(f64, lapack_sys::dggsvd3_);
+impl_gsvd_r!(f32, lapack_sys::sggsvd3_);
@@ -0,0 +1,136 @@
+//! Hessenberg decomposition of general matrices
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgehrd | dgehrd | cgehrd | zgehrd |
+//! | sorghr | dorghr | cunghr | zunghr |
+//!
+
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Compute the Hessenberg decomposition $A = QHQ^H$ of a general matrix
+///
+/// `a` is overwritten by the upper Hessenberg matrix $H$,
+/// whose elements below the first subdiagonal are set to zero.
+/// If `calc_q` is true, the unitary matrix $Q$ is returned in the same layout as `a`.
+pub trait HessenbergImpl: Scalar {
+    fn hessenberg(l: MatrixLayout, calc_q: bool, a: &mut [Self]) -> Result<Option<Vec<Self>>>;
+}
+
+macro_rules! impl_hessenberg {
+    ($s:ty, $gehrd:path, $orghr:path) => {
+        impl HessenbergImpl for $s {
+            fn hessenberg(
+                l: MatrixLayout,
+                calc_q: bool,
+                a: &mut [Self],
+            ) -> Result<Option<Vec<Self>>> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                let mut tau: Vec<MaybeUninit<Self>> = vec_uninit((n as usize).saturating_sub(1));
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gehrd(
+                        &n,
+                        &1,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // reduce into Hessenberg form
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gehrd(
+                        &n,
+                        &1,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let tau = unsafe { tau.assume_init() };
+
+                // generate Q from the reflectors stored below the subdiagonal
+                let mut q = if calc_q {
+                    let mut q = a.to_vec();
+                    let mut work_size = [Self::zero()];
+                    unsafe {
+                        $orghr(
+                            &n,
+                            &1,
+                            &n,
+                            AsPtr::as_mut_ptr(&mut q),
+                            &n,
+                            AsPtr::as_ptr(&tau),
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()?;
+                    let lwork = work_size[0].to_usize().unwrap();
+                    let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                    unsafe {
+                        $orghr(
+                            &n,
+                            &1,
+                            &n,
+                            AsPtr::as_mut_ptr(&mut q),
+                            &n,
+                            AsPtr::as_ptr(&tau),
+                            AsPtr::as_mut_ptr(&mut work),
+                            &(lwork as i32),
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()?;
+                    Some(q)
+                } else {
+                    None
+                };
+
+                let n = n as usize;
+                for j in 0..n {
+                    for i in (j + 2)..n {
+                        a[i + j * n] = Self::zero();
+                    }
+                }
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                    if let Some(q) = q.as_mut() {
+                        square_transpose(l, q);
+                    }
+                }
+                Ok(q)
+            }
+        }
+    };
+}
+impl_hessenberg!(c64, lapack_sys::zgehrd_, lapack_sys::zunghr_);
+impl_hessenberg!(c32, lapack_sys::cgehrd_, lapack_sys::cunghr_);
+impl_hessenberg!(f64, lapack_sys::dgehrd_, lapack_sys::dorghr_);
+impl_hessenberg!(f32, lapack_sys::sgehrd_, lapack_sys::sorghr_);
@@ -278,3 +278,331 @@ pub fn transpose_over<T: Copy>(layout: MatrixLayout, from: &[T], to: &mut [T]) -
     }
     transposed
 }
+
+/// Identity matrix of size `n x n`
+///
+/// Examples
+/// ---------
+///
+/// ```rust
+/// # use lax::layout::*;
+/// let (l, e) = eye::<f64>(2, false);
+/// assert_eq!(l, MatrixLayout::C { row: 2, lda: 2 });
+/// assert_eq!(e, &[1., 0., 0., 1.]);
+/// let (l, e) = eye::<f64>(2, true);
+/// assert_eq!(l, MatrixLayout::F { col: 2, lda: 2 });
+/// assert_eq!(e, &[1., 0., 0., 1.]);
+/// ```
+///
+/// Multiplying the identity does not change a matrix:
+///
+/// ```rust
+/// # use lax::layout::*;
+/// let a = vec![1., 2., 3., 4., 5., 6.]; // 2x3 matrix in C layout
+/// let (_, e) = eye::<f64>(3, false);
+/// let mut ae = vec![0.0; 6];
+/// for i in 0..2 {
+///     for j in 0..3 {
+///         for k in 0..3 {
+///             ae[i * 3 + j] += a[i * 3 + k] * e[k * 3 + j];
+///         }
+///     }
+/// }
+/// assert_eq!(ae, a);
+/// ```
+///
+pub fn eye<T: Scalar>(n: usize, layout_is_fortran: bool) -> (MatrixLayout, Vec<T>) {
+    let layout = if layout_is_fortran {
+        MatrixLayout::F {
+            col: n as i32,
+            lda: n as i32,
+        }
+    } else {
+        MatrixLayout::C {
+            row: n as i32,
+            lda: n as i32,
+        }
+    };
+    let mut a = vec![T::zero(); n * n];
+    for i in 0..n {
+        a[i * n + i] = T::one();
+    }
+    (layout, a)
+}
+
+/// Square diagonal matrix whose diagonal elements are `d`
+///
+/// Since a diagonal matrix is symmetric, the returned memory is valid for both C and F layout.
+/// This returns C layout.
+///
+/// ```rust
+/// # use lax::layout::*;
+/// let (l, a) = from_diagonal(&[1., 2.]);
+/// assert_eq!(l, MatrixLayout::C { row: 2, lda: 2 });
+/// assert_eq!(a, &[1., 0., 0., 2.]);
+/// ```
+pub fn from_diagonal<T: Scalar>(d: &[T]) -> (MatrixLayout, Vec<T>) {
+    let n = d.len();
+    let (layout, mut a) = eye(n, false);
+    for (i, d) in d.iter().enumerate() {
+        a[i * n + i] = *d;
+    }
+    (layout, a)
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Scale the rows of a matrix, i.e. compute $DA$ in-place for a diagonal matrix $D = \mathrm{diag}(d)$
+///
+/// This is equivalent to the multiplication with the matrix from [from_diagonal],
+/// without forming the dense diagonal matrix:
+///
+/// ```rust
+/// # use lax::layout::*;
+/// let layout = MatrixLayout::F { col: 3, lda: 2 };
+/// let mut a = vec![1., 2., 3., 4., 5., 6.];
+/// let d = [2., -1.];
+/// // gemm with explicit diagonal matrix
+/// let (_, dm) = from_diagonal(&d);
+/// let mut da = vec![0.0; 6];
+/// for i in 0..2 {
+///     for j in 0..3 {
+///         for k in 0..2 {
+///             da[j * 2 + i] += dm[i * 2 + k] * a[j * 2 + k];
+///         }
+///     }
+/// }
+/// diag_mul_left(&d, &mut a, layout);
+/// assert_eq!(a, da);
+/// ```
+///
+/// Panics
+/// ------
+/// - If the length of `d` is not equal to the number of rows
+/// - If size of `a` and `layout` size mismatch
+///
+pub fn diag_mul_left<T: Scalar>(d: &[T::Real], a: &mut [T], layout: MatrixLayout) {
+    let (m, n) = layout.size();
+    let (m, n) = (m as usize, n as usize);
+    assert_eq!(d.len(), m);
+    assert_eq!(a.len(), m * n);
+    match layout {
+        MatrixLayout::C { .. } => {
+            for i in 0..m {
+                for j in 0..n {
+                    a[i * n + j] = a[i * n + j].mul_real(d[i]);
+                }
+            }
+        }
+        MatrixLayout::F { .. } => {
+            for j in 0..n {
+                for i in 0..m {
+                    a[j * m + i] = a[j * m + i].mul_real(d[i]);
+                }
+            }
+        }
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Scale the columns of a matrix, i.e. compute $AD$ in-place for a diagonal matrix $D = \mathrm{diag}(d)$
+///
+/// ```rust
+/// # use lax::layout::*;
+/// let layout = MatrixLayout::C { row: 2, lda: 3 };
+/// let mut a = vec![1., 2., 3., 4., 5., 6.];
+/// let d = [2., -1., 0.5];
+/// // gemm with explicit diagonal matrix
+/// let (_, dm) = from_diagonal(&d);
+/// let mut ad = vec![0.0; 6];
+/// for i in 0..2 {
+///     for j in 0..3 {
+///         for k in 0..3 {
+///             ad[i * 3 + j] += a[i * 3 + k] * dm[k * 3 + j];
+///         }
+///     }
+/// }
+/// diag_mul_right(&d, &mut a, layout);
+/// assert_eq!(a, ad);
+/// ```
+///
+/// Panics
+/// ------
+/// - If the length of `d` is not equal to the number of columns
+/// - If size of `a` and `layout` size mismatch
+///
+pub fn diag_mul_right<T: Scalar>(d: &[T::Real], a: &mut [T], layout: MatrixLayout) {
+    // Scaling columns of A is scaling rows of A^T, whose memory representation is same.
+    diag_mul_left(d, a, layout.t());
+}
+
+/// Conjugate all elements in-place
+///
+/// This is a no-op for real numbers.
+///
+/// ```rust
+/// # use lax::layout::*;
+/// # use cauchy::*;
+/// let mut a = vec![c64::new(1.0, 2.0), c64::new(3.0, -4.0)];
+/// conj_in_place(&mut a);
+/// assert_eq!(a, &[c64::new(1.0, -2.0), c64::new(3.0, 4.0)]);
+/// conj_in_place(&mut a);
+/// assert_eq!(a, &[c64::new(1.0, 2.0), c64::new(3.0, -4.0)]);
+///
+/// let mut b = vec![1.0, -2.0];
+/// conj_in_place(&mut b);
+/// assert_eq!(b, &[1.0, -2.0]);
+/// ```
+pub fn conj_in_place<T: Scalar>(a: &mut [T]) {
+    for val in a.iter_mut() {
+        *val = val.conj();
+    }
+}
+
+/// Fill the other triangle of a square matrix from the stored one specified by `uplo`
+///
+/// The result is symmetric $A = A^T$, or Hermitian $A = A^H$ if `hermitian` is true.
+/// In the Hermitian case, the imaginary parts of the diagonal elements are dropped.
+///
+/// ```rust
+/// # use lax::{layout::*, UPLO};
+/// # use cauchy::*;
+/// let layout = MatrixLayout::C { row: 2, lda: 2 };
+/// let mut a = vec![1.0, 2.0, 0.0, 3.0];
+/// symmetrize_from_triangle(&mut a, layout, UPLO::Upper, false);
+/// assert_eq!(a, &[1.0, 2.0, 2.0, 3.0]);
+///
+/// let layout = MatrixLayout::F { col: 2, lda: 2 };
+/// let mut a = vec![c64::new(1.0, 0.0), c64::new(2.0, 1.0), c64::new(0.0, 0.0), c64::new(3.0, 0.0)];
+/// symmetrize_from_triangle(&mut a, layout, UPLO::Lower, true);
+/// assert_eq!(a[2], c64::new(2.0, -1.0));
+/// let (_, t) = transpose(layout, &a);
+/// let mut h = t.clone();
+/// conj_in_place(&mut h);
+/// assert_eq!(h, a);
+/// ```
+///
+/// Panics
+/// ------
+/// - If the matrix is not square
+/// - If size of `a` and `layout` size mismatch
+///
+pub fn symmetrize_from_triangle<T: Scalar>(
+    a: &mut [T],
+    layout: MatrixLayout,
+    uplo: UPLO,
+    hermitian: bool,
+) {
+    let (m, n) = layout.size();
+    assert_eq!(m, n, "matrix must be square");
+    let n = n as usize;
+    let lda = layout.lda() as usize;
+    assert_eq!(a.len(), n * lda);
+    let index = |i: usize, j: usize| match layout {
+        MatrixLayout::C { .. } => i * lda + j,
+        MatrixLayout::F { .. } => j * lda + i,
+    };
+    for i in 0..n {
+        if hermitian {
+            a[index(i, i)] = T::from_real(a[index(i, i)].re());
+        }
+        for j in (i + 1)..n {
+            // (src, dst) = stored and filled positions
+            let (src, dst) = match uplo {
+                UPLO::Upper => (index(i, j), index(j, i)),
+                UPLO::Lower => (index(j, i), index(i, j)),
+            };
+            a[dst] = if hermitian { a[src].conj() } else { a[src] };
+        }
+    }
+}
+
+/// Index of $a_{ij}$ in the packed storage of the `n x n` triangular part specified by `uplo`
+///
+/// The triangle is packed column by column as in LAPACK:
+///
+/// - [UPLO::Upper]: `ap[i + j * (j + 1) / 2] = a[i, j]` for `i <= j`
+/// - [UPLO::Lower]: `ap[i + j * (2 * n - j - 1) / 2] = a[i, j]` for `j <= i`
+#[cfg_attr(doc, katexit::katexit)]
+fn packed_index(n: usize, uplo: UPLO, i: usize, j: usize) -> usize {
+    match uplo {
+        UPLO::Upper => i + j * (j + 1) / 2,
+        UPLO::Lower => i + j * (2 * n - j - 1) / 2,
+    }
+}
+
+/// Pack the triangular part of a column-major `n x n` matrix into the packed storage of LAPACK
+///
+/// The triangle specified by `uplo` is stored column by column into `n * (n + 1) / 2` elements,
+/// which is the input format of the packed routines, e.g. `*pptrf` and `*spev`.
+///
+/// ```rust
+/// # use lax::{layout::*, UPLO};
+/// // 5x5 matrix in column-major, a[i, j] = 10 * i + j
+/// let full: Vec<f64> = (0..25).map(|k| (10 * (k % 5) + k / 5) as f64).collect();
+///
+/// let upper = pack_triangle(&full, 5, UPLO::Upper);
+/// assert_eq!(&upper[..6], &[0.0, 1.0, 11.0, 2.0, 12.0, 22.0]);
+/// let lower = pack_triangle(&full, 5, UPLO::Lower);
+/// assert_eq!(&lower[..6], &[0.0, 10.0, 20.0, 30.0, 40.0, 11.0]);
+///
+/// // Round trip keeps the triangle, and the other elements are zero
+/// for (packed, uplo) in [(upper, UPLO::Upper), (lower, UPLO::Lower)] {
+///     assert_eq!(packed.len(), 15);
+///     let unpacked = unpack_triangle(&packed, 5, uplo);
+///     for j in 0..5 {
+///         for i in 0..5 {
+///             let stored = match uplo {
+///                 UPLO::Upper => i <= j,
+///                 UPLO::Lower => j <= i,
+///             };
+///             let expected = if stored { full[i + 5 * j] } else { 0.0 };
+///             assert_eq!(unpacked[i + 5 * j], expected);
+///         }
+///     }
+///     assert_eq!(pack_triangle(&unpacked, 5, uplo), packed);
+/// }
+/// ```
+///
+/// Panics
+/// ------
+/// - If the length of `full` is not `n * n`
+///
+pub fn pack_triangle<T: Scalar>(full: &[T], n: usize, uplo: UPLO) -> Vec<T> {
+    assert_eq!(full.len(), n * n);
+    let mut packed = vec![T::zero(); n * (n + 1) / 2];
+    for j in 0..n {
+        let rows = match uplo {
+            UPLO::Upper => 0..j + 1,
+            UPLO::Lower => j..n,
+        };
+        for i in rows {
+            packed[packed_index(n, uplo, i, j)] = full[i + j * n];
+        }
+    }
+    packed
+}
+
+/// Unpack the packed storage of LAPACK into a column-major `n x n` matrix
+///
+/// This is the inverse of [pack_triangle].
+/// The elements of the other triangle are filled by zero,
+/// use [symmetrize_from_triangle] to make it symmetric/Hermitian.
+///
+/// Panics
+/// ------
+/// - If the length of `packed` is not `n * (n + 1) / 2`
+///
+pub fn unpack_triangle<T: Scalar>(packed: &[T], n: usize, uplo: UPLO) -> Vec<T> {
+    assert_eq!(packed.len(), n * (n + 1) / 2);
+    let mut full = vec![T::zero(); n * n];
+    for j in 0..n {
+        let rows = match uplo {
+            UPLO::Upper => 0..j + 1,
+            UPLO::Lower => j..n,
+        };
+        for i in rows {
+            full[i + j * n] = packed[packed_index(n, uplo, i, j)];
+        }
+    }
+    full
+}
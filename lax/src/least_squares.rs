@@ -2,7 +2,7 @@
 
 use crate::{error::*, layout::*, *};
 use cauchy::*;
-use num_traits::{ToPrimitive, Zero};
+use num_traits::{Float, ToPrimitive, Zero};
 
 /// Result of LeastSquares
 pub struct LeastSquaresOwned<A: Scalar> {
@@ -336,3 +336,376 @@ macro_rules! impl_least_squares_work_r {
 }
 impl_least_squares_work_r!(f64, lapack_sys::dgelsd_);
 impl_least_squares_work_r!(f32, lapack_sys::sgelsd_);
+
+/// LAPACK driver used to solve least squares problem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LeastSquaresDriver {
+    /// SVD with divide-and-conquer algorithm (`*gelsd`), see [Lapack::least_squares_nrhs]
+    Gelsd,
+    /// Complete orthogonal factorization (`*gelsy`), see [Lapack::least_squares_cod]
+    ///
+    /// This is usually several times faster than the SVD drivers for well-conditioned problems,
+    /// but the singular values are not computed.
+    Gelsy,
+    /// SVD with QR iteration (`*gelss`), see [Lapack::least_squares_svd]
+    Gelss,
+}
+
+/// Solve least squares problems $\argmin_X \| AX - B\|$ using the specified driver
+///
+/// The singular values are empty for [LeastSquaresDriver::Gelsy].
+pub fn least_squares_with_driver<A: Lapack>(
+    driver: LeastSquaresDriver,
+    a_layout: MatrixLayout,
+    a: &mut [A],
+    b_layout: MatrixLayout,
+    b: &mut [A],
+) -> Result<LeastSquaresOwned<A>> {
+    match driver {
+        LeastSquaresDriver::Gelsd => A::least_squares_nrhs(a_layout, a, b_layout, b),
+        LeastSquaresDriver::Gelsy => A::least_squares_cod(a_layout, a, b_layout, b),
+        LeastSquaresDriver::Gelss => A::least_squares_svd(a_layout, a, b_layout, b),
+    }
+}
+
+/// Call `f` with `a` and `b` in column-major order, and write back `b` in its original layout
+///
+/// `a` is not written back since it is destroyed by the drivers.
+fn with_col_major<T: Scalar, R>(
+    a_layout: MatrixLayout,
+    a: &mut [T],
+    b_layout: MatrixLayout,
+    b: &mut [T],
+    f: impl FnOnce(&mut [T], &mut [T]) -> Result<R>,
+) -> Result<R> {
+    let mut a_t = match a_layout {
+        MatrixLayout::C { .. } => Some(transpose(a_layout, a).1),
+        MatrixLayout::F { .. } => None,
+    };
+    let (b_layout_t, mut b_t) = match b_layout {
+        MatrixLayout::C { .. } => {
+            let (layout, t) = transpose(b_layout, b);
+            (layout, Some(t))
+        }
+        MatrixLayout::F { .. } => (b_layout, None),
+    };
+    let res = f(
+        a_t.as_deref_mut().unwrap_or(a),
+        b_t.as_deref_mut().unwrap_or(b),
+    )?;
+    if let Some(b_t) = b_t {
+        transpose_over(b_layout_t, &b_t, b);
+    }
+    Ok(res)
+}
+
+/// Solve least squares problems $\argmin_X \| AX - B\|$ by complete orthogonal factorization (`*gelsy`)
+///
+/// $A$ is factorized by QR decomposition with column pivoting, and the effective rank is
+/// the order of the largest leading triangular block of $R$ whose estimated condition number
+/// is less than $1 / \epsilon$. `b` must have `max(m, n)` rows as [Lapack::least_squares_nrhs].
+/// The singular values of the output are empty.
+pub trait LeastSquaresCodImpl: Scalar {
+    fn least_squares_cod(
+        a_layout: MatrixLayout,
+        a: &mut [Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<LeastSquaresOwned<Self>>;
+}
+
+/// Solve least squares problems $\argmin_X \| AX - B\|$ by SVD with QR iteration (`*gelss`)
+///
+/// `b` must have `max(m, n)` rows as [Lapack::least_squares_nrhs].
+pub trait LeastSquaresSvdImpl: Scalar {
+    fn least_squares_svd(
+        a_layout: MatrixLayout,
+        a: &mut [Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<LeastSquaresOwned<Self>>;
+}
+
+macro_rules! impl_least_squares_drivers_c {
+    ($c:ty, $gelsy:path, $gelss:path) => {
+        impl LeastSquaresCodImpl for $c {
+            fn least_squares_cod(
+                a_layout: MatrixLayout,
+                a: &mut [Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<LeastSquaresOwned<Self>> {
+                let (m, n) = a_layout.size();
+                let (m_, nrhs) = b_layout.size();
+                assert!(m_ >= m.max(n));
+                with_col_major(a_layout, a, b_layout, b, |a, b| {
+                    let rcond = <<Self as Scalar>::Real as Float>::epsilon();
+                    let mut rank: i32 = 0;
+                    // Zero means that the column is free to be pivoted
+                    let mut jpvt = vec![0; n as usize];
+                    let mut rwork: Vec<MaybeUninit<<Self as Scalar>::Real>> =
+                        vec_uninit(2 * n as usize);
+
+                    // eval work size
+                    let mut info = 0;
+                    let mut work_size = [Self::zero()];
+                    unsafe {
+                        $gelsy(
+                            &m,
+                            &n,
+                            &nrhs,
+                            AsPtr::as_mut_ptr(a),
+                            &m.max(1),
+                            AsPtr::as_mut_ptr(b),
+                            &m_.max(1),
+                            jpvt.as_mut_ptr(),
+                            &rcond,
+                            &mut rank,
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            AsPtr::as_mut_ptr(&mut rwork),
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()?;
+
+                    let lwork = work_size[0].to_usize().unwrap().max(1);
+                    let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                    unsafe {
+                        $gelsy(
+                            &m,
+                            &n,
+                            &nrhs,
+                            AsPtr::as_mut_ptr(a),
+                            &m.max(1),
+                            AsPtr::as_mut_ptr(b),
+                            &m_.max(1),
+                            jpvt.as_mut_ptr(),
+                            &rcond,
+                            &mut rank,
+                            AsPtr::as_mut_ptr(&mut work),
+                            &(lwork as i32),
+                            AsPtr::as_mut_ptr(&mut rwork),
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()?;
+                    Ok(LeastSquaresOwned {
+                        singular_values: Vec::new(),
+                        rank,
+                    })
+                })
+            }
+        }
+
+        impl LeastSquaresSvdImpl for $c {
+            fn least_squares_svd(
+                a_layout: MatrixLayout,
+                a: &mut [Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<LeastSquaresOwned<Self>> {
+                let (m, n) = a_layout.size();
+                let (m_, nrhs) = b_layout.size();
+                assert!(m_ >= m.max(n));
+                with_col_major(a_layout, a, b_layout, b, |a, b| {
+                    let k = m.min(n) as usize;
+                    let rcond: <Self as Scalar>::Real = -1.;
+                    let mut rank: i32 = 0;
+                    let mut singular_values: Vec<MaybeUninit<<Self as Scalar>::Real>> =
+                        vec_uninit(k);
+                    let mut rwork: Vec<MaybeUninit<<Self as Scalar>::Real>> = vec_uninit(5 * k);
+
+                    // eval work size
+                    let mut info = 0;
+                    let mut work_size = [Self::zero()];
+                    unsafe {
+                        $gelss(
+                            &m,
+                            &n,
+                            &nrhs,
+                            AsPtr::as_mut_ptr(a),
+                            &m.max(1),
+                            AsPtr::as_mut_ptr(b),
+                            &m_.max(1),
+                            AsPtr::as_mut_ptr(&mut singular_values),
+                            &rcond,
+                            &mut rank,
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            AsPtr::as_mut_ptr(&mut rwork),
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()?;
+
+                    let lwork = work_size[0].to_usize().unwrap().max(1);
+                    let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                    unsafe {
+                        $gelss(
+                            &m,
+                            &n,
+                            &nrhs,
+                            AsPtr::as_mut_ptr(a),
+                            &m.max(1),
+                            AsPtr::as_mut_ptr(b),
+                            &m_.max(1),
+                            AsPtr::as_mut_ptr(&mut singular_values),
+                            &rcond,
+                            &mut rank,
+                            AsPtr::as_mut_ptr(&mut work),
+                            &(lwork as i32),
+                            AsPtr::as_mut_ptr(&mut rwork),
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()?;
+                    Ok(LeastSquaresOwned {
+                        singular_values: unsafe { singular_values.assume_init() },
+                        rank,
+                    })
+                })
+            }
+        }
+    };
+}
+impl_least_squares_drivers_c!(c64, lapack_sys::zgelsy_, lapack_sys::zgelss_);
+impl_least_squares_drivers_c!(c32, lapack_sys::cgelsy_, lapack_sys::cgelss_);
+
+macro_rules! impl_least_squares_drivers_r {
+    ($r:ty, $gelsy:path, $gelss:path) => {
+        impl LeastSquaresCodImpl for $r {
+            fn least_squares_cod(
+                a_layout: MatrixLayout,
+                a: &mut [Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<LeastSquaresOwned<Self>> {
+                let (m, n) = a_layout.size();
+                let (m_, nrhs) = b_layout.size();
+                assert!(m_ >= m.max(n));
+                with_col_major(a_layout, a, b_layout, b, |a, b| {
+                    let rcond = <Self as Float>::epsilon();
+                    let mut rank: i32 = 0;
+                    // Zero means that the column is free to be pivoted
+                    let mut jpvt = vec![0; n as usize];
+
+                    // eval work size
+                    let mut info = 0;
+                    let mut work_size = [Self::zero()];
+                    unsafe {
+                        $gelsy(
+                            &m,
+                            &n,
+                            &nrhs,
+                            AsPtr::as_mut_ptr(a),
+                            &m.max(1),
+                            AsPtr::as_mut_ptr(b),
+                            &m_.max(1),
+                            jpvt.as_mut_ptr(),
+                            &rcond,
+                            &mut rank,
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()?;
+
+                    let lwork = work_size[0].to_usize().unwrap().max(1);
+                    let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                    unsafe {
+                        $gelsy(
+                            &m,
+                            &n,
+                            &nrhs,
+                            AsPtr::as_mut_ptr(a),
+                            &m.max(1),
+                            AsPtr::as_mut_ptr(b),
+                            &m_.max(1),
+                            jpvt.as_mut_ptr(),
+                            &rcond,
+                            &mut rank,
+                            AsPtr::as_mut_ptr(&mut work),
+                            &(lwork as i32),
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()?;
+                    Ok(LeastSquaresOwned {
+                        singular_values: Vec::new(),
+                        rank,
+                    })
+                })
+            }
+        }
+
+        impl LeastSquaresSvdImpl for $r {
+            fn least_squares_svd(
+                a_layout: MatrixLayout,
+                a: &mut [Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<LeastSquaresOwned<Self>> {
+                let (m, n) = a_layout.size();
+                let (m_, nrhs) = b_layout.size();
+                assert!(m_ >= m.max(n));
+                with_col_major(a_layout, a, b_layout, b, |a, b| {
+                    let k = m.min(n) as usize;
+                    let rcond: Self = -1.;
+                    let mut rank: i32 = 0;
+                    let mut singular_values: Vec<MaybeUninit<Self>> = vec_uninit(k);
+
+                    // eval work size
+                    let mut info = 0;
+                    let mut work_size = [Self::zero()];
+                    unsafe {
+                        $gelss(
+                            &m,
+                            &n,
+                            &nrhs,
+                            AsPtr::as_mut_ptr(a),
+                            &m.max(1),
+                            AsPtr::as_mut_ptr(b),
+                            &m_.max(1),
+                            AsPtr::as_mut_ptr(&mut singular_values),
+                            &rcond,
+                            &mut rank,
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()?;
+
+                    let lwork = work_size[0].to_usize().unwrap().max(1);
+                    let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                    unsafe {
+                        $gelss(
+                            &m,
+                            &n,
+                            &nrhs,
+                            AsPtr::as_mut_ptr(a),
+                            &m.max(1),
+                            AsPtr::as_mut_ptr(b),
+                            &m_.max(1),
+                            AsPtr::as_mut_ptr(&mut singular_values),
+                            &rcond,
+                            &mut rank,
+                            AsPtr::as_mut_ptr(&mut work),
+                            &(lwork as i32),
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()?;
+                    Ok(LeastSquaresOwned {
+                        singular_values: unsafe { singular_values.assume_init() },
+                        rank,
+                    })
+                })
+            }
+        }
+    };
+}
+impl_least_squares_drivers_r!(f64, lapack_sys::dgelsy_, lapack_sys::dgelss_);
+impl_least_squares_drivers_r!(f32, lapack_sys::sgelsy_, lapack_sys::sgelss_);
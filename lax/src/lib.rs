@@ -52,8 +52,14 @@
 //! According to the property input metrix, several types of triangular decomposition are used:
 //!
 //! - [solve] module provides methods for LU-decomposition for general matrix.
+//! - [solve_banded] module provides methods for LU-decomposition for general band matrix.
+//! - [solve_expert] module provides the expert drivers for general and positive definite matrices, which also estimate the error of the solution.
+//! - [equilibrate] module provides the row and column scaling of badly scaled matrices.
+//! - [solve_mixed] module provides the mixed precision solver for double precision matrices.
+//! - [refine] module provides the iterative refinement of the solution using the LU or Cholesky decomposition.
 //! - [solveh] module provides methods for Bunch-Kaufman diagonal pivoting method for symmetric/Hermitian indefinite matrix.
 //! - [cholesky] module provides methods for Cholesky decomposition for symmetric/Hermitian positive dinite matrix.
+//! - [sylvester] module provides the solver of Sylvester equation for matrices in Schur form.
 //!
 //! Eigenvalue Problem
 //! -------------------
@@ -61,16 +67,29 @@
 //! According to the property input metrix,
 //! there are several types of eigenvalue problem API
 //!
-//! - [eig] module for eigenvalue problem for general matrix.
+//! - [eig] module for eigenvalue problem and generalized eigenvalue problem for general matrix.
 //! - [eigh] module for eigenvalue problem for symmetric/Hermitian matrix.
 //! - [eigh_generalized] module for generalized eigenvalue problem for symmetric/Hermitian matrix.
+//! - [packed] module for symmetric/Hermitian matrix in the packed storage.
+//! - [eigh_banded] module for eigenvalue problem for symmetric/Hermitian band matrix.
+//! - [eigh_generalized_banded] module for generalized eigenvalue problem for symmetric/Hermitian band matrix.
+//! - [schur] module for Schur form of general matrix and generalized Schur form of matrix pencil.
+//! - [hessenberg] module for Hessenberg form of general matrix.
+//! - [tridiagonal] module for eigenvalue problem for real symmetric tridiagonal matrix.
 //!
 //! Singular Value Decomposition
 //! -----------------------------
 //!
 //! - [svd] module for singular value decomposition (SVD) for general matrix
 //! - [svddc] module for singular value decomposition (SVD) with divided-and-conquer algorithm for general matrix
-//! - [least_squares] module for solving least square problem using SVD
+//! - [svd_jacobi] module for singular value decomposition (SVD) with one-sided Jacobi method for general matrix
+//! - [svd_select] module for singular value decomposition (SVD) computing only a subset of singular values
+//! - [gsvd] module for generalized singular value decomposition (GSVD) of a matrix pair
+//! - [least_squares] module for solving least square problem using SVD or complete orthogonal factorization
+//! - [generalized_least_squares] module for linear equality-constrained least squares problem
+//!   and general Gauss-Markov linear model
+//! - [normal_equations] module for solving least square problem by the normal equations
+//! - [bidiagonal] module for bidiagonalization and SVD of bidiagonal matrix
 //!
 
 #![deny(rustdoc::broken_intra_doc_links, rustdoc::private_intra_doc_links)]
@@ -85,28 +104,58 @@ extern crate openblas_src as _src;
 extern crate netlib_src as _src;
 
 pub mod alloc;
+pub mod bidiagonal;
 pub mod cholesky;
 pub mod eig;
 pub mod eigh;
+pub mod eigh_banded;
 pub mod eigh_generalized;
+pub mod eigh_generalized_banded;
+pub mod equilibrate;
 pub mod error;
 pub mod flags;
+pub mod generalized_least_squares;
+pub mod gsvd;
+pub mod hessenberg;
 pub mod layout;
 pub mod least_squares;
+pub mod normal_equations;
 pub mod opnorm;
+pub mod packed;
 pub mod qr;
 pub mod rcond;
+pub mod refine;
+pub mod schur;
 pub mod solve;
+pub mod solve_banded;
+pub mod solve_expert;
+pub mod solve_mixed;
 pub mod solveh;
 pub mod svd;
+pub mod svd_jacobi;
+pub mod svd_select;
 pub mod svddc;
+pub mod sylvester;
 pub mod triangular;
 pub mod tridiagonal;
 
+pub use self::bidiagonal::BidiagonalOwned;
+pub use self::eig::EigGeneralizedOwned;
+pub use self::eigh_banded::EighBandedOwned;
+pub use self::eigh_generalized_banded::EighGeneralizedBandedOwned;
+pub use self::equilibrate::{EquilibrateHOwned, EquilibrateOwned};
 pub use self::flags::*;
-pub use self::least_squares::LeastSquaresOwned;
+pub use self::generalized_least_squares::GaussMarkovOwned;
+pub use self::gsvd::GsvdOwned;
+pub use self::least_squares::{LeastSquaresDriver, LeastSquaresOwned};
+pub use self::packed::EighPackedOwned;
+pub use self::schur::{GeneralizedSchurOwned, SchurOwned};
+pub use self::solve_expert::SolveExpertOwned;
 pub use self::svd::{SvdOwned, SvdRef};
-pub use self::tridiagonal::{LUFactorizedTridiagonal, Tridiagonal};
+pub use self::svd_select::SingularRange;
+pub use self::tridiagonal::{
+    EighRange, EighTridiagonalOwned, LUFactorizedTridiagonal, Tridiagonal,
+};
 
 use self::{alloc::*, error::*, layout::*};
 use cauchy::*;
@@ -124,6 +173,16 @@ pub trait Lapack: Scalar {
         a: &mut [Self],
     ) -> Result<(Vec<Self::Complex>, Vec<Self::Complex>)>;
 
+    /// Compute generalized eigenvalues and eigenvectors for a pair of general matrices,
+    /// see [eig::EigGeneralizedImpl] for detail
+    fn eig_generalized(
+        calc_vl: bool,
+        calc_vr: bool,
+        l: MatrixLayout,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<EigGeneralizedOwned<Self>>;
+
     /// Compute right eigenvalue and eigenvectors for a symmetric or Hermitian matrix
     fn eigh(
         calc_eigenvec: bool,
@@ -141,6 +200,140 @@ pub trait Lapack: Scalar {
         b: &mut [Self],
     ) -> Result<Vec<Self::Real>>;
 
+    /// Compute eigenvalues and eigenvectors for a generalized eigenvalue problem
+    /// of the type `itype` for a symmetric/Hermitian matrix and a positive definite matrix
+    ///
+    /// See [eigh_generalized::EighGeneralizedDcImpl] for detail.
+    fn eigh_generalized_dc(
+        calc_eigenvec: bool,
+        l: MatrixLayout,
+        itype: GeneralizedEigType,
+        uplo: UPLO,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<Vec<Self::Real>>;
+
+    /// Reduce a generalized eigenvalue problem for symmetric/Hermitian matrices into the standard one
+    ///
+    /// `b` is the Cholesky factor of $B$ computed by [Lapack::cholesky] with the same `uplo`,
+    /// and `a` is overwritten by the reduced matrix.
+    /// See [eigh_generalized::ReduceGeneralizedImpl] for detail.
+    fn reduce_generalized(
+        l: MatrixLayout,
+        itype: GeneralizedEigType,
+        uplo: UPLO,
+        a: &mut [Self],
+        b: &[Self],
+    ) -> Result<()>;
+
+    /// Compute eigenvalues and eigenvectors for symmetric/Hermitian band matrix
+    ///
+    /// See [eigh_banded::EighBandedImpl] for detail.
+    fn eigh_banded(
+        calc_eigenvec: bool,
+        uplo: UPLO,
+        n: i32,
+        kd: i32,
+        ab: &mut [Self],
+    ) -> Result<EighBandedOwned<Self>>;
+
+    /// Compute eigenvalues and eigenvectors for a generalized eigenvalue problem
+    /// of symmetric/Hermitian band matrices
+    ///
+    /// `ab` and `bb` are `n x n` matrices in the band storage with the bandwidths `ka >= kb`,
+    /// see [eigh_generalized_banded] for the storage format.
+    fn eigh_generalized_banded(
+        calc_eigenvec: bool,
+        uplo: UPLO,
+        n: i32,
+        ka: i32,
+        kb: i32,
+        ab: &mut [Self],
+        bb: &mut [Self],
+    ) -> Result<EighGeneralizedBandedOwned<Self>>;
+
+    /// Compute eigenvalues and eigenvectors for a real symmetric tridiagonal matrix
+    ///
+    /// The matrix is given by its diagonal `d` and off-diagonal `e` elements,
+    /// and eigenvalues are stored into `d` in ascending order.
+    /// Eigenvectors are returned in column-major order if `calc_eigenvec` is true.
+    fn eigh_tridiagonal(
+        calc_eigenvec: bool,
+        d: &mut [Self::Real],
+        e: &[Self::Real],
+    ) -> Result<Option<Vec<Self::Real>>>;
+
+    /// Compute a subset of eigenvalues, and eigenvectors if requested, for a real symmetric tridiagonal matrix
+    ///
+    /// See [tridiagonal::EighTridiagonalSelectImpl] for detail.
+    fn eigh_tridiagonal_select(
+        calc_eigenvec: bool,
+        range: &EighRange<Self::Real>,
+        d: &[Self::Real],
+        e: &[Self::Real],
+    ) -> Result<EighTridiagonalOwned<Self::Real>>;
+
+    /// Compute the Schur decomposition $A = ZTZ^H$ of a general matrix
+    ///
+    /// See [schur::SchurImpl] for detail.
+    fn schur(l: MatrixLayout, calc_z: bool, a: &mut [Self]) -> Result<SchurOwned<Self>>;
+
+    /// Compute the generalized Schur (QZ) decomposition $A = QSZ^H$, $B = QTZ^H$ of a matrix pencil
+    ///
+    /// See [schur::GeneralizedSchurImpl] for detail.
+    fn generalized_schur(
+        l: MatrixLayout,
+        calc_q: bool,
+        calc_z: bool,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<GeneralizedSchurOwned<Self>>;
+
+    /// Move a diagonal block of the Schur form $T$ from the row `ifst` to the row `ilst`,
+    /// and update the Schur vectors $Z$ if given
+    ///
+    /// See [schur::ReorderSchurImpl] for detail.
+    fn reorder_schur(
+        l: MatrixLayout,
+        t: &mut [Self],
+        z: Option<&mut [Self]>,
+        ifst: usize,
+        ilst: usize,
+    ) -> Result<(usize, usize)>;
+
+    /// Move a diagonal block of the generalized Schur form $(S, T)$ from the row `ifst` to the row `ilst`,
+    /// and update the Schur vectors $Q$ and $Z$ if given
+    ///
+    /// See [schur::ReorderGeneralizedSchurImpl] for detail.
+    fn reorder_generalized_schur(
+        l: MatrixLayout,
+        s: &mut [Self],
+        t: &mut [Self],
+        q: Option<&mut [Self]>,
+        z: Option<&mut [Self]>,
+        ifst: usize,
+        ilst: usize,
+    ) -> Result<(usize, usize)>;
+
+    /// Compute the Hessenberg decomposition $A = QHQ^H$ of a general matrix
+    ///
+    /// See [hessenberg::HessenbergImpl] for detail.
+    fn hessenberg(l: MatrixLayout, calc_q: bool, a: &mut [Self]) -> Result<Option<Vec<Self>>>;
+
+    /// Solve the Sylvester equation $\mathrm{op}(A) X \pm X \mathrm{op}(B) = \alpha C$
+    /// for $A$ and $B$ in Schur form
+    ///
+    /// See [sylvester::SylvesterImpl] for detail.
+    fn sylvester(
+        l: MatrixLayout,
+        trana: Transpose,
+        tranb: Transpose,
+        isgn: i32,
+        a: &[Self],
+        b: &[Self],
+        c: &mut [Self],
+    ) -> Result<Self::Real>;
+
     /// Execute Householder reflection as the first step of QR-decomposition
     ///
     /// For C-continuous array,
@@ -150,15 +343,153 @@ pub trait Lapack: Scalar {
     /// Reconstruct Q-matrix from Householder-reflectors
     fn q(l: MatrixLayout, a: &mut [Self], tau: &[Self]) -> Result<()>;
 
+    /// Compute the Gram matrix $Q^H Q$ of the columns of $Q$,
+    /// see [qr::GramImpl]
+    fn gram(l: MatrixLayout, q: &[Self]) -> Vec<Self>;
+
+    /// Execute Householder reflection with column pivoting $AP = QR$
+    ///
+    /// Returns the scalar factors of the reflectors and the 1-based permutation.
+    /// Only Fortran-order array is supported.
+    /// See [qr::HouseholderPivotedWork] for detail.
+    fn householder_pivoted(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self>, Pivot)>;
+
+    /// Execute Householder reflection of the QR, LQ, QL or RQ decomposition
+    ///
+    /// Only Fortran-order array is supported.
+    /// See [qr::OrthogonalFactorization] for the storage of the factors.
+    fn householder_orthogonal(
+        kind: qr::OrthogonalFactorization,
+        l: MatrixLayout,
+        a: &mut [Self],
+    ) -> Result<Vec<Self>>;
+
+    /// Reconstruct the orthogonal factor from the reflectors of [Lapack::householder_orthogonal]
+    fn q_orthogonal(
+        kind: qr::OrthogonalFactorization,
+        l: MatrixLayout,
+        a: &mut [Self],
+        tau: &[Self],
+    ) -> Result<()>;
+
+    /// Multiply a matrix by $Q$ represented by the Householder reflectors of [Lapack::householder]
+    ///
+    /// See [qr::ApplyQImpl] for detail.
+    fn apply_q(
+        l: MatrixLayout,
+        side: Side,
+        trans: Transpose,
+        a: &[Self],
+        tau: &[Self],
+        lc: MatrixLayout,
+        c: &mut [Self],
+    ) -> Result<()>;
+
     /// Execute QR-decomposition at once
     fn qr(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
 
     /// Compute singular-value decomposition (SVD)
     fn svd(l: MatrixLayout, calc_u: bool, calc_vt: bool, a: &mut [Self]) -> Result<SvdOwned<Self>>;
 
+    /// Compute thin SVD, where only the first $\min(m, n)$ columns of $U$ and rows of $V^T$ are computed
+    fn svd_thin(
+        l: MatrixLayout,
+        calc_u: bool,
+        calc_vt: bool,
+        a: &mut [Self],
+    ) -> Result<SvdOwned<Self>>;
+
     /// Compute singular value decomposition (SVD) with divide-and-conquer algorithm
     fn svddc(layout: MatrixLayout, jobz: JobSvd, a: &mut [Self]) -> Result<SvdOwned<Self>>;
 
+    /// Compute singular value decomposition (SVD) with one-sided Jacobi method,
+    /// see [svd_jacobi::SvdJacobiImpl] for the requirements on `a`
+    fn svd_jacobi(
+        l: MatrixLayout,
+        calc_u: bool,
+        calc_vt: bool,
+        a: &mut [Self],
+    ) -> Result<SvdOwned<Self>>;
+
+    /// Compute singular value decomposition (SVD) only for the singular values in `range`,
+    /// see [svd_select::SvdSelectImpl] for the shape of the output
+    fn svd_select(
+        l: MatrixLayout,
+        calc_u: bool,
+        calc_vt: bool,
+        range: &SingularRange<Self::Real>,
+        a: &mut [Self],
+    ) -> Result<SvdOwned<Self>>;
+
+    /// Compute generalized singular value decomposition of $m \times n$ matrix $A$ and $p \times n$ matrix $B$
+    ///
+    /// Both matrices must be column-major, see [gsvd::GsvdImpl].
+    fn gsvd(
+        la: MatrixLayout,
+        lb: MatrixLayout,
+        calc_u: bool,
+        calc_v: bool,
+        calc_q: bool,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<GsvdOwned<Self>>;
+
+    /// Solve linear equality-constrained least squares problem $\min_x \| b - Ax \|_2$ subject to $Cx = d$
+    ///
+    /// Both matrices must be column-major,
+    /// see [generalized_least_squares::LeastSquaresConstrainedImpl].
+    fn least_squares_constrained(
+        la: MatrixLayout,
+        lc: MatrixLayout,
+        a: &mut [Self],
+        b: &mut [Self],
+        c: &mut [Self],
+        d: &mut [Self],
+    ) -> Result<Vec<Self>>;
+
+    /// Solve general Gauss-Markov linear model $\min_{x, y} \| y \|_2$ subject to $b = Ax + Wy$
+    ///
+    /// Both matrices must be column-major, see [generalized_least_squares::GaussMarkovImpl].
+    fn gauss_markov(
+        la: MatrixLayout,
+        lw: MatrixLayout,
+        a: &mut [Self],
+        w: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<GaussMarkovOwned<Self>>;
+
+    /// Reduce a general matrix into bidiagonal form $A = QBP^H$
+    ///
+    /// `a` is overwritten by the reflectors representing $Q$ and $P$,
+    /// which can be expanded into matrices by [Lapack::generate_bidiagonal].
+    /// For C-continuous array, this bidiagonalizes the transposed matrix $A^T$.
+    fn bidiagonal(l: MatrixLayout, a: &mut [Self]) -> Result<BidiagonalOwned<Self>>;
+
+    /// Generate $Q$ or $P^H$ from the output of [Lapack::bidiagonal]
+    ///
+    /// `k` is the number of columns (for $Q$) or rows (for $P^H$) of the original matrix.
+    /// See [bidiagonal::GenerateBidiagonalWork] for the shape of `a`.
+    fn generate_bidiagonal(
+        factor: BidiagonalFactor,
+        l: MatrixLayout,
+        k: i32,
+        a: &mut [Self],
+        tau: &[Self],
+    ) -> Result<()>;
+
+    /// Compute SVD of a real bidiagonal matrix $B = U_B \Sigma V_B^H$,
+    /// and apply the rotations to `vt`, `u`, and `c` if given.
+    ///
+    /// See [bidiagonal::SvdBidiagonalImpl] for detail.
+    fn svd_bidiagonal(
+        uplo: UPLO,
+        d: &mut [Self::Real],
+        e: &mut [Self::Real],
+        vt: Option<(MatrixLayout, &mut [Self])>,
+        u: Option<(MatrixLayout, &mut [Self])>,
+        c: Option<(MatrixLayout, &mut [Self])>,
+    ) -> Result<()>;
+
     /// Compute a vector $x$ which minimizes Euclidian norm $\| Ax - b\|$
     /// for a given matrix $A$ and a vector $b$.
     fn least_squares(
@@ -175,6 +506,31 @@ pub trait Lapack: Scalar {
         b: &mut [Self],
     ) -> Result<LeastSquaresOwned<Self>>;
 
+    /// Solve least square problems $\argmin_X \| AX - B\|$ by complete orthogonal factorization
+    ///
+    /// See [least_squares::LeastSquaresCodImpl] for detail.
+    fn least_squares_cod(
+        a_layout: MatrixLayout,
+        a: &mut [Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<LeastSquaresOwned<Self>>;
+
+    /// Solve least square problems $\argmin_X \| AX - B\|$ by SVD with QR iteration
+    ///
+    /// See [least_squares::LeastSquaresSvdImpl] for detail.
+    fn least_squares_svd(
+        a_layout: MatrixLayout,
+        a: &mut [Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<LeastSquaresOwned<Self>>;
+
+    /// Solve least square problem $\argmin_x \| Ax - b\|$ by the normal equations $A^H A x = A^H b$
+    ///
+    /// See [normal_equations::NormalEquationsImpl] for detail.
+    fn solve_normal_equations(l: MatrixLayout, a: &[Self], b: &[Self]) -> Result<Vec<Self>>;
+
     /// Computes the LU decomposition of a general $m \times n$ matrix
     /// with partial pivoting with row interchanges.
     ///
@@ -209,6 +565,63 @@ pub trait Lapack: Scalar {
     /// Solve linear equations $Ax = b$ using the output of LU-decomposition
     fn solve(l: MatrixLayout, t: Transpose, a: &[Self], p: &Pivot, b: &mut [Self]) -> Result<()>;
 
+    /// Solve linear equations $Ax = b$ with equilibration and iterative refinement
+    ///
+    /// In addition to the solution $x$, this returns the reciprocal condition number of $A$,
+    /// and forward and backward error bounds of $x$.
+    /// `a` and `b` are overwritten by the equilibrated ones.
+    fn solve_expert(
+        l: MatrixLayout,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<SolveExpertOwned<Self>>;
+
+    /// Solve Hermitian (or real symmetric) positive definite linear equations $Ax = b$
+    /// with equilibration and iterative refinement
+    ///
+    /// See [solve_expert::SolveCholeskyExpertImpl] for detail.
+    fn solvec_expert(
+        uplo: UPLO,
+        n: i32,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<SolveExpertOwned<Self>>;
+
+    /// Compute row and column scale factors to equilibrate a general matrix
+    ///
+    /// See [equilibrate::EquilibrateImpl] for detail.
+    fn equilibrate(l: MatrixLayout, a: &[Self]) -> Result<EquilibrateOwned<Self>>;
+
+    /// Compute symmetric scale factors to equilibrate a Hermitian (or real symmetric) positive definite matrix
+    ///
+    /// See [equilibrate::EquilibrateHImpl] for detail.
+    fn equilibrateh(l: MatrixLayout, a: &[Self]) -> Result<EquilibrateHOwned<Self>>;
+
+    /// Refine the solution of $\mathrm{op}(A) x = b$ using the output of LU-decomposition
+    ///
+    /// See [refine::RefineImpl] for detail.
+    fn refine(
+        l: MatrixLayout,
+        t: Transpose,
+        a: &[Self],
+        af: &[Self],
+        ipiv: &Pivot,
+        b: &[Self],
+        x: &mut [Self],
+    ) -> Result<(Self::Real, Self::Real)>;
+
+    /// Refine the solution of $Ax = b$ using the output of Cholesky decomposition
+    ///
+    /// See [refine::RefineCholeskyImpl] for detail.
+    fn refine_cholesky(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &[Self],
+        af: &[Self],
+        b: &[Self],
+        x: &mut [Self],
+    ) -> Result<(Self::Real, Self::Real)>;
+
     /// Factorize symmetric/Hermitian matrix using Bunch-Kaufman diagonal pivoting method
     ///
     /// For a given symmetric matrix $A$,
@@ -246,12 +659,93 @@ pub trait Lapack: Scalar {
     ///
     fn cholesky(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<()>;
 
+    /// Compute Cholesky decomposition with complete pivoting of a positive semi-definite matrix
+    ///
+    /// See [cholesky::CholeskyPivotedImpl] for detail.
+    fn cholesky_pivoted(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &mut [Self],
+        tol: Self::Real,
+    ) -> Result<(Pivot, i32)>;
+
     /// Compute inverse matrix $A^{-1}$ using $U$ or $L$ calculated by [Lapack::cholesky]
     fn inv_cholesky(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<()>;
 
     /// Solve linear equation $Ax = b$ using $U$ or $L$ calculated by [Lapack::cholesky]
     fn solve_cholesky(l: MatrixLayout, uplo: UPLO, a: &[Self], b: &mut [Self]) -> Result<()>;
 
+    /// Compute LU decomposition of a general band matrix in the band storage
+    ///
+    /// See [solve_banded] for the storage and [solve_banded::LuBandedImpl] for detail.
+    fn lu_banded(n: i32, kl: i32, ku: i32, ab: &mut [Self]) -> Result<Pivot>;
+
+    /// Solve linear equation $\mathrm{op}(A) x = b$ using the result of [Lapack::lu_banded]
+    fn solve_banded(
+        t: Transpose,
+        n: i32,
+        kl: i32,
+        ku: i32,
+        ab: &[Self],
+        ipiv: &Pivot,
+        b: &mut [Self],
+    ) -> Result<()>;
+
+    /// Estimates the reciprocal of the condition number in 1-norm using the result of [Lapack::lu_banded]
+    ///
+    /// `anorm` should be the 1-norm of the original band matrix.
+    fn rcond_banded(
+        n: i32,
+        kl: i32,
+        ku: i32,
+        ab: &[Self],
+        ipiv: &Pivot,
+        anorm: Self::Real,
+    ) -> Result<Self::Real>;
+
+    /// Compute Cholesky decomposition of a positive definite band matrix in the band storage
+    ///
+    /// See [cholesky::CholeskyBandedImpl] for detail.
+    fn cholesky_banded(uplo: UPLO, n: i32, kd: i32, ab: &mut [Self]) -> Result<()>;
+
+    /// Solve linear equation $Ax = b$ using the factor of a band matrix calculated by [Lapack::cholesky_banded]
+    fn solve_cholesky_banded(
+        uplo: UPLO,
+        n: i32,
+        kd: i32,
+        ab: &[Self],
+        b: &mut [Self],
+    ) -> Result<()>;
+
+    /// Bunch-Kaufman factorization of a symmetric/Hermitian matrix in the packed storage
+    ///
+    /// See [packed] for the storage and [packed::BkPackedImpl] for detail.
+    fn bk_packed(uplo: UPLO, n: i32, ap: &mut [Self]) -> Result<Pivot>;
+
+    /// Solve symmetric/Hermitian linear equation $Ax = b$ using the result of [Lapack::bk_packed]
+    fn solveh_packed(uplo: UPLO, n: i32, ap: &[Self], ipiv: &Pivot, b: &mut [Self]) -> Result<()>;
+
+    /// Cholesky factorization of a positive definite matrix in the packed storage
+    ///
+    /// See [packed::CholeskyPackedImpl] for detail.
+    fn cholesky_packed(uplo: UPLO, n: i32, ap: &mut [Self]) -> Result<()>;
+
+    /// Solve linear equation $Ax = b$ using the factor calculated by [Lapack::cholesky_packed]
+    fn solve_cholesky_packed(uplo: UPLO, n: i32, ap: &[Self], b: &mut [Self]) -> Result<()>;
+
+    /// Compute eigenvalues and eigenvectors of a symmetric/Hermitian matrix in the packed storage
+    ///
+    /// See [packed::EighPackedImpl] for detail.
+    fn eigh_packed(
+        calc_eigenvec: bool,
+        uplo: UPLO,
+        n: i32,
+        ap: &mut [Self],
+    ) -> Result<EighPackedOwned<Self>>;
+
+    /// Compute $UU^H$ or $L^H L$ in-place for a triangular matrix $U$ or $L$
+    fn triangular_product(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<()>;
+
     /// Estimates the the reciprocal of the condition number of the matrix in 1-norm.
     ///
     /// `anorm` should be the 1-norm of the matrix `a`.
@@ -331,6 +825,17 @@ macro_rules! impl_lapack {
                 Ok((eigs, vr.or(vl).unwrap_or_default()))
             }
 
+            fn eig_generalized(
+                calc_vl: bool,
+                calc_vr: bool,
+                l: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<EigGeneralizedOwned<Self>> {
+                use eig::*;
+                EigGeneralizedImpl::eig_generalized(calc_vl, calc_vr, l, a, b)
+            }
+
             fn eigh(
                 calc_eigenvec: bool,
                 layout: MatrixLayout,
@@ -354,6 +859,139 @@ macro_rules! impl_lapack {
                 work.eval(uplo, a, b)
             }
 
+            fn eigh_generalized_dc(
+                calc_eigenvec: bool,
+                l: MatrixLayout,
+                itype: GeneralizedEigType,
+                uplo: UPLO,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<Vec<Self::Real>> {
+                use eigh_generalized::*;
+                EighGeneralizedDcImpl::eigh_generalized_dc(calc_eigenvec, l, itype, uplo, a, b)
+            }
+
+            fn reduce_generalized(
+                l: MatrixLayout,
+                itype: GeneralizedEigType,
+                uplo: UPLO,
+                a: &mut [Self],
+                b: &[Self],
+            ) -> Result<()> {
+                use eigh_generalized::*;
+                ReduceGeneralizedImpl::reduce_generalized(l, itype, uplo, a, b)
+            }
+
+            fn eigh_banded(
+                calc_eigenvec: bool,
+                uplo: UPLO,
+                n: i32,
+                kd: i32,
+                ab: &mut [Self],
+            ) -> Result<EighBandedOwned<Self>> {
+                use eigh_banded::*;
+                EighBandedImpl::eigh_banded(calc_eigenvec, uplo, n, kd, ab)
+            }
+
+            fn eigh_generalized_banded(
+                calc_eigenvec: bool,
+                uplo: UPLO,
+                n: i32,
+                ka: i32,
+                kb: i32,
+                ab: &mut [Self],
+                bb: &mut [Self],
+            ) -> Result<EighGeneralizedBandedOwned<Self>> {
+                use eigh_generalized_banded::*;
+                let work = EighGeneralizedBandedWork::<$s>::new(calc_eigenvec, n, ka, kb)?;
+                work.eval(uplo, ab, bb)
+            }
+
+            fn eigh_tridiagonal(
+                calc_eigenvec: bool,
+                d: &mut [Self::Real],
+                e: &[Self::Real],
+            ) -> Result<Option<Vec<Self::Real>>> {
+                use tridiagonal::*;
+                let work = EighTridiagonalWork::<<$s as Scalar>::Real>::new(
+                    calc_eigenvec,
+                    d.len() as i32,
+                )?;
+                work.eval(d, e)
+            }
+
+            fn eigh_tridiagonal_select(
+                calc_eigenvec: bool,
+                range: &EighRange<Self::Real>,
+                d: &[Self::Real],
+                e: &[Self::Real],
+            ) -> Result<EighTridiagonalOwned<Self::Real>> {
+                use tridiagonal::*;
+                EighTridiagonalSelectImpl::eigh_tridiagonal_select(calc_eigenvec, range, d, e)
+            }
+
+            fn schur(l: MatrixLayout, calc_z: bool, a: &mut [Self]) -> Result<SchurOwned<Self>> {
+                use schur::*;
+                SchurImpl::schur(l, calc_z, a)
+            }
+
+            fn generalized_schur(
+                l: MatrixLayout,
+                calc_q: bool,
+                calc_z: bool,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<GeneralizedSchurOwned<Self>> {
+                use schur::*;
+                GeneralizedSchurImpl::generalized_schur(l, calc_q, calc_z, a, b)
+            }
+
+            fn reorder_schur(
+                l: MatrixLayout,
+                t: &mut [Self],
+                z: Option<&mut [Self]>,
+                ifst: usize,
+                ilst: usize,
+            ) -> Result<(usize, usize)> {
+                use schur::*;
+                ReorderSchurImpl::reorder_schur(l, t, z, ifst, ilst)
+            }
+
+            fn reorder_generalized_schur(
+                l: MatrixLayout,
+                s: &mut [Self],
+                t: &mut [Self],
+                q: Option<&mut [Self]>,
+                z: Option<&mut [Self]>,
+                ifst: usize,
+                ilst: usize,
+            ) -> Result<(usize, usize)> {
+                use schur::*;
+                ReorderGeneralizedSchurImpl::reorder_generalized_schur(l, s, t, q, z, ifst, ilst)
+            }
+
+            fn hessenberg(
+                l: MatrixLayout,
+                calc_q: bool,
+                a: &mut [Self],
+            ) -> Result<Option<Vec<Self>>> {
+                use hessenberg::*;
+                HessenbergImpl::hessenberg(l, calc_q, a)
+            }
+
+            fn sylvester(
+                l: MatrixLayout,
+                trana: Transpose,
+                tranb: Transpose,
+                isgn: i32,
+                a: &[Self],
+                b: &[Self],
+                c: &mut [Self],
+            ) -> Result<Self::Real> {
+                use sylvester::*;
+                SylvesterImpl::sylvester(l, trana, tranb, isgn, a, b, c)
+            }
+
             fn householder(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>> {
                 use qr::*;
                 let work = HouseholderWork::<$s>::new(l)?;
@@ -367,6 +1005,51 @@ macro_rules! impl_lapack {
                 Ok(())
             }
 
+            fn gram(l: MatrixLayout, q: &[Self]) -> Vec<Self> {
+                use qr::*;
+                GramImpl::gram(l, q)
+            }
+
+            fn householder_pivoted(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self>, Pivot)> {
+                use qr::*;
+                let work = HouseholderPivotedWork::<$s>::new(l)?;
+                work.eval(a)
+            }
+
+            fn householder_orthogonal(
+                kind: qr::OrthogonalFactorization,
+                l: MatrixLayout,
+                a: &mut [Self],
+            ) -> Result<Vec<Self>> {
+                use qr::*;
+                let work = OrthogonalWork::<$s>::new(kind, l)?;
+                work.eval(a)
+            }
+
+            fn q_orthogonal(
+                kind: qr::OrthogonalFactorization,
+                l: MatrixLayout,
+                a: &mut [Self],
+                tau: &[Self],
+            ) -> Result<()> {
+                use qr::*;
+                let mut work = OrthogonalQWork::<$s>::new(kind, l)?;
+                work.calc(a, tau)
+            }
+
+            fn apply_q(
+                l: MatrixLayout,
+                side: Side,
+                trans: Transpose,
+                a: &[Self],
+                tau: &[Self],
+                lc: MatrixLayout,
+                c: &mut [Self],
+            ) -> Result<()> {
+                use qr::*;
+                ApplyQImpl::apply_q(l, side, trans, a, tau, lc, c)
+            }
+
             fn qr(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>> {
                 let tau = Self::householder(l, a)?;
                 let r = Vec::from(&*a);
@@ -385,12 +1068,111 @@ macro_rules! impl_lapack {
                 work.eval(a)
             }
 
+            fn svd_thin(
+                l: MatrixLayout,
+                calc_u: bool,
+                calc_vt: bool,
+                a: &mut [Self],
+            ) -> Result<SvdOwned<Self>> {
+                use svd::*;
+                let job = |calc| if calc { JobSvd::Some } else { JobSvd::None };
+                let work = SvdWork::<$s>::new_with_job(l, job(calc_u), job(calc_vt))?;
+                work.eval(a)
+            }
+
             fn svddc(layout: MatrixLayout, jobz: JobSvd, a: &mut [Self]) -> Result<SvdOwned<Self>> {
                 use svddc::*;
                 let work = SvdDcWork::<$s>::new(layout, jobz)?;
                 work.eval(a)
             }
 
+            fn svd_jacobi(
+                l: MatrixLayout,
+                calc_u: bool,
+                calc_vt: bool,
+                a: &mut [Self],
+            ) -> Result<SvdOwned<Self>> {
+                use svd_jacobi::*;
+                SvdJacobiImpl::svd_jacobi(l, calc_u, calc_vt, a)
+            }
+
+            fn svd_select(
+                l: MatrixLayout,
+                calc_u: bool,
+                calc_vt: bool,
+                range: &SingularRange<Self::Real>,
+                a: &mut [Self],
+            ) -> Result<SvdOwned<Self>> {
+                use svd_select::*;
+                SvdSelectImpl::svd_select(l, calc_u, calc_vt, range, a)
+            }
+
+            fn gsvd(
+                la: MatrixLayout,
+                lb: MatrixLayout,
+                calc_u: bool,
+                calc_v: bool,
+                calc_q: bool,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<GsvdOwned<Self>> {
+                use gsvd::*;
+                GsvdImpl::gsvd(la, lb, calc_u, calc_v, calc_q, a, b)
+            }
+
+            fn least_squares_constrained(
+                la: MatrixLayout,
+                lc: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+                c: &mut [Self],
+                d: &mut [Self],
+            ) -> Result<Vec<Self>> {
+                use generalized_least_squares::*;
+                LeastSquaresConstrainedImpl::least_squares_constrained(la, lc, a, b, c, d)
+            }
+
+            fn gauss_markov(
+                la: MatrixLayout,
+                lw: MatrixLayout,
+                a: &mut [Self],
+                w: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<GaussMarkovOwned<Self>> {
+                use generalized_least_squares::*;
+                GaussMarkovImpl::gauss_markov(la, lw, a, w, b)
+            }
+
+            fn bidiagonal(l: MatrixLayout, a: &mut [Self]) -> Result<BidiagonalOwned<Self>> {
+                use bidiagonal::*;
+                let work = BidiagonalWork::<$s>::new(l)?;
+                work.eval(a)
+            }
+
+            fn generate_bidiagonal(
+                factor: BidiagonalFactor,
+                l: MatrixLayout,
+                k: i32,
+                a: &mut [Self],
+                tau: &[Self],
+            ) -> Result<()> {
+                use bidiagonal::*;
+                let mut work = GenerateBidiagonalWork::<$s>::new(factor, l, k)?;
+                work.calc(a, tau)
+            }
+
+            fn svd_bidiagonal(
+                uplo: UPLO,
+                d: &mut [Self::Real],
+                e: &mut [Self::Real],
+                vt: Option<(MatrixLayout, &mut [Self])>,
+                u: Option<(MatrixLayout, &mut [Self])>,
+                c: Option<(MatrixLayout, &mut [Self])>,
+            ) -> Result<()> {
+                use bidiagonal::*;
+                SvdBidiagonalImpl::svd_bidiagonal(uplo, d, e, vt, u, c)
+            }
+
             fn least_squares(
                 l: MatrixLayout,
                 a: &mut [Self],
@@ -411,6 +1193,35 @@ macro_rules! impl_lapack {
                 work.eval(a, b)
             }
 
+            fn least_squares_cod(
+                a_layout: MatrixLayout,
+                a: &mut [Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<LeastSquaresOwned<Self>> {
+                use least_squares::*;
+                LeastSquaresCodImpl::least_squares_cod(a_layout, a, b_layout, b)
+            }
+
+            fn least_squares_svd(
+                a_layout: MatrixLayout,
+                a: &mut [Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<LeastSquaresOwned<Self>> {
+                use least_squares::*;
+                LeastSquaresSvdImpl::least_squares_svd(a_layout, a, b_layout, b)
+            }
+
+            fn solve_normal_equations(
+                l: MatrixLayout,
+                a: &[Self],
+                b: &[Self],
+            ) -> Result<Vec<Self>> {
+                use normal_equations::*;
+                NormalEquationsImpl::solve_normal_equations(l, a, b)
+            }
+
             fn lu(l: MatrixLayout, a: &mut [Self]) -> Result<Pivot> {
                 use solve::*;
                 LuImpl::lu(l, a)
@@ -434,6 +1245,61 @@ macro_rules! impl_lapack {
                 SolveImpl::solve(l, t, a, p, b)
             }
 
+            fn solve_expert(
+                l: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<SolveExpertOwned<Self>> {
+                use solve_expert::*;
+                let work = SolveExpertWork::<$s>::new(l);
+                work.eval(a, b)
+            }
+
+            fn solvec_expert(
+                uplo: UPLO,
+                n: i32,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<SolveExpertOwned<Self>> {
+                use solve_expert::*;
+                SolveCholeskyExpertImpl::solvec_expert(uplo, n, a, b)
+            }
+
+            fn equilibrate(l: MatrixLayout, a: &[Self]) -> Result<EquilibrateOwned<Self>> {
+                use equilibrate::*;
+                EquilibrateImpl::equilibrate(l, a)
+            }
+
+            fn equilibrateh(l: MatrixLayout, a: &[Self]) -> Result<EquilibrateHOwned<Self>> {
+                use equilibrate::*;
+                EquilibrateHImpl::equilibrateh(l, a)
+            }
+
+            fn refine(
+                l: MatrixLayout,
+                t: Transpose,
+                a: &[Self],
+                af: &[Self],
+                ipiv: &Pivot,
+                b: &[Self],
+                x: &mut [Self],
+            ) -> Result<(Self::Real, Self::Real)> {
+                use refine::*;
+                RefineImpl::refine(l, t, a, af, ipiv, b, x)
+            }
+
+            fn refine_cholesky(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                af: &[Self],
+                b: &[Self],
+                x: &mut [Self],
+            ) -> Result<(Self::Real, Self::Real)> {
+                use refine::*;
+                RefineCholeskyImpl::refine_cholesky(l, uplo, a, af, b, x)
+            }
+
             fn bk(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<Pivot> {
                 use solveh::*;
                 let work = BkWork::<$s>::new(l)?;
@@ -462,6 +1328,16 @@ macro_rules! impl_lapack {
                 CholeskyImpl::cholesky(l, uplo, a)
             }
 
+            fn cholesky_pivoted(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &mut [Self],
+                tol: Self::Real,
+            ) -> Result<(Pivot, i32)> {
+                use cholesky::*;
+                CholeskyPivotedImpl::cholesky_pivoted(l, uplo, a, tol)
+            }
+
             fn inv_cholesky(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<()> {
                 use cholesky::*;
                 InvCholeskyImpl::inv_cholesky(l, uplo, a)
@@ -477,6 +1353,98 @@ macro_rules! impl_lapack {
                 SolveCholeskyImpl::solve_cholesky(l, uplo, a, b)
             }
 
+            fn lu_banded(n: i32, kl: i32, ku: i32, ab: &mut [Self]) -> Result<Pivot> {
+                use solve_banded::*;
+                LuBandedImpl::lu_banded(n, kl, ku, ab)
+            }
+
+            fn solve_banded(
+                t: Transpose,
+                n: i32,
+                kl: i32,
+                ku: i32,
+                ab: &[Self],
+                ipiv: &Pivot,
+                b: &mut [Self],
+            ) -> Result<()> {
+                use solve_banded::*;
+                SolveBandedImpl::solve_banded(t, n, kl, ku, ab, ipiv, b)
+            }
+
+            fn rcond_banded(
+                n: i32,
+                kl: i32,
+                ku: i32,
+                ab: &[Self],
+                ipiv: &Pivot,
+                anorm: Self::Real,
+            ) -> Result<Self::Real> {
+                use solve_banded::*;
+                RcondBandedImpl::rcond_banded(n, kl, ku, ab, ipiv, anorm)
+            }
+
+            fn cholesky_banded(uplo: UPLO, n: i32, kd: i32, ab: &mut [Self]) -> Result<()> {
+                use cholesky::*;
+                CholeskyBandedImpl::cholesky_banded(uplo, n, kd, ab)
+            }
+
+            fn solve_cholesky_banded(
+                uplo: UPLO,
+                n: i32,
+                kd: i32,
+                ab: &[Self],
+                b: &mut [Self],
+            ) -> Result<()> {
+                use cholesky::*;
+                SolveCholeskyBandedImpl::solve_cholesky_banded(uplo, n, kd, ab, b)
+            }
+
+            fn bk_packed(uplo: UPLO, n: i32, ap: &mut [Self]) -> Result<Pivot> {
+                use packed::*;
+                BkPackedImpl::bk_packed(uplo, n, ap)
+            }
+
+            fn solveh_packed(
+                uplo: UPLO,
+                n: i32,
+                ap: &[Self],
+                ipiv: &Pivot,
+                b: &mut [Self],
+            ) -> Result<()> {
+                use packed::*;
+                SolvehPackedImpl::solveh_packed(uplo, n, ap, ipiv, b)
+            }
+
+            fn cholesky_packed(uplo: UPLO, n: i32, ap: &mut [Self]) -> Result<()> {
+                use packed::*;
+                CholeskyPackedImpl::cholesky_packed(uplo, n, ap)
+            }
+
+            fn solve_cholesky_packed(
+                uplo: UPLO,
+                n: i32,
+                ap: &[Self],
+                b: &mut [Self],
+            ) -> Result<()> {
+                use packed::*;
+                SolveCholeskyPackedImpl::solve_cholesky_packed(uplo, n, ap, b)
+            }
+
+            fn eigh_packed(
+                calc_eigenvec: bool,
+                uplo: UPLO,
+                n: i32,
+                ap: &mut [Self],
+            ) -> Result<EighPackedOwned<Self>> {
+                use packed::*;
+                EighPackedImpl::eigh_packed(calc_eigenvec, uplo, n, ap)
+            }
+
+            fn triangular_product(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<()> {
+                use cholesky::*;
+                TriangularProductImpl::triangular_product(l, uplo, a)
+            }
+
             fn rcond(l: MatrixLayout, a: &[Self], anorm: Self::Real) -> Result<Self::Real> {
                 use rcond::*;
                 let mut work = RcondWork::<$s>::new(l);
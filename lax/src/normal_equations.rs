@@ -0,0 +1,146 @@
+//! Solve least square problem by the normal equations
+
+use super::*;
+use crate::{error::*, layout::*};
+use cauchy::*;
+use cblas_sys::{CBLAS_LAYOUT::*, CBLAS_TRANSPOSE, CBLAS_UPLO::*};
+use num_traits::Zero;
+
+/// Solve least square problem $\argmin_x \| Ax - b\|$ by the normal equations $A^H A x = A^H b$
+///
+/// $A^H A$ is formed by a rank-k update, $A^H b$ by a matrix-vector product,
+/// and then the normal equations are solved by Cholesky decomposition.
+/// Only the $n \times n$ matrix $A^H A$ is factorized,
+/// which is much faster than [least_squares](crate::least_squares) for a tall matrix $A$.
+///
+/// The condition number of $A^H A$ is the square of that of $A$,
+/// i.e. this loses about twice as many digits as the QR or SVD based solvers.
+/// $A$ must have full column rank, otherwise the Cholesky decomposition fails.
+///
+/// BLAS/LAPACK correspondance
+/// ---------------------------
+///
+/// | f32   | f64   | c32   | c64   |
+/// |:------|:------|:------|:------|
+/// | ssyrk | dsyrk | cherk | zherk |
+/// | sgemv | dgemv | cgemv | zgemv |
+/// | sposv | dposv | cposv | zposv |
+///
+pub trait NormalEquationsImpl: Scalar {
+    fn solve_normal_equations(l: MatrixLayout, a: &[Self], b: &[Self]) -> Result<Vec<Self>>;
+}
+
+macro_rules! impl_normal_equations {
+    ($s:ty, $trans:ident, $rk:path, $gemv:path, $posv:path, $one:expr, $zero:expr) => {
+        impl NormalEquationsImpl for $s {
+            fn solve_normal_equations(
+                l: MatrixLayout,
+                a: &[Self],
+                b: &[Self],
+            ) -> Result<Vec<Self>> {
+                let (m, n) = l.size();
+                if b.len() != m as usize {
+                    return Err(Error::InvalidShape);
+                }
+                if n == 0 {
+                    return Ok(Vec::new());
+                }
+                // C-continuous `A` is regarded as F-continuous `A^T`,
+                // which yields the complex conjugate of the normal equations
+                let (trans, rows, cols, lda, b) = match l {
+                    MatrixLayout::F { lda, .. } => (CBLAS_TRANSPOSE::$trans, m, n, lda, b.to_vec()),
+                    MatrixLayout::C { lda, .. } => (
+                        CBLAS_TRANSPOSE::CblasNoTrans,
+                        n,
+                        m,
+                        lda,
+                        b.iter().map(|x| x.conj()).collect(),
+                    ),
+                };
+                let mut aha = vec![Self::zero(); (n * n) as usize];
+                let mut x = vec![Self::zero(); n as usize];
+                let mut info = 0;
+                unsafe {
+                    $rk(
+                        CblasColMajor,
+                        CblasUpper,
+                        trans,
+                        n,
+                        m,
+                        1.0,
+                        a.as_ptr() as *const _,
+                        lda,
+                        0.0,
+                        aha.as_mut_ptr() as *mut _,
+                        n,
+                    );
+                    $gemv(
+                        CblasColMajor,
+                        trans,
+                        rows,
+                        cols,
+                        $one,
+                        a.as_ptr() as *const _,
+                        lda,
+                        b.as_ptr() as *const _,
+                        1,
+                        $zero,
+                        x.as_mut_ptr() as *mut _,
+                        1,
+                    );
+                    $posv(
+                        UPLO::Upper.as_ptr(),
+                        &n,
+                        &1,
+                        AsPtr::as_mut_ptr(&mut aha),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut x),
+                        &n,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if matches!(l, MatrixLayout::C { .. }) {
+                    x.iter_mut().for_each(|v| *v = v.conj());
+                }
+                Ok(x)
+            }
+        }
+    };
+}
+impl_normal_equations!(
+    f64,
+    CblasTrans,
+    cblas_sys::cblas_dsyrk,
+    cblas_sys::cblas_dgemv,
+    lapack_sys::dposv_,
+    1.0,
+    0.0
+);
+impl_normal_equations!(
+    f32,
+    CblasTrans,
+    cblas_sys::cblas_ssyrk,
+    cblas_sys::cblas_sgemv,
+    lapack_sys::sposv_,
+    1.0,
+    0.0
+);
+impl_normal_equations!(
+    c64,
+    CblasConjTrans,
+    cblas_sys::cblas_zherk,
+    cblas_sys::cblas_zgemv,
+    lapack_sys::zposv_,
+    &[1.0, 0.0],
+    &[0.0, 0.0]
+);
+impl_normal_equations!(
+    c32,
+    CblasConjTrans,
+    cblas_sys::cblas_cherk,
+    cblas_sys::cblas_cgemv,
+    lapack_sys::cposv_,
+    &[1.0, 0.0],
+    &[0.0, 0.0]
+);
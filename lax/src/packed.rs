@@ -0,0 +1,285 @@
+//! Symmetric/Hermitian matrices in the packed storage
+//!
+//! The upper (or lower) triangular part of a `n x n` matrix is stored column by column
+//! in the array `ap` of length `n (n + 1) / 2`:
+//!
+//! - `UPLO::Upper`: `ap[i + j (j + 1) / 2] = a[i, j]` for `i <= j`
+//! - `UPLO::Lower`: `ap[i + (2n - j - 1) j / 2] = a[i, j]` for `j <= i`
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | ssptrf | dsptrf | chptrf | zhptrf |
+//! | ssptrs | dsptrs | chptrs | zhptrs |
+//! | spptrf | dpptrf | cpptrf | zpptrf |
+//! | spptrs | dpptrs | cpptrs | zpptrs |
+//! | sspev  | dspev  | chpev  | zhpev  |
+//!
+
+use super::*;
+use crate::error::*;
+use cauchy::*;
+
+/// Bunch-Kaufman factorization of a symmetric/Hermitian matrix in the packed storage
+///
+/// `ap` is overwritten by the block diagonal matrix and the multipliers.
+/// Returns [crate::error::Error::LapackComputationalFailure] if the matrix is exactly singular.
+pub trait BkPackedImpl: Scalar {
+    fn bk_packed(uplo: UPLO, n: i32, ap: &mut [Self]) -> Result<Pivot>;
+}
+
+macro_rules! impl_bk_packed {
+    ($s:ty, $trf:path) => {
+        impl BkPackedImpl for $s {
+            fn bk_packed(uplo: UPLO, n: i32, ap: &mut [Self]) -> Result<Pivot> {
+                let n_ = n as usize;
+                assert_eq!(ap.len(), n_ * (n_ + 1) / 2);
+                let mut ipiv = vec_uninit(n_);
+                let mut info = 0;
+                unsafe {
+                    $trf(
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(ap),
+                        AsPtr::as_mut_ptr(&mut ipiv),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let ipiv = unsafe { ipiv.assume_init() };
+                Ok(ipiv)
+            }
+        }
+    };
+}
+impl_bk_packed!(c64, lapack_sys::zhptrf_);
+impl_bk_packed!(c32, lapack_sys::chptrf_);
+impl_bk_packed!(f64, lapack_sys::dsptrf_);
+impl_bk_packed!(f32, lapack_sys::ssptrf_);
+
+/// Solve $Ax = b$ using the result of [BkPackedImpl::bk_packed]
+pub trait SolvehPackedImpl: Scalar {
+    fn solveh_packed(uplo: UPLO, n: i32, ap: &[Self], ipiv: &Pivot, b: &mut [Self]) -> Result<()>;
+}
+
+macro_rules! impl_solveh_packed {
+    ($s:ty, $trs:path) => {
+        impl SolvehPackedImpl for $s {
+            fn solveh_packed(
+                uplo: UPLO,
+                n: i32,
+                ap: &[Self],
+                ipiv: &Pivot,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let n_ = n as usize;
+                assert_eq!(ap.len(), n_ * (n_ + 1) / 2);
+                assert_eq!(b.len(), n_);
+                let nrhs = 1;
+                let mut info = 0;
+                unsafe {
+                    $trs(
+                        uplo.as_ptr(),
+                        &n,
+                        &nrhs,
+                        AsPtr::as_ptr(ap),
+                        ipiv.as_ptr(),
+                        AsPtr::as_mut_ptr(b),
+                        &n.max(1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+impl_solveh_packed!(c64, lapack_sys::zhptrs_);
+impl_solveh_packed!(c32, lapack_sys::chptrs_);
+impl_solveh_packed!(f64, lapack_sys::dsptrs_);
+impl_solveh_packed!(f32, lapack_sys::ssptrs_);
+
+/// Cholesky factorization of a positive definite matrix in the packed storage
+///
+/// `ap` is overwritten by the factor $U$ or $L$ in the same storage.
+/// Returns [crate::error::Error::LapackComputationalFailure] if the matrix is not positive definite.
+pub trait CholeskyPackedImpl: Scalar {
+    fn cholesky_packed(uplo: UPLO, n: i32, ap: &mut [Self]) -> Result<()>;
+}
+
+macro_rules! impl_cholesky_packed {
+    ($s:ty, $pptrf:path) => {
+        impl CholeskyPackedImpl for $s {
+            fn cholesky_packed(uplo: UPLO, n: i32, ap: &mut [Self]) -> Result<()> {
+                let n_ = n as usize;
+                assert_eq!(ap.len(), n_ * (n_ + 1) / 2);
+                let mut info = 0;
+                unsafe {
+                    $pptrf(uplo.as_ptr(), &n, AsPtr::as_mut_ptr(ap), &mut info);
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+impl_cholesky_packed!(c64, lapack_sys::zpptrf_);
+impl_cholesky_packed!(c32, lapack_sys::cpptrf_);
+impl_cholesky_packed!(f64, lapack_sys::dpptrf_);
+impl_cholesky_packed!(f32, lapack_sys::spptrf_);
+
+/// Solve $Ax = b$ using the factor computed by [CholeskyPackedImpl::cholesky_packed]
+pub trait SolveCholeskyPackedImpl: Scalar {
+    fn solve_cholesky_packed(uplo: UPLO, n: i32, ap: &[Self], b: &mut [Self]) -> Result<()>;
+}
+
+macro_rules! impl_solve_cholesky_packed {
+    ($s:ty, $pptrs:path) => {
+        impl SolveCholeskyPackedImpl for $s {
+            fn solve_cholesky_packed(
+                uplo: UPLO,
+                n: i32,
+                ap: &[Self],
+                b: &mut [Self],
+            ) -> Result<()> {
+                let n_ = n as usize;
+                assert_eq!(ap.len(), n_ * (n_ + 1) / 2);
+                assert_eq!(b.len(), n_);
+                let nrhs = 1;
+                let mut info = 0;
+                unsafe {
+                    $pptrs(
+                        uplo.as_ptr(),
+                        &n,
+                        &nrhs,
+                        AsPtr::as_ptr(ap),
+                        AsPtr::as_mut_ptr(b),
+                        &n.max(1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+impl_solve_cholesky_packed!(c64, lapack_sys::zpptrs_);
+impl_solve_cholesky_packed!(c32, lapack_sys::cpptrs_);
+impl_solve_cholesky_packed!(f64, lapack_sys::dpptrs_);
+impl_solve_cholesky_packed!(f32, lapack_sys::spptrs_);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EighPackedOwned<T: Scalar> {
+    /// Eigenvalues in ascending order
+    pub eigs: Vec<T::Real>,
+    /// Orthonormal eigenvectors in column-major order
+    pub z: Option<Vec<T>>,
+}
+
+/// Compute eigenvalues, and eigenvectors if `calc_eigenvec`
+///
+/// `ap` is destroyed.
+pub trait EighPackedImpl: Scalar {
+    fn eigh_packed(
+        calc_eigenvec: bool,
+        uplo: UPLO,
+        n: i32,
+        ap: &mut [Self],
+    ) -> Result<EighPackedOwned<Self>>;
+}
+
+macro_rules! impl_eigh_packed_c {
+    ($c:ty, $hpev:path) => {
+        impl EighPackedImpl for $c {
+            fn eigh_packed(
+                calc_eigenvec: bool,
+                uplo: UPLO,
+                n: i32,
+                ap: &mut [Self],
+            ) -> Result<EighPackedOwned<Self>> {
+                let n_ = n as usize;
+                assert_eq!(ap.len(), n_ * (n_ + 1) / 2);
+                let jobz = if calc_eigenvec {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let mut eigs: Vec<MaybeUninit<Self::Real>> = vec_uninit(n_);
+                let mut z: Option<Vec<MaybeUninit<Self>>> = jobz.then(|| vec_uninit(n_ * n_));
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit((2 * n_).max(2) - 1);
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit((3 * n_).max(3) - 2);
+                let mut info = 0;
+                unsafe {
+                    $hpev(
+                        jobz.as_ptr(),
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(ap),
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(z.as_mut().map(|z| z.as_mut_slice()).unwrap_or(&mut [])),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(EighPackedOwned {
+                    eigs: unsafe { eigs.assume_init() },
+                    z: z.map(|z| unsafe { z.assume_init() }),
+                })
+            }
+        }
+    };
+}
+impl_eigh_packed_c!(c64, lapack_sys::zhpev_);
+impl_eigh_packed_c!(c32, lapack_sys::chpev_);
+
+macro_rules! impl_eigh_packed_r {
+    ($f:ty, $spev:path) => {
+        impl EighPackedImpl for $f {
+            fn eigh_packed(
+                calc_eigenvec: bool,
+                uplo: UPLO,
+                n: i32,
+                ap: &mut [Self],
+            ) -> Result<EighPackedOwned<Self>> {
+                let n_ = n as usize;
+                assert_eq!(ap.len(), n_ * (n_ + 1) / 2);
+                let jobz = if calc_eigenvec {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let mut eigs: Vec<MaybeUninit<Self::Real>> = vec_uninit(n_);
+                let mut z: Option<Vec<MaybeUninit<Self>>> = jobz.then(|| vec_uninit(n_ * n_));
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit((3 * n_).max(1));
+                let mut info = 0;
+                unsafe {
+                    $spev(
+                        jobz.as_ptr(),
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(ap),
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(z.as_mut().map(|z| z.as_mut_slice()).unwrap_or(&mut [])),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(EighPackedOwned {
+                    eigs: unsafe { eigs.assume_init() },
+                    z: z.map(|z| unsafe { z.assume_init() }),
+                })
+            }
+        }
+    };
+}
+impl_eigh_packed_r!(f64, lapack_sys::dspev_);
+impl_eigh_packed_r!(f32, lapack_sys::sspev_);
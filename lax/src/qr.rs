@@ -214,3 +214,757 @@ impl_q_work!(c64, lapack_sys::zungqr_, lapack_sys::zunglq_);
 impl_q_work!(c32, lapack_sys::cungqr_, lapack_sys::cunglq_);
 impl_q_work!(f64, lapack_sys::dorgqr_, lapack_sys::dorglq_);
 impl_q_work!(f32, lapack_sys::sorgqr_, lapack_sys::sorglq_);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Working memory for the QR decomposition with column pivoting $AP = QR$ by `*geqp3`
+///
+/// Only Fortran-order array is supported.
+/// `a` is overwritten by $R$ and the Householder reflectors as [Lapack::householder] does,
+/// and `jpvt` is the permutation where the `j`-th column of $AP$ is the `jpvt[j]`-th (1-based) column of $A$.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | sgeqp3 | dgeqp3 | cgeqp3 | zgeqp3 |
+///
+/// Panics
+/// ------
+/// - If `l` is not Fortran order
+///
+pub struct HouseholderPivotedWork<T: Scalar> {
+    pub m: i32,
+    pub n: i32,
+    pub jpvt: Vec<i32>,
+    pub tau: Vec<MaybeUninit<T>>,
+    pub work: Vec<MaybeUninit<T>>,
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+}
+
+pub trait HouseholderPivotedWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(l: MatrixLayout) -> Result<Self>;
+    fn calc(&mut self, a: &mut [Self::Elem]) -> Result<(&[Self::Elem], &[i32])>;
+    fn eval(self, a: &mut [Self::Elem]) -> Result<(Vec<Self::Elem>, Pivot)>;
+}
+
+macro_rules! impl_householder_pivoted_work_c {
+    ($s:ty, $qp3:path) => {
+        impl HouseholderPivotedWorkImpl for HouseholderPivotedWork<$s> {
+            type Elem = $s;
+
+            fn new(l: MatrixLayout) -> Result<Self> {
+                assert!(matches!(l, MatrixLayout::F { .. }));
+                let (m, n) = l.size();
+                let k = m.min(n);
+                let mut jpvt = vec![0; n as usize];
+                let mut tau = vec_uninit(k as usize);
+                let mut rwork = vec_uninit(2 * n as usize);
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                unsafe {
+                    $qp3(
+                        &m,
+                        &n,
+                        std::ptr::null_mut(),
+                        &m.max(1),
+                        jpvt.as_mut_ptr(),
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap().max(1);
+                let work = vec_uninit(lwork);
+                Ok(HouseholderPivotedWork {
+                    m,
+                    n,
+                    jpvt,
+                    tau,
+                    work,
+                    rwork: Some(rwork),
+                })
+            }
+
+            fn calc(&mut self, a: &mut [Self::Elem]) -> Result<(&[Self::Elem], &[i32])> {
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                // All the columns are free to be pivoted
+                self.jpvt.fill(0);
+                unsafe {
+                    $qp3(
+                        &self.m,
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.m.max(1),
+                        self.jpvt.as_mut_ptr(),
+                        AsPtr::as_mut_ptr(&mut self.tau),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok((unsafe { self.tau.slice_assume_init_ref() }, &self.jpvt))
+            }
+
+            fn eval(mut self, a: &mut [Self::Elem]) -> Result<(Vec<Self::Elem>, Pivot)> {
+                let _ = self.calc(a)?;
+                Ok((unsafe { self.tau.assume_init() }, self.jpvt))
+            }
+        }
+    };
+}
+impl_householder_pivoted_work_c!(c64, lapack_sys::zgeqp3_);
+impl_householder_pivoted_work_c!(c32, lapack_sys::cgeqp3_);
+
+macro_rules! impl_householder_pivoted_work_r {
+    ($s:ty, $qp3:path) => {
+        impl HouseholderPivotedWorkImpl for HouseholderPivotedWork<$s> {
+            type Elem = $s;
+
+            fn new(l: MatrixLayout) -> Result<Self> {
+                assert!(matches!(l, MatrixLayout::F { .. }));
+                let (m, n) = l.size();
+                let k = m.min(n);
+                let mut jpvt = vec![0; n as usize];
+                let mut tau = vec_uninit(k as usize);
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                unsafe {
+                    $qp3(
+                        &m,
+                        &n,
+                        std::ptr::null_mut(),
+                        &m.max(1),
+                        jpvt.as_mut_ptr(),
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap().max(1);
+                let work = vec_uninit(lwork);
+                Ok(HouseholderPivotedWork {
+                    m,
+                    n,
+                    jpvt,
+                    tau,
+                    work,
+                    rwork: None,
+                })
+            }
+
+            fn calc(&mut self, a: &mut [Self::Elem]) -> Result<(&[Self::Elem], &[i32])> {
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                // All the columns are free to be pivoted
+                self.jpvt.fill(0);
+                unsafe {
+                    $qp3(
+                        &self.m,
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.m.max(1),
+                        self.jpvt.as_mut_ptr(),
+                        AsPtr::as_mut_ptr(&mut self.tau),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok((unsafe { self.tau.slice_assume_init_ref() }, &self.jpvt))
+            }
+
+            fn eval(mut self, a: &mut [Self::Elem]) -> Result<(Vec<Self::Elem>, Pivot)> {
+                let _ = self.calc(a)?;
+                Ok((unsafe { self.tau.assume_init() }, self.jpvt))
+            }
+        }
+    };
+}
+impl_householder_pivoted_work_r!(f64, lapack_sys::dgeqp3_);
+impl_householder_pivoted_work_r!(f32, lapack_sys::sgeqp3_);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Orthogonal/unitary factorizations of a $m \times n$ matrix $A$ computed by [OrthogonalWork]
+///
+/// With $k = \min(m, n)$, the factors are stored in the Fortran-order array of $A$ as follows:
+///
+/// | Variant | Factorization | Triangular factor            | Orthogonal factor             | LAPACK (real, complex)  |
+/// |:--------|:--------------|:-----------------------------|:------------------------------|:------------------------|
+/// | `QR`    | $A = QR$      | upper, in the first $k$ rows    | $m \times k$, in the first $k$ columns | `*geqrf`, `*orgqr`/`*ungqr` |
+/// | `LQ`    | $A = LQ$      | lower, in the first $k$ columns | $k \times n$, in the first $k$ rows    | `*gelqf`, `*orglq`/`*unglq` |
+/// | `QL`    | $A = QL$      | lower, in the last $k$ rows     | $m \times k$, in the last $k$ columns  | `*geqlf`, `*orgql`/`*ungql` |
+/// | `RQ`    | $A = RQ$      | upper, in the last $k$ columns  | $k \times n$, in the last $k$ rows     | `*gerqf`, `*orgrq`/`*ungrq` |
+///
+/// The triangular factor of `QL` and `RQ` is aligned to the bottom-right corner of $A$,
+/// e.g. $L_{ij} = 0$ for $j > i + n - k$ in the last $k$ rows for `QL`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OrthogonalFactorization {
+    QR,
+    LQ,
+    QL,
+    RQ,
+}
+
+/// Working memory for the Householder reflection of [OrthogonalFactorization]
+///
+/// Only Fortran-order array is supported.
+/// [OrthogonalWorkImpl::calc] overwrites `a` by the triangular factor and the Householder reflectors,
+/// and returns their scalar factors.
+///
+/// Panics
+/// ------
+/// - If `layout` is not Fortran order
+///
+pub struct OrthogonalWork<T: Scalar> {
+    pub m: i32,
+    pub n: i32,
+    pub kind: OrthogonalFactorization,
+    pub tau: Vec<MaybeUninit<T>>,
+    pub work: Vec<MaybeUninit<T>>,
+}
+
+pub trait OrthogonalWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(kind: OrthogonalFactorization, layout: MatrixLayout) -> Result<Self>;
+    fn calc(&mut self, a: &mut [Self::Elem]) -> Result<&[Self::Elem]>;
+    fn eval(self, a: &mut [Self::Elem]) -> Result<Vec<Self::Elem>>;
+}
+
+macro_rules! impl_orthogonal_work {
+    ($s:ty, $qrf:path, $lqf:path, $qlf:path, $rqf:path) => {
+        impl OrthogonalWorkImpl for OrthogonalWork<$s> {
+            type Elem = $s;
+
+            fn new(kind: OrthogonalFactorization, layout: MatrixLayout) -> Result<Self> {
+                assert!(matches!(layout, MatrixLayout::F { .. }));
+                let (m, n) = layout.size();
+                let k = m.min(n);
+                let mut tau = vec_uninit(k as usize);
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                let f = match kind {
+                    OrthogonalFactorization::QR => $qrf,
+                    OrthogonalFactorization::LQ => $lqf,
+                    OrthogonalFactorization::QL => $qlf,
+                    OrthogonalFactorization::RQ => $rqf,
+                };
+                unsafe {
+                    f(
+                        &m,
+                        &n,
+                        std::ptr::null_mut(),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap().max(1);
+                let work = vec_uninit(lwork);
+                Ok(OrthogonalWork {
+                    m,
+                    n,
+                    kind,
+                    tau,
+                    work,
+                })
+            }
+
+            fn calc(&mut self, a: &mut [Self::Elem]) -> Result<&[Self::Elem]> {
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                let f = match self.kind {
+                    OrthogonalFactorization::QR => $qrf,
+                    OrthogonalFactorization::LQ => $lqf,
+                    OrthogonalFactorization::QL => $qlf,
+                    OrthogonalFactorization::RQ => $rqf,
+                };
+                unsafe {
+                    f(
+                        &self.m,
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.m.max(1),
+                        AsPtr::as_mut_ptr(&mut self.tau),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(unsafe { self.tau.slice_assume_init_ref() })
+            }
+
+            fn eval(mut self, a: &mut [Self::Elem]) -> Result<Vec<Self::Elem>> {
+                let _tau = self.calc(a)?;
+                Ok(unsafe { self.tau.assume_init() })
+            }
+        }
+    };
+}
+impl_orthogonal_work!(
+    c64,
+    lapack_sys::zgeqrf_,
+    lapack_sys::zgelqf_,
+    lapack_sys::zgeqlf_,
+    lapack_sys::zgerqf_
+);
+impl_orthogonal_work!(
+    c32,
+    lapack_sys::cgeqrf_,
+    lapack_sys::cgelqf_,
+    lapack_sys::cgeqlf_,
+    lapack_sys::cgerqf_
+);
+impl_orthogonal_work!(
+    f64,
+    lapack_sys::dgeqrf_,
+    lapack_sys::dgelqf_,
+    lapack_sys::dgeqlf_,
+    lapack_sys::dgerqf_
+);
+impl_orthogonal_work!(
+    f32,
+    lapack_sys::sgeqrf_,
+    lapack_sys::sgelqf_,
+    lapack_sys::sgeqlf_,
+    lapack_sys::sgerqf_
+);
+
+/// Working memory for reconstructing the orthogonal factor of [OrthogonalFactorization]
+///
+/// [OrthogonalQWorkImpl::calc] overwrites the part of `a` described in [OrthogonalFactorization]
+/// by the orthogonal factor, using the reflectors and `tau` computed by [OrthogonalWork].
+///
+/// Panics
+/// ------
+/// - If `layout` is not Fortran order
+///
+pub struct OrthogonalQWork<T: Scalar> {
+    pub kind: OrthogonalFactorization,
+    pub layout: MatrixLayout,
+    pub work: Vec<MaybeUninit<T>>,
+}
+
+pub trait OrthogonalQWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(kind: OrthogonalFactorization, layout: MatrixLayout) -> Result<Self>;
+    fn calc(&mut self, a: &mut [Self::Elem], tau: &[Self::Elem]) -> Result<()>;
+}
+
+/// Size `(rows, cols)` of the orthogonal factor and its offset in the Fortran-order array
+fn orthogonal_q_part(kind: OrthogonalFactorization, layout: MatrixLayout) -> (i32, i32, usize) {
+    let (m, n) = layout.size();
+    let k = m.min(n);
+    match kind {
+        OrthogonalFactorization::QR => (m, k, 0),
+        OrthogonalFactorization::LQ => (k, n, 0),
+        OrthogonalFactorization::QL => (m, k, ((n - k) * m) as usize),
+        OrthogonalFactorization::RQ => (k, n, (m - k) as usize),
+    }
+}
+
+macro_rules! impl_orthogonal_q_work {
+    ($s:ty, $gqr:path, $glq:path, $gql:path, $grq:path) => {
+        impl OrthogonalQWorkImpl for OrthogonalQWork<$s> {
+            type Elem = $s;
+
+            fn new(kind: OrthogonalFactorization, layout: MatrixLayout) -> Result<Self> {
+                assert!(matches!(layout, MatrixLayout::F { .. }));
+                let lda = layout.lda().max(1);
+                let (m, n, _) = orthogonal_q_part(kind, layout);
+                let k = m.min(n);
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                let f = match kind {
+                    OrthogonalFactorization::QR => $gqr,
+                    OrthogonalFactorization::LQ => $glq,
+                    OrthogonalFactorization::QL => $gql,
+                    OrthogonalFactorization::RQ => $grq,
+                };
+                unsafe {
+                    f(
+                        &m,
+                        &n,
+                        &k,
+                        std::ptr::null_mut(),
+                        &lda,
+                        std::ptr::null(),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap().max(1);
+                let work = vec_uninit(lwork);
+                Ok(OrthogonalQWork { kind, layout, work })
+            }
+
+            fn calc(&mut self, a: &mut [Self::Elem], tau: &[Self::Elem]) -> Result<()> {
+                let lda = self.layout.lda().max(1);
+                let (m, n, offset) = orthogonal_q_part(self.kind, self.layout);
+                let k = m.min(n);
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                let f = match self.kind {
+                    OrthogonalFactorization::QR => $gqr,
+                    OrthogonalFactorization::LQ => $glq,
+                    OrthogonalFactorization::QL => $gql,
+                    OrthogonalFactorization::RQ => $grq,
+                };
+                unsafe {
+                    f(
+                        &m,
+                        &n,
+                        &k,
+                        AsPtr::as_mut_ptr(&mut a[offset..]),
+                        &lda,
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+impl_orthogonal_q_work!(
+    c64,
+    lapack_sys::zungqr_,
+    lapack_sys::zunglq_,
+    lapack_sys::zungql_,
+    lapack_sys::zungrq_
+);
+impl_orthogonal_q_work!(
+    c32,
+    lapack_sys::cungqr_,
+    lapack_sys::cunglq_,
+    lapack_sys::cungql_,
+    lapack_sys::cungrq_
+);
+impl_orthogonal_q_work!(
+    f64,
+    lapack_sys::dorgqr_,
+    lapack_sys::dorglq_,
+    lapack_sys::dorgql_,
+    lapack_sys::dorgrq_
+);
+impl_orthogonal_q_work!(
+    f32,
+    lapack_sys::sorgqr_,
+    lapack_sys::sorglq_,
+    lapack_sys::sorgql_,
+    lapack_sys::sorgrq_
+);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Multiply a matrix by $Q$ of the QR decomposition without forming $Q$
+///
+/// `a` and `tau` are the $m \times k$ Householder reflectors and the scalar factors
+/// computed by [Lapack::householder] for a Fortran-order array of the layout `l`,
+/// and `c` is the Fortran-order array of the layout `lc`,
+/// which is overwritten by $\mathrm{op}(Q) C$ for [Side::Left] or $C \mathrm{op}(Q)$ for [Side::Right],
+/// where $\mathrm{op}$ is specified by `trans`.
+/// [Transpose::Hermite] is the same as [Transpose::Transpose] for real matrices,
+/// and complex matrices accept only [Transpose::No] and [Transpose::Hermite].
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | sormqr | dormqr | cunmqr | zunmqr |
+///
+/// Panics
+/// ------
+/// - If `l` or `lc` is not Fortran order, or the sizes of $Q$ and $C$ mismatch
+///
+pub trait ApplyQImpl: Scalar {
+    fn apply_q(
+        l: MatrixLayout,
+        side: Side,
+        trans: Transpose,
+        a: &[Self],
+        tau: &[Self],
+        lc: MatrixLayout,
+        c: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_apply_q {
+    ($s:ty, $mqr:path, $adjoint:expr) => {
+        impl ApplyQImpl for $s {
+            fn apply_q(
+                l: MatrixLayout,
+                side: Side,
+                trans: Transpose,
+                a: &[Self],
+                tau: &[Self],
+                lc: MatrixLayout,
+                c: &mut [Self],
+            ) -> Result<()> {
+                assert!(matches!(l, MatrixLayout::F { .. }));
+                assert!(matches!(lc, MatrixLayout::F { .. }));
+                let (m, n) = lc.size();
+                let nq = match side {
+                    Side::Left => m,
+                    Side::Right => n,
+                };
+                assert_eq!(l.lda(), nq);
+                let k = tau.len().to_i32().unwrap();
+                let trans = match trans {
+                    Transpose::Hermite => $adjoint,
+                    _ => trans,
+                };
+                let lda = nq.max(1);
+                let ldc = lc.lda().max(1);
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $mqr(
+                        side.as_ptr(),
+                        trans.as_ptr(),
+                        &m,
+                        &n,
+                        &k,
+                        AsPtr::as_ptr(a),
+                        &lda,
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(c),
+                        &ldc,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // apply reflectors
+                let lwork = work_size[0].to_usize().unwrap().max(1);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $mqr(
+                        side.as_ptr(),
+                        trans.as_ptr(),
+                        &m,
+                        &n,
+                        &k,
+                        AsPtr::as_ptr(a),
+                        &lda,
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(c),
+                        &ldc,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &lwork.to_i32().unwrap(),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+impl_apply_q!(c64, lapack_sys::zunmqr_, Transpose::Hermite);
+impl_apply_q!(c32, lapack_sys::cunmqr_, Transpose::Hermite);
+impl_apply_q!(f64, lapack_sys::dormqr_, Transpose::Transpose);
+impl_apply_q!(f32, lapack_sys::sormqr_, Transpose::Transpose);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Extract Householder reflectors from the output of [Lapack::householder]
+///
+/// Returns the pairs $(v_i, \tau_i)$ for $i = 1, \ldots, \min(m, n)$
+/// which represent the elementary reflectors $H_i = I - \tau_i v_i v_i^H$
+/// of the $m \times n$ matrix $A = H_1 \cdots H_k R$.
+/// $v_i$ has length $m$, its first $i - 1$ elements are zero and the $i$-th element is one.
+///
+/// For C-continuous array, [Lapack::householder] computes LQ-decomposition of $A^T$,
+/// and this returns the reflectors converted for $A$ itself.
+///
+/// Panics
+/// ------
+/// - If size of `a` and `l` size mismatch
+///
+pub fn householder_vectors<T: Scalar>(a: &[T], tau: &[T], l: MatrixLayout) -> Vec<(Vec<T>, T)> {
+    let (m, n) = l.size();
+    let (m, n) = (m as usize, n as usize);
+    let lda = l.lda() as usize;
+    assert_eq!(a.len(), m * n);
+    let k = m.min(n);
+    assert_eq!(tau.len(), k);
+    (0..k)
+        .map(|i| {
+            let mut v = vec![T::zero(); m];
+            v[i] = T::one();
+            for (r, v) in v.iter_mut().enumerate().skip(i + 1) {
+                *v = match l {
+                    MatrixLayout::F { .. } => a[i * lda + r],
+                    MatrixLayout::C { .. } => a[r * lda + i],
+                };
+            }
+            let tau = match l {
+                MatrixLayout::F { .. } => tau[i],
+                // `*gelqf` stores the reflectors of $A^T$ as conjugated ones
+                MatrixLayout::C { .. } => tau[i].conj(),
+            };
+            (v, tau)
+        })
+        .collect()
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Apply an elementary reflector $H = I - \tau v v^H$ to a vector $x$ in-place
+///
+/// ```rust
+/// # use lax::qr::*;
+/// // Reflect x = (3, 4) to (-5, 0) using v = (1, 1/2), tau = 8/5
+/// let mut x = vec![3.0, 4.0];
+/// apply_householder(&[1.0, 0.5], 1.6, &mut x);
+/// assert!((x[0] + 5.0_f64).abs() < 1e-12);
+/// assert!(x[1].abs() < 1e-12);
+/// ```
+///
+/// Panics
+/// ------
+/// - If the length of `v` and `x` mismatch
+///
+pub fn apply_householder<T: Scalar>(v: &[T], tau: T, x: &mut [T]) {
+    assert_eq!(v.len(), x.len());
+    let vx = v
+        .iter()
+        .zip(x.iter())
+        .fold(T::zero(), |acc, (v, x)| acc + v.conj() * *x);
+    let c = tau * vx;
+    for (x, v) in x.iter_mut().zip(v.iter()) {
+        *x -= c * *v;
+    }
+}
+
+/// Compute the Gram matrix $Q^H Q$ of the columns of a $m \times k$ matrix $Q$
+///
+/// The $k \times k$ result is stored in the same order as $Q$.
+///
+/// BLAS correspondance
+/// --------------------
+///
+/// | f32   | f64   | c32   | c64   |
+/// |:------|:------|:------|:------|
+/// | sgemm | dgemm | cgemm | zgemm |
+///
+pub trait GramImpl: Scalar {
+    fn gram(l: MatrixLayout, q: &[Self]) -> Vec<Self>;
+}
+
+macro_rules! impl_gram {
+    ($s:ty, $trans:ident, $gemm:path, $one:expr, $zero:expr) => {
+        impl GramImpl for $s {
+            fn gram(l: MatrixLayout, q: &[Self]) -> Vec<Self> {
+                use cblas_sys::{CBLAS_LAYOUT::*, CBLAS_TRANSPOSE::*};
+                let (m, k) = l.size();
+                let order = match l {
+                    MatrixLayout::C { .. } => CblasRowMajor,
+                    MatrixLayout::F { .. } => CblasColMajor,
+                };
+                let lda = l.lda();
+                let mut g = vec![Self::zero(); (k * k) as usize];
+                if k == 0 {
+                    return g;
+                }
+                unsafe {
+                    $gemm(
+                        order,
+                        $trans,
+                        CblasNoTrans,
+                        k,
+                        k,
+                        m,
+                        $one,
+                        q.as_ptr() as *const _,
+                        lda,
+                        q.as_ptr() as *const _,
+                        lda,
+                        $zero,
+                        g.as_mut_ptr() as *mut _,
+                        k,
+                    );
+                }
+                g
+            }
+        }
+    };
+}
+impl_gram!(f64, CblasTrans, cblas_sys::cblas_dgemm, 1.0, 0.0);
+impl_gram!(f32, CblasTrans, cblas_sys::cblas_sgemm, 1.0, 0.0);
+impl_gram!(
+    c64,
+    CblasConjTrans,
+    cblas_sys::cblas_zgemm,
+    &[1.0, 0.0],
+    &[0.0, 0.0]
+);
+impl_gram!(
+    c32,
+    CblasConjTrans,
+    cblas_sys::cblas_cgemm,
+    &[1.0, 0.0],
+    &[0.0, 0.0]
+);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Measure the loss of orthogonality $\| Q^H Q - I \|_\infty$ of the columns of a $m \times k$ matrix $Q$
+///
+/// $Q^H Q$ is computed by [Lapack::gram], i.e. `*gemm`.
+/// This is intended to check the factors computed by [Lapack::q] and other factorizations in tests,
+/// and is close to the machine epsilon for a properly computed $Q$.
+///
+/// ```rust
+/// use lax::{layout::MatrixLayout, qr::max_orthogonality_error, Lapack};
+///
+/// let l = MatrixLayout::F { col: 3, lda: 4 };
+/// let mut a = vec![
+///     1.0, 2.0, 3.0, 4.0,
+///     -1.0, 0.5, 2.0, 1.0,
+///     0.0, 3.0, -2.0, 1.0,
+/// ];
+/// let tau = f64::householder(l, &mut a).unwrap();
+/// f64::q(l, &mut a, &tau).unwrap();
+/// assert!(max_orthogonality_error(&a, l) < 10.0 * f64::EPSILON);
+///
+/// // Not orthonormal
+/// let b: Vec<f64> = vec![1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+/// assert!((max_orthogonality_error(&b, l) - 2.0).abs() < 1e-12);
+/// ```
+///
+/// Panics
+/// ------
+/// - If size of `q` and `l` size mismatch
+///
+pub fn max_orthogonality_error<T: Lapack>(q: &[T], l: MatrixLayout) -> T::Real {
+    let (m, k) = l.size();
+    assert_eq!(q.len(), (m * k) as usize);
+    // $Q^H Q - I$ in the same order as `q`
+    let mut g = T::gram(l, q);
+    for i in 0..k as usize {
+        g[i * k as usize + i] -= T::one();
+    }
+    T::opnorm(NormType::Infinity, l.resized(k, k), &g)
+}
@@ -0,0 +1,187 @@
+//! Iterative refinement of the solution of linear equations
+//!
+//! Given an approximate solution $x$ of $Ax = b$ obtained by the factorization of $A$,
+//! the residual $r = b - Ax$ is computed with the original matrix
+//! and $x$ is corrected by the solution of $A \delta = r$ using the same factorization.
+//! In addition, the componentwise relative backward error and an estimated bound of the forward error
+//! of the refined solution are returned.
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgerfs | dgerfs | cgerfs | zgerfs |
+//! | sporfs | dporfs | cporfs | zporfs |
+//!
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::Zero;
+
+/// Refine the solution $x$ of $\mathrm{op}(A) x = b$ using the LU decomposition of $A$
+///
+/// `af` and `ipiv` are the results of [Lapack::lu] for `a` in the same layout `l`,
+/// and `x` is overwritten by the refined solution.
+/// Returns the estimated forward error bound `max|x - x_true| / max|x|`
+/// and the componentwise relative backward error.
+pub trait RefineImpl: Scalar {
+    fn refine(
+        l: MatrixLayout,
+        t: Transpose,
+        a: &[Self],
+        af: &[Self],
+        ipiv: &Pivot,
+        b: &[Self],
+        x: &mut [Self],
+    ) -> Result<(Self::Real, Self::Real)>;
+}
+
+macro_rules! impl_refine {
+    ($s:ty, $rfs:path, $work_size:expr, $iwork:ty) => {
+        impl RefineImpl for $s {
+            fn refine(
+                l: MatrixLayout,
+                t: Transpose,
+                a: &[Self],
+                af: &[Self],
+                ipiv: &Pivot,
+                b: &[Self],
+                x: &mut [Self],
+            ) -> Result<(Self::Real, Self::Real)> {
+                // C-continuous `a` is regarded as the transposed F-continuous one, see [Lapack::solve]
+                let (t, conj) = match l {
+                    MatrixLayout::C { .. } => match t {
+                        Transpose::No => (Transpose::Transpose, false),
+                        Transpose::Transpose => (Transpose::No, false),
+                        Transpose::Hermite => (Transpose::No, true),
+                    },
+                    MatrixLayout::F { .. } => (t, false),
+                };
+                let n = l.len();
+                assert_eq!(b.len(), n as usize);
+                assert_eq!(x.len(), n as usize);
+                let mut b = b.to_vec();
+                if conj {
+                    conj_in_place(&mut b);
+                    conj_in_place(x);
+                }
+                let nrhs = 1;
+                let mut ferr = Self::Real::zero();
+                let mut berr = Self::Real::zero();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit($work_size * n as usize);
+                let mut iwork: Vec<MaybeUninit<$iwork>> = vec_uninit(n as usize);
+                let mut info = 0;
+                unsafe {
+                    $rfs(
+                        t.as_ptr(),
+                        &n,
+                        &nrhs,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_ptr(af),
+                        &l.lda(),
+                        ipiv.as_ptr(),
+                        AsPtr::as_ptr(&b),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(x),
+                        &n.max(1),
+                        &mut ferr,
+                        &mut berr,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if conj {
+                    conj_in_place(x);
+                }
+                Ok((ferr, berr))
+            }
+        }
+    };
+}
+impl_refine!(c64, lapack_sys::zgerfs_, 2, f64);
+impl_refine!(c32, lapack_sys::cgerfs_, 2, f32);
+impl_refine!(f64, lapack_sys::dgerfs_, 3, i32);
+impl_refine!(f32, lapack_sys::sgerfs_, 3, i32);
+
+/// Refine the solution $x$ of $Ax = b$ using the Cholesky decomposition
+/// of a Hermitian (or real symmetric) positive definite $A$
+///
+/// `af` is the result of [Lapack::cholesky] for `a` in the same layout `l` and `uplo`,
+/// and only the `uplo` part of `a` is referenced.
+/// Returns the estimated forward error bound and the componentwise relative backward error
+/// as [RefineImpl::refine].
+pub trait RefineCholeskyImpl: Scalar {
+    fn refine_cholesky(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &[Self],
+        af: &[Self],
+        b: &[Self],
+        x: &mut [Self],
+    ) -> Result<(Self::Real, Self::Real)>;
+}
+
+macro_rules! impl_refine_cholesky {
+    ($s:ty, $rfs:path, $work_size:expr, $iwork:ty) => {
+        impl RefineCholeskyImpl for $s {
+            fn refine_cholesky(
+                l: MatrixLayout,
+                mut uplo: UPLO,
+                a: &[Self],
+                af: &[Self],
+                b: &[Self],
+                x: &mut [Self],
+            ) -> Result<(Self::Real, Self::Real)> {
+                let n = l.len();
+                assert_eq!(b.len(), n as usize);
+                assert_eq!(x.len(), n as usize);
+                let mut b = b.to_vec();
+                // C-continuous `a` is the conjugate of the F-continuous one, see [Lapack::solve_cholesky]
+                if matches!(l, MatrixLayout::C { .. }) {
+                    uplo = uplo.t();
+                    conj_in_place(&mut b);
+                    conj_in_place(x);
+                }
+                let nrhs = 1;
+                let mut ferr = Self::Real::zero();
+                let mut berr = Self::Real::zero();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit($work_size * n as usize);
+                let mut iwork: Vec<MaybeUninit<$iwork>> = vec_uninit(n as usize);
+                let mut info = 0;
+                unsafe {
+                    $rfs(
+                        uplo.as_ptr(),
+                        &n,
+                        &nrhs,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_ptr(af),
+                        &l.lda(),
+                        AsPtr::as_ptr(&b),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(x),
+                        &n.max(1),
+                        &mut ferr,
+                        &mut berr,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if matches!(l, MatrixLayout::C { .. }) {
+                    conj_in_place(x);
+                }
+                Ok((ferr, berr))
+            }
+        }
+    };
+}
+impl_refine_cholesky!(c64, lapack_sys::zporfs_, 2, f64);
+impl_refine_cholesky!(c32, lapack_sys::cporfs_, 2, f32);
+impl_refine_cholesky!(f64, lapack_sys::dporfs_, 3, i32);
+impl_refine_cholesky!(f32, lapack_sys::sporfs_, 3, i32);
@@ -0,0 +1,782 @@
+//! Schur form of general matrices
+
+use super::*;
+use crate::{error::*, layout::*};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Compute the Schur decomposition $A = ZTZ^H$ of a general matrix
+///
+/// `a` is overwritten by the Schur form $T$, and the Schur vectors $Z$ are returned if `calc_z` is true.
+///
+/// For real matrices, $T$ is quasi-triangular and has $2 \times 2$ blocks
+/// for complex conjugate pairs of eigenvalues, and $Z$ is orthogonal.
+/// For complex matrices, $T$ is triangular.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32   | f64   | c32   | c64   |
+/// |:------|:------|:------|:------|
+/// | sgees | dgees | cgees | zgees |
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchurOwned<T: Scalar> {
+    /// Eigenvalues in the order of the diagonal of $T$
+    pub eigs: Vec<T::Complex>,
+    /// Schur vectors $Z$ in the same layout as the input
+    pub z: Option<Vec<T>>,
+}
+
+pub trait SchurImpl: Scalar {
+    fn schur(l: MatrixLayout, calc_z: bool, a: &mut [Self]) -> Result<SchurOwned<Self>>;
+}
+
+macro_rules! impl_schur_c {
+    ($s:ty, $gees:path) => {
+        impl SchurImpl for $s {
+            fn schur(l: MatrixLayout, calc_z: bool, a: &mut [Self]) -> Result<SchurOwned<Self>> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                let jobvs = if calc_z { b'V' } else { b'N' } as std::ffi::c_char;
+                let sort = b'N' as std::ffi::c_char;
+                let mut sdim = 0;
+                let mut w: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut z: Option<Vec<MaybeUninit<Self>>> =
+                    calc_z.then(|| vec_uninit((n * n) as usize));
+                let ldvs = if calc_z { n.max(1) } else { 1 };
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gees(
+                        &jobvs,
+                        &sort,
+                        None,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut w),
+                        AsPtr::as_mut_ptr(z.as_mut().map(|z| z.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldvs,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        // BWORK is not referenced without sorting
+                        std::ptr::null_mut(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc Schur form
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gees(
+                        &jobvs,
+                        &sort,
+                        None,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut w),
+                        AsPtr::as_mut_ptr(z.as_mut().map(|z| z.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldvs,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let mut z = z.map(|z| unsafe { z.assume_init() });
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                    if let Some(z) = z.as_mut() {
+                        square_transpose(l, z);
+                    }
+                }
+                Ok(SchurOwned {
+                    eigs: unsafe { w.assume_init() },
+                    z,
+                })
+            }
+        }
+    };
+}
+impl_schur_c!(c64, lapack_sys::zgees_);
+impl_schur_c!(c32, lapack_sys::cgees_);
+
+macro_rules! impl_schur_r {
+    ($s:ty, $gees:path) => {
+        impl SchurImpl for $s {
+            fn schur(l: MatrixLayout, calc_z: bool, a: &mut [Self]) -> Result<SchurOwned<Self>> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                let jobvs = if calc_z { b'V' } else { b'N' } as std::ffi::c_char;
+                let sort = b'N' as std::ffi::c_char;
+                let mut sdim = 0;
+                let mut wr: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut wi: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut z: Option<Vec<MaybeUninit<Self>>> =
+                    calc_z.then(|| vec_uninit((n * n) as usize));
+                let ldvs = if calc_z { n.max(1) } else { 1 };
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gees(
+                        &jobvs,
+                        &sort,
+                        None,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut wr),
+                        AsPtr::as_mut_ptr(&mut wi),
+                        AsPtr::as_mut_ptr(z.as_mut().map(|z| z.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldvs,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        // BWORK is not referenced without sorting
+                        std::ptr::null_mut(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc Schur form
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gees(
+                        &jobvs,
+                        &sort,
+                        None,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut wr),
+                        AsPtr::as_mut_ptr(&mut wi),
+                        AsPtr::as_mut_ptr(z.as_mut().map(|z| z.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldvs,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let wr = unsafe { wr.assume_init() };
+                let wi = unsafe { wi.assume_init() };
+                let eigs = wr
+                    .iter()
+                    .zip(wi.iter())
+                    .map(|(&re, &im)| Self::complex(re, im))
+                    .collect();
+                let mut z = z.map(|z| unsafe { z.assume_init() });
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                    if let Some(z) = z.as_mut() {
+                        square_transpose(l, z);
+                    }
+                }
+                Ok(SchurOwned { eigs, z })
+            }
+        }
+    };
+}
+impl_schur_r!(f64, lapack_sys::dgees_);
+impl_schur_r!(f32, lapack_sys::sgees_);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneralizedSchurOwned<T: Scalar> {
+    /// Numerators of the generalized eigenvalues `alpha[i] / beta[i]`
+    pub alpha: Vec<T::Complex>,
+    /// Denominators of the generalized eigenvalues, which are real and non-negative for real matrices
+    pub beta: Vec<T>,
+    /// Left Schur vectors $Q$ in the same layout as the input
+    pub q: Option<Vec<T>>,
+    /// Right Schur vectors $Z$ in the same layout as the input
+    pub z: Option<Vec<T>>,
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Compute the generalized Schur (QZ) decomposition $A = QSZ^H$, $B = QTZ^H$ of a matrix pencil $(A, B)$
+///
+/// `a` and `b` must have the same layout, and they are overwritten by $S$ and $T$, respectively.
+/// The left and right Schur vectors $Q$ and $Z$ are returned if requested.
+///
+/// For real matrices, $S$ is quasi-triangular with $2 \times 2$ blocks for complex conjugate pairs of
+/// generalized eigenvalues, and $T$ is upper triangular with non-negative diagonal.
+/// For complex matrices, both $S$ and $T$ are upper triangular.
+/// In either case, the generalized eigenvalues are `alpha[i] / beta[i]`,
+/// which may be infinite (`beta[i] == 0`) or undetermined (`alpha[i] == beta[i] == 0`) for singular pencils.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32   | f64   | c32   | c64   |
+/// |:------|:------|:------|:------|
+/// | sgges | dgges | cgges | zgges |
+///
+pub trait GeneralizedSchurImpl: Scalar {
+    fn generalized_schur(
+        l: MatrixLayout,
+        calc_q: bool,
+        calc_z: bool,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<GeneralizedSchurOwned<Self>>;
+}
+
+macro_rules! impl_generalized_schur_c {
+    ($s:ty, $gges:path) => {
+        impl GeneralizedSchurImpl for $s {
+            fn generalized_schur(
+                l: MatrixLayout,
+                calc_q: bool,
+                calc_z: bool,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<GeneralizedSchurOwned<Self>> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                    square_transpose(l, b);
+                }
+                let jobvsl = if calc_q { b'V' } else { b'N' } as std::ffi::c_char;
+                let jobvsr = if calc_z { b'V' } else { b'N' } as std::ffi::c_char;
+                let sort = b'N' as std::ffi::c_char;
+                let mut sdim = 0;
+                let mut alpha: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut beta: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut q: Option<Vec<MaybeUninit<Self>>> =
+                    calc_q.then(|| vec_uninit((n * n) as usize));
+                let mut z: Option<Vec<MaybeUninit<Self>>> =
+                    calc_z.then(|| vec_uninit((n * n) as usize));
+                let ldq = if calc_q { n.max(1) } else { 1 };
+                let ldz = if calc_z { n.max(1) } else { 1 };
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(8 * n as usize);
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gges(
+                        &jobvsl,
+                        &jobvsr,
+                        &sort,
+                        None,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        &n.max(1),
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut alpha),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(q.as_mut().map(|q| q.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldq,
+                        AsPtr::as_mut_ptr(z.as_mut().map(|z| z.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldz,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        // BWORK is not referenced without sorting
+                        std::ptr::null_mut(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc generalized Schur form
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gges(
+                        &jobvsl,
+                        &jobvsr,
+                        &sort,
+                        None,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        &n.max(1),
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut alpha),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(q.as_mut().map(|q| q.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldq,
+                        AsPtr::as_mut_ptr(z.as_mut().map(|z| z.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldz,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let mut q = q.map(|q| unsafe { q.assume_init() });
+                let mut z = z.map(|z| unsafe { z.assume_init() });
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                    square_transpose(l, b);
+                    if let Some(q) = q.as_mut() {
+                        square_transpose(l, q);
+                    }
+                    if let Some(z) = z.as_mut() {
+                        square_transpose(l, z);
+                    }
+                }
+                Ok(GeneralizedSchurOwned {
+                    alpha: unsafe { alpha.assume_init() },
+                    beta: unsafe { beta.assume_init() },
+                    q,
+                    z,
+                })
+            }
+        }
+    };
+}
+impl_generalized_schur_c!(c64, lapack_sys::zgges_);
+impl_generalized_schur_c!(c32, lapack_sys::cgges_);
+
+macro_rules! impl_generalized_schur_r {
+    ($s:ty, $gges:path) => {
+        impl GeneralizedSchurImpl for $s {
+            fn generalized_schur(
+                l: MatrixLayout,
+                calc_q: bool,
+                calc_z: bool,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<GeneralizedSchurOwned<Self>> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                    square_transpose(l, b);
+                }
+                let jobvsl = if calc_q { b'V' } else { b'N' } as std::ffi::c_char;
+                let jobvsr = if calc_z { b'V' } else { b'N' } as std::ffi::c_char;
+                let sort = b'N' as std::ffi::c_char;
+                let mut sdim = 0;
+                let mut alphar: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut alphai: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut beta: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut q: Option<Vec<MaybeUninit<Self>>> =
+                    calc_q.then(|| vec_uninit((n * n) as usize));
+                let mut z: Option<Vec<MaybeUninit<Self>>> =
+                    calc_z.then(|| vec_uninit((n * n) as usize));
+                let ldq = if calc_q { n.max(1) } else { 1 };
+                let ldz = if calc_z { n.max(1) } else { 1 };
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gges(
+                        &jobvsl,
+                        &jobvsr,
+                        &sort,
+                        None,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        &n.max(1),
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut alphar),
+                        AsPtr::as_mut_ptr(&mut alphai),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(q.as_mut().map(|q| q.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldq,
+                        AsPtr::as_mut_ptr(z.as_mut().map(|z| z.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldz,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        // BWORK is not referenced without sorting
+                        std::ptr::null_mut(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc generalized Schur form
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gges(
+                        &jobvsl,
+                        &jobvsr,
+                        &sort,
+                        None,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        &n.max(1),
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut alphar),
+                        AsPtr::as_mut_ptr(&mut alphai),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(q.as_mut().map(|q| q.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldq,
+                        AsPtr::as_mut_ptr(z.as_mut().map(|z| z.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldz,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let alphar = unsafe { alphar.assume_init() };
+                let alphai = unsafe { alphai.assume_init() };
+                let alpha = alphar
+                    .iter()
+                    .zip(alphai.iter())
+                    .map(|(&re, &im)| Self::complex(re, im))
+                    .collect();
+                let mut q = q.map(|q| unsafe { q.assume_init() });
+                let mut z = z.map(|z| unsafe { z.assume_init() });
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                    square_transpose(l, b);
+                    if let Some(q) = q.as_mut() {
+                        square_transpose(l, q);
+                    }
+                    if let Some(z) = z.as_mut() {
+                        square_transpose(l, z);
+                    }
+                }
+                Ok(GeneralizedSchurOwned {
+                    alpha,
+                    beta: unsafe { beta.assume_init() },
+                    q,
+                    z,
+                })
+            }
+        }
+    };
+}
+impl_generalized_schur_r!(f64, lapack_sys::dgges_);
+impl_generalized_schur_r!(f32, lapack_sys::sgges_);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Reorder the Schur form $A = ZTZ^H$ by moving a diagonal block of $T$
+///
+/// The diagonal block of $T$ starting at the row `ifst` is moved to the row `ilst`
+/// by a sequence of swaps of adjacent diagonal blocks, and the Schur vectors $Z$ are updated
+/// if given. Indices are 0-based.
+///
+/// For real matrices, $T$ is quasi-triangular and has $2 \times 2$ blocks
+/// for complex conjugate pairs of eigenvalues.
+/// If `ifst` points to the second row of a $2 \times 2$ block, it is adjusted to the first row,
+/// and `ilst` may be also adjusted so that a block is not split.
+/// The actual `(ifst, ilst)` are returned.
+/// For complex matrices, $T$ is triangular and `(ifst, ilst)` are returned as is.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | strexc | dtrexc | ctrexc | ztrexc |
+///
+pub trait ReorderSchurImpl: Scalar {
+    fn reorder_schur(
+        l: MatrixLayout,
+        t: &mut [Self],
+        z: Option<&mut [Self]>,
+        ifst: usize,
+        ilst: usize,
+    ) -> Result<(usize, usize)>;
+}
+
+macro_rules! impl_reorder_schur_c {
+    ($s:ty, $trexc:path) => {
+        impl ReorderSchurImpl for $s {
+            fn reorder_schur(
+                l: MatrixLayout,
+                t: &mut [Self],
+                mut z: Option<&mut [Self]>,
+                ifst: usize,
+                ilst: usize,
+            ) -> Result<(usize, usize)> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, t);
+                    if let Some(z) = z.as_mut() {
+                        square_transpose(l, z);
+                    }
+                }
+                let compq = if z.is_some() { b'V' } else { b'N' } as std::ffi::c_char;
+                let ifst_ = ifst as i32 + 1;
+                let ilst_ = ilst as i32 + 1;
+                let mut info = 0;
+                unsafe {
+                    $trexc(
+                        &compq,
+                        &n,
+                        AsPtr::as_mut_ptr(t),
+                        &n,
+                        AsPtr::as_mut_ptr(z.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        &ifst_,
+                        &ilst_,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, t);
+                    if let Some(z) = z.as_mut() {
+                        square_transpose(l, z);
+                    }
+                }
+                Ok((ifst, ilst))
+            }
+        }
+    };
+}
+impl_reorder_schur_c!(c64, lapack_sys::ztrexc_);
+impl_reorder_schur_c!(c32, lapack_sys::ctrexc_);
+
+macro_rules! impl_reorder_schur_r {
+    ($s:ty, $trexc:path) => {
+        impl ReorderSchurImpl for $s {
+            fn reorder_schur(
+                l: MatrixLayout,
+                t: &mut [Self],
+                mut z: Option<&mut [Self]>,
+                ifst: usize,
+                ilst: usize,
+            ) -> Result<(usize, usize)> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, t);
+                    if let Some(z) = z.as_mut() {
+                        square_transpose(l, z);
+                    }
+                }
+                let compq = if z.is_some() { b'V' } else { b'N' } as std::ffi::c_char;
+                let mut ifst_ = ifst as i32 + 1;
+                let mut ilst_ = ilst as i32 + 1;
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut info = 0;
+                unsafe {
+                    $trexc(
+                        &compq,
+                        &n,
+                        AsPtr::as_mut_ptr(t),
+                        &n,
+                        AsPtr::as_mut_ptr(z.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        &mut ifst_,
+                        &mut ilst_,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &mut info,
+                    );
+                }
+                // `info == 1` means that two adjacent blocks were too close to swap,
+                // in which case `t` and `z` may have been partially reordered.
+                info.as_lapack_result()?;
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, t);
+                    if let Some(z) = z.as_mut() {
+                        square_transpose(l, z);
+                    }
+                }
+                Ok((ifst_ as usize - 1, ilst_ as usize - 1))
+            }
+        }
+    };
+}
+impl_reorder_schur_r!(f64, lapack_sys::dtrexc_);
+impl_reorder_schur_r!(f32, lapack_sys::strexc_);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Reorder the generalized Schur form $A = QSZ^H$, $B = QTZ^H$ by moving a diagonal block of $(S, T)$
+///
+/// The diagonal block of $(S, T)$ starting at the row `ifst` is moved to the row `ilst`
+/// by a sequence of swaps of adjacent diagonal blocks, and the Schur vectors $Q$ and $Z$ are updated
+/// if given. Indices are 0-based. All matrices must have the same layout `l`.
+///
+/// For real matrices, $S$ may have $2 \times 2$ blocks, and `ifst` and `ilst` are adjusted
+/// in the same way as [ReorderSchurImpl]. The actual `(ifst, ilst)` are returned.
+///
+/// LAPACK correspondance
+/// ----------------------
+///
+/// | f32    | f64    | c32    | c64    |
+/// |:-------|:-------|:-------|:-------|
+/// | stgexc | dtgexc | ctgexc | ztgexc |
+///
+pub trait ReorderGeneralizedSchurImpl: Scalar {
+    fn reorder_generalized_schur(
+        l: MatrixLayout,
+        s: &mut [Self],
+        t: &mut [Self],
+        q: Option<&mut [Self]>,
+        z: Option<&mut [Self]>,
+        ifst: usize,
+        ilst: usize,
+    ) -> Result<(usize, usize)>;
+}
+
+macro_rules! impl_reorder_generalized_schur_c {
+    ($s:ty, $tgexc:path) => {
+        impl ReorderGeneralizedSchurImpl for $s {
+            fn reorder_generalized_schur(
+                l: MatrixLayout,
+                s: &mut [Self],
+                t: &mut [Self],
+                mut q: Option<&mut [Self]>,
+                mut z: Option<&mut [Self]>,
+                ifst: usize,
+                ilst: usize,
+            ) -> Result<(usize, usize)> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, s);
+                    square_transpose(l, t);
+                    if let Some(q) = q.as_mut() {
+                        square_transpose(l, q);
+                    }
+                    if let Some(z) = z.as_mut() {
+                        square_transpose(l, z);
+                    }
+                }
+                let wantq = q.is_some() as i32;
+                let wantz = z.is_some() as i32;
+                let ifst_ = ifst as i32 + 1;
+                let mut ilst_ = ilst as i32 + 1;
+                let mut info = 0;
+                unsafe {
+                    $tgexc(
+                        &wantq,
+                        &wantz,
+                        &n,
+                        AsPtr::as_mut_ptr(s),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(t),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(q.as_deref_mut().unwrap_or(&mut [])),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(z.as_deref_mut().unwrap_or(&mut [])),
+                        &n.max(1),
+                        &ifst_,
+                        &mut ilst_,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, s);
+                    square_transpose(l, t);
+                    if let Some(q) = q.as_mut() {
+                        square_transpose(l, q);
+                    }
+                    if let Some(z) = z.as_mut() {
+                        square_transpose(l, z);
+                    }
+                }
+                Ok((ifst, ilst_ as usize - 1))
+            }
+        }
+    };
+}
+impl_reorder_generalized_schur_c!(c64, lapack_sys::ztgexc_);
+impl_reorder_generalized_schur_c!(c32, lapack_sys::ctgexc_);
+
+macro_rules! impl_reorder_generalized_schur_r {
+    ($s:ty, $tgexc:path) => {
+        impl ReorderGeneralizedSchurImpl for $s {
+            fn reorder_generalized_schur(
+                l: MatrixLayout,
+                s: &mut [Self],
+                t: &mut [Self],
+                mut q: Option<&mut [Self]>,
+                mut z: Option<&mut [Self]>,
+                ifst: usize,
+                ilst: usize,
+            ) -> Result<(usize, usize)> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, s);
+                    square_transpose(l, t);
+                    if let Some(q) = q.as_mut() {
+                        square_transpose(l, q);
+                    }
+                    if let Some(z) = z.as_mut() {
+                        square_transpose(l, z);
+                    }
+                }
+                let wantq = q.is_some() as i32;
+                let wantz = z.is_some() as i32;
+                let mut ifst_ = ifst as i32 + 1;
+                let mut ilst_ = ilst as i32 + 1;
+                let lwork = if n <= 1 { 1 } else { 4 * n + 16 };
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork as usize);
+                let mut info = 0;
+                unsafe {
+                    $tgexc(
+                        &wantq,
+                        &wantz,
+                        &n,
+                        AsPtr::as_mut_ptr(s),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(t),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(q.as_deref_mut().unwrap_or(&mut [])),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(z.as_deref_mut().unwrap_or(&mut [])),
+                        &n.max(1),
+                        &mut ifst_,
+                        &mut ilst_,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &lwork,
+                        &mut info,
+                    );
+                }
+                // `info == 1` means that two adjacent blocks were too close to swap,
+                // in which case the matrices may have been partially reordered.
+                info.as_lapack_result()?;
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, s);
+                    square_transpose(l, t);
+                    if let Some(q) = q.as_mut() {
+                        square_transpose(l, q);
+                    }
+                    if let Some(z) = z.as_mut() {
+                        square_transpose(l, z);
+                    }
+                }
+                Ok((ifst_ as usize - 1, ilst_ as usize - 1))
+            }
+        }
+    };
+}
+impl_reorder_generalized_schur_r!(f64, lapack_sys::dtgexc_);
+impl_reorder_generalized_schur_r!(f32, lapack_sys::stgexc_);
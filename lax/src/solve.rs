@@ -113,9 +113,7 @@ macro_rules! impl_solve {
                 let ldb = l.lda();
                 let mut info = 0;
                 if conj {
-                    for b_elem in &mut *b {
-                        *b_elem = b_elem.conj();
-                    }
+                    conj_in_place(b);
                 }
                 unsafe {
                     $getrs(
@@ -131,9 +129,7 @@ macro_rules! impl_solve {
                     )
                 };
                 if conj {
-                    for b_elem in &mut *b {
-                        *b_elem = b_elem.conj();
-                    }
+                    conj_in_place(b);
                 }
                 info.as_lapack_result()?;
                 Ok(())
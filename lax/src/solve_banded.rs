@@ -0,0 +1,218 @@
+//! LU decomposition of general band matrices
+//!
+//! A `n x n` matrix $A$ with `kl` sub-diagonals and `ku` super-diagonals
+//! is stored in the `ldab x n` column-major array `ab` with `ldab = 2 kl + ku + 1`,
+//! where `ab[kl + ku + i - j + j * ldab]` is $A_{ij}$ for `max(0, j - ku) <= i <= min(n - 1, j + kl)`.
+//! The first `kl` rows are the workspace for the fill-in of the partial pivoting.
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgbtrf | dgbtrf | cgbtrf | zgbtrf |
+//! | sgbtrs | dgbtrs | cgbtrs | zgbtrs |
+//! | sgbcon | dgbcon | cgbcon | zgbcon |
+//!
+
+use crate::{error::*, *};
+use cauchy::*;
+use num_traits::Zero;
+
+/// Compute LU decomposition of a band matrix with partial pivoting
+///
+/// `ab` is overwritten by $U$ with `kl + ku` super-diagonals and the multipliers of $L$,
+/// see the module document for the storage.
+/// Returns [crate::error::Error::LapackComputationalFailure] if $U$ is exactly singular.
+pub trait LuBandedImpl: Scalar {
+    fn lu_banded(n: i32, kl: i32, ku: i32, ab: &mut [Self]) -> Result<Pivot>;
+}
+
+macro_rules! impl_lu_banded {
+    ($s:ty, $trf:path) => {
+        impl LuBandedImpl for $s {
+            fn lu_banded(n: i32, kl: i32, ku: i32, ab: &mut [Self]) -> Result<Pivot> {
+                let ldab = 2 * kl + ku + 1;
+                assert_eq!(ab.len(), (ldab * n) as usize);
+                let mut ipiv = vec_uninit(n as usize);
+                let mut info = 0;
+                unsafe {
+                    $trf(
+                        &n,
+                        &n,
+                        &kl,
+                        &ku,
+                        AsPtr::as_mut_ptr(ab),
+                        &ldab.max(1),
+                        AsPtr::as_mut_ptr(&mut ipiv),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let ipiv = unsafe { ipiv.assume_init() };
+                Ok(ipiv)
+            }
+        }
+    };
+}
+impl_lu_banded!(c64, lapack_sys::zgbtrf_);
+impl_lu_banded!(c32, lapack_sys::cgbtrf_);
+impl_lu_banded!(f64, lapack_sys::dgbtrf_);
+impl_lu_banded!(f32, lapack_sys::sgbtrf_);
+
+/// Solve linear equation $\mathrm{op}(A) x = b$ using the LU decomposition of a band matrix
+///
+/// `ab` and `ipiv` are the results of [LuBandedImpl::lu_banded] with the same `kl` and `ku`.
+pub trait SolveBandedImpl: Scalar {
+    fn solve_banded(
+        t: Transpose,
+        n: i32,
+        kl: i32,
+        ku: i32,
+        ab: &[Self],
+        ipiv: &Pivot,
+        b: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_solve_banded {
+    ($s:ty, $trs:path) => {
+        impl SolveBandedImpl for $s {
+            fn solve_banded(
+                t: Transpose,
+                n: i32,
+                kl: i32,
+                ku: i32,
+                ab: &[Self],
+                ipiv: &Pivot,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let ldab = 2 * kl + ku + 1;
+                assert_eq!(ab.len(), (ldab * n) as usize);
+                assert_eq!(b.len(), n as usize);
+                let nrhs = 1;
+                let mut info = 0;
+                unsafe {
+                    $trs(
+                        t.as_ptr(),
+                        &n,
+                        &kl,
+                        &ku,
+                        &nrhs,
+                        AsPtr::as_ptr(ab),
+                        &ldab.max(1),
+                        ipiv.as_ptr(),
+                        AsPtr::as_mut_ptr(b),
+                        &n.max(1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+        }
+    };
+}
+impl_solve_banded!(c64, lapack_sys::zgbtrs_);
+impl_solve_banded!(c32, lapack_sys::cgbtrs_);
+impl_solve_banded!(f64, lapack_sys::dgbtrs_);
+impl_solve_banded!(f32, lapack_sys::sgbtrs_);
+
+/// Estimate the reciprocal of the condition number in 1-norm using the LU decomposition of a band matrix
+///
+/// `ab` and `ipiv` are the results of [LuBandedImpl::lu_banded] with the same `kl` and `ku`,
+/// and `anorm` should be the 1-norm of the original matrix.
+pub trait RcondBandedImpl: Scalar {
+    fn rcond_banded(
+        n: i32,
+        kl: i32,
+        ku: i32,
+        ab: &[Self],
+        ipiv: &Pivot,
+        anorm: Self::Real,
+    ) -> Result<Self::Real>;
+}
+
+macro_rules! impl_rcond_banded_c {
+    ($c:ty, $con:path) => {
+        impl RcondBandedImpl for $c {
+            fn rcond_banded(
+                n: i32,
+                kl: i32,
+                ku: i32,
+                ab: &[Self],
+                ipiv: &Pivot,
+                anorm: Self::Real,
+            ) -> Result<Self::Real> {
+                let ldab = 2 * kl + ku + 1;
+                assert_eq!(ab.len(), (ldab * n) as usize);
+                let mut rcond = Self::Real::zero();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(2 * n as usize);
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                let mut info = 0;
+                unsafe {
+                    $con(
+                        NormType::One.as_ptr(),
+                        &n,
+                        &kl,
+                        &ku,
+                        AsPtr::as_ptr(ab),
+                        &ldab.max(1),
+                        ipiv.as_ptr(),
+                        &anorm,
+                        &mut rcond,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
+        }
+    };
+}
+impl_rcond_banded_c!(c64, lapack_sys::zgbcon_);
+impl_rcond_banded_c!(c32, lapack_sys::cgbcon_);
+
+macro_rules! impl_rcond_banded_r {
+    ($r:ty, $con:path) => {
+        impl RcondBandedImpl for $r {
+            fn rcond_banded(
+                n: i32,
+                kl: i32,
+                ku: i32,
+                ab: &[Self],
+                ipiv: &Pivot,
+                anorm: Self::Real,
+            ) -> Result<Self::Real> {
+                let ldab = 2 * kl + ku + 1;
+                assert_eq!(ab.len(), (ldab * n) as usize);
+                let mut rcond = Self::Real::zero();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(3 * n as usize);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(n as usize);
+                let mut info = 0;
+                unsafe {
+                    $con(
+                        NormType::One.as_ptr(),
+                        &n,
+                        &kl,
+                        &ku,
+                        AsPtr::as_ptr(ab),
+                        &ldab.max(1),
+                        ipiv.as_ptr(),
+                        &anorm,
+                        &mut rcond,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
+        }
+    };
+}
+impl_rcond_banded_r!(f64, lapack_sys::dgbcon_);
+impl_rcond_banded_r!(f32, lapack_sys::sgbcon_);
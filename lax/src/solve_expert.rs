@@ -0,0 +1,453 @@
+//! Solve linear equations with the expert driver
+//!
+//! The expert driver equilibrates the matrix if necessary, computes its LU decomposition,
+//! solves the system, and then improves the solution by iterative refinement.
+//! In addition to the solution, it returns the reciprocal condition number
+//! and error bounds of the solution.
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgesvx | dgesvx | cgesvx | zgesvx |
+//! | sposvx | dposvx | cposvx | zposvx |
+//!
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::Zero;
+use std::ffi::c_char;
+
+/// Result of [SolveExpertWorkImpl::eval]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolveExpertOwned<A: Scalar> {
+    /// Solution of the linear equation
+    pub x: Vec<A>,
+    /// Estimate of the reciprocal condition number of the (equilibrated) matrix
+    pub rcond: A::Real,
+    /// Estimated forward error bound, i.e. relative error of `x` in max-norm
+    pub ferr: A::Real,
+    /// Componentwise relative backward error
+    pub berr: A::Real,
+    /// `true` if the matrix has been equilibrated before factorization
+    pub equilibrated: bool,
+}
+
+/// Result of [SolveExpertWorkImpl::calc]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolveExpertRef<'work, A: Scalar> {
+    /// Solution of the linear equation
+    pub x: &'work [A],
+    /// Estimate of the reciprocal condition number of the (equilibrated) matrix
+    pub rcond: A::Real,
+    /// Estimated forward error bound, i.e. relative error of `x` in max-norm
+    pub ferr: A::Real,
+    /// Componentwise relative backward error
+    pub berr: A::Real,
+    /// `true` if the matrix has been equilibrated before factorization
+    pub equilibrated: bool,
+}
+
+/// Working memory for the expert driver of linear equations
+pub struct SolveExpertWork<T: Scalar> {
+    pub layout: MatrixLayout,
+    /// Factors $L$ and $U$ of the (equilibrated) matrix
+    pub af: Vec<MaybeUninit<T>>,
+    pub ipiv: Vec<MaybeUninit<i32>>,
+    /// Row scale factors
+    pub r: Vec<MaybeUninit<T::Real>>,
+    /// Column scale factors
+    pub c: Vec<MaybeUninit<T::Real>>,
+    pub x: Vec<MaybeUninit<T>>,
+    pub work: Vec<MaybeUninit<T>>,
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+    pub iwork: Option<Vec<MaybeUninit<i32>>>,
+}
+
+pub trait SolveExpertWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(layout: MatrixLayout) -> Self;
+    /// Solve $Ax = b$. Both `a` and `b` are overwritten by its equilibrated version.
+    fn calc(
+        &mut self,
+        a: &mut [Self::Elem],
+        b: &mut [Self::Elem],
+    ) -> Result<SolveExpertRef<'_, Self::Elem>>;
+    fn eval(
+        self,
+        a: &mut [Self::Elem],
+        b: &mut [Self::Elem],
+    ) -> Result<SolveExpertOwned<Self::Elem>>;
+}
+
+macro_rules! impl_solve_expert_work_c {
+    ($s:ty, $gesvx:path) => {
+        impl SolveExpertWorkImpl for SolveExpertWork<$s> {
+            type Elem = $s;
+
+            fn new(layout: MatrixLayout) -> Self {
+                let (n, _) = layout.size();
+                let n = n as usize;
+                SolveExpertWork {
+                    layout,
+                    af: vec_uninit(n * n),
+                    ipiv: vec_uninit(n),
+                    r: vec_uninit(n),
+                    c: vec_uninit(n),
+                    x: vec_uninit(n),
+                    work: vec_uninit(2 * n),
+                    rwork: Some(vec_uninit(2 * n)),
+                    iwork: None,
+                }
+            }
+
+            fn calc(
+                &mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<SolveExpertRef<'_, Self::Elem>> {
+                let (n, _) = self.layout.size();
+                assert_eq!(a.len() as i32, n * n);
+                assert_eq!(b.len() as i32, n);
+                // C-continuous array is regarded as a transposed matrix in Fortran layout
+                let trans = match self.layout {
+                    MatrixLayout::C { .. } => Transpose::Transpose,
+                    MatrixLayout::F { .. } => Transpose::No,
+                };
+                let fact = b'E' as c_char;
+                let mut equed = b'N' as c_char;
+                let nrhs = 1;
+                let mut rcond = <Self::Elem as Scalar>::Real::zero();
+                let mut ferr = <Self::Elem as Scalar>::Real::zero();
+                let mut berr = <Self::Elem as Scalar>::Real::zero();
+                let mut info = 0;
+                if n > 0 {
+                    unsafe {
+                        $gesvx(
+                            &fact,
+                            trans.as_ptr(),
+                            &n,
+                            &nrhs,
+                            AsPtr::as_mut_ptr(a),
+                            &n,
+                            AsPtr::as_mut_ptr(&mut self.af),
+                            &n,
+                            AsPtr::as_mut_ptr(&mut self.ipiv),
+                            &mut equed,
+                            AsPtr::as_mut_ptr(&mut self.r),
+                            AsPtr::as_mut_ptr(&mut self.c),
+                            AsPtr::as_mut_ptr(b),
+                            &n,
+                            AsPtr::as_mut_ptr(&mut self.x),
+                            &n,
+                            &mut rcond,
+                            &mut ferr,
+                            &mut berr,
+                            AsPtr::as_mut_ptr(&mut self.work),
+                            AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                            &mut info,
+                        )
+                    };
+                }
+                // `info == n + 1` means that the matrix is singular to working precision,
+                // but the solution and the error bounds are still computed.
+                if info != n + 1 {
+                    info.as_lapack_result()?;
+                }
+                Ok(SolveExpertRef {
+                    x: unsafe { self.x.slice_assume_init_ref() },
+                    rcond,
+                    ferr,
+                    berr,
+                    equilibrated: equed != b'N' as c_char,
+                })
+            }
+
+            fn eval(
+                mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<SolveExpertOwned<Self::Elem>> {
+                let SolveExpertRef {
+                    rcond,
+                    ferr,
+                    berr,
+                    equilibrated,
+                    ..
+                } = self.calc(a, b)?;
+                Ok(SolveExpertOwned {
+                    x: unsafe { self.x.assume_init() },
+                    rcond,
+                    ferr,
+                    berr,
+                    equilibrated,
+                })
+            }
+        }
+    };
+}
+impl_solve_expert_work_c!(c64, lapack_sys::zgesvx_);
+impl_solve_expert_work_c!(c32, lapack_sys::cgesvx_);
+
+macro_rules! impl_solve_expert_work_r {
+    ($s:ty, $gesvx:path) => {
+        impl SolveExpertWorkImpl for SolveExpertWork<$s> {
+            type Elem = $s;
+
+            fn new(layout: MatrixLayout) -> Self {
+                let (n, _) = layout.size();
+                let n = n as usize;
+                SolveExpertWork {
+                    layout,
+                    af: vec_uninit(n * n),
+                    ipiv: vec_uninit(n),
+                    r: vec_uninit(n),
+                    c: vec_uninit(n),
+                    x: vec_uninit(n),
+                    work: vec_uninit(4 * n),
+                    rwork: None,
+                    iwork: Some(vec_uninit(n)),
+                }
+            }
+
+            fn calc(
+                &mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<SolveExpertRef<'_, Self::Elem>> {
+                let (n, _) = self.layout.size();
+                assert_eq!(a.len() as i32, n * n);
+                assert_eq!(b.len() as i32, n);
+                // C-continuous array is regarded as a transposed matrix in Fortran layout
+                let trans = match self.layout {
+                    MatrixLayout::C { .. } => Transpose::Transpose,
+                    MatrixLayout::F { .. } => Transpose::No,
+                };
+                let fact = b'E' as c_char;
+                let mut equed = b'N' as c_char;
+                let nrhs = 1;
+                let mut rcond = <Self::Elem as Scalar>::Real::zero();
+                let mut ferr = <Self::Elem as Scalar>::Real::zero();
+                let mut berr = <Self::Elem as Scalar>::Real::zero();
+                let mut info = 0;
+                if n > 0 {
+                    unsafe {
+                        $gesvx(
+                            &fact,
+                            trans.as_ptr(),
+                            &n,
+                            &nrhs,
+                            AsPtr::as_mut_ptr(a),
+                            &n,
+                            AsPtr::as_mut_ptr(&mut self.af),
+                            &n,
+                            AsPtr::as_mut_ptr(&mut self.ipiv),
+                            &mut equed,
+                            AsPtr::as_mut_ptr(&mut self.r),
+                            AsPtr::as_mut_ptr(&mut self.c),
+                            AsPtr::as_mut_ptr(b),
+                            &n,
+                            AsPtr::as_mut_ptr(&mut self.x),
+                            &n,
+                            &mut rcond,
+                            &mut ferr,
+                            &mut berr,
+                            AsPtr::as_mut_ptr(&mut self.work),
+                            AsPtr::as_mut_ptr(self.iwork.as_mut().unwrap()),
+                            &mut info,
+                        )
+                    };
+                }
+                // `info == n + 1` means that the matrix is singular to working precision,
+                // but the solution and the error bounds are still computed.
+                if info != n + 1 {
+                    info.as_lapack_result()?;
+                }
+                Ok(SolveExpertRef {
+                    x: unsafe { self.x.slice_assume_init_ref() },
+                    rcond,
+                    ferr,
+                    berr,
+                    equilibrated: equed != b'N' as c_char,
+                })
+            }
+
+            fn eval(
+                mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+            ) -> Result<SolveExpertOwned<Self::Elem>> {
+                let SolveExpertRef {
+                    rcond,
+                    ferr,
+                    berr,
+                    equilibrated,
+                    ..
+                } = self.calc(a, b)?;
+                Ok(SolveExpertOwned {
+                    x: unsafe { self.x.assume_init() },
+                    rcond,
+                    ferr,
+                    berr,
+                    equilibrated,
+                })
+            }
+        }
+    };
+}
+impl_solve_expert_work_r!(f64, lapack_sys::dgesvx_);
+impl_solve_expert_work_r!(f32, lapack_sys::sgesvx_);
+
+/// Solve $Ax = b$ for a Hermitian (or real symmetric) positive definite matrix
+/// with the expert driver using the Cholesky decomposition
+///
+/// `a` is the `n x n` matrix in column-major order whose `uplo` part is referenced,
+/// and `a` and `b` are overwritten by the equilibrated ones.
+/// Returns [crate::error::Error::LapackComputationalFailure] if the matrix is not positive definite.
+pub trait SolveCholeskyExpertImpl: Scalar {
+    fn solvec_expert(
+        uplo: UPLO,
+        n: i32,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<SolveExpertOwned<Self>>;
+}
+
+macro_rules! impl_solvec_expert_c {
+    ($s:ty, $posvx:path) => {
+        impl SolveCholeskyExpertImpl for $s {
+            fn solvec_expert(
+                uplo: UPLO,
+                n: i32,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<SolveExpertOwned<Self>> {
+                assert_eq!(a.len() as i32, n * n);
+                assert_eq!(b.len() as i32, n);
+                let n_ = n as usize;
+                let fact = b'E' as c_char;
+                let mut equed = b'N' as c_char;
+                let nrhs = 1;
+                let mut af: Vec<MaybeUninit<Self>> = vec_uninit(n_ * n_);
+                let mut s: Vec<MaybeUninit<Self::Real>> = vec_uninit(n_);
+                let mut x: Vec<MaybeUninit<Self>> = vec_uninit(n_);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(2 * n_);
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(n_);
+                let mut rcond = Self::Real::zero();
+                let mut ferr = Self::Real::zero();
+                let mut berr = Self::Real::zero();
+                let mut info = 0;
+                if n > 0 {
+                    unsafe {
+                        $posvx(
+                            &fact,
+                            uplo.as_ptr(),
+                            &n,
+                            &nrhs,
+                            AsPtr::as_mut_ptr(a),
+                            &n,
+                            AsPtr::as_mut_ptr(&mut af),
+                            &n,
+                            &mut equed,
+                            AsPtr::as_mut_ptr(&mut s),
+                            AsPtr::as_mut_ptr(b),
+                            &n,
+                            AsPtr::as_mut_ptr(&mut x),
+                            &n,
+                            &mut rcond,
+                            &mut ferr,
+                            &mut berr,
+                            AsPtr::as_mut_ptr(&mut work),
+                            AsPtr::as_mut_ptr(&mut rwork),
+                            &mut info,
+                        )
+                    };
+                }
+                // `info == n + 1` means that the matrix is singular to working precision,
+                // but the solution and the error bounds are still computed.
+                if info != n + 1 {
+                    info.as_lapack_result()?;
+                }
+                Ok(SolveExpertOwned {
+                    x: unsafe { x.assume_init() },
+                    rcond,
+                    ferr,
+                    berr,
+                    equilibrated: equed != b'N' as c_char,
+                })
+            }
+        }
+    };
+}
+impl_solvec_expert_c!(c64, lapack_sys::zposvx_);
+impl_solvec_expert_c!(c32, lapack_sys::cposvx_);
+
+macro_rules! impl_solvec_expert_r {
+    ($s:ty, $posvx:path) => {
+        impl SolveCholeskyExpertImpl for $s {
+            fn solvec_expert(
+                uplo: UPLO,
+                n: i32,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<SolveExpertOwned<Self>> {
+                assert_eq!(a.len() as i32, n * n);
+                assert_eq!(b.len() as i32, n);
+                let n_ = n as usize;
+                let fact = b'E' as c_char;
+                let mut equed = b'N' as c_char;
+                let nrhs = 1;
+                let mut af: Vec<MaybeUninit<Self>> = vec_uninit(n_ * n_);
+                let mut s: Vec<MaybeUninit<Self::Real>> = vec_uninit(n_);
+                let mut x: Vec<MaybeUninit<Self>> = vec_uninit(n_);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(3 * n_);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(n_);
+                let mut rcond = Self::Real::zero();
+                let mut ferr = Self::Real::zero();
+                let mut berr = Self::Real::zero();
+                let mut info = 0;
+                if n > 0 {
+                    unsafe {
+                        $posvx(
+                            &fact,
+                            uplo.as_ptr(),
+                            &n,
+                            &nrhs,
+                            AsPtr::as_mut_ptr(a),
+                            &n,
+                            AsPtr::as_mut_ptr(&mut af),
+                            &n,
+                            &mut equed,
+                            AsPtr::as_mut_ptr(&mut s),
+                            AsPtr::as_mut_ptr(b),
+                            &n,
+                            AsPtr::as_mut_ptr(&mut x),
+                            &n,
+                            &mut rcond,
+                            &mut ferr,
+                            &mut berr,
+                            AsPtr::as_mut_ptr(&mut work),
+                            AsPtr::as_mut_ptr(&mut iwork),
+                            &mut info,
+                        )
+                    };
+                }
+                // `info == n + 1` means that the matrix is singular to working precision,
+                // but the solution and the error bounds are still computed.
+                if info != n + 1 {
+                    info.as_lapack_result()?;
+                }
+                Ok(SolveExpertOwned {
+                    x: unsafe { x.assume_init() },
+                    rcond,
+                    ferr,
+                    berr,
+                    equilibrated: equed != b'N' as c_char,
+                })
+            }
+        }
+    };
+}
+impl_solvec_expert_r!(f64, lapack_sys::dposvx_);
+impl_solvec_expert_r!(f32, lapack_sys::sposvx_);
@@ -0,0 +1,99 @@
+//! Solve linear equations with mixed precision iterative refinement
+//!
+//! The matrix is factorized in single precision, which is about twice as fast as in double precision,
+//! and the solution is refined to the double precision accuracy by iterative refinement.
+//! If the refinement does not converge, e.g. for ill-conditioned matrices,
+//! the system is solved again in double precision.
+//!
+//! These drivers exist only for the double precision types, and thus this is not a part of [Lapack].
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f64    | c64    |
+//! |:-------|:-------|
+//! | dsgesv | zcgesv |
+//!
+
+use crate::{error::*, *};
+use cauchy::*;
+
+/// Solve $Ax = b$ for a `n x n` matrix in the column-major order
+///
+/// Returns the solution and the number of the refinement iterations `iter`,
+/// where the negative `iter` means that the system has been solved in double precision,
+/// and then `a` is overwritten by its LU decomposition.
+/// Returns [crate::error::Error::LapackComputationalFailure] if $A$ is exactly singular.
+pub trait SolveMixedPrecisionImpl: Scalar {
+    fn solve_mixed_precision(n: i32, a: &mut [Self], b: &[Self]) -> Result<(Vec<Self>, i32)>;
+}
+
+impl SolveMixedPrecisionImpl for f64 {
+    fn solve_mixed_precision(n: i32, a: &mut [Self], b: &[Self]) -> Result<(Vec<Self>, i32)> {
+        assert_eq!(a.len(), (n * n) as usize);
+        assert_eq!(b.len(), n as usize);
+        let nrhs = 1;
+        let mut ipiv: Vec<MaybeUninit<i32>> = vec_uninit(n as usize);
+        let mut x: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+        let mut work: Vec<MaybeUninit<f64>> = vec_uninit(n as usize);
+        let mut swork: Vec<MaybeUninit<f32>> = vec_uninit((n * (n + 1)) as usize);
+        let mut iter = 0;
+        let mut info = 0;
+        unsafe {
+            lapack_sys::dsgesv_(
+                &n,
+                &nrhs,
+                AsPtr::as_mut_ptr(a),
+                &n.max(1),
+                AsPtr::as_mut_ptr(&mut ipiv),
+                AsPtr::as_ptr(b),
+                &n.max(1),
+                AsPtr::as_mut_ptr(&mut x),
+                &n.max(1),
+                AsPtr::as_mut_ptr(&mut work),
+                AsPtr::as_mut_ptr(&mut swork),
+                &mut iter,
+                &mut info,
+            );
+        }
+        info.as_lapack_result()?;
+        let x = unsafe { x.assume_init() };
+        Ok((x, iter))
+    }
+}
+
+impl SolveMixedPrecisionImpl for c64 {
+    fn solve_mixed_precision(n: i32, a: &mut [Self], b: &[Self]) -> Result<(Vec<Self>, i32)> {
+        assert_eq!(a.len(), (n * n) as usize);
+        assert_eq!(b.len(), n as usize);
+        let nrhs = 1;
+        let mut ipiv: Vec<MaybeUninit<i32>> = vec_uninit(n as usize);
+        let mut x: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+        let mut work: Vec<MaybeUninit<c64>> = vec_uninit(n as usize);
+        let mut swork: Vec<MaybeUninit<c32>> = vec_uninit((n * (n + 1)) as usize);
+        let mut rwork: Vec<MaybeUninit<f64>> = vec_uninit(n as usize);
+        let mut iter = 0;
+        let mut info = 0;
+        unsafe {
+            lapack_sys::zcgesv_(
+                &n,
+                &nrhs,
+                AsPtr::as_mut_ptr(a),
+                &n.max(1),
+                AsPtr::as_mut_ptr(&mut ipiv),
+                AsPtr::as_ptr(b),
+                &n.max(1),
+                AsPtr::as_mut_ptr(&mut x),
+                &n.max(1),
+                AsPtr::as_mut_ptr(&mut work),
+                AsPtr::as_mut_ptr(&mut swork),
+                AsPtr::as_mut_ptr(&mut rwork),
+                &mut iter,
+                &mut info,
+            );
+        }
+        info.as_lapack_result()?;
+        let x = unsafe { x.assume_init() };
+        Ok((x, iter))
+    }
+}
@@ -39,41 +39,56 @@ pub struct SvdOwned<T: Scalar> {
 
 pub trait SvdWorkImpl: Sized {
     type Elem: Scalar;
-    fn new(layout: MatrixLayout, calc_u: bool, calc_vt: bool) -> Result<Self>;
+    /// Prepare for SVD computing all or none of the columns of $U$ and rows of $V^T$
+    fn new(layout: MatrixLayout, calc_u: bool, calc_vt: bool) -> Result<Self> {
+        Self::new_with_job(
+            layout,
+            JobSvd::from_bool(calc_u),
+            JobSvd::from_bool(calc_vt),
+        )
+    }
+    /// Prepare for SVD with the job for $U$ and $V^T$ specified separately
+    ///
+    /// With [JobSvd::Some], $U$ is $m \times k$ and $V^T$ is $k \times n$ for $k = \min(m, n)$.
+    fn new_with_job(layout: MatrixLayout, job_u: JobSvd, job_vt: JobSvd) -> Result<Self>;
     fn calc(&mut self, a: &mut [Self::Elem]) -> Result<SvdRef<Self::Elem>>;
     fn eval(self, a: &mut [Self::Elem]) -> Result<SvdOwned<Self::Elem>>;
 }
 
+/// Leading dimension of $V^T$ in column-major order
+fn ldvt(jvt: JobSvd, m: i32, n: i32) -> i32 {
+    match jvt {
+        JobSvd::Some => std::cmp::max(std::cmp::min(m, n), 1),
+        JobSvd::All | JobSvd::None => n,
+    }
+}
+
 macro_rules! impl_svd_work_c {
     ($s:ty, $svd:path) => {
         impl SvdWorkImpl for SvdWork<$s> {
             type Elem = $s;
 
-            fn new(layout: MatrixLayout, calc_u: bool, calc_vt: bool) -> Result<Self> {
-                let ju = match layout {
-                    MatrixLayout::F { .. } => JobSvd::from_bool(calc_u),
-                    MatrixLayout::C { .. } => JobSvd::from_bool(calc_vt),
-                };
-                let jvt = match layout {
-                    MatrixLayout::F { .. } => JobSvd::from_bool(calc_vt),
-                    MatrixLayout::C { .. } => JobSvd::from_bool(calc_u),
+            fn new_with_job(layout: MatrixLayout, job_u: JobSvd, job_vt: JobSvd) -> Result<Self> {
+                let (ju, jvt) = match layout {
+                    MatrixLayout::F { .. } => (job_u, job_vt),
+                    MatrixLayout::C { .. } => (job_vt, job_u),
                 };
 
                 let m = layout.lda();
+                let n = layout.len();
+                let k = std::cmp::min(m, n);
                 let mut u = match ju {
                     JobSvd::All => Some(vec_uninit((m * m) as usize)),
+                    JobSvd::Some => Some(vec_uninit((m * k) as usize)),
                     JobSvd::None => None,
-                    _ => unimplemented!("SVD with partial vector output is not supported yet"),
                 };
-
-                let n = layout.len();
                 let mut vt = match jvt {
                     JobSvd::All => Some(vec_uninit((n * n) as usize)),
+                    JobSvd::Some => Some(vec_uninit((k * n) as usize)),
                     JobSvd::None => None,
-                    _ => unimplemented!("SVD with partial vector output is not supported yet"),
                 };
+                let ldvt = ldvt(jvt, m, n);
 
-                let k = std::cmp::min(m, n);
                 let mut s = vec_uninit(k as usize);
                 let mut rwork = vec_uninit(5 * k as usize);
 
@@ -92,7 +107,7 @@ macro_rules! impl_svd_work_c {
                         AsPtr::as_mut_ptr(u.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
                         &m,
                         AsPtr::as_mut_ptr(vt.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
-                        &n,
+                        &ldvt,
                         AsPtr::as_mut_ptr(&mut work_size),
                         &(-1),
                         AsPtr::as_mut_ptr(&mut rwork),
@@ -139,7 +154,7 @@ macro_rules! impl_svd_work_c {
                                 .map(|x| x.as_mut_slice())
                                 .unwrap_or(&mut []),
                         ),
-                        &n,
+                        &ldvt(self.jvt, m, n),
                         AsPtr::as_mut_ptr(&mut self.work),
                         &(lwork as i32),
                         AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
@@ -185,31 +200,27 @@ macro_rules! impl_svd_work_r {
         impl SvdWorkImpl for SvdWork<$s> {
             type Elem = $s;
 
-            fn new(layout: MatrixLayout, calc_u: bool, calc_vt: bool) -> Result<Self> {
-                let ju = match layout {
-                    MatrixLayout::F { .. } => JobSvd::from_bool(calc_u),
-                    MatrixLayout::C { .. } => JobSvd::from_bool(calc_vt),
-                };
-                let jvt = match layout {
-                    MatrixLayout::F { .. } => JobSvd::from_bool(calc_vt),
-                    MatrixLayout::C { .. } => JobSvd::from_bool(calc_u),
+            fn new_with_job(layout: MatrixLayout, job_u: JobSvd, job_vt: JobSvd) -> Result<Self> {
+                let (ju, jvt) = match layout {
+                    MatrixLayout::F { .. } => (job_u, job_vt),
+                    MatrixLayout::C { .. } => (job_vt, job_u),
                 };
 
                 let m = layout.lda();
+                let n = layout.len();
+                let k = std::cmp::min(m, n);
                 let mut u = match ju {
                     JobSvd::All => Some(vec_uninit((m * m) as usize)),
+                    JobSvd::Some => Some(vec_uninit((m * k) as usize)),
                     JobSvd::None => None,
-                    _ => unimplemented!("SVD with partial vector output is not supported yet"),
                 };
-
-                let n = layout.len();
                 let mut vt = match jvt {
                     JobSvd::All => Some(vec_uninit((n * n) as usize)),
+                    JobSvd::Some => Some(vec_uninit((k * n) as usize)),
                     JobSvd::None => None,
-                    _ => unimplemented!("SVD with partial vector output is not supported yet"),
                 };
+                let ldvt = ldvt(jvt, m, n);
 
-                let k = std::cmp::min(m, n);
                 let mut s = vec_uninit(k as usize);
 
                 // eval work size
@@ -227,7 +238,7 @@ macro_rules! impl_svd_work_r {
                         AsPtr::as_mut_ptr(u.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
                         &m,
                         AsPtr::as_mut_ptr(vt.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
-                        &n,
+                        &ldvt,
                         AsPtr::as_mut_ptr(&mut work_size),
                         &(-1),
                         &mut info,
@@ -273,7 +284,7 @@ macro_rules! impl_svd_work_r {
                                 .map(|x| x.as_mut_slice())
                                 .unwrap_or(&mut []),
                         ),
-                        &n,
+                        &ldvt(self.jvt, m, n),
                         AsPtr::as_mut_ptr(&mut self.work),
                         &(lwork as i32),
                         &mut info,
@@ -312,3 +323,78 @@ macro_rules! impl_svd_work_r {
 }
 impl_svd_work_r!(f64, lapack_sys::dgesvd_);
 impl_svd_work_r!(f32, lapack_sys::sgesvd_);
+
+/// LAPACK driver used to compute SVD
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SvdDriver {
+    /// QR iteration (`*gesvd`), see [Lapack::svd]
+    Gesvd,
+    /// Divide-and-conquer algorithm (`*gesdd`), see [Lapack::svddc]
+    Gesdd,
+}
+
+impl SvdDriver {
+    /// Minimum of `min(m, n)` above which [SvdDriver::select] chooses [SvdDriver::Gesdd]
+    pub const GESDD_MIN_SIZE: i32 = 100;
+
+    /// Select the driver for a `m x n` matrix
+    ///
+    /// `*gesdd` is much faster than `*gesvd` for large matrices when singular vectors are requested,
+    /// but it always computes both $U$ and $V^T$ and requires larger working memory.
+    /// Thus [SvdDriver::Gesdd] is selected only when both $U$ and $V^T$ are requested
+    /// and `min(m, n) > GESDD_MIN_SIZE`. Otherwise [SvdDriver::Gesvd] is selected.
+    ///
+    /// ```
+    /// use lax::{layout::MatrixLayout, svd::SvdDriver};
+    ///
+    /// let l = MatrixLayout::F { col: 200, lda: 300 };
+    /// assert_eq!(SvdDriver::select(l, true, true), SvdDriver::Gesdd);
+    /// assert_eq!(SvdDriver::select(l, true, false), SvdDriver::Gesvd);
+    /// let l = MatrixLayout::C { row: 300, lda: 50 };
+    /// assert_eq!(SvdDriver::select(l, true, true), SvdDriver::Gesvd);
+    /// ```
+    pub fn select(l: MatrixLayout, calc_u: bool, calc_vt: bool) -> Self {
+        let (m, n) = l.size();
+        if calc_u && calc_vt && m.min(n) > Self::GESDD_MIN_SIZE {
+            SvdDriver::Gesdd
+        } else {
+            SvdDriver::Gesvd
+        }
+    }
+}
+
+/// Compute SVD using the driver selected by [SvdDriver::select]
+///
+/// The output is same as [Lapack::svd], i.e. $U$ and $V^T$ are square matrices if requested.
+pub fn svd_auto<A: Lapack>(
+    l: MatrixLayout,
+    calc_u: bool,
+    calc_vt: bool,
+    a: &mut [A],
+) -> Result<SvdOwned<A>> {
+    svd_with_driver(SvdDriver::select(l, calc_u, calc_vt), l, calc_u, calc_vt, a)
+}
+
+/// Compute SVD using the specified driver
+///
+/// Since `*gesdd` cannot compute only one of $U$ and $V^T$,
+/// both are computed and the unrequested one is dropped in this case.
+pub fn svd_with_driver<A: Lapack>(
+    driver: SvdDriver,
+    l: MatrixLayout,
+    calc_u: bool,
+    calc_vt: bool,
+    a: &mut [A],
+) -> Result<SvdOwned<A>> {
+    match driver {
+        SvdDriver::Gesvd => A::svd(l, calc_u, calc_vt, a),
+        SvdDriver::Gesdd => {
+            let SvdOwned { s, u, vt } = A::svddc(l, JobSvd::from_bool(calc_u || calc_vt), a)?;
+            Ok(SvdOwned {
+                s,
+                u: u.filter(|_| calc_u),
+                vt: vt.filter(|_| calc_vt),
+            })
+        }
+    }
+}
@@ -0,0 +1,161 @@
+//! Singular value decomposition by one-sided Jacobi method
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgesvj | dgesvj | cgesvj | zgesvj |
+//!
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Compute SVD $A = U \Sigma V^H$ by one-sided Jacobi method
+///
+/// Unlike `*gesvd` and `*gesdd`, which reduce $A$ into bidiagonal form,
+/// the Jacobi method computes small singular values with high relative accuracy
+/// if $A = BD$ for a well-conditioned $B$ and a diagonal $D$, e.g. badly column-scaled matrices.
+/// It is slower than them in general.
+///
+/// `a` must be a $m \times n$ matrix with $m \ge n$ in column-major order.
+/// Otherwise [Error::InvalidShape] is returned.
+/// $U$ is $m \times n$, and $V^H$ is $n \times n$, both in column-major order.
+/// The singular values are sorted in descending order.
+pub trait SvdJacobiImpl: Scalar {
+    fn svd_jacobi(
+        l: MatrixLayout,
+        calc_u: bool,
+        calc_vt: bool,
+        a: &mut [Self],
+    ) -> Result<SvdOwned<Self>>;
+}
+
+macro_rules! impl_svd_jacobi_c {
+    ($s:ty, $gesvj:path) => {
+        impl SvdJacobiImpl for $s {
+            fn svd_jacobi(
+                l: MatrixLayout,
+                calc_u: bool,
+                calc_vt: bool,
+                a: &mut [Self],
+            ) -> Result<SvdOwned<Self>> {
+                let (m, n) = match l {
+                    MatrixLayout::F { col, lda } if lda >= col => (lda, col),
+                    _ => return Err(Error::InvalidShape),
+                };
+                let joba = b'G' as std::ffi::c_char;
+                let jobu = if calc_u { b'U' } else { b'N' } as std::ffi::c_char;
+                let jobv = if calc_vt { b'V' } else { b'N' } as std::ffi::c_char;
+                let mut s: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                let mut v: Option<Vec<MaybeUninit<Self>>> =
+                    calc_vt.then(|| vec_uninit((n * n) as usize));
+                let lwork = m + n;
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork as usize);
+                let lrwork = std::cmp::max(6, n);
+                let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(lrwork as usize);
+                let mut info = 0;
+                unsafe {
+                    $gesvj(
+                        &joba,
+                        &jobu,
+                        &jobv,
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        AsPtr::as_mut_ptr(&mut s),
+                        &0,
+                        AsPtr::as_mut_ptr(v.as_mut().map(|v| v.as_mut_slice()).unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        &lrwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                // The singular values are `rwork[0] * s` to avoid overflow
+                let scale = unsafe { rwork[0].assume_init() };
+                let s = unsafe { s.assume_init() };
+                Ok(SvdOwned {
+                    s: s.into_iter().map(|s| scale * s).collect(),
+                    u: calc_u.then(|| a.to_vec()),
+                    vt: v.map(|v| conjugate_transpose(n, &unsafe { v.assume_init() })),
+                })
+            }
+        }
+    };
+}
+impl_svd_jacobi_c!(c64, lapack_sys::zgesvj_);
+impl_svd_jacobi_c!(c32, lapack_sys::cgesvj_);
+
+macro_rules! impl_svd_jacobi_r {
+    ($s:ty, $gesvj:path) => {
+        impl SvdJacobiImpl for $s {
+            fn svd_jacobi(
+                l: MatrixLayout,
+                calc_u: bool,
+                calc_vt: bool,
+                a: &mut [Self],
+            ) -> Result<SvdOwned<Self>> {
+                let (m, n) = match l {
+                    MatrixLayout::F { col, lda } if lda >= col => (lda, col),
+                    _ => return Err(Error::InvalidShape),
+                };
+                let joba = b'G' as std::ffi::c_char;
+                let jobu = if calc_u { b'U' } else { b'N' } as std::ffi::c_char;
+                let jobv = if calc_vt { b'V' } else { b'N' } as std::ffi::c_char;
+                let mut s: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+                let mut v: Option<Vec<MaybeUninit<Self>>> =
+                    calc_vt.then(|| vec_uninit((n * n) as usize));
+                let lwork = std::cmp::max(6, m + n);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork as usize);
+                let mut info = 0;
+                unsafe {
+                    $gesvj(
+                        &joba,
+                        &jobu,
+                        &jobv,
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        AsPtr::as_mut_ptr(&mut s),
+                        &0,
+                        AsPtr::as_mut_ptr(v.as_mut().map(|v| v.as_mut_slice()).unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &lwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                // The singular values are `work[0] * s` to avoid overflow
+                let scale = unsafe { work[0].assume_init() };
+                let s = unsafe { s.assume_init() };
+                Ok(SvdOwned {
+                    s: s.into_iter().map(|s| scale * s).collect(),
+                    u: calc_u.then(|| a.to_vec()),
+                    vt: v.map(|v| conjugate_transpose(n, &unsafe { v.assume_init() })),
+                })
+            }
+        }
+    };
+}
+impl_svd_jacobi_r!(f64, lapack_sys::dgesvj_);
+impl_svd_jacobi_r!(f32, lapack_sys::sgesvj_);
+
+/// Conjugate transpose of `n x n` column-major matrix
+fn conjugate_transpose<T: Scalar>(n: i32, v: &[T]) -> Vec<T> {
+    let n = n as usize;
+    let mut vt = vec![T::zero(); n * n];
+    for i in 0..n {
+        for j in 0..n {
+            vt[i + j * n] = v[j + i * n].conj();
+        }
+    }
+    vt
+}
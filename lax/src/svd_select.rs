@@ -0,0 +1,319 @@
+//! Singular value decomposition for a subset of singular values
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32     | f64     | c32     | c64     |
+//! |:--------|:--------|:--------|:--------|
+//! | sgesvdx | dgesvdx | cgesvdx | zgesvdx |
+//!
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+use std::ops::Range;
+
+/// Subset of singular values to be computed by [Lapack::svd_select]
+#[derive(Debug, Clone, PartialEq)]
+pub enum SingularRange<T> {
+    /// All singular values
+    All,
+    /// Singular values in the half-open interval `(lower, upper]`
+    Values { lower: T, upper: T },
+    /// Singular values of the indices in the range,
+    /// where the singular values are indexed from zero in descending order.
+    /// The range must be non-empty and its end must not exceed `min(m, n)`.
+    Indices(Range<usize>),
+}
+
+impl<T> SingularRange<T> {
+    /// `RANGE` argument of `*gesvdx`
+    fn job(&self) -> std::ffi::c_char {
+        (match self {
+            SingularRange::All => b'A',
+            SingularRange::Values { .. } => b'V',
+            SingularRange::Indices(_) => b'I',
+        }) as std::ffi::c_char
+    }
+
+    /// Maximum number of the singular values in the range
+    fn max_len(&self, k: i32) -> i32 {
+        match self {
+            SingularRange::Indices(r) => r.len() as i32,
+            _ => k,
+        }
+    }
+
+    /// One-based `(il, iu)`, which are not referenced unless [SingularRange::Indices]
+    fn indices(&self) -> (i32, i32) {
+        match self {
+            SingularRange::Indices(r) => (r.start as i32 + 1, r.end as i32),
+            _ => (0, 0),
+        }
+    }
+}
+
+impl<T: Zero + Copy> SingularRange<T> {
+    /// `(vl, vu)`, which are not referenced unless [SingularRange::Values]
+    fn values(&self) -> (T, T) {
+        match self {
+            SingularRange::Values { lower, upper } => (*lower, *upper),
+            _ => (T::zero(), T::zero()),
+        }
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Compute a subset of singular values, and corresponding singular vectors if requested
+///
+/// For a $m \times n$ matrix and $n_s$ singular values found in the range,
+/// the singular values are returned in descending order,
+/// $U$ is $m \times n_s$, and $V^T$ is $n_s \times n$, both in the same layout as `a`.
+pub trait SvdSelectImpl: Scalar {
+    fn svd_select(
+        l: MatrixLayout,
+        calc_u: bool,
+        calc_vt: bool,
+        range: &SingularRange<Self::Real>,
+        a: &mut [Self],
+    ) -> Result<SvdOwned<Self>>;
+}
+
+/// Pack `ns x n` matrix in column-major order stored with the leading dimension `ld` into contiguous memory
+fn pack<T: Copy>(ns: usize, n: usize, ld: usize, a: &[MaybeUninit<T>]) -> Vec<MaybeUninit<T>> {
+    (0..n)
+        .flat_map(|j| a[j * ld..j * ld + ns].iter().cloned())
+        .collect()
+}
+
+macro_rules! impl_svd_select_c {
+    ($s:ty, $gesvdx:path) => {
+        impl SvdSelectImpl for $s {
+            fn svd_select(
+                l: MatrixLayout,
+                calc_u: bool,
+                calc_vt: bool,
+                range: &SingularRange<Self::Real>,
+                a: &mut [Self],
+            ) -> Result<SvdOwned<Self>> {
+                let (calc_u, calc_vt) = match l {
+                    MatrixLayout::F { .. } => (calc_u, calc_vt),
+                    MatrixLayout::C { .. } => (calc_vt, calc_u),
+                };
+                let jobu = if calc_u { b'V' } else { b'N' } as std::ffi::c_char;
+                let jobvt = if calc_vt { b'V' } else { b'N' } as std::ffi::c_char;
+                let m = l.lda();
+                let n = l.len();
+                let k = std::cmp::min(m, n);
+                let ucol = range.max_len(k);
+                let (vl, vu) = range.values();
+                let (il, iu) = range.indices();
+                let job_range = range.job();
+
+                let mut ns = 0;
+                let mut s: Vec<MaybeUninit<Self::Real>> = vec_uninit(k as usize);
+                let mut u: Option<Vec<MaybeUninit<Self>>> =
+                    calc_u.then(|| vec_uninit((m * ucol) as usize));
+                let mut vt: Option<Vec<MaybeUninit<Self>>> =
+                    calc_vt.then(|| vec_uninit((ucol * n) as usize));
+                let ldvt = std::cmp::max(ucol, 1);
+                let mut rwork: Vec<MaybeUninit<Self::Real>> =
+                    vec_uninit((k * (k * 2 + 15 * k)) as usize);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(12 * k as usize);
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gesvdx(
+                        &jobu,
+                        &jobvt,
+                        &job_range,
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &mut ns,
+                        AsPtr::as_mut_ptr(&mut s),
+                        AsPtr::as_mut_ptr(u.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
+                        &m,
+                        AsPtr::as_mut_ptr(vt.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldvt,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // do SVD
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gesvdx(
+                        &jobu,
+                        &jobvt,
+                        &job_range,
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &mut ns,
+                        AsPtr::as_mut_ptr(&mut s),
+                        AsPtr::as_mut_ptr(u.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
+                        &m,
+                        AsPtr::as_mut_ptr(vt.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldvt,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // Only the first `ns` singular values and vectors are found
+                let ns = ns as usize;
+                let (m, n) = (m as usize, n as usize);
+                s.truncate(ns);
+                let s = unsafe { s.assume_init() };
+                let u = u.map(|mut u| {
+                    u.truncate(m * ns);
+                    unsafe { u.assume_init() }
+                });
+                let vt = vt.map(|vt| unsafe { pack(ns, n, ldvt as usize, &vt).assume_init() });
+                match l {
+                    MatrixLayout::F { .. } => Ok(SvdOwned { s, u, vt }),
+                    MatrixLayout::C { .. } => Ok(SvdOwned { s, u: vt, vt: u }),
+                }
+            }
+        }
+    };
+}
+impl_svd_select_c!(c64, lapack_sys::zgesvdx_);
+impl_svd_select_c!(c32, lapack_sys::cgesvdx_);
+
+macro_rules! impl_svd_select_r {
+    ($s:ty, $gesvdx:path) => {
+        impl SvdSelectImpl for $s {
+            fn svd_select(
+                l: MatrixLayout,
+                calc_u: bool,
+                calc_vt: bool,
+                range: &SingularRange<Self::Real>,
+                a: &mut [Self],
+            ) -> Result<SvdOwned<Self>> {
+                let (calc_u, calc_vt) = match l {
+                    MatrixLayout::F { .. } => (calc_u, calc_vt),
+                    MatrixLayout::C { .. } => (calc_vt, calc_u),
+                };
+                let jobu = if calc_u { b'V' } else { b'N' } as std::ffi::c_char;
+                let jobvt = if calc_vt { b'V' } else { b'N' } as std::ffi::c_char;
+                let m = l.lda();
+                let n = l.len();
+                let k = std::cmp::min(m, n);
+                let ucol = range.max_len(k);
+                let (vl, vu) = range.values();
+                let (il, iu) = range.indices();
+                let job_range = range.job();
+
+                let mut ns = 0;
+                let mut s: Vec<MaybeUninit<Self::Real>> = vec_uninit(k as usize);
+                let mut u: Option<Vec<MaybeUninit<Self>>> =
+                    calc_u.then(|| vec_uninit((m * ucol) as usize));
+                let mut vt: Option<Vec<MaybeUninit<Self>>> =
+                    calc_vt.then(|| vec_uninit((ucol * n) as usize));
+                let ldvt = std::cmp::max(ucol, 1);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(12 * k as usize);
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gesvdx(
+                        &jobu,
+                        &jobvt,
+                        &job_range,
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &mut ns,
+                        AsPtr::as_mut_ptr(&mut s),
+                        AsPtr::as_mut_ptr(u.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
+                        &m,
+                        AsPtr::as_mut_ptr(vt.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldvt,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // do SVD
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gesvdx(
+                        &jobu,
+                        &jobvt,
+                        &job_range,
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &mut ns,
+                        AsPtr::as_mut_ptr(&mut s),
+                        AsPtr::as_mut_ptr(u.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
+                        &m,
+                        AsPtr::as_mut_ptr(vt.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldvt,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // Only the first `ns` singular values and vectors are found
+                let ns = ns as usize;
+                let (m, n) = (m as usize, n as usize);
+                s.truncate(ns);
+                let s = unsafe { s.assume_init() };
+                let u = u.map(|mut u| {
+                    u.truncate(m * ns);
+                    unsafe { u.assume_init() }
+                });
+                let vt = vt.map(|vt| unsafe { pack(ns, n, ldvt as usize, &vt).assume_init() });
+                match l {
+                    MatrixLayout::F { .. } => Ok(SvdOwned { s, u, vt }),
+                    MatrixLayout::C { .. } => Ok(SvdOwned { s, u: vt, vt: u }),
+                }
+            }
+        }
+    };
+}
+impl_svd_select_r!(f64, lapack_sys::dgesvdx_);
+impl_svd_select_r!(f32, lapack_sys::sgesvdx_);
@@ -0,0 +1,101 @@
+//! Sylvester equation for matrices in Schur form
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | strsyl | dtrsyl | ctrsyl | ztrsyl |
+//!
+
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::Zero;
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Solve the Sylvester equation $\mathrm{op}(A) X + s X \mathrm{op}(B) = \alpha C$
+///
+/// - $A$ is $m \times m$ and $B$ is $n \times n$, both in Schur form,
+///   i.e. upper triangular for complex matrices and quasi-triangular with $2 \times 2$ blocks
+///   of the standard form computed by [crate::Lapack::schur] for real matrices.
+/// - $\mathrm{op}$ is specified by `trana` and `tranb`.
+///   Complex matrices accept only [Transpose::No] and [Transpose::Hermite].
+/// - $s$ is given by `isgn` which must be `1` or `-1`.
+/// - `c` is the $m \times n$ matrix of the layout `l`, and `a` and `b` must be stored in the same order.
+///   C-continuous arrays are copied into Fortran order internally.
+///
+/// `c` is overwritten by $X$, and the scaling factor $\alpha \le 1$ chosen to avoid overflow is returned.
+/// Returns [crate::error::Error::LapackComputationalFailure] with `return_code: 1`
+/// if $\mathrm{op}(A)$ and $-s\,\mathrm{op}(B)$ have common or very close eigenvalues,
+/// i.e. the solution is not unique or ill-conditioned.
+pub trait SylvesterImpl: Scalar {
+    fn sylvester(
+        l: MatrixLayout,
+        trana: Transpose,
+        tranb: Transpose,
+        isgn: i32,
+        a: &[Self],
+        b: &[Self],
+        c: &mut [Self],
+    ) -> Result<Self::Real>;
+}
+
+macro_rules! impl_sylvester {
+    ($s:ty, $trsyl:path) => {
+        impl SylvesterImpl for $s {
+            fn sylvester(
+                l: MatrixLayout,
+                trana: Transpose,
+                tranb: Transpose,
+                isgn: i32,
+                a: &[Self],
+                b: &[Self],
+                c: &mut [Self],
+            ) -> Result<Self::Real> {
+                let (m, n) = l.size();
+                // LAPACK requires Fortran order, and the triangular structure is lost
+                // by viewing C-continuous arrays as the transposed ones
+                let (a_t, b_t, mut c_t) = match l {
+                    MatrixLayout::F { .. } => (Vec::new(), Vec::new(), Vec::new()),
+                    MatrixLayout::C { .. } => (
+                        transpose(MatrixLayout::C { row: m, lda: m }, a).1,
+                        transpose(MatrixLayout::C { row: n, lda: n }, b).1,
+                        transpose(l, c).1,
+                    ),
+                };
+                let (a, b, c_f): (&[Self], &[Self], &mut [Self]) = match l {
+                    MatrixLayout::F { .. } => (a, b, &mut *c),
+                    MatrixLayout::C { .. } => (&a_t, &b_t, &mut c_t),
+                };
+                let mut scale = Self::Real::zero();
+                let mut info = 0;
+                unsafe {
+                    $trsyl(
+                        trana.as_ptr(),
+                        tranb.as_ptr(),
+                        &isgn,
+                        &m,
+                        &n,
+                        AsPtr::as_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_ptr(b),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(c_f),
+                        &m.max(1),
+                        &mut scale,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if matches!(l, MatrixLayout::C { .. }) {
+                    transpose_over(MatrixLayout::F { col: n, lda: m }, &c_t, c);
+                }
+                Ok(scale)
+            }
+        }
+    };
+}
+impl_sylvester!(c64, lapack_sys::ztrsyl_);
+impl_sylvester!(c32, lapack_sys::ctrsyl_);
+impl_sylvester!(f64, lapack_sys::dtrsyl_);
+impl_sylvester!(f32, lapack_sys::strsyl_);
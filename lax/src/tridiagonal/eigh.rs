@@ -0,0 +1,278 @@
+use crate::*;
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+use std::ops::Range;
+
+/// Working memory for eigenvalue problem of real symmetric tridiagonal matrix
+///
+/// This is implemented only for real types,
+/// since a Hermitian tridiagonal matrix can be transformed into a real symmetric one
+/// by a diagonal unitary matrix.
+pub struct EighTridiagonalWork<T: Scalar> {
+    /// Problem size
+    pub n: i32,
+    /// Compute eigenvectors or not
+    pub jobz: JobEv,
+    /// Off-diagonal elements, which are destroyed by LAPACK
+    pub e: Vec<MaybeUninit<T>>,
+    /// Eigenvectors in column-major order
+    pub z: Option<Vec<MaybeUninit<T>>>,
+    pub work: Vec<MaybeUninit<T>>,
+    pub iwork: Vec<MaybeUninit<i32>>,
+}
+
+pub trait EighTridiagonalWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(calc_eigenvectors: bool, n: i32) -> Result<Self>;
+    /// Compute eigenvalues of the tridiagonal matrix
+    /// given by the diagonal `d` and the off-diagonal `e` elements.
+    ///
+    /// Eigenvalues are stored into `d` in ascending order,
+    /// and the eigenvectors are returned if they are requested.
+    fn calc(&mut self, d: &mut [Self::Elem], e: &[Self::Elem]) -> Result<Option<&[Self::Elem]>>;
+    fn eval(self, d: &mut [Self::Elem], e: &[Self::Elem]) -> Result<Option<Vec<Self::Elem>>>;
+}
+
+macro_rules! impl_eigh_tridiagonal_work_r {
+    ($f:ty, $stevd:path) => {
+        impl EighTridiagonalWorkImpl for EighTridiagonalWork<$f> {
+            type Elem = $f;
+
+            fn new(calc_eigenvectors: bool, n: i32) -> Result<Self> {
+                let jobz = if calc_eigenvectors {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let ldz = std::cmp::max(n, 1);
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $stevd(
+                        jobz.as_ptr(),
+                        &n,
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                        &ldz,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                let liwork = iwork_size[0].to_usize().unwrap();
+                let z = jobz.then(|| vec_uninit((n * n) as usize));
+                Ok(EighTridiagonalWork {
+                    n,
+                    jobz,
+                    e: vec_uninit(n as usize),
+                    z,
+                    work: vec_uninit(lwork),
+                    iwork: vec_uninit(liwork),
+                })
+            }
+
+            fn calc(
+                &mut self,
+                d: &mut [Self::Elem],
+                e: &[Self::Elem],
+            ) -> Result<Option<&[Self::Elem]>> {
+                let n = self.n as usize;
+                assert_eq!(d.len(), n);
+                assert_eq!(e.len(), n.saturating_sub(1));
+                for (dst, src) in self.e.iter_mut().zip(e) {
+                    dst.write(*src);
+                }
+                let ldz = std::cmp::max(self.n, 1);
+                let lwork = self.work.len().to_i32().unwrap();
+                let liwork = self.iwork.len().to_i32().unwrap();
+                let mut info = 0;
+                unsafe {
+                    $stevd(
+                        self.jobz.as_ptr(),
+                        &self.n,
+                        AsPtr::as_mut_ptr(d),
+                        AsPtr::as_mut_ptr(&mut self.e),
+                        AsPtr::as_mut_ptr(
+                            self.z.as_mut().map(|z| z.as_mut_slice()).unwrap_or(&mut []),
+                        ),
+                        &ldz,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(&mut self.iwork),
+                        &liwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(self
+                    .z
+                    .as_ref()
+                    .map(|z| unsafe { z.slice_assume_init_ref() }))
+            }
+
+            fn eval(
+                mut self,
+                d: &mut [Self::Elem],
+                e: &[Self::Elem],
+            ) -> Result<Option<Vec<Self::Elem>>> {
+                let _ = self.calc(d, e)?;
+                Ok(self.z.map(|z| unsafe { z.assume_init() }))
+            }
+        }
+    };
+}
+impl_eigh_tridiagonal_work_r!(f64, lapack_sys::dstevd_);
+impl_eigh_tridiagonal_work_r!(f32, lapack_sys::sstevd_);
+
+/// Subset of eigenvalues to be computed by [Lapack::eigh_tridiagonal_select]
+#[derive(Debug, Clone, PartialEq)]
+pub enum EighRange<T> {
+    /// All eigenvalues
+    All,
+    /// Eigenvalues in the half-open interval `(lower, upper]`
+    Values { lower: T, upper: T },
+    /// Eigenvalues of the indices in the range,
+    /// where the eigenvalues are indexed from zero in ascending order.
+    /// The range must be non-empty and its end must not exceed `n`.
+    Indices(Range<usize>),
+}
+
+impl<T: Zero + Copy> EighRange<T> {
+    /// `RANGE` argument of `*stevr`
+    fn job(&self) -> std::ffi::c_char {
+        (match self {
+            EighRange::All => b'A',
+            EighRange::Values { .. } => b'V',
+            EighRange::Indices(_) => b'I',
+        }) as std::ffi::c_char
+    }
+
+    /// Maximum number of the eigenvalues in the range
+    fn max_len(&self, n: i32) -> i32 {
+        match self {
+            EighRange::Indices(r) => r.len() as i32,
+            _ => n,
+        }
+    }
+
+    /// One-based `(il, iu)`, which are not referenced unless [EighRange::Indices]
+    fn indices(&self) -> (i32, i32) {
+        match self {
+            EighRange::Indices(r) => (r.start as i32 + 1, r.end as i32),
+            _ => (0, 0),
+        }
+    }
+
+    /// `(vl, vu)`, which are not referenced unless [EighRange::Values]
+    fn values(&self) -> (T, T) {
+        match self {
+            EighRange::Values { lower, upper } => (*lower, *upper),
+            _ => (T::zero(), T::zero()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EighTridiagonalOwned<T> {
+    /// Eigenvalues in ascending order
+    pub eigs: Vec<T>,
+    /// `n x m` eigenvectors in column-major order for `m` eigenvalues
+    pub z: Option<Vec<T>>,
+}
+
+/// Compute a subset of eigenvalues of a real symmetric tridiagonal matrix,
+/// and corresponding eigenvectors if requested, using the MRRR algorithm
+///
+/// The matrix is given by the diagonal `d` and the off-diagonal `e` elements, which are not modified.
+pub trait EighTridiagonalSelectImpl: Sized {
+    fn eigh_tridiagonal_select(
+        calc_eigenvec: bool,
+        range: &EighRange<Self>,
+        d: &[Self],
+        e: &[Self],
+    ) -> Result<EighTridiagonalOwned<Self>>;
+}
+
+macro_rules! impl_eigh_tridiagonal_select_r {
+    ($f:ty, $stevr:path) => {
+        impl EighTridiagonalSelectImpl for $f {
+            fn eigh_tridiagonal_select(
+                calc_eigenvec: bool,
+                range: &EighRange<Self>,
+                d: &[Self],
+                e: &[Self],
+            ) -> Result<EighTridiagonalOwned<Self>> {
+                let n_ = d.len();
+                assert_eq!(e.len(), n_.saturating_sub(1));
+                let n = n_ as i32;
+                let jobz = if calc_eigenvec {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let job_range = range.job();
+                let (vl, vu) = range.values();
+                let (il, iu) = range.indices();
+                let zcol = range.max_len(n).max(1) as usize;
+
+                // `d` and `e` are destroyed, and `e` must have the length `n`
+                let mut d = d.to_vec();
+                let mut e_: Vec<Self> = e.to_vec();
+                e_.push(Self::zero());
+                let mut m = 0;
+                let mut eigs: Vec<MaybeUninit<Self>> = vec_uninit(n_);
+                let mut z: Option<Vec<MaybeUninit<Self>>> = jobz.then(|| vec_uninit(n_ * zcol));
+                let mut isuppz: Vec<MaybeUninit<i32>> = vec_uninit(2 * zcol);
+                let lwork = (20 * n).max(1);
+                let liwork = (10 * n).max(1);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork as usize);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(liwork as usize);
+                let mut info = 0;
+                unsafe {
+                    $stevr(
+                        jobz.as_ptr(),
+                        &job_range,
+                        &n,
+                        AsPtr::as_mut_ptr(&mut d),
+                        AsPtr::as_mut_ptr(&mut e_),
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &Self::zero(),
+                        &mut m,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(z.as_mut().map(|z| z.as_mut_slice()).unwrap_or(&mut [])),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut isuppz),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &liwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // Only the first `m` eigenvalues and eigenvectors are found
+                let m = m as usize;
+                eigs.truncate(m);
+                Ok(EighTridiagonalOwned {
+                    eigs: unsafe { eigs.assume_init() },
+                    z: z.map(|mut z| {
+                        z.truncate(n_ * m);
+                        unsafe { z.assume_init() }
+                    }),
+                })
+            }
+        }
+    };
+}
+impl_eigh_tridiagonal_select_r!(f64, lapack_sys::dstevr_);
+impl_eigh_tridiagonal_select_r!(f32, lapack_sys::sstevr_);
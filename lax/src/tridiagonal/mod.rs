@@ -1,11 +1,26 @@
 //! Implement linear solver using LU decomposition
-//! for tridiagonal matrix
+//! for tridiagonal matrix,
+//! and eigenvalue problem for symmetric tridiagonal matrix
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgttrf | dgttrf | cgttrf | zgttrf |
+//! | sgtcon | dgtcon | cgtcon | zgtcon |
+//! | sgttrs | dgttrs | cgttrs | zgttrs |
+//! | sstevd | dstevd | -      | -      |
+//! | sstevr | dstevr | -      | -      |
+//!
 
+mod eigh;
 mod lu;
 mod matrix;
 mod rcond;
 mod solve;
 
+pub use eigh::*;
 pub use lu::*;
 pub use matrix::*;
 pub use rcond::*;
@@ -0,0 +1,27 @@
+use criterion::*;
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn least_squares_tall(c: &mut Criterion) {
+    let mut group = c.benchmark_group("least_squares");
+    for &m in &[100, 1000, 10000] {
+        let n = 10;
+        group.bench_with_input(BenchmarkId::new("svd", m), &m, |bench, m| {
+            let a: Array2<f64> = random((*m, n));
+            let b: Array1<f64> = random(*m);
+            bench.iter(|| {
+                let _x = a.least_squares(&b).unwrap();
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("normal_equations", m), &m, |bench, m| {
+            let a: Array2<f64> = random((*m, n));
+            let b: Array1<f64> = random(*m);
+            bench.iter(|| {
+                let _x = solve_normal_equations(&a, &b).unwrap();
+            })
+        });
+    }
+}
+
+criterion_group!(least_squares, least_squares_tall);
+criterion_main!(least_squares);
@@ -32,5 +32,39 @@ fn solve_small(c: &mut Criterion) {
     }
 }
 
-criterion_group!(solve, solve_small);
+fn solve_3x3_batch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("solve_3x3_batch");
+    group.sample_size(10);
+    let count = 1_000_000;
+    let a: Array3<f64> = random((count, 3, 3));
+    let b: Array2<f64> = random((count, 3));
+    group.bench_function("unrolled", |bench| {
+        bench.iter(|| {
+            for (a, b) in a.outer_iter().zip(b.outer_iter()) {
+                let a = [
+                    [a[(0, 0)], a[(0, 1)], a[(0, 2)]],
+                    [a[(1, 0)], a[(1, 1)], a[(1, 2)]],
+                    [a[(2, 0)], a[(2, 1)], a[(2, 2)]],
+                ];
+                let _x = solve_3x3(&a, &[b[0], b[1], b[2]]);
+            }
+        })
+    });
+    group.bench_function("solve_small", |bench| {
+        bench.iter(|| {
+            for (a, b) in a.outer_iter().zip(b.outer_iter()) {
+                let _x = ndarray_linalg::solve_small(&a, &b).unwrap();
+            }
+        })
+    });
+    group.bench_function("lapack", |bench| {
+        bench.iter(|| {
+            for (a, b) in a.outer_iter().zip(b.outer_iter()) {
+                let _x = a.factorize().unwrap().solve(&b).unwrap();
+            }
+        })
+    });
+}
+
+criterion_group!(solve, solve_small, solve_3x3_batch);
 criterion_main!(solve);
@@ -0,0 +1,596 @@
+//! Band matrices
+//!
+//! # Symmetric/Hermitian band matrices
+//!
+//! A symmetric/Hermitian `n x n` matrix `A` with the bandwidth `k`, i.e. `a[i, j] = 0` for `|i - j| > k`,
+//! is stored in the `(k + 1) x n` array `AB` which keeps only the upper or lower band:
+//!
+//! - [UPLO::Upper]: `AB[k + i - j, j] = A[i, j]` for `max(0, j - k) <= i <= j`
+//! - [UPLO::Lower]: `AB[i - j, j] = A[i, j]` for `j <= i <= min(n - 1, j + k)`
+//!
+//! This is the band storage of LAPACK, and reduces the memory from `O(n^2)` to `O(kn)`,
+//! e.g. for the stiffness and mass matrices of the finite element method.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a = arr2(&[
+//!     [2.0, -1.0, 0.0],
+//!     [-1.0, 2.0, -1.0],
+//!     [0.0, -1.0, 2.0],
+//! ]);
+//! let band = SymmetricBand::from_dense(&a, 1, UPLO::Upper);
+//! assert_eq!(band.ab, arr2(&[[0.0, -1.0, -1.0], [2.0, 2.0, 2.0]]));
+//! assert_eq!(band.to_dense(), a);
+//! ```
+//!
+//! # General band matrices
+//!
+//! [BandedMatrix] keeps `kl` sub-diagonals and `ku` super-diagonals,
+//! and is solved by the LU decomposition with partial pivoting using `*gbtrf`.
+
+use ndarray::*;
+use num_traits::{Float, Zero};
+
+use crate::cholesky::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::solve::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// Symmetric/Hermitian band matrix in the band storage of LAPACK
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymmetricBand<A: Scalar> {
+    /// Which part of the band is stored in `ab`
+    pub uplo: UPLO,
+    /// Bandwidth, i.e. the number of super- (or sub-) diagonals
+    pub k: usize,
+    /// `(k + 1) x n` array in column-major order
+    pub ab: Array2<A>,
+}
+
+impl<A: Scalar> SymmetricBand<A> {
+    /// Pack the upper or lower band of a symmetric/Hermitian matrix
+    ///
+    /// Elements outside of the band are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` is not square.
+    pub fn from_dense<S>(a: &ArrayBase<S, Ix2>, k: usize, uplo: UPLO) -> Self
+    where
+        S: Data<Elem = A>,
+    {
+        assert!(a.is_square(), "Band matrix must be square");
+        let n = a.nrows();
+        let mut ab = Array2::zeros((k + 1, n).f());
+        for j in 0..n {
+            match uplo {
+                UPLO::Upper => {
+                    for i in j.saturating_sub(k)..=j {
+                        ab[(k + i - j, j)] = a[(i, j)];
+                    }
+                }
+                UPLO::Lower => {
+                    for i in j..n.min(j + k + 1) {
+                        ab[(i - j, j)] = a[(i, j)];
+                    }
+                }
+            }
+        }
+        SymmetricBand { uplo, k, ab }
+    }
+
+    /// Size of the matrix
+    pub fn len(&self) -> usize {
+        self.ab.ncols()
+    }
+
+    /// Returns true if the matrix is `0 x 0`
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Unpack into the dense symmetric/Hermitian matrix
+    pub fn to_dense(&self) -> Array2<A> {
+        let n = self.len();
+        let k = self.k;
+        let mut a = Array2::zeros((n, n));
+        for j in 0..n {
+            match self.uplo {
+                UPLO::Upper => {
+                    for i in j.saturating_sub(k)..=j {
+                        a[(j, i)] = self.ab[(k + i - j, j)].conj();
+                        a[(i, j)] = self.ab[(k + i - j, j)];
+                    }
+                }
+                UPLO::Lower => {
+                    for i in j..n.min(j + k + 1) {
+                        a[(j, i)] = self.ab[(i - j, j)].conj();
+                        a[(i, j)] = self.ab[(i - j, j)];
+                    }
+                }
+            }
+        }
+        a
+    }
+
+    /// Repack with another bandwidth and storage part
+    ///
+    /// Elements outside of the new band are dropped.
+    pub fn repack(&self, k: usize, uplo: UPLO) -> Self {
+        if k == self.k && uplo == self.uplo {
+            return self.clone();
+        }
+        Self::from_dense(&self.to_dense(), k, uplo)
+    }
+}
+
+/// Cholesky decomposition of a Hermitian (or real symmetric) positive definite band matrix
+#[derive(Debug, Clone, PartialEq)]
+pub struct CholeskyFactorizedBanded<A: Scalar> {
+    /// `U` from the decomposition `A = U^H U` if `factor.uplo` is [UPLO::Upper],
+    /// or `L` from `A = L L^H` if [UPLO::Lower], in the band storage with the bandwidth of `A`
+    pub factor: SymmetricBand<A>,
+}
+
+impl<A> SolveC<A> for CholeskyFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solvec_inplace<'a, Sb>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let f = &self.factor;
+        A::solve_cholesky_banded(
+            f.uplo,
+            f.len() as i32,
+            f.k as i32,
+            f.ab.as_allocated()?,
+            b.as_slice_mut().unwrap(),
+        )?;
+        Ok(b)
+    }
+}
+
+/// Cholesky decomposition of Hermitian (or real symmetric) positive definite band matrix
+pub trait CholeskyBanded<A: Scalar> {
+    /// Computes the Cholesky decomposition using `*pbtrf`
+    /// in the storage part specified by `self.uplo`
+    ///
+    /// Returns [LinalgError::Lapack] with a positive return code
+    /// if the matrix is not positive definite.
+    fn cholesky_banded(&self) -> Result<CholeskyFactorizedBanded<A>>;
+}
+
+impl<A> CholeskyBanded<A> for SymmetricBand<A>
+where
+    A: Scalar + Lapack,
+{
+    fn cholesky_banded(&self) -> Result<CholeskyFactorizedBanded<A>> {
+        let mut factor = SymmetricBand {
+            uplo: self.uplo,
+            k: self.k,
+            ab: fortran_copy(&self.ab),
+        };
+        A::cholesky_banded(
+            factor.uplo,
+            factor.len() as i32,
+            factor.k as i32,
+            factor.ab.as_allocated_mut()?,
+        )?;
+        Ok(CholeskyFactorizedBanded { factor })
+    }
+}
+
+impl<A> SolveC<A> for SymmetricBand<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solvec_inplace<'a, Sb>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.cholesky_banded()?.solvec_inplace(b)
+    }
+}
+
+/// Eigenvalue problem for symmetric/Hermitian band matrices
+///
+/// This is implemented for [SymmetricBand] using `*sbev`/`*hbev`,
+/// which reduces the matrix into the tridiagonal form without forming the dense matrix.
+pub trait EighBanded<A: Scalar> {
+    /// Compute eigenvalues in ascending order and orthonormal eigenvectors
+    fn eigh_banded(&self) -> Result<(Array1<A::Real>, Array2<A>)>;
+    /// Compute eigenvalues in ascending order
+    fn eigvalsh_banded(&self) -> Result<Array1<A::Real>>;
+}
+
+impl<A> EighBanded<A> for SymmetricBand<A>
+where
+    A: Scalar + Lapack,
+{
+    fn eigh_banded(&self) -> Result<(Array1<A::Real>, Array2<A>)> {
+        let n = self.len();
+        let mut ab = fortran_copy(&self.ab);
+        let lax::EighBandedOwned { eigs, z } = A::eigh_banded(
+            true,
+            self.uplo,
+            n as i32,
+            self.k as i32,
+            ab.as_allocated_mut()?,
+        )?;
+        let z = Array2::from_shape_vec((n, n).f(), z.unwrap()).unwrap();
+        Ok((Array1::from(eigs), z))
+    }
+
+    fn eigvalsh_banded(&self) -> Result<Array1<A::Real>> {
+        let mut ab = fortran_copy(&self.ab);
+        let lax::EighBandedOwned { eigs, .. } = A::eigh_banded(
+            false,
+            self.uplo,
+            self.len() as i32,
+            self.k as i32,
+            ab.as_allocated_mut()?,
+        )?;
+        Ok(Array1::from(eigs))
+    }
+}
+
+/// Generalized eigenvalue problem `A x = lambda B x` for symmetric/Hermitian band matrices
+///
+/// `B` must be positive definite.
+/// This is implemented for the pair `(A, B)` of [SymmetricBand] using `*sbgv`/`*hbgv`,
+/// and requires only `O(n k)` memory for the matrices in addition to the eigenvectors.
+/// `A` and `B` may have different bandwidths and storage parts,
+/// and they are repacked if needed since LAPACK requires the same storage part and `k_A >= k_B`.
+pub trait EighGeneralizedBanded<A: Scalar> {
+    /// Compute eigenvalues in ascending order and eigenvectors normalized as `V^H B V = I`
+    fn eigh_generalized_banded(&self) -> Result<(Array1<A::Real>, Array2<A>)>;
+    /// Compute eigenvalues in ascending order
+    fn eigvalsh_generalized_banded(&self) -> Result<Array1<A::Real>>;
+}
+
+impl<A> EighGeneralizedBanded<A> for (SymmetricBand<A>, SymmetricBand<A>)
+where
+    A: Scalar + Lapack,
+{
+    fn eigh_generalized_banded(&self) -> Result<(Array1<A::Real>, Array2<A>)> {
+        let n = self.0.len();
+        let (eigs, z) = eigh_generalized_banded(&self.0, &self.1, true)?;
+        let z = Array2::from_shape_vec((n, n).f(), z.unwrap()).unwrap();
+        Ok((eigs, z))
+    }
+
+    fn eigvalsh_generalized_banded(&self) -> Result<Array1<A::Real>> {
+        let (eigs, _) = eigh_generalized_banded(&self.0, &self.1, false)?;
+        Ok(eigs)
+    }
+}
+
+fn eigh_generalized_banded<A: Scalar + Lapack>(
+    a: &SymmetricBand<A>,
+    b: &SymmetricBand<A>,
+    calc_eigenvec: bool,
+) -> Result<(Array1<A::Real>, Option<Vec<A>>)> {
+    assert_eq!(a.len(), b.len(), "The sizes of A and B must be identical");
+    let uplo = a.uplo;
+    let ka = a.k.max(b.k);
+    let (kb, n) = (b.k, a.len());
+    let mut ab = fortran_copy(&a.repack(ka, uplo).ab);
+    let mut bb = fortran_copy(&b.repack(kb, uplo).ab);
+    let lax::EighGeneralizedBandedOwned { eigs, z } = A::eigh_generalized_banded(
+        calc_eigenvec,
+        uplo,
+        n as i32,
+        ka as i32,
+        kb as i32,
+        ab.as_allocated_mut()?,
+        bb.as_allocated_mut()?,
+    )?;
+    Ok((Array1::from(eigs), z))
+}
+
+/// General band matrix in the band storage of LAPACK
+///
+/// A `n x n` matrix `A` with `kl` sub-diagonals and `ku` super-diagonals,
+/// i.e. `a[i, j] = 0` for `i > j + kl` or `j > i + ku`,
+/// is stored in the `(kl + ku + 1) x n` array `AB` as `AB[ku + i - j, j] = A[i, j]`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Finite difference of -u'' + u' on 4 points
+/// let a = arr2(&[
+///     [2.5, -0.5, 0.0, 0.0],
+///     [-1.5, 2.5, -0.5, 0.0],
+///     [0.0, -1.5, 2.5, -0.5],
+///     [0.0, 0.0, -1.5, 2.5],
+/// ]);
+/// let band = BandedMatrix::from_dense(&a, 1, 1);
+/// assert_eq!(band.to_dense(), a);
+/// let b = array![1.0, 2.0, 3.0, 4.0];
+/// let x = band.solve(&b).unwrap();
+/// assert_close_l2!(&a.dot(&x), &b, 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandedMatrix<A: Scalar> {
+    /// Number of sub-diagonals
+    pub kl: usize,
+    /// Number of super-diagonals
+    pub ku: usize,
+    /// `(kl + ku + 1) x n` array in column-major order
+    pub ab: Array2<A>,
+}
+
+impl<A: Scalar> BandedMatrix<A> {
+    /// Pack the band of a square matrix
+    ///
+    /// Elements outside of the band are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` is not square.
+    pub fn from_dense<S>(a: &ArrayBase<S, Ix2>, kl: usize, ku: usize) -> Self
+    where
+        S: Data<Elem = A>,
+    {
+        assert!(a.is_square(), "Band matrix must be square");
+        let n = a.nrows();
+        let mut ab = Array2::zeros((kl + ku + 1, n).f());
+        for j in 0..n {
+            for i in j.saturating_sub(ku)..n.min(j + kl + 1) {
+                ab[(ku + i - j, j)] = a[(i, j)];
+            }
+        }
+        BandedMatrix { kl, ku, ab }
+    }
+
+    /// Size of the matrix
+    pub fn len(&self) -> usize {
+        self.ab.ncols()
+    }
+
+    /// Returns true if the matrix is `0 x 0`
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Unpack into the dense matrix
+    pub fn to_dense(&self) -> Array2<A> {
+        let n = self.len();
+        let (kl, ku) = (self.kl, self.ku);
+        let mut a = Array2::zeros((n, n));
+        for j in 0..n {
+            for i in j.saturating_sub(ku)..n.min(j + kl + 1) {
+                a[(i, j)] = self.ab[(ku + i - j, j)];
+            }
+        }
+        a
+    }
+
+    /// Computes the 1-norm, i.e. the maximum absolute column sum
+    pub fn opnorm_one(&self) -> A::Real {
+        self.ab
+            .columns()
+            .into_iter()
+            .map(|col| col.iter().map(|x| x.abs()).sum::<A::Real>())
+            .fold(A::Real::zero(), |a, b| if a > b { a } else { b })
+    }
+}
+
+/// LU decomposition of a general band matrix with partial pivoting
+#[derive(Debug, Clone, PartialEq)]
+pub struct LUFactorizedBanded<A: Scalar> {
+    kl: usize,
+    ku: usize,
+    /// `(2 kl + ku + 1) x n` array of `U` with `kl + ku` super-diagonals and the multipliers of `L`
+    ab: Array2<A>,
+    ipiv: Pivot,
+    /// 1-norm of the original matrix for the condition number
+    anorm: A::Real,
+}
+
+/// LU decomposition of a general band matrix
+pub trait FactorizeBanded<A: Scalar> {
+    /// Computes the LU decomposition using `*gbtrf`
+    ///
+    /// Returns [LinalgError::Lapack] with a positive return code if the matrix is singular.
+    fn factorize_banded(&self) -> Result<LUFactorizedBanded<A>>;
+}
+
+impl<A> FactorizeBanded<A> for BandedMatrix<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorize_banded(&self) -> Result<LUFactorizedBanded<A>> {
+        let (kl, ku, n) = (self.kl, self.ku, self.len());
+        // The first `kl` rows are the workspace for the fill-in
+        let mut ab = Array2::zeros((2 * kl + ku + 1, n).f());
+        ab.slice_mut(s![kl.., ..]).assign(&self.ab);
+        let ipiv = A::lu_banded(n as i32, kl as i32, ku as i32, ab.as_allocated_mut()?)?;
+        Ok(LUFactorizedBanded {
+            kl,
+            ku,
+            ab,
+            ipiv,
+            anorm: self.opnorm_one(),
+        })
+    }
+}
+
+impl<A: Scalar + Lapack> LUFactorizedBanded<A> {
+    /// Size of the matrix
+    pub fn len(&self) -> usize {
+        self.ab.ncols()
+    }
+
+    /// Returns true if the matrix is `0 x 0`
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn solve_banded<'a, Sb>(
+        &self,
+        t: Transpose,
+        rhs: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let n = self.ab.ncols();
+        if rhs.len() != n {
+            return Err(LinalgError::Shape(ShapeError::from_kind(
+                ErrorKind::IncompatibleShape,
+            )));
+        }
+        A::solve_banded(
+            t,
+            n as i32,
+            self.kl as i32,
+            self.ku as i32,
+            self.ab.as_allocated()?,
+            &self.ipiv,
+            rhs.as_slice_mut().unwrap(),
+        )?;
+        Ok(rhs)
+    }
+}
+
+impl<A> Solve<A> for LUFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.solve_banded(Transpose::No, rhs)
+    }
+
+    fn solve_t_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.solve_banded(Transpose::Transpose, rhs)
+    }
+
+    fn solve_h_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.solve_banded(Transpose::Hermite, rhs)
+    }
+}
+
+impl<A> Solve<A> for BandedMatrix<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.factorize_banded()?.solve_inplace(rhs)
+    }
+
+    fn solve_t_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.factorize_banded()?.solve_t_inplace(rhs)
+    }
+
+    fn solve_h_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.factorize_banded()?.solve_h_inplace(rhs)
+    }
+}
+
+impl<A> Determinant<A> for LUFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn sln_det(&self) -> Result<(A, A::Real)> {
+        // The diagonal of `U` is the `kl + ku`-th row
+        let u_diag = self.ab.row(self.kl + self.ku);
+        Ok(lu_sln_det(self.ipiv.iter().cloned(), u_diag.iter()))
+    }
+}
+
+impl<A> Determinant<A> for BandedMatrix<A>
+where
+    A: Scalar + Lapack,
+{
+    fn sln_det(&self) -> Result<(A, A::Real)> {
+        match self.factorize_banded() {
+            Ok(fac) => fac.sln_det(),
+            Err(LinalgError::Lapack(lax::error::Error::LapackComputationalFailure { .. })) => {
+                // The determinant is zero.
+                Ok((A::zero(), A::Real::neg_infinity()))
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl<A> ReciprocalConditionNum<A> for LUFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn rcond(&self) -> Result<A::Real> {
+        Ok(A::rcond_banded(
+            self.ab.ncols() as i32,
+            self.kl as i32,
+            self.ku as i32,
+            self.ab.as_allocated()?,
+            &self.ipiv,
+            self.anorm,
+        )?)
+    }
+}
+
+impl<A> ReciprocalConditionNum<A> for BandedMatrix<A>
+where
+    A: Scalar + Lapack,
+{
+    fn rcond(&self) -> Result<A::Real> {
+        self.factorize_banded()?.rcond()
+    }
+}
+
+fn fortran_copy<A: Scalar>(a: &Array2<A>) -> Array2<A> {
+    let mut b = Array2::zeros(a.dim().f());
+    b.assign(a);
+    b
+}
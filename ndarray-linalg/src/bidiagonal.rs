@@ -0,0 +1,164 @@
+//! Bidiagonal form of matrices
+//!
+//! A `m x n` matrix `A` can be reduced into a bidiagonal form `A = Q B P^H` by Householder reflections,
+//! where `B` is upper bidiagonal if `m >= n` and lower bidiagonal otherwise.
+//! This is the first step of SVD in LAPACK.
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// Real bidiagonal matrix represented by its diagonal and off-diagonal elements
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bidiagonal<A: Scalar> {
+    /// [UPLO::Upper] for upper bidiagonal, [UPLO::Lower] for lower bidiagonal
+    pub uplo: UPLO,
+    /// Diagonal elements
+    pub d: Array1<A::Real>,
+    /// Off-diagonal elements
+    pub e: Array1<A::Real>,
+}
+
+impl<A> Bidiagonal<A>
+where
+    A: Scalar + Lapack,
+{
+    /// Compute SVD `B = U S V^H` of the bidiagonal matrix using `*bdsqr`
+    ///
+    /// Singular values are returned in descending order.
+    pub fn svd(
+        &self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Array2<A>>, Array1<A::Real>, Option<Array2<A>>)> {
+        let n = self.d.len();
+        let eye = || {
+            let mut a = Array2::zeros((n, n).f());
+            a.diag_mut().fill(A::one());
+            a
+        };
+        let mut u = calc_u.then(eye);
+        let mut vt = calc_vt.then(eye);
+        let s = self.svd_apply(u.as_mut(), vt.as_mut(), None)?;
+        Ok((u, s, vt))
+    }
+
+    /// Compute singular values of the bidiagonal matrix `B = U_B S V_B^H` using `*bdsqr`,
+    /// and apply the rotations to the given matrices
+    ///
+    /// - `u` is overwritten by `u * U_B`
+    /// - `vt` is overwritten by `V_B^H * vt`
+    /// - `c` is overwritten by `U_B^H * c`
+    ///
+    /// When `B` comes from the bidiagonalization `A = Q B P^H` by `*gebrd`,
+    /// passing `Q` and `P^H` expanded from `tauq` and `taup` by `*orgbr`/`*ungbr` as `u` and `vt`
+    /// yields the SVD of `A` itself, which is what [svd_with_bidiagonal] does.
+    /// `c` can be used to compute `U^H C` without forming `U`,
+    /// e.g. when `C = Q^H B` has been computed by `*ormbr`/`*unmbr` for least squares problems.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of columns of `u`, the number of rows of `vt`, or the number of rows of `c`
+    /// is not equal to the size of `B`.
+    pub fn svd_apply(
+        &self,
+        u: Option<&mut Array2<A>>,
+        vt: Option<&mut Array2<A>>,
+        c: Option<&mut Array2<A>>,
+    ) -> Result<Array1<A::Real>> {
+        let mut d = self.d.to_vec();
+        let mut e = self.e.to_vec();
+        let mut u_f = u.as_deref().map(fortran_copy);
+        let mut vt_f = vt.as_deref().map(fortran_copy);
+        let mut c_f = c.as_deref().map(fortran_copy);
+        A::svd_bidiagonal(
+            self.uplo,
+            &mut d,
+            &mut e,
+            as_lapack(&mut vt_f)?,
+            as_lapack(&mut u_f)?,
+            as_lapack(&mut c_f)?,
+        )?;
+        for (a, a_f) in [(u, u_f), (vt, vt_f), (c, c_f)] {
+            if let (Some(a), Some(a_f)) = (a, a_f) {
+                a.assign(&a_f);
+            }
+        }
+        Ok(Array1::from(d))
+    }
+}
+
+/// Compute the reduced SVD `A = U S V^H` through the explicit bidiagonal form `A = Q B P^H`
+///
+/// This computes the SVD in the same way as `*gesvd`, i.e. `*gebrd` followed by `*bdsqr`,
+/// and returns the intermediate bidiagonal matrix `B` in addition to `U`, `S`, and `V^H`.
+/// `U` is `m x k` and `V^H` is `k x n` where `k = min(m, n)`.
+pub fn svd_with_bidiagonal<A, S>(
+    a: &ArrayBase<S, Ix2>,
+) -> Result<(Array2<A>, Array1<A::Real>, Array2<A>, Bidiagonal<A>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    let mut a = fortran_copy(a);
+    let lax::BidiagonalOwned {
+        uplo,
+        d,
+        e,
+        tauq,
+        taup,
+    } = A::bidiagonal(a.layout()?, a.as_allocated_mut()?)?;
+
+    let mut u = fortran_copy(&a.slice(s![.., ..k]));
+    let mut vt = fortran_copy(&a.slice(s![..k, ..]));
+    let (u_layout, vt_layout) = (u.layout()?, vt.layout()?);
+    A::generate_bidiagonal(
+        lax::BidiagonalFactor::Q,
+        u_layout,
+        n as i32,
+        u.as_allocated_mut()?,
+        &tauq,
+    )?;
+    A::generate_bidiagonal(
+        lax::BidiagonalFactor::P,
+        vt_layout,
+        m as i32,
+        vt.as_allocated_mut()?,
+        &taup,
+    )?;
+
+    let bidiagonal = Bidiagonal {
+        uplo,
+        d: Array1::from(d),
+        e: Array1::from(e),
+    };
+    let mut s = bidiagonal.d.to_vec();
+    let mut e = bidiagonal.e.to_vec();
+    A::svd_bidiagonal(
+        uplo,
+        &mut s,
+        &mut e,
+        Some((vt_layout, vt.as_allocated_mut()?)),
+        Some((u_layout, u.as_allocated_mut()?)),
+        None,
+    )?;
+    Ok((u, Array1::from(s), vt, bidiagonal))
+}
+
+fn fortran_copy<A: Scalar, S: Data<Elem = A>>(a: &ArrayBase<S, Ix2>) -> Array2<A> {
+    let mut b = Array2::zeros(a.dim().f());
+    b.assign(a);
+    b
+}
+
+fn as_lapack<A: Scalar>(a: &mut Option<Array2<A>>) -> Result<Option<(MatrixLayout, &mut [A])>> {
+    match a {
+        Some(a) => Ok(Some((a.layout()?, a.as_allocated_mut()?))),
+        None => Ok(None),
+    }
+}
@@ -44,7 +44,7 @@
 //! ```
 
 use ndarray::*;
-use num_traits::Float;
+use num_traits::{Float, One, Zero};
 
 use crate::convert::*;
 use crate::error::*;
@@ -92,6 +92,92 @@ where
             UPLO::Upper => self.factor,
         }
     }
+
+    /// Updates the factorization of `A` into that of `A + x * x^H` in `O(n^2)` operations.
+    pub fn update<Sx>(&mut self, x: &ArrayBase<Sx, Ix1>) -> Result<()>
+    where
+        Sx: Data<Elem = A>,
+    {
+        rank_one_modify(&mut self.factor, self.uplo, x, false)
+    }
+
+    /// Downdates the factorization of `A` into that of `A - x * x^H` in `O(n^2)` operations.
+    ///
+    /// [LinalgError::Lapack] is returned if `A - x * x^H` is not positive definite,
+    /// and then the factorization is left in an unspecified state.
+    pub fn downdate<Sx>(&mut self, x: &ArrayBase<Sx, Ix1>) -> Result<()>
+    where
+        Sx: Data<Elem = A>,
+    {
+        rank_one_modify(&mut self.factor, self.uplo, x, true)
+    }
+}
+
+/// Rank-1 update (or downdate) of the Cholesky factor by the sequence of (hyperbolic) rotations
+///
+/// The `k`-th column of `L` is combined with `x` so that the `k`-th element of `x` is eliminated,
+/// where `L` is accessed as the conjugate transpose of `U` for [UPLO::Upper].
+fn rank_one_modify<A, S, Sx>(
+    factor: &mut ArrayBase<S, Ix2>,
+    uplo: UPLO,
+    x: &ArrayBase<Sx, Ix1>,
+    downdate: bool,
+) -> Result<()>
+where
+    A: Scalar,
+    S: DataMut<Elem = A>,
+    Sx: Data<Elem = A>,
+{
+    let n = factor.square_layout()?.len() as usize;
+    if x.len() != n {
+        return Err(LinalgError::Shape(ShapeError::from_kind(
+            ErrorKind::IncompatibleShape,
+        )));
+    }
+    let index = |i: usize, k: usize| match uplo {
+        UPLO::Lower => (i, k),
+        UPLO::Upper => (k, i),
+    };
+    let get = |f: &ArrayBase<S, Ix2>, i, k| match uplo {
+        UPLO::Lower => f[index(i, k)],
+        UPLO::Upper => f[index(i, k)].conj(),
+    };
+    let mut x = x.to_owned();
+    for k in 0..n {
+        let lkk = factor[(k, k)].re();
+        let r2 = if downdate {
+            lkk * lkk - x[k].square()
+        } else {
+            lkk * lkk + x[k].square()
+        };
+        if r2 <= A::Real::zero() {
+            return Err(LinalgError::Lapack(
+                lax::error::Error::LapackComputationalFailure {
+                    return_code: k as i32 + 1,
+                },
+            ));
+        }
+        let r = Float::sqrt(r2);
+        let c_inv = lkk / r;
+        let s = x[k].mul_real(A::Real::one() / lkk);
+        factor[(k, k)] = A::from_real(r);
+        for i in (k + 1)..n {
+            let l = get(factor, i, k);
+            let (l_new, x_new) = if downdate {
+                let l_new = (l - s.conj() * x[i]).mul_real(c_inv);
+                (l_new, x[i].mul_real(r / lkk) - s * l_new)
+            } else {
+                let l_new = (l + s.conj() * x[i]).mul_real(c_inv);
+                (l_new, (x[i] - s * l).mul_real(c_inv))
+            };
+            x[i] = x_new;
+            factor[index(i, k)] = match uplo {
+                UPLO::Lower => l_new,
+                UPLO::Upper => l_new.conj(),
+            };
+        }
+    }
+    Ok(())
 }
 
 impl<A, S> DeterminantC for CholeskyFactorized<S>
@@ -466,3 +552,66 @@ where
         Ok(self.factorizec_into(UPLO::Upper)?.ln_detc_into())
     }
 }
+
+/// Result of [cholesky_pivoted]
+#[derive(Debug, Clone)]
+pub struct CholeskyPivoted<A: Scalar> {
+    /// `U` from the decomposition `P^T A P = U^H * U` or `L` from `P^T A P = L * L^H`,
+    /// whose rows (or columns) after `rank` are zero
+    pub factor: Array2<A>,
+    /// If this is `UPLO::Lower`, then `self.factor` is `L`. If this is
+    /// `UPLO::Upper`, then `self.factor` is `U`.
+    pub uplo: UPLO,
+    /// The `j`-th column of `A P` is the `perm[j]`-th column of `A`
+    pub perm: Vec<usize>,
+    /// Numerical rank of `A` determined by the tolerance
+    pub rank: usize,
+}
+
+/// Cholesky decomposition with complete pivoting of a Hermitian (or real symmetric)
+/// positive semi-definite matrix
+///
+/// The diagonal element of the largest remaining value is chosen as the pivot at each step,
+/// and the factorization stops when it is not larger than `tol`.
+/// The residual `P^T A P - U^H U` is then the Schur complement of the leading `rank x rank` block,
+/// whose diagonal elements are not larger than `tol`.
+/// A negative `tol` uses the LAPACK default `n * eps * max(diag(A))`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Rank 2 matrix
+/// let x: Array2<f64> = array![[1.0, 0.0], [0.0, 2.0], [1.0, 2.0]];
+/// let a = x.dot(&x.t());
+/// let c = cholesky_pivoted(&a, UPLO::Upper, -1.0).unwrap();
+/// assert_eq!(c.rank, 2);
+/// let pa: Array2<f64> = a.select(Axis(0), &c.perm).select(Axis(1), &c.perm);
+/// assert!(c.factor.t().dot(&c.factor).abs_diff_eq(&pa, 1e-9));
+/// ```
+pub fn cholesky_pivoted<A, S>(
+    a: &ArrayBase<S, Ix2>,
+    uplo: UPLO,
+    tol: A::Real,
+) -> Result<CholeskyPivoted<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let mut factor = replicate(a);
+    let (piv, rank) = A::cholesky_pivoted(
+        factor.square_layout()?,
+        uplo,
+        factor.as_allocated_mut()?,
+        tol,
+    )?;
+    let rank = rank as usize;
+    let mut factor = factor.into_triangular(uplo);
+    factor.slice_mut(s![rank.., rank..]).fill(A::zero());
+    Ok(CholeskyPivoted {
+        factor,
+        uplo,
+        perm: piv.iter().map(|&p| p as usize - 1).collect(),
+        rank,
+    })
+}
@@ -0,0 +1,131 @@
+//! Circulant matrices
+//!
+//! A `n x n` circulant matrix `C[i, j] = c_{(i - j) mod n}` is determined by its first column `c`,
+//! and `C x` is the periodic convolution of `c` and `x`.
+//! It is diagonalized by the discrete Fourier transform `F` as `C = F^{-1} diag(F c) F`,
+//! so that the eigenvalues, determinant and linear equations are computed in `O(n log n)` operations
+//! using the FFT of [RustFFT](https://github.com/ejmahler/RustFFT).
+//!
+//! This module requires the `rustfft` feature.
+//! The results are returned in complex numbers even for a real matrix, as [crate::Sqrtm] does.
+
+use ndarray::*;
+use num_complex::Complex;
+use num_traits::{Float, One, Zero};
+use rustfft::{FftNum, FftPlanner};
+
+use crate::error::*;
+use crate::types::*;
+
+/// Circulant matrix given by the first column
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let c = CirculantMatrix::new(array![4.0, 1.0, 0.0, 1.0]);
+/// assert_eq!(c.to_dense().row(1), array![1.0, 4.0, 1.0, 0.0]);
+/// // Eigenvalues are the DFT of the first column
+/// let w = c.eigvals();
+/// assert_close_l2!(&w, &array![6.0, 4.0, 2.0, 4.0].mapv(c64::from), 1e-12);
+/// let b = array![1.0, 2.0, 3.0, 4.0];
+/// let x = c.solve(&b).unwrap();
+/// let ax = c.to_dense().mapv(c64::from).dot(&x);
+/// assert_close_l2!(&ax, &b.mapv(c64::from), 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CirculantMatrix<A: Scalar> {
+    /// The first column `(c_0, c_1, ..., c_{n-1})`
+    pub c: Array1<A>,
+}
+
+impl<A: Scalar> CirculantMatrix<A> {
+    /// Circulant matrix with the first column `c`
+    pub fn new(c: Array1<A>) -> Self {
+        CirculantMatrix { c }
+    }
+
+    /// Size of the matrix
+    pub fn len(&self) -> usize {
+        self.c.len()
+    }
+
+    /// Returns true if the matrix is `0 x 0`
+    pub fn is_empty(&self) -> bool {
+        self.c.is_empty()
+    }
+
+    /// Construct the dense matrix
+    pub fn to_dense(&self) -> Array2<A> {
+        let n = self.len();
+        Array2::from_shape_fn((n, n), |(i, j)| self.c[(n + i - j) % n])
+    }
+}
+
+impl<A, R> CirculantMatrix<A>
+where
+    A: Scalar<Real = R, Complex = Complex<R>>,
+    R: FftNum + Float,
+{
+    /// Eigenvalues `F c` in the order of the frequencies `0, 1, ..., n - 1`
+    ///
+    /// The `k`-th eigenvector is `(1, w^k, w^{2k}, ...)` with `w = exp(2 pi i / n)`.
+    pub fn eigvals(&self) -> Array1<A::Complex> {
+        let mut w: Vec<_> = self.c.iter().map(|x| x.as_c()).collect();
+        fft(&mut w, false);
+        Array1::from(w)
+    }
+
+    /// Determinant, i.e. the product of the eigenvalues
+    pub fn det(&self) -> A::Complex {
+        self.eigvals()
+            .iter()
+            .fold(Complex::one(), |acc, &w| acc * w)
+    }
+
+    /// Solve `C x = b` by `x = F^{-1} (F b / F c)`
+    ///
+    /// Returns [LinalgError::Lapack] if an eigenvalue is exactly zero.
+    pub fn solve<S>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A::Complex>>
+    where
+        S: Data<Elem = A>,
+    {
+        let n = self.len();
+        if b.len() != n {
+            return Err(LinalgError::Shape(ShapeError::from_kind(
+                ErrorKind::IncompatibleShape,
+            )));
+        }
+        let w = self.eigvals();
+        if let Some(k) = w.iter().position(|w| w.is_zero()) {
+            return Err(LinalgError::Lapack(
+                lax::error::Error::LapackComputationalFailure {
+                    return_code: k as i32 + 1,
+                },
+            ));
+        }
+        let mut x: Vec<_> = b.iter().map(|x| x.as_c()).collect();
+        fft(&mut x, false);
+        for (x, w) in x.iter_mut().zip(&w) {
+            *x = *x / *w;
+        }
+        fft(&mut x, true);
+        let scale = R::one() / A::real(n);
+        Ok(Array1::from(x).mapv_into(|x| x * scale))
+    }
+}
+
+/// Unnormalized discrete Fourier transform with the kernel `exp(-2 pi i jk / n)`,
+/// or `exp(2 pi i jk / n)` if `inverse`
+fn fft<R: FftNum>(x: &mut [Complex<R>], inverse: bool) {
+    if x.is_empty() {
+        return;
+    }
+    let mut planner = FftPlanner::new();
+    let fft = if inverse {
+        planner.plan_fft_inverse(x.len())
+    } else {
+        planner.plan_fft_forward(x.len())
+    };
+    fft.process(x);
+}
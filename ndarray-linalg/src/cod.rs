@@ -0,0 +1,115 @@
+//! Complete orthogonal decomposition
+//!
+//! A `m x n` matrix `A` of numerical rank `r` is decomposed as `A P = Q [T 0; 0 0] Z^H`,
+//! where `P` is a permutation, `Q` and `Z` are unitary, and `T` is `r x r` upper triangular.
+//! The rank and `P` are determined by [qr_pivoted] as `A P = Q [R_1; R_2]`,
+//! and the upper trapezoidal `R_1` is reduced into `[T 0] Z^H` as `*tzrzf` does,
+//! by the QR decomposition of `R_1^H` with reversed rows and columns.
+//!
+//! The minimum-norm least squares solution `x = P Z [T^{-1} Q^H b; 0]`
+//! of rank deficient problems is obtained without the SVD.
+//!
+//! - G. H. Golub and C. F. Van Loan, "Matrix Computations", 4th ed., Section 5.4.2.
+
+use ndarray::*;
+
+use crate::convert::replicate;
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::qr::*;
+use crate::triangular::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// Result of [complete_orthogonal]
+///
+/// Only the leading `rank` columns of `Q` and `Z` are kept, i.e. `A P = Q T Z^H`.
+#[derive(Debug, Clone)]
+pub struct CompleteOrthogonal<A: Scalar> {
+    /// `m x rank` matrix with orthonormal columns
+    pub q: Array2<A>,
+    /// `rank x rank` upper triangular matrix
+    pub t: Array2<A>,
+    /// `n x rank` matrix with orthonormal columns
+    pub z: Array2<A>,
+    /// The `j`-th column of `A P` is the `perm[j]`-th column of `A`
+    pub perm: Vec<usize>,
+    /// Numerical rank of `A`, see [QRPivoted::rank]
+    pub rank: usize,
+}
+
+/// Complete orthogonal decomposition `A P = Q [T 0; 0 0] Z^H`
+///
+/// The diagonal elements of `R` of the column-pivoted QR decomposition
+/// not larger than `tol` are regarded as zero.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Rank 1 matrix
+/// let a: Array2<f64> = array![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+/// let cod = complete_orthogonal(&a, 1e-10).unwrap();
+/// assert_eq!(cod.rank, 1);
+/// // Same as the pseudo-inverse solution
+/// let x = cod.solve_min_norm(&array![1.0, 2.0, 3.0]).unwrap();
+/// assert_close_l2!(&x, &array![0.2, 0.4], 1e-12);
+/// ```
+pub fn complete_orthogonal<A, S>(
+    a: &ArrayBase<S, Ix2>,
+    tol: A::Real,
+) -> Result<CompleteOrthogonal<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let (m, n) = a.dim();
+    let qrp = qr_pivoted(a, PivotRule::Largest)?;
+    let rank = qrp.rank(tol);
+    let q = qrp.q.slice(s![.., ..rank]).to_owned();
+    if rank == 0 {
+        return Ok(CompleteOrthogonal {
+            q: Array2::zeros((m, 0)),
+            t: Array2::zeros((0, 0)),
+            z: Array2::zeros((n, 0)),
+            perm: qrp.perm,
+            rank,
+        });
+    }
+    // J R_1^H J = W S gives R_1 = (J S^H J) (J W J)^H with the reversal permutation J
+    let r1 = qrp.r.slice(s![..rank, ..]);
+    let r1h_rev: Array2<A> = conjugate(&r1.slice(s![..;-1, ..;-1]));
+    let (w, s): (Array2<A>, Array2<A>) = r1h_rev.qr()?;
+    Ok(CompleteOrthogonal {
+        q,
+        t: conjugate(&s.slice(s![..;-1, ..;-1])),
+        z: replicate(&w.slice(s![..;-1, ..;-1])),
+        perm: qrp.perm,
+        rank,
+    })
+}
+
+impl<A: Scalar + Lapack> CompleteOrthogonal<A> {
+    /// Minimum-norm solution of the least squares problem `min |A x - b|_2`
+    pub fn solve_min_norm<S>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>>
+    where
+        S: Data<Elem = A>,
+    {
+        let (m, n) = (self.q.nrows(), self.z.nrows());
+        if b.len() != m {
+            return Err(LinalgError::Shape(ShapeError::from_kind(
+                ErrorKind::IncompatibleShape,
+            )));
+        }
+        let mut x = Array1::zeros(n);
+        if self.rank == 0 {
+            return Ok(x);
+        }
+        let c = conjugate::<_, _, OwnedRepr<_>>(&self.q).dot(b);
+        let y = self.t.solve_triangular(UPLO::Upper, Diag::NonUnit, &c)?;
+        for (&p, &v) in self.perm.iter().zip(self.z.dot(&y).iter()) {
+            x[p] = v;
+        }
+        Ok(x)
+    }
+}
@@ -37,6 +37,10 @@ where
 {
     type Elem = A;
 
+    fn shape(&self) -> Option<(usize, usize)> {
+        Some((self.diag.len(), self.diag.len()))
+    }
+
     fn apply_mut<S>(&self, a: &mut ArrayBase<S, Ix1>)
     where
         S: DataMut<Elem = A>,
@@ -46,3 +50,20 @@ where
         }
     }
 }
+
+impl<A, Sa> AdjointOperator for Diagonal<Sa>
+where
+    A: Scalar,
+    Sa: Data<Elem = A>,
+{
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        let mut a = a.to_owned();
+        for (val, d) in a.iter_mut().zip(self.diag.iter()) {
+            *val *= d.conj();
+        }
+        a
+    }
+}
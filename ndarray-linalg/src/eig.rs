@@ -77,3 +77,115 @@ where
         Ok(ArrayBase::from(s))
     }
 }
+
+/// Result of [EigGeneralized]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneralizedEig<A: Scalar> {
+    /// Numerators of the generalized eigenvalues `alpha[i] / beta[i]`
+    pub alpha: Array1<A::Complex>,
+    /// Denominators of the generalized eigenvalues, real and non-negative for real matrices.
+    /// `beta[i]` is zero for an infinite eigenvalue.
+    pub beta: Array1<A>,
+    /// Right eigenvectors `A v_i = lambda_i B v_i` as columns
+    pub vr: Array2<A::Complex>,
+    /// Left eigenvectors `u_i^H A = lambda_i u_i^H B` as columns, if requested
+    pub vl: Option<Array2<A::Complex>>,
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Generalized eigenvalue decomposition $A v = \lambda B v$ of a pair of general matrices using `*ggev`
+///
+/// The eigenvalues are returned as pairs $\lambda_i = \alpha_i / \beta_i$
+/// without forming $B^{-1} A$, so that a singular $B$ yields infinite eigenvalues $\beta_i = 0$
+/// instead of failing.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[1.0, 2.0], [3.0, 4.0]];
+/// let b: Array2<f64> = array![[1.0, 0.0], [0.0, 0.0]];
+/// let res = a.eig_generalized(&b).unwrap();
+/// // `det(A - lambda B) = 4 (1 - lambda) - 6` has only one finite root
+/// let finite: Vec<_> = (0..2)
+///     .filter(|&i| res.beta[i].abs() > 1e-12)
+///     .map(|i| res.alpha[i] / res.beta[i])
+///     .collect();
+/// assert_eq!(finite.len(), 1);
+/// assert!((finite[0].re + 0.5).abs() < 1e-12);
+/// ```
+pub trait EigGeneralized<A, S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    /// Compute generalized eigenvalues and right eigenvectors
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes of `self` and `b` are different.
+    fn eig_generalized(&self, b: &ArrayBase<S, Ix2>) -> Result<GeneralizedEig<A>>;
+
+    /// Compute generalized eigenvalues, and both right and left eigenvectors
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes of `self` and `b` are different.
+    fn eig_generalized_with_left(&self, b: &ArrayBase<S, Ix2>) -> Result<GeneralizedEig<A>>;
+}
+
+impl<A, Sa, Sb> EigGeneralized<A, Sb> for ArrayBase<Sa, Ix2>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    fn eig_generalized(&self, b: &ArrayBase<Sb, Ix2>) -> Result<GeneralizedEig<A>> {
+        eig_generalized(self, b, false)
+    }
+
+    fn eig_generalized_with_left(&self, b: &ArrayBase<Sb, Ix2>) -> Result<GeneralizedEig<A>> {
+        eig_generalized(self, b, true)
+    }
+}
+
+fn eig_generalized<A, Sa, Sb>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    calc_vl: bool,
+) -> Result<GeneralizedEig<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    assert_eq!(
+        a.shape(),
+        b.shape(),
+        "The shapes of A and B must be identical"
+    );
+    let mut a = a.to_owned();
+    let layout = a.square_layout()?;
+    // `*ggev` requires `A` and `B` in the same memory order
+    let mut b_ = match layout {
+        MatrixLayout::C { .. } => Array2::zeros(b.dim()),
+        MatrixLayout::F { .. } => Array2::zeros(b.dim().f()),
+    };
+    b_.assign(b);
+    let res = A::eig_generalized(
+        calc_vl,
+        true,
+        layout,
+        a.as_allocated_mut()?,
+        b_.as_allocated_mut()?,
+    )?;
+    let n = layout.len() as usize;
+    Ok(GeneralizedEig {
+        alpha: ArrayBase::from(res.alpha),
+        beta: ArrayBase::from(res.beta),
+        vr: Array2::from_shape_vec((n, n).f(), res.vr.unwrap()).unwrap(),
+        vl: res
+            .vl
+            .map(|vl| Array2::from_shape_vec((n, n).f(), vl).unwrap()),
+    })
+}
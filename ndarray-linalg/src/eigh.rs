@@ -34,6 +34,7 @@
 //! ```
 
 use ndarray::*;
+use std::ops::Range;
 
 use crate::diagonal::*;
 use crate::error::*;
@@ -42,6 +43,8 @@ use crate::operator::LinearOperator;
 use crate::types::*;
 use crate::UPLO;
 
+pub use lax::GeneralizedEigType;
+
 /// Eigenvalue decomposition of Hermite matrix reference
 pub trait Eigh {
     type EigVal;
@@ -236,6 +239,191 @@ where
     }
 }
 
+impl<A, S, S2> EigValshInto for (ArrayBase<S, Ix2>, ArrayBase<S2, Ix2>)
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+    S2: DataMut<Elem = A>,
+{
+    type EigVal = Array1<A::Real>;
+
+    fn eigvalsh_into(mut self, uplo: UPLO) -> Result<Self::EigVal> {
+        self.eigvalsh_inplace(uplo)
+    }
+}
+
+impl<A, S, S2> EigValsh for (ArrayBase<S, Ix2>, ArrayBase<S2, Ix2>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Real>;
+
+    fn eigvalsh(&self, uplo: UPLO) -> Result<Self::EigVal> {
+        let (a, b) = (self.0.to_owned(), self.1.to_owned());
+        (a, b).eigvalsh_into(uplo)
+    }
+}
+
+impl<A, S, S2> EigValshInplace for (ArrayBase<S, Ix2>, ArrayBase<S2, Ix2>)
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+    S2: DataMut<Elem = A>,
+{
+    type EigVal = Array1<A::Real>;
+
+    /// Solves the generalized eigenvalue problem without computing eigenvectors.
+    ///
+    /// Both matrices are overwritten: `A` is destroyed and `B` holds its Cholesky factor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes of the matrices are different.
+    fn eigvalsh_inplace(&mut self, uplo: UPLO) -> Result<Self::EigVal> {
+        assert_eq!(
+            self.0.shape(),
+            self.1.shape(),
+            "The shapes of the matrices must be identical.",
+        );
+        // Both matrices must be in the same layout, since `uplo` is shared
+        let layout = self.0.square_layout()?;
+        match layout {
+            MatrixLayout::C { .. } => self.0.swap_axes(0, 1),
+            MatrixLayout::F { .. } => {}
+        }
+        let layout = self.1.square_layout()?;
+        match layout {
+            MatrixLayout::C { .. } => self.1.swap_axes(0, 1),
+            MatrixLayout::F { .. } => {}
+        }
+
+        let s = A::eigh_generalized(
+            false,
+            self.0.square_layout()?,
+            uplo,
+            self.0.as_allocated_mut()?,
+            self.1.as_allocated_mut()?,
+        )?;
+        Ok(ArrayBase::from(s))
+    }
+}
+
+/// Generalized eigenvalue problem for a Hermitian matrix `A` and a positive definite Hermitian matrix `B`
+/// using the divide and conquer driver `*sygvd`/`*hegvd`
+///
+/// The type of the problem is selected by [GeneralizedEigType]:
+/// `A x = lambda B x`, `A B x = lambda x` or `B A x = lambda x`,
+/// while [Eigh] for the tuple `(A, B)` only solves the first one.
+/// `uplo` specifies the triangle referenced in both `A` and `B`.
+/// The eigenvalues are in ascending order, and the eigenvectors `V` are normalized
+/// as `V^H B V = I` for the first two types and `V^H B^{-1} V = I` for the last one.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let k: Array2<f64> = array![[2.0, -1.0], [-1.0, 2.0]];
+/// let m: Array2<f64> = array![[2.0, 0.0], [0.0, 1.0]];
+/// // Natural frequencies of a vibrating system `K x = omega^2 M x`
+/// let (w2, x) = k
+///     .eigh_generalized(&m, UPLO::Lower, GeneralizedEigType::AxLambdaBx)
+///     .unwrap();
+/// assert_close_l2!(&k.dot(&x), &m.dot(&x).dot(&Array2::from_diag(&w2)), 1e-9);
+/// ```
+pub trait EighGeneralized<A, S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    /// Compute the eigenvalues and eigenvectors of the pair of `self` and `b`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes of the matrices are different.
+    fn eigh_generalized(
+        &self,
+        b: &ArrayBase<S, Ix2>,
+        uplo: UPLO,
+        itype: GeneralizedEigType,
+    ) -> Result<(Array1<A::Real>, Array2<A>)>;
+
+    /// Compute the eigenvalues of the pair of `self` and `b` without eigenvectors
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes of the matrices are different.
+    fn eigvalsh_generalized(
+        &self,
+        b: &ArrayBase<S, Ix2>,
+        uplo: UPLO,
+        itype: GeneralizedEigType,
+    ) -> Result<Array1<A::Real>>;
+}
+
+impl<A, Sa, Sb> EighGeneralized<A, Sb> for ArrayBase<Sa, Ix2>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    fn eigh_generalized(
+        &self,
+        b: &ArrayBase<Sb, Ix2>,
+        uplo: UPLO,
+        itype: GeneralizedEigType,
+    ) -> Result<(Array1<A::Real>, Array2<A>)> {
+        eigh_generalized(self, b, uplo, itype, true)
+    }
+
+    fn eigvalsh_generalized(
+        &self,
+        b: &ArrayBase<Sb, Ix2>,
+        uplo: UPLO,
+        itype: GeneralizedEigType,
+    ) -> Result<Array1<A::Real>> {
+        let (eigs, _) = eigh_generalized(self, b, uplo, itype, false)?;
+        Ok(eigs)
+    }
+}
+
+fn eigh_generalized<A, Sa, Sb>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    uplo: UPLO,
+    itype: GeneralizedEigType,
+    calc_eigenvec: bool,
+) -> Result<(Array1<A::Real>, Array2<A>)>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    assert_eq!(
+        a.shape(),
+        b.shape(),
+        "The shapes of the matrices must be identical.",
+    );
+    let mut a = a.to_owned();
+    let layout = a.square_layout()?;
+    // `A` and `B` must be in the same memory order, since `uplo` is shared
+    let mut b_ = match layout {
+        MatrixLayout::C { .. } => Array2::zeros(b.dim()),
+        MatrixLayout::F { .. } => Array2::zeros(b.dim().f()),
+    };
+    b_.assign(b);
+    let s = A::eigh_generalized_dc(
+        calc_eigenvec,
+        layout,
+        itype,
+        uplo,
+        a.as_allocated_mut()?,
+        b_.as_allocated_mut()?,
+    )?;
+    Ok((ArrayBase::from(s), a))
+}
+
 /// Calculate symmetric square-root matrix using `eigh`
 pub trait SymmetricSqrt {
     type Output;
@@ -275,3 +463,40 @@ where
         Ok(v.apply2(&ev))
     }
 }
+
+/// Detect clusters of close eigenvalues
+///
+/// The eigenvectors belonging to a cluster of close eigenvalues are ill-determined,
+/// i.e. only the subspace spanned by them is reliable, not the individual vectors.
+/// This returns the index ranges of `values` in which adjacent eigenvalues are separated
+/// by less than `rel_gap` times the spectrum width `values[n-1] - values[0]`.
+/// Only clusters with two or more eigenvalues are returned.
+///
+/// `values` must be sorted in ascending order, as returned by [Eigh] and [EigValsh].
+///
+/// ```
+/// use ndarray_linalg::detect_eigenvalue_clusters;
+/// let e = [1.0, 2.0, 2.0 + 1e-10, 5.0];
+/// assert_eq!(detect_eigenvalue_clusters(&e, 1e-8), vec![1..3]);
+/// ```
+pub fn detect_eigenvalue_clusters<T: num_traits::Float>(
+    values: &[T],
+    rel_gap: T,
+) -> Vec<Range<usize>> {
+    let n = values.len();
+    if n < 2 {
+        return Vec::new();
+    }
+    let tol = rel_gap * (values[n - 1] - values[0]);
+    let mut clusters = Vec::new();
+    let mut start = 0;
+    for i in 1..=n {
+        if i == n || values[i] - values[i - 1] > tol {
+            if i - start > 1 {
+                clusters.push(start..i);
+            }
+            start = i;
+        }
+    }
+    clusters
+}
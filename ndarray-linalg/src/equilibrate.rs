@@ -0,0 +1,193 @@
+//! Equilibration of badly scaled matrices
+//!
+//! A matrix whose rows or columns have very different magnitudes is rescaled as `R A C`
+//! with the diagonal matrices `R` and `C` computed by `*geequ`,
+//! so that the largest absolute value in each row and column becomes 1.
+//! For a Hermitian (or real symmetric) positive definite matrix,
+//! `*poequ` computes the symmetric scaling `S A S` with `S_i = 1 / sqrt(A_ii)`.
+//!
+//! The linear equation `A x = b` is then solved as `(R A C) y = R b` and `x = C y`,
+//! which [SolveEquilibrated] does transparently.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![[1e10, 2e10], [3e-10, 1e-10]];
+//! let (eq, scaled) = a.equilibrate().unwrap();
+//! assert!(scaled.iter().all(|x| x.abs() <= 1.0 + 1e-12));
+//! assert_eq!(eq.r.len(), 2);
+//!
+//! let b = array![3e10, 4e-10];
+//! let x = a.solve_equilibrated(&b).unwrap();
+//! assert_close_l2!(&x, &array![1.0, 1.0], 1e-12);
+//! ```
+
+use ndarray::*;
+
+use crate::cholesky::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::solve::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// Row and column scale factors of a matrix
+#[derive(Debug, Clone, PartialEq)]
+pub struct Equilibration<A: Scalar> {
+    /// Row scale factors, i.e. the diagonal elements of `R`
+    pub r: Array1<A::Real>,
+    /// Column scale factors, i.e. the diagonal elements of `C`
+    pub c: Array1<A::Real>,
+    /// Ratio of the smallest to the largest element of `r`
+    pub rowcnd: A::Real,
+    /// Ratio of the smallest to the largest element of `c`
+    pub colcnd: A::Real,
+    /// Largest absolute value of the elements of the original matrix
+    pub amax: A::Real,
+}
+
+impl<A: Scalar> Equilibration<A> {
+    /// Scaled matrix `R A C`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shape of `a` is not `r.len() x c.len()`.
+    pub fn scale_matrix<S>(&self, a: &ArrayBase<S, Ix2>) -> Array2<A>
+    where
+        S: Data<Elem = A>,
+    {
+        assert_eq!(a.dim(), (self.r.len(), self.c.len()));
+        Array2::from_shape_fn(a.dim(), |(i, j)| a[(i, j)].mul_real(self.r[i] * self.c[j]))
+    }
+
+    /// Scaled right hand side `R b`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` is not `r.len()`.
+    pub fn scale_rhs<S>(&self, b: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        assert_eq!(b.len(), self.r.len());
+        Zip::from(b).and(&self.r).map_collect(|b, &r| b.mul_real(r))
+    }
+
+    /// Solution `x = C y` of `A x = b` from the solution `y` of `(R A C) y = R b`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `y` is not `c.len()`.
+    pub fn unscale_solution<S>(&self, y: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        assert_eq!(y.len(), self.c.len());
+        Zip::from(y).and(&self.c).map_collect(|y, &c| y.mul_real(c))
+    }
+}
+
+/// Equilibration of general matrices using `*geequ`
+pub trait Equilibrate<A: Scalar> {
+    /// Computes the scale factors and returns them with the scaled matrix `R A C`
+    ///
+    /// [LinalgError::Lapack] is returned if a row or column is exactly zero.
+    fn equilibrate(&self) -> Result<(Equilibration<A>, Array2<A>)>;
+}
+
+impl<A, S> Equilibrate<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn equilibrate(&self) -> Result<(Equilibration<A>, Array2<A>)> {
+        let l = self.layout()?;
+        let lax::EquilibrateOwned {
+            r,
+            c,
+            rowcnd,
+            colcnd,
+            amax,
+        } = A::equilibrate(l, self.as_allocated()?)?;
+        let eq = Equilibration {
+            r: Array1::from(r),
+            c: Array1::from(c),
+            rowcnd,
+            colcnd,
+            amax,
+        };
+        let scaled = eq.scale_matrix(self);
+        Ok((eq, scaled))
+    }
+}
+
+/// Equilibration of Hermitian (or real symmetric) positive definite matrices using `*poequ`
+pub trait EquilibrateH<A: Scalar> {
+    /// Computes the symmetric scale factors `r = c = S` and returns them with the scaled matrix `S A S`
+    ///
+    /// Only the diagonal elements are referenced to compute the factors,
+    /// and [LinalgError::Lapack] is returned if one of them is not positive.
+    fn equilibrateh(&self) -> Result<(Equilibration<A>, Array2<A>)>;
+}
+
+impl<A, S> EquilibrateH<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn equilibrateh(&self) -> Result<(Equilibration<A>, Array2<A>)> {
+        let l = self.square_layout()?;
+        let lax::EquilibrateHOwned { s, scond, amax } = A::equilibrateh(l, self.as_allocated()?)?;
+        let s = Array1::from(s);
+        let eq = Equilibration {
+            r: s.clone(),
+            c: s,
+            rowcnd: scond,
+            colcnd: scond,
+            amax,
+        };
+        let scaled = eq.scale_matrix(self);
+        Ok((eq, scaled))
+    }
+}
+
+/// Solve linear equations after equilibrating the coefficient matrix
+pub trait SolveEquilibrated<A: Scalar> {
+    /// Solves `A x = b` by the LU decomposition of `R A C` computed by [Equilibrate]
+    fn solve_equilibrated<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>>;
+
+    /// Solves `A x = b` for a Hermitian (or real symmetric) positive definite `A`
+    /// by the Cholesky decomposition of `S A S` computed by [EquilibrateH]
+    fn solvec_equilibrated<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>>;
+}
+
+impl<A, Sa> SolveEquilibrated<A> for ArrayBase<Sa, Ix2>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+{
+    fn solve_equilibrated<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>> {
+        if b.len() != self.nrows() {
+            return Err(LinalgError::Shape(ShapeError::from_kind(
+                ErrorKind::IncompatibleShape,
+            )));
+        }
+        let (eq, scaled) = self.equilibrate()?;
+        let y = scaled.solve_into(eq.scale_rhs(b))?;
+        Ok(eq.unscale_solution(&y))
+    }
+
+    fn solvec_equilibrated<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>> {
+        if b.len() != self.nrows() {
+            return Err(LinalgError::Shape(ShapeError::from_kind(
+                ErrorKind::IncompatibleShape,
+            )));
+        }
+        let (eq, scaled) = self.equilibrateh()?;
+        let y = scaled
+            .factorizec(UPLO::Upper)?
+            .solvec_into(eq.scale_rhs(b))?;
+        Ok(eq.unscale_solution(&y))
+    }
+}
@@ -35,4 +35,16 @@ pub enum LinalgError {
     /// Strides of the array is not supported
     #[error(transparent)]
     Shape(#[from] ShapeError),
+
+    /// Matrix is not positive definite
+    #[error("Matrix is not positive definite")]
+    NotPositiveDefinite,
+
+    /// Matrix contains infinite or NaN elements
+    #[error("Matrix contains infinite or NaN elements")]
+    NonFinite,
+
+    /// Function is not analytic around a cluster of eigenvalues, see [crate::funm::Funm::funm]
+    #[error("Function is not analytic around the eigenvalues")]
+    NotAnalytic,
 }
@@ -0,0 +1,422 @@
+//! Matrix exponential
+//!
+//! The matrix exponential `exp(A) = I + A + A^2 / 2! + ...` is computed by the scaling and squaring method
+//! with Padé approximation, i.e. `exp(A) = r(A / 2^s)^(2^s)` where `r` is the `[m/m]` Padé approximant.
+//! The degree `m` and the number of squarings `s` are selected from the 1-norm of `A`
+//! following Higham (2005), which is the algorithm used in MATLAB and SciPy.
+//!
+//! - N. J. Higham, "The Scaling and Squaring Method for the Matrix Exponential Revisited",
+//!   SIAM J. Matrix Anal. Appl. 26 (4), 2005.
+//!
+//! The action `exp(tA) B` is computed by [expm_multiply] without forming `exp(tA)`,
+//! using the truncated Taylor series `exp(tA / s)` applied `s` times,
+//! where the degree `m` and `s` are selected from the 1-norm of `A` to minimize the number `m s` of products `A B`.
+//!
+//! - A. H. Al-Mohy and N. J. Higham, "Computing the Action of the Matrix Exponential,
+//!   with an Application to Exponential Integrators", SIAM J. Sci. Comput. 33 (2), 2011.
+
+use ndarray::linalg::general_mat_mul;
+use ndarray::*;
+use num_traits::{Float, ToPrimitive, Zero};
+
+use crate::error::*;
+use crate::layout::*;
+use crate::opnorm::*;
+use crate::solve::*;
+use crate::types::*;
+
+/// Maximum 1-norm of `A` for which the `[m/m]` Padé approximant is accurate in double precision
+const THETA: [(usize, f64); 4] = [
+    (3, 1.495585217958292e-2),
+    (5, 2.53939833006323e-1),
+    (7, 9.504178996162932e-1),
+    (9, 2.097847961257068e0),
+];
+const THETA_13: f64 = 5.371920351148152e0;
+
+/// Maximum 1-norm of `tA / s` for which the degree `m` Taylor series is accurate in double precision
+const THETA_TAYLOR: [(usize, f64); 35] = [
+    (1, 2.29e-16),
+    (2, 2.58e-8),
+    (3, 1.39e-5),
+    (4, 3.40e-4),
+    (5, 2.40e-3),
+    (6, 9.07e-3),
+    (7, 2.38e-2),
+    (8, 5.00e-2),
+    (9, 8.96e-2),
+    (10, 1.44e-1),
+    (11, 2.14e-1),
+    (12, 3.00e-1),
+    (13, 4.00e-1),
+    (14, 5.14e-1),
+    (15, 6.41e-1),
+    (16, 7.81e-1),
+    (17, 9.31e-1),
+    (18, 1.09),
+    (19, 1.26),
+    (20, 1.44),
+    (21, 1.62),
+    (22, 1.82),
+    (23, 2.01),
+    (24, 2.22),
+    (25, 2.43),
+    (26, 2.64),
+    (27, 2.86),
+    (28, 3.08),
+    (29, 3.31),
+    (30, 3.54),
+    (35, 4.7),
+    (40, 6.0),
+    (45, 7.2),
+    (50, 8.5),
+    (55, 9.9),
+];
+
+/// Coefficients of the numerator of the `[m/m]` Padé approximant of `exp(x)`
+fn pade_coefficients(m: usize) -> &'static [f64] {
+    match m {
+        3 => &[120., 60., 12., 1.],
+        5 => &[30240., 15120., 3360., 420., 30., 1.],
+        7 => &[
+            17297280., 8648640., 1995840., 277200., 25200., 1512., 56., 1.,
+        ],
+        9 => &[
+            17643225600.,
+            8821612800.,
+            2075673600.,
+            302702400.,
+            30270240.,
+            2162160.,
+            110880.,
+            3960.,
+            90.,
+            1.,
+        ],
+        13 => &[
+            64764752532480000.,
+            32382376266240000.,
+            7771770303897600.,
+            1187353796428800.,
+            129060195264000.,
+            10559470521600.,
+            670442572800.,
+            33522128640.,
+            1323241920.,
+            40840800.,
+            960960.,
+            16380.,
+            182.,
+            1.,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Matrix exponential of a square matrix
+///
+/// The workspace of the Padé approximant is allocated on each call;
+/// use [expm_with_work] to reuse it.
+/// [LinalgError::NonFinite] is returned if `A` contains infinite or NaN elements.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Rotation by 1 radian
+/// let a: Array2<f64> = array![[0.0, -1.0], [1.0, 0.0]];
+/// let r = a.expm().unwrap();
+/// let (c, s) = (1.0_f64.cos(), 1.0_f64.sin());
+/// assert_close_l2!(&r, &array![[c, -s], [s, c]], 1e-12);
+/// ```
+pub trait Expm {
+    type Output;
+    fn expm(&self) -> Result<Self::Output>;
+}
+
+impl<A, S> Expm for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn expm(&self) -> Result<Self::Output> {
+        expm_with_work(self, &mut ExpmWork::new(self.nrows()))
+    }
+}
+
+/// Workspace of [expm_with_work] reused for `n x n` matrices
+///
+/// The scaled `A`, its powers, and the odd and even parts `U`, `V` of the Padé approximant
+/// are allocated once in [ExpmWork::new], and overwritten by each call.
+#[derive(Debug, Clone)]
+pub struct ExpmWork<A: Scalar> {
+    a: Array2<A>,
+    a2: Array2<A>,
+    a4: Array2<A>,
+    a6: Array2<A>,
+    u: Array2<A>,
+    v: Array2<A>,
+    tmp: Array2<A>,
+}
+
+impl<A: Scalar + Lapack> ExpmWork<A> {
+    pub fn new(n: usize) -> Self {
+        let zeros = || Array2::zeros((n, n));
+        ExpmWork {
+            a: zeros(),
+            a2: zeros(),
+            a4: zeros(),
+            a6: zeros(),
+            u: zeros(),
+            v: zeros(),
+            tmp: zeros(),
+        }
+    }
+
+    /// Size `n` of the matrices this workspace accepts
+    pub fn size(&self) -> usize {
+        self.a.nrows()
+    }
+
+    /// `[m/m]` Padé approximant `r(A) = (V - U)^{-1} (V + U)` for `m <= 9`,
+    /// where `U` and `V` are the odd and even parts of the numerator
+    fn pade(&mut self, b: &[f64]) -> Result<Array2<A>> {
+        let ExpmWork {
+            a,
+            a2,
+            a4: pow,
+            a6: next,
+            u,
+            v,
+            tmp: odd,
+        } = self;
+        general_mat_mul(A::one(), a, a, A::zero(), a2);
+        set_identity(pow);
+        odd.fill(A::zero());
+        v.fill(A::zero());
+        for (j, c) in b.chunks(2).enumerate() {
+            if j > 0 {
+                general_mat_mul(A::one(), pow, a2, A::zero(), next);
+                std::mem::swap(pow, next);
+            }
+            v.scaled_add(A::from_real(A::real(c[0])), pow);
+            odd.scaled_add(A::from_real(A::real(c[1])), pow);
+        }
+        general_mat_mul(A::one(), a, odd, A::zero(), u);
+        self.solve_pade()
+    }
+
+    /// `[13/13]` Padé approximant evaluated with six matrix multiplications
+    fn pade13(&mut self) -> Result<Array2<A>> {
+        let b: Vec<A> = pade_coefficients(13)
+            .iter()
+            .map(|&c| A::from_real(A::real(c)))
+            .collect();
+        let ExpmWork {
+            a,
+            a2,
+            a4,
+            a6,
+            u,
+            v,
+            tmp,
+        } = self;
+        general_mat_mul(A::one(), a, a, A::zero(), a2);
+        general_mat_mul(A::one(), a2, a2, A::zero(), a4);
+        general_mat_mul(A::one(), a4, a2, A::zero(), a6);
+        // U = A (A6 (b13 A6 + b11 A4 + b9 A2) + b7 A6 + b5 A4 + b3 A2 + b1 I)
+        combine(tmp, &[(b[13], a6), (b[11], a4), (b[9], a2)]);
+        general_mat_mul(A::one(), a6, tmp, A::zero(), v);
+        add_combination(v, &[(b[7], a6), (b[5], a4), (b[3], a2)], b[1]);
+        general_mat_mul(A::one(), a, v, A::zero(), u);
+        // V = A6 (b12 A6 + b10 A4 + b8 A2) + b6 A6 + b4 A4 + b2 A2 + b0 I
+        combine(tmp, &[(b[12], a6), (b[10], a4), (b[8], a2)]);
+        general_mat_mul(A::one(), a6, tmp, A::zero(), v);
+        add_combination(v, &[(b[6], a6), (b[4], a4), (b[2], a2)], b[0]);
+        self.solve_pade()
+    }
+
+    /// Solve `(V - U) R = V + U`
+    fn solve_pade(&mut self) -> Result<Array2<A>> {
+        let mut r = Array2::zeros(self.u.dim().f());
+        r.assign(&self.v);
+        r += &self.u;
+        self.v -= &self.u;
+        let l = self.v.square_layout()?;
+        let ipiv = A::lu(l, self.v.as_allocated_mut()?)?;
+        for mut col in r.axis_iter_mut(Axis(1)) {
+            A::solve(
+                l,
+                Transpose::No,
+                self.v.as_allocated()?,
+                &ipiv,
+                col.as_slice_mut().unwrap(),
+            )?;
+        }
+        Ok(r)
+    }
+}
+
+/// Matrix exponential reusing the workspace for the matrices of the same size
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let mut work = ExpmWork::new(3);
+/// for _ in 0..3 {
+///     let a: Array2<f64> = random((3, 3));
+///     let r = expm_with_work(&a, &mut work).unwrap();
+///     assert_close_l2!(&r, &a.expm().unwrap(), 1e-12);
+/// }
+/// ```
+pub fn expm_with_work<A, S>(a: &ArrayBase<S, Ix2>, work: &mut ExpmWork<A>) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    a.square_layout()?;
+    if a.nrows() != work.size() {
+        return Err(LinalgError::Shape(ShapeError::from_kind(
+            ErrorKind::IncompatibleShape,
+        )));
+    }
+    let norm = a.opnorm_one()?;
+    if !norm.is_finite() {
+        return Err(LinalgError::NonFinite);
+    }
+    work.a.assign(a);
+    for (m, theta) in THETA {
+        if norm <= A::real(theta) {
+            return work.pade(pade_coefficients(m));
+        }
+    }
+    // Scale `A` so that its 1-norm is at most `THETA_13`
+    let s = (norm / A::real(THETA_13)).log2().ceil();
+    let s = if s > A::Real::zero() {
+        s.to_i32().unwrap()
+    } else {
+        0
+    };
+    let scale = A::from_real(A::real(0.5_f64.powi(s)));
+    work.a.mapv_inplace(|x| x * scale);
+    let mut r = work.pade13()?;
+    for _ in 0..s {
+        general_mat_mul(A::one(), &r, &r, A::zero(), &mut work.tmp);
+        std::mem::swap(&mut r, &mut work.tmp);
+    }
+    Ok(r)
+}
+
+fn set_identity<A: Scalar>(a: &mut Array2<A>) {
+    a.fill(A::zero());
+    a.diag_mut().fill(A::one());
+}
+
+/// `Y = sum_i c_i X_i`
+fn combine<A: Scalar>(y: &mut Array2<A>, terms: &[(A, &Array2<A>)]) {
+    y.fill(A::zero());
+    add_combination(y, terms, A::zero());
+}
+
+/// `Y += sum_i c_i X_i + c I`
+fn add_combination<A: Scalar>(y: &mut Array2<A>, terms: &[(A, &Array2<A>)], c: A) {
+    for (ci, x) in terms {
+        y.scaled_add(*ci, x);
+    }
+    y.diag_mut().mapv_inplace(|d| d + c);
+}
+
+/// Compute `exp(tA) B` without forming `exp(tA)`
+///
+/// `B` is a vector or a matrix, and only products of `A` and `B` are evaluated,
+/// which is much cheaper than [Expm::expm] when `B` has a few columns.
+/// [LinalgError::NonFinite] is returned if `tA` contains infinite or NaN elements.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = random((10, 10));
+/// let b: Array1<f64> = random(10);
+/// let x = expm_multiply(&a, &b, 0.5).unwrap();
+/// let ans = a.mapv(|x| x * 0.5).expm().unwrap().dot(&b);
+/// assert_close_l2!(&x, &ans, 1e-9);
+/// ```
+pub fn expm_multiply<A, Sa, Sb, D>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, D>,
+    t: A,
+) -> Result<Array<A, D>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    D: Dimension,
+    Array2<A>: linalg::Dot<Array<A, D>, Output = Array<A, D>>,
+{
+    a.square_layout()?;
+    let n = a.nrows();
+    if b.shape()[0] != n {
+        return Err(LinalgError::Shape(ShapeError::from_kind(
+            ErrorKind::IncompatibleShape,
+        )));
+    }
+    if n == 0 {
+        return Ok(b.to_owned());
+    }
+    // Shift by the mean of eigenvalues to reduce the norm, exp(tA) = exp(t mu) exp(t (A - mu I))
+    let mu = a.diag().sum() / A::from_real(A::real(n));
+    let mut a = a.to_owned();
+    a.diag_mut().map_inplace(|x| *x -= mu);
+
+    let norm = t.abs() * a.opnorm_one()?;
+    if !norm.is_finite() {
+        return Err(LinalgError::NonFinite);
+    }
+    let (m, s) = if norm.is_zero() {
+        (0, 1)
+    } else {
+        THETA_TAYLOR
+            .iter()
+            .map(|&(m, theta)| {
+                let s = (norm / A::real(theta))
+                    .ceil()
+                    .to_usize()
+                    .unwrap_or(usize::MAX);
+                (m, s.max(1))
+            })
+            .min_by_key(|&(m, s)| m.saturating_mul(s))
+            .unwrap()
+    };
+
+    let tol = A::Real::epsilon();
+    let eta = (t * mu / A::from_real(A::real(s))).exp();
+    let mut f = b.to_owned();
+    for _ in 0..s {
+        let mut b = f.clone();
+        let mut c1 = max_abs(&b);
+        for j in 1..=m {
+            let coef = t / A::from_real(A::real(s * j));
+            b = a.dot(&b).mapv_into(|x| x * coef);
+            let c2 = max_abs(&b);
+            f += &b;
+            // Truncate when two consecutive terms are negligible
+            if c1 + c2 <= tol * max_abs(&f) {
+                break;
+            }
+            c1 = c2;
+        }
+        f.mapv_inplace(|x| x * eta);
+    }
+    Ok(f)
+}
+
+/// Maximum absolute value of the elements
+fn max_abs<A: Scalar, D: Dimension>(a: &Array<A, D>) -> A::Real {
+    a.iter()
+        .map(|x| x.abs())
+        .fold(A::Real::zero(), |a, b| if a > b { a } else { b })
+}
@@ -0,0 +1,289 @@
+//! General matrix functions
+//!
+//! A matrix function `f(A)` for a scalar function `f` analytic on the spectrum of `A`
+//! is computed by the Schur-Parlett algorithm:
+//! `A = Z T Z^H` is reduced into the complex upper triangular `T`,
+//! which is reordered so that close eigenvalues form contiguous diagonal blocks.
+//! `f` of each diagonal block is evaluated by the Cauchy integral
+//! `f(T_ii) = (2 pi i)^{-1} int f(z) (z I - T_ii)^{-1} dz` along a circle enclosing its eigenvalues.
+//! The circle is shrunk toward the eigenvalues while the result does not reproduce `f`
+//! on the diagonal, i.e. while a singularity of `f` is inside the circle,
+//! and the off-diagonal blocks are determined by the block Parlett recurrence,
+//! i.e. the Sylvester equations derived from `f(T) T = T f(T)`.
+//!
+//! - P. I. Davies and N. J. Higham, "A Schur-Parlett algorithm for computing matrix functions",
+//!   SIAM J. Matrix Anal. Appl. 25 (2), 2003.
+
+use ndarray::*;
+use num_traits::{ToPrimitive, Zero};
+
+use crate::error::*;
+use crate::expm::*;
+use crate::generate::conjugate;
+use crate::logm::*;
+use crate::schur::*;
+use crate::triangular::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// Eigenvalues closer than this are put into the same diagonal block
+const DELTA: f64 = 0.1;
+
+/// Minimum number of the quadrature points of the Cauchy integral on a diagonal block
+const CONTOUR_POINTS: usize = 64;
+
+/// Maximum number of the quadrature points of the Cauchy integral on a diagonal block
+const MAX_CONTOUR_POINTS: usize = 4096;
+
+/// Number of the circles tried for a diagonal block
+const CONTOUR_TRIALS: usize = 8;
+
+/// Matrix functions of a square matrix
+///
+/// The results are complex even for real matrices, as [Logm] and [Sqrtm](crate::sqrtm::Sqrtm).
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = random((4, 4));
+/// // cos(A)^2 + sin(A)^2 = I
+/// let c = a.funm(|z| z.cos()).unwrap();
+/// let s = a.funm(|z| z.sin()).unwrap();
+/// assert_close_l2!(&(c.dot(&c) + s.dot(&s)), &Array2::eye(4), 1e-9);
+/// // A^(1/3) cubed is A
+/// let r = a.powm(1.0 / 3.0).unwrap();
+/// assert_close_l2!(&r.dot(&r).dot(&r), &a.mapv(|x| x.as_c()), 1e-9);
+/// ```
+pub trait Funm {
+    type Complex;
+    type Real;
+    type Output;
+
+    /// Compute `f(A)` by the Schur-Parlett algorithm
+    ///
+    /// `f` is called with complex arguments. It must be analytic on the eigenvalues of `A`,
+    /// and for each cluster of eigenvalues closer than `0.1` to each other, in a disk around the cluster.
+    /// The radius of the disk is twice the spread of the cluster but at least `0.05`,
+    /// and it is shrunk toward the spread if `f` is found to have a singularity inside the disk.
+    /// [LinalgError::NotAnalytic] is returned if no disk is found.
+    fn funm<F>(&self, f: F) -> Result<Self::Output>
+    where
+        F: Fn(Self::Complex) -> Self::Complex;
+
+    /// Compute the principal power `A^p = exp(p log(A))` for a real exponent `p`
+    ///
+    /// [LinalgError::Lapack] is returned for singular matrices as [Logm::logm].
+    fn powm(&self, p: Self::Real) -> Result<Self::Output>;
+}
+
+impl<A, S> Funm for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    A::Complex: Lapack,
+    S: Data<Elem = A>,
+{
+    type Complex = A::Complex;
+    type Real = A::Real;
+    type Output = Array2<A::Complex>;
+
+    fn funm<F>(&self, f: F) -> Result<Self::Output>
+    where
+        F: Fn(A::Complex) -> A::Complex,
+    {
+        let (mut t, mut z) = complex_schur(self)?;
+        let blocks = reorder_clusters(&mut t, &mut z)?;
+        let ft = funm_triangular(&t, &blocks, &f)?;
+        Ok(z.dot(&ft).dot(&conjugate::<_, _, OwnedRepr<_>>(&z)))
+    }
+
+    fn powm(&self, p: A::Real) -> Result<Self::Output> {
+        let p = A::Complex::from_real(p);
+        self.logm()?.mapv(|x| x * p).expm()
+    }
+}
+
+/// Reorder the Schur form so that each cluster of close eigenvalues forms a contiguous diagonal block,
+/// and return the ranges of the blocks
+fn reorder_clusters<T: Scalar + Lapack>(
+    t: &mut Array2<T>,
+    z: &mut Array2<T>,
+) -> Result<Vec<std::ops::Range<usize>>> {
+    let n = t.nrows();
+    let eigs: Vec<T> = t.diag().to_vec();
+    // Connected components of the graph where eigenvalues closer than `DELTA` are adjacent
+    let mut cluster: Vec<usize> = (0..n).collect();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for i in 0..n {
+            for j in 0..n {
+                if cluster[j] < cluster[i] && (eigs[i] - eigs[j]).abs() <= T::real(DELTA) {
+                    cluster[i] = cluster[j];
+                    changed = true;
+                }
+            }
+        }
+    }
+    // Move the eigenvalues of each cluster next to each other
+    let mut blocks = Vec::new();
+    let mut start = 0;
+    while start < n {
+        let mut end = start + 1;
+        for q in (start + 1)..n {
+            if cluster[q] == cluster[start] {
+                if q != end {
+                    reorder_schur(t, Some(&mut *z), q, end)?;
+                    let moved = cluster.remove(q);
+                    cluster.insert(end, moved);
+                }
+                end += 1;
+            }
+        }
+        blocks.push(start..end);
+        start = end;
+    }
+    Ok(blocks)
+}
+
+/// `f(T)` of an upper triangular matrix by the block Parlett recurrence
+fn funm_triangular<T, F>(
+    t: &Array2<T>,
+    blocks: &[std::ops::Range<usize>],
+    f: &F,
+) -> Result<Array2<T>>
+where
+    T: Scalar<Complex = T> + Lapack,
+    F: Fn(T) -> T,
+{
+    let n = t.nrows();
+    let mut ft = Array2::zeros((n, n));
+    for (j, bj) in blocks.iter().enumerate() {
+        let tjj = t.slice(s![bj.clone(), bj.clone()]).to_owned();
+        ft.slice_mut(s![bj.clone(), bj.clone()])
+            .assign(&funm_block(&tjj, f)?);
+        for i in (0..j).rev() {
+            let bi = blocks[i].clone();
+            // T_ii F_ij - F_ij T_jj = F_ii T_ij - T_ij F_jj + sum_k (F_ik T_kj - T_ik F_kj)
+            let tij = t.slice(s![bi.clone(), bj.clone()]);
+            let mut c = ft.slice(s![bi.clone(), bi.clone()]).dot(&tij)
+                - tij.dot(&ft.slice(s![bj.clone(), bj.clone()]));
+            let mid = bi.end..bj.start;
+            if !mid.is_empty() {
+                c = c + ft
+                    .slice(s![bi.clone(), mid.clone()])
+                    .dot(&t.slice(s![mid.clone(), bj.clone()]))
+                    - t.slice(s![bi.clone(), mid.clone()])
+                        .dot(&ft.slice(s![mid.clone(), bj.clone()]));
+            }
+            let tii = t.slice(s![bi.clone(), bi.clone()]).to_owned();
+            let fij = solve_triangular_sylvester(&tii, &tjj, c)?;
+            ft.slice_mut(s![bi, bj.clone()]).assign(&fij);
+        }
+    }
+    Ok(ft)
+}
+
+/// `f(T)` of a diagonal block by the trapezoidal rule on a circle enclosing its eigenvalues
+fn funm_block<T, F>(t: &Array2<T>, f: &F) -> Result<Array2<T>>
+where
+    T: Scalar<Complex = T> + Lapack,
+    F: Fn(T) -> T,
+{
+    let m = t.nrows();
+    if m == 1 {
+        return Ok(Array2::from_elem((1, 1), f(t[(0, 0)])));
+    }
+    let center = t.diag().sum() / T::from_real(T::real(m as f64));
+    let spread = t
+        .diag()
+        .iter()
+        .map(|x| (*x - center).abs())
+        .fold(T::Real::zero(), |a, b| if a > b { a } else { b });
+    let mut radius = {
+        let r = spread + spread;
+        let r_min = T::real(DELTA / 2.0);
+        if r > r_min {
+            r
+        } else {
+            r_min
+        }
+    };
+    for _ in 0..CONTOUR_TRIALS {
+        if let Some(ft) = contour_integral(t, f, center, spread, radius)? {
+            return Ok(ft);
+        }
+        radius = spread + (radius - spread) / T::real(2.0);
+    }
+    Err(LinalgError::NotAnalytic)
+}
+
+/// Cauchy integral of `f(T)` on the circle `|z - center| = radius`
+///
+/// `None` is returned if the diagonal of the result is not `f` of the eigenvalues,
+/// which means that `f` has a singularity inside the circle.
+fn contour_integral<T, F>(
+    t: &Array2<T>,
+    f: &F,
+    center: T,
+    spread: T::Real,
+    radius: T::Real,
+) -> Result<Option<Array2<T>>>
+where
+    T: Scalar<Complex = T> + Lapack,
+    F: Fn(T) -> T,
+{
+    let m = t.nrows();
+    let eps: T::Real = num_traits::Float::epsilon();
+    // The quadrature error decays as `(spread / radius)^points`
+    let points = if spread > T::Real::zero() {
+        num_traits::Float::ceil(eps.ln() / (spread / radius).ln())
+            .to_usize()
+            .unwrap_or(MAX_CONTOUR_POINTS)
+            .clamp(CONTOUR_POINTS, MAX_CONTOUR_POINTS)
+    } else {
+        CONTOUR_POINTS
+    };
+    let mut ft = Array2::zeros((m, m));
+    let mut scale = T::Real::zero();
+    for k in 0..points {
+        let theta = 2.0 * std::f64::consts::PI * k as f64 / points as f64;
+        let dz = T::complex(theta.cos(), theta.sin()).mul_real(radius);
+        let z = center + dz;
+        // (z I - T)^{-1}
+        let mut shifted = t.mapv(|x| -x);
+        shifted.diag_mut().map_inplace(|x| *x += z);
+        let resolvent = shifted.solve_triangular(UPLO::Upper, Diag::NonUnit, &Array2::eye(m))?;
+        let fz = f(z);
+        if fz.abs() > scale {
+            scale = fz.abs();
+        }
+        ft.scaled_add(fz * dz / T::from_real(T::real(points as f64)), &resolvent);
+    }
+    let tol = eps.sqrt() * scale;
+    // `false` also for NaN
+    let reproduced = (0..m).all(|i| (ft[(i, i)] - f(t[(i, i)])).abs() <= tol);
+    Ok(if reproduced { Some(ft) } else { None })
+}
+
+/// Solve the Sylvester equation `A X - X B = C` for upper triangular `A` and `B`
+/// by substitution column by column
+fn solve_triangular_sylvester<T: Scalar + Lapack>(
+    a: &Array2<T>,
+    b: &Array2<T>,
+    c: Array2<T>,
+) -> Result<Array2<T>> {
+    let mut x = c;
+    for q in 0..b.nrows() {
+        // (A - b_qq I) x_q = c_q + sum_{p < q} x_p b_pq
+        let mut rhs = x.column(q).to_owned();
+        for p in 0..q {
+            rhs.scaled_add(b[(p, q)], &x.column(p));
+        }
+        let mut shifted = a.clone();
+        shifted.diag_mut().map_inplace(|v| *v -= b[(q, q)]);
+        let xq = shifted.solve_triangular(UPLO::Upper, Diag::NonUnit, &rhs)?;
+        x.column_mut(q).assign(&xq);
+    }
+    Ok(x)
+}
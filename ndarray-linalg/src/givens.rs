@@ -0,0 +1,110 @@
+//! Givens rotation
+//!
+//! A Givens rotation `G = [c s; -conj(s) c]` with real `c` and `c^2 + |s|^2 = 1`
+//! acting on the `i`-th and `j`-th coordinates eliminates an element of a vector or a matrix.
+//! [Givens::new] follows the convention of `*lartg` in LAPACK 3.10,
+//! i.e. `G [f; g] = [r; 0]` with `c >= 0`, and `r` has the same phase as `f` if `f != 0`.
+//! It is implemented in Rust since `*lartg` is not exported by `lapack-sys`.
+//!
+//! - E. Anderson, "Algorithm 978: Safe Scaling in the Level 1 BLAS", ACM Trans. Math. Softw. 44 (1), 2017.
+
+use ndarray::*;
+use num_traits::{Float, One, Zero};
+
+use crate::types::*;
+
+/// Givens rotation `G = [c s; -conj(s) c]`
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let g = Givens::new(3.0, 4.0);
+/// let (r, z) = g.rotate(3.0, 4.0);
+/// assert!((r - 5.0_f64).abs() < 1e-12 && z.abs() < 1e-12);
+///
+/// // Eliminate a[(2, 0)] by rotating the 1st and 3rd rows
+/// let mut a: Array2<f64> = array![[3.0, 1.0], [1.0, 2.0], [4.0, 0.0]];
+/// g.rotate_rows(&mut a, 0, 2);
+/// assert!(a[(2, 0)].abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Givens<A: Scalar> {
+    pub c: A::Real,
+    pub s: A,
+}
+
+impl<A: Scalar> Givens<A> {
+    /// Rotation satisfying `G [f; g] = [r; 0]`
+    pub fn new(f: A, g: A) -> Self {
+        let zero = A::Real::zero();
+        if g.abs() == zero {
+            return Givens {
+                c: A::Real::one(),
+                s: A::zero(),
+            };
+        }
+        if f.abs() == zero {
+            return Givens {
+                c: zero,
+                s: g.conj().mul_real(A::Real::one() / g.abs()),
+            };
+        }
+        let (fa, ga) = (f.abs(), g.abs());
+        let d = Float::hypot(fa, ga);
+        Givens {
+            c: fa / d,
+            s: (g.conj() * f.mul_real(A::Real::one() / fa)).mul_real(A::Real::one() / d),
+        }
+    }
+
+    /// Computes `G [f; g]`
+    pub fn rotate(&self, f: A, g: A) -> (A, A) {
+        (
+            f.mul_real(self.c) + self.s * g,
+            g.mul_real(self.c) - self.s.conj() * f,
+        )
+    }
+
+    /// Overwrites `A` by `G A` where `G` acts on the `i`-th and `j`-th rows
+    ///
+    /// Panics if `i` or `j` is out of bounds, or `i == j`.
+    pub fn rotate_rows<S>(&self, a: &mut ArrayBase<S, Ix2>, i: usize, j: usize)
+    where
+        S: DataMut<Elem = A>,
+    {
+        assert_ne!(i, j);
+        for mut col in a.columns_mut() {
+            let (x, y) = self.rotate(col[i], col[j]);
+            col[i] = x;
+            col[j] = y;
+        }
+    }
+
+    /// Overwrites `A` by `A G^H` where `G` acts on the `i`-th and `j`-th columns
+    ///
+    /// `G A G^H` is obtained with [Givens::rotate_rows].
+    /// Panics if `i` or `j` is out of bounds, or `i == j`.
+    pub fn rotate_cols<S>(&self, a: &mut ArrayBase<S, Ix2>, i: usize, j: usize)
+    where
+        S: DataMut<Elem = A>,
+    {
+        assert_ne!(i, j);
+        // (A G^H)^H = G A^H
+        for mut row in a.rows_mut() {
+            let (x, y) = self.rotate(row[i].conj(), row[j].conj());
+            row[i] = x.conj();
+            row[j] = y.conj();
+        }
+    }
+
+    /// `n x n` unitary matrix of the rotation acting on the `i`-th and `j`-th coordinates
+    ///
+    /// Products of rotations are accumulated by applying [Givens::rotate_rows]
+    /// or [Givens::rotate_cols] to this or the identity matrix.
+    pub fn to_matrix(&self, n: usize, i: usize, j: usize) -> Array2<A> {
+        let mut g = Array2::eye(n);
+        self.rotate_rows(&mut g, i, j);
+        g
+    }
+}
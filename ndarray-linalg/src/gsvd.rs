@@ -0,0 +1,130 @@
+//! Generalized singular value decomposition (GSVD) of a matrix pair
+//!
+//! For a `m x n` matrix `A` and a `p x n` matrix `B`, GSVD decomposes them simultaneously as
+//!
+//! ```text
+//! A = U D1 [0 R] Q^H
+//! B = V D2 [0 R] Q^H
+//! ```
+//!
+//! where `U`, `V` and `Q` are unitary, `R` is a `(k + l) x (k + l)` nonsingular upper triangular matrix,
+//! and `D1`, `D2` are "diagonal" matrices of the generalized singular value pairs `(alpha_i, beta_i)`.
+//! The ratios `alpha_i / beta_i` are the generalized singular values,
+//! which reduce to the singular values of `A B^{-1}` if `B` is square and nonsingular.
+//!
+//! - [Wikipedia article on GSVD](https://en.wikipedia.org/wiki/Generalized_singular_value_decomposition)
+
+use ndarray::*;
+
+use crate::convert::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+/// Result of [GSVD::gsvd]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gsvd<A: Scalar> {
+    /// `m x m` unitary matrix
+    pub u: Array2<A>,
+    /// `p x p` unitary matrix
+    pub v: Array2<A>,
+    /// `n x n` unitary matrix
+    pub q: Array2<A>,
+    /// `n` generalized singular value pairs `(alpha[i], beta[i])` with `alpha[i]^2 + beta[i]^2 = 1`.
+    /// Only the first `k + l` pairs are meaningful, and `alpha[..k]` are one.
+    pub alpha: Array1<A::Real>,
+    pub beta: Array1<A::Real>,
+    /// `(k + l) x (k + l)` upper triangular matrix
+    pub r: Array2<A>,
+    pub k: usize,
+    /// `k + l` is the effective numerical rank of `[A; B]`
+    pub l: usize,
+}
+
+impl<A: Scalar> Gsvd<A> {
+    /// `m x (k + l)` matrix `D1` whose `(i, i)` element is `alpha[i]`
+    pub fn d1(&self) -> Array2<A> {
+        let m = self.u.nrows();
+        let kl = self.k + self.l;
+        let mut d1 = Array2::zeros((m, kl));
+        for i in 0..m.min(kl) {
+            d1[(i, i)] = A::from_real(self.alpha[i]);
+        }
+        d1
+    }
+
+    /// `p x (k + l)` matrix `D2` whose `(i - k, i)` element is `beta[i]` for `k <= i < k + l`
+    pub fn d2(&self) -> Array2<A> {
+        let p = self.v.nrows();
+        let kl = self.k + self.l;
+        let mut d2 = Array2::zeros((p, kl));
+        for i in self.k..kl {
+            d2[(i - self.k, i)] = A::from_real(self.beta[i]);
+        }
+        d2
+    }
+
+    /// `(k + l) x n` matrix `[0 R]`
+    pub fn zero_r(&self) -> Array2<A> {
+        let n = self.q.nrows();
+        let kl = self.k + self.l;
+        let mut zr = Array2::zeros((kl, n));
+        zr.slice_mut(s![.., n - kl..]).assign(&self.r);
+        zr
+    }
+}
+
+/// Generalized singular value decomposition of a matrix pair using `*ggsvd3`
+pub trait GSVD<A, S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    /// Compute GSVD of `self` and `b`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of columns of `self` and `b` mismatch.
+    fn gsvd(&self, b: &ArrayBase<S, Ix2>) -> Result<Gsvd<A>>;
+}
+
+impl<A, Sa, Sb> GSVD<A, Sb> for ArrayBase<Sa, Ix2>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    fn gsvd(&self, b: &ArrayBase<Sb, Ix2>) -> Result<Gsvd<A>> {
+        let (m, n) = self.dim();
+        let p = b.nrows();
+        assert_eq!(b.ncols(), n, "The number of columns of A and B mismatch");
+        // `*ggsvd3` only accepts column-major matrices
+        let mut a_f = Array2::zeros((m, n).f());
+        a_f.assign(self);
+        let mut b_f = Array2::zeros((p, n).f());
+        b_f.assign(b);
+        let la = a_f.layout()?;
+        let lb = b_f.layout()?;
+        let res = A::gsvd(
+            la,
+            lb,
+            true,
+            true,
+            true,
+            a_f.as_allocated_mut()?,
+            b_f.as_allocated_mut()?,
+        )?;
+        let kl = (res.k + res.l) as i32;
+        let (m, n, p) = (m as i32, n as i32, p as i32);
+        Ok(Gsvd {
+            u: into_matrix(la.resized(m, m), res.u.unwrap())?,
+            v: into_matrix(lb.resized(p, p), res.v.unwrap())?,
+            q: into_matrix(la.resized(n, n), res.q.unwrap())?,
+            alpha: Array1::from(res.alpha),
+            beta: Array1::from(res.beta),
+            r: into_matrix(la.resized(kl, kl), res.r)?,
+            k: res.k,
+            l: res.l,
+        })
+    }
+}
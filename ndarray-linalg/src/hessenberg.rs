@@ -0,0 +1,65 @@
+//! Hessenberg form of general matrices
+//!
+//! A square matrix `A` is reduced into the upper Hessenberg form `A = Q H Q^H` by Householder reflections,
+//! where `H` is zero below the first subdiagonal and `Q` is unitary.
+//! This is the first step of the QR algorithm for the eigenvalue problem,
+//! and a common starting point of iterative eigenvalue solvers and matrix functions.
+
+use ndarray::*;
+
+use crate::convert::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+/// Hessenberg decomposition `A = Q H Q^H` of a general matrix using `*gehrd` and `*orghr`/`*unghr`
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = random((4, 4));
+/// let (h, q) = a.hessenberg().unwrap();
+/// assert_close_l2!(&q.dot(&h).dot(&q.t()), &a, 1e-9);
+/// ```
+pub trait Hessenberg {
+    type H;
+    type Q;
+    fn hessenberg(&self) -> Result<(Self::H, Self::Q)>;
+}
+
+/// Hessenberg decomposition of a general matrix, consuming it to store `H`
+pub trait HessenbergInto {
+    type H;
+    type Q;
+    fn hessenberg_into(self) -> Result<(Self::H, Self::Q)>;
+}
+
+impl<A, S> HessenbergInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type H = Self;
+    type Q = Array2<A>;
+
+    fn hessenberg_into(mut self) -> Result<(Self::H, Self::Q)> {
+        let l = self.square_layout()?;
+        let q = A::hessenberg(l, true, self.as_allocated_mut()?)?;
+        let q = into_matrix(l, q.unwrap())?;
+        Ok((self, q))
+    }
+}
+
+impl<A, S> Hessenberg for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type H = Array2<A>;
+    type Q = Array2<A>;
+
+    fn hessenberg(&self) -> Result<(Self::H, Self::Q)> {
+        self.to_owned().hessenberg_into()
+    }
+}
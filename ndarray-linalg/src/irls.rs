@@ -0,0 +1,190 @@
+//! Robust regression by iteratively reweighted least squares (IRLS)
+//!
+//! The M-estimator minimizes `sum_i rho(r_i / s)` of the residuals `r = b - Ax`
+//! scaled by `s`, where the loss `rho` grows slower than the square for the large residuals
+//! so that the outliers have less influence than in the ordinary least squares.
+//! This is solved by repeating the weighted least squares with the weights
+//! `w_i = psi(u_i) / u_i` for the standardized residuals `u_i = r_i / s` of the previous solution,
+//! where `psi = rho'`. The scale `s` is re-estimated in each iteration
+//! by the median absolute deviation `median(|r_i|) / 0.6745`.
+//!
+//! - P. J. Huber and E. M. Ronchetti, "Robust Statistics", 2nd ed., Wiley (2009), Section 7.8.
+
+use ndarray::*;
+use num_traits::Float;
+
+use crate::error::*;
+use crate::least_squares::*;
+use crate::norm::*;
+use crate::types::*;
+
+/// Loss function of the M-estimator for [irls]
+pub trait RobustLoss<A> {
+    /// Weight `psi(u) / u` for the standardized residual `u`
+    fn weight(&self, u: A) -> A;
+}
+
+/// Huber loss, which is quadratic for `|u| <= c` and linear otherwise
+///
+/// The default `c = 1.345` gives 95% efficiency for the normal distribution.
+#[derive(Debug, Clone, Copy)]
+pub struct Huber<A> {
+    pub c: A,
+}
+
+impl<A: Float> Default for Huber<A> {
+    fn default() -> Self {
+        Huber {
+            c: A::from(1.345).unwrap(),
+        }
+    }
+}
+
+impl<A: Float> RobustLoss<A> for Huber<A> {
+    fn weight(&self, u: A) -> A {
+        let u = u.abs();
+        if u <= self.c {
+            A::one()
+        } else {
+            self.c / u
+        }
+    }
+}
+
+/// Tukey's bisquare (biweight) loss, which ignores the residuals `|u| >= c` completely
+///
+/// The default `c = 4.685` gives 95% efficiency for the normal distribution.
+#[derive(Debug, Clone, Copy)]
+pub struct Bisquare<A> {
+    pub c: A,
+}
+
+impl<A: Float> Default for Bisquare<A> {
+    fn default() -> Self {
+        Bisquare {
+            c: A::from(4.685).unwrap(),
+        }
+    }
+}
+
+impl<A: Float> RobustLoss<A> for Bisquare<A> {
+    fn weight(&self, u: A) -> A {
+        let t = u / self.c;
+        if t.abs() < A::one() {
+            (A::one() - t * t).powi(2)
+        } else {
+            A::zero()
+        }
+    }
+}
+
+/// Result of [irls]
+#[derive(Debug, Clone)]
+pub struct IrlsResult<A> {
+    /// Coefficients `x`
+    pub solution: Array1<A>,
+    /// Weights of the rows used for the last weighted least squares
+    pub weights: Array1<A>,
+    /// Scale of the residuals estimated by the median absolute deviation
+    pub scale: A,
+    /// The number of the weighted least squares solved
+    pub iterations: usize,
+    /// Whether the relative change of `x` has become smaller than `tol`
+    pub converged: bool,
+}
+
+/// Robust linear regression `b ~ Ax` by iteratively reweighted least squares
+///
+/// Starting from the ordinary least squares solution, the weighted least squares are solved
+/// by [LeastSquaresWeighted] until the relative change `|x_new - x| / |x_new|` becomes
+/// not larger than `tol`, or `max_iter` iterations are done.
+/// For the bisquare loss, which is not convex, the result depends on the starting point;
+/// starting from the solution with [Huber] loss is a common choice.
+/// [LinalgError::NonFinite] is returned if a residual becomes NaN.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // y = 1 + 2 t with an outlier at t = 3
+/// let t = Array1::range(0.0, 10.0, 1.0);
+/// let mut b = t.mapv(|t| 1.0 + 2.0 * t);
+/// b[3] += 100.0;
+/// let a = stack![Axis(1), Array1::ones(10), t];
+/// let result = irls(&a, &b, &Bisquare::default(), 1e-10, 50).unwrap();
+/// assert!(result.converged);
+/// assert_close_l2!(&result.solution, &array![1.0, 2.0], 1e-8);
+/// assert_eq!(result.weights[3], 0.0);
+/// ```
+pub fn irls<A, S1, S2, L>(
+    a: &ArrayBase<S1, Ix2>,
+    b: &ArrayBase<S2, Ix1>,
+    loss: &L,
+    tol: A,
+    max_iter: usize,
+) -> Result<IrlsResult<A>>
+where
+    A: Scalar<Real = A> + Float + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    L: RobustLoss<A>,
+{
+    let m = a.nrows();
+    if m != b.len() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let mut x = a.least_squares(&b.to_owned())?.solution;
+    let mut weights = Array1::ones(m);
+    let mut scale = A::zero();
+    let mut iterations = 0;
+    let mut converged = false;
+    while iterations < max_iter {
+        let r = b - &a.dot(&x);
+        scale = mad(&r)?;
+        if scale.is_zero() {
+            // Exact fit for more than half of the rows
+            converged = true;
+            break;
+        }
+        weights = r.mapv(|r| loss.weight(r / scale));
+        let x_new = a
+            .least_squares_weighted(b, &LeastSquaresWeights::Diagonal(weights.clone()))?
+            .solution;
+        iterations += 1;
+        let dx = (&x_new - &x).norm_l2();
+        x = x_new;
+        if dx <= tol * x.norm_l2() {
+            converged = true;
+            break;
+        }
+    }
+    Ok(IrlsResult {
+        solution: x,
+        weights,
+        scale,
+        iterations,
+        converged,
+    })
+}
+
+/// Median absolute deviation `median(|r_i|) / 0.6745`,
+/// a consistent estimator of the standard deviation for the normal distribution
+///
+/// [LinalgError::NonFinite] is returned if a residual is NaN.
+fn mad<A: Float>(r: &Array1<A>) -> Result<A> {
+    let mut abs: Vec<A> = r.iter().map(|r| r.abs()).collect();
+    if abs.is_empty() {
+        return Ok(A::zero());
+    }
+    if abs.iter().any(|x| x.is_nan()) {
+        return Err(LinalgError::NonFinite);
+    }
+    abs.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    let n = abs.len();
+    let median = if n % 2 == 1 {
+        abs[n / 2]
+    } else {
+        (abs[n / 2 - 1] + abs[n / 2]) / A::from(2.0).unwrap()
+    };
+    Ok(median / A::from(0.6745).unwrap())
+}
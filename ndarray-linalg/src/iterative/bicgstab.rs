@@ -0,0 +1,121 @@
+//! Biconjugate gradient stabilized method, BiCGSTAB
+//!
+//! Unlike GMRES, only a fixed number of vectors are kept,
+//! and each iteration costs two products by `A` and `O(n)` operations regardless of the iteration count.
+//! The residual norm does not decrease monotonically, and the iteration may break down
+//! when one of the inner products in the recurrence vanishes.
+//!
+//! - H. A. van der Vorst, "Bi-CGSTAB: A Fast and Smoothly Converging Variant of Bi-CG
+//!   for the Solution of Nonsymmetric Linear Systems", SIAM J. Sci. Stat. Comput. 13 (2), 1992.
+
+use super::*;
+use crate::{inner::InnerProduct, norm::Norm};
+
+/// Solve `Ax = b` for a general operator `A` by BiCGSTAB
+///
+/// This is [bicgstab_preconditioned] without preconditioner.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{iterative::*, *};
+///
+/// // Nonsymmetric and diagonally dominant
+/// let r: Array2<f64> = random((20, 20));
+/// let a = Array2::eye(20) * 10.0 + r;
+/// let b: Array1<f64> = random(20);
+/// let result = bicgstab(&a, &b, 1e-10, 100);
+/// assert!(result.converged);
+/// assert_close_l2!(&a.dot(&result.solution), &b, 1e-8);
+/// ```
+pub fn bicgstab<A, S, Op>(
+    a: &Op,
+    b: &ArrayBase<S, Ix1>,
+    tol: A::Real,
+    max_iter: usize,
+) -> IterativeResult<A>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    Op: LinearOperator<Elem = A> + ?Sized,
+{
+    bicgstab_preconditioned(a, &Identity(PhantomData), b, tol, max_iter)
+}
+
+/// Solve `Ax = b` by BiCGSTAB with the right preconditioner `M`
+///
+/// `m_inv` applies `M^{-1}` as [gmres_preconditioned].
+/// If the iteration breaks down, it stops with `converged == false`.
+pub fn bicgstab_preconditioned<A, S, Op, P>(
+    a: &Op,
+    m_inv: &P,
+    b: &ArrayBase<S, Ix1>,
+    tol: A::Real,
+    max_iter: usize,
+) -> IterativeResult<A>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    Op: LinearOperator<Elem = A> + ?Sized,
+    P: Preconditioner<Elem = A> + ?Sized,
+{
+    let n = b.len();
+    let threshold = tol * b.norm_l2();
+    let mut x = Array1::<A>::zeros(n);
+    let mut r = b.to_owned();
+    // Shadow residual
+    let r0 = r.clone();
+    let mut p = Array1::<A>::zeros(n);
+    let mut v = Array1::<A>::zeros(n);
+    let (mut rho, mut alpha, mut omega) = (A::one(), A::one(), A::one());
+    let mut residual_norms = vec![r.norm_l2()];
+    let mut iterations = 0;
+    let mut converged = residual_norms[0] <= threshold;
+    while !converged && iterations < max_iter {
+        let rho_new = r0.inner(&r);
+        if rho_new.is_zero() {
+            break;
+        }
+        let beta = (rho_new / rho) * (alpha / omega);
+        rho = rho_new;
+        azip!((p in &mut p, &r in &r, &v in &v) *p = r + beta * (*p - omega * v));
+        let y = m_inv.precondition(&p);
+        v = a.apply(&y);
+        let r0v = r0.inner(&v);
+        if r0v.is_zero() {
+            break;
+        }
+        alpha = rho / r0v;
+        x.scaled_add(alpha, &y);
+        // `r` is now the intermediate residual `s`
+        r.scaled_add(-alpha, &v);
+        iterations += 1;
+        let s_norm = r.norm_l2();
+        if s_norm <= threshold {
+            residual_norms.push(s_norm);
+            converged = true;
+            break;
+        }
+
+        let z = m_inv.precondition(&r);
+        let t = a.apply(&z);
+        let tt = t.inner(&t);
+        if tt.is_zero() {
+            residual_norms.push(s_norm);
+            break;
+        }
+        omega = t.inner(&r) / tt;
+        x.scaled_add(omega, &z);
+        r.scaled_add(-omega, &t);
+        residual_norms.push(r.norm_l2());
+        converged = residual_norms[iterations] <= threshold;
+        if omega.is_zero() {
+            break;
+        }
+    }
+    IterativeResult {
+        solution: x,
+        iterations,
+        residual_norms,
+        converged,
+    }
+}
@@ -0,0 +1,108 @@
+//! Conjugate gradient method
+//!
+//! - M. R. Hestenes and E. Stiefel, "Methods of Conjugate Gradients for Solving Linear Systems",
+//!   J. Res. Nat. Bur. Standards 49 (6), 1952.
+
+use super::*;
+use crate::{inner::InnerProduct, norm::Norm};
+use num_traits::Zero;
+
+/// Solve `Ax = b` for a Hermitian positive definite operator `A` by the conjugate gradient method
+///
+/// Each iteration costs one product `A p` and `O(n)` operations,
+/// and the error in the `A`-norm decreases by the factor `(sqrt(k) - 1) / (sqrt(k) + 1)`
+/// per iteration for the condition number `k` of `A`.
+/// If `A` is found not to be positive definite, i.e. `p^H A p <= 0` for a search direction `p`,
+/// the iteration stops with `converged == false`.
+/// This is [cg_preconditioned] without preconditioner.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{iterative::*, *};
+///
+/// let a: Array2<f64> = random_hpd(20);
+/// let b: Array1<f64> = random(20);
+/// let result = cg(&a, &b, 1e-10, 100);
+/// assert!(result.converged);
+/// assert_close_l2!(&a.dot(&result.solution), &b, 1e-8);
+/// ```
+pub fn cg<A, S, Op>(
+    a: &Op,
+    b: &ArrayBase<S, Ix1>,
+    tol: A::Real,
+    max_iter: usize,
+) -> IterativeResult<A>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    Op: LinearOperator<Elem = A> + ?Sized,
+{
+    cg_preconditioned(a, &Identity(PhantomData), b, tol, max_iter)
+}
+
+/// Solve `Ax = b` by the preconditioned conjugate gradient method
+///
+/// `m_inv` applies `M^{-1}` for a Hermitian positive definite `M`,
+/// e.g. [IncompleteCholesky], and the convergence rate is determined by the condition number
+/// of `M^{-1} A` instead of `A`. The residual norms are those of `b - Ax`,
+/// not of the preconditioned residual `M^{-1} (b - Ax)`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{iterative::*, *};
+///
+/// let a: Array2<f64> = random_hpd(20);
+/// let b: Array1<f64> = random(20);
+/// let m = Ssor::from_dense(&a, 1.2).unwrap();
+/// let result = cg_preconditioned(&a, &m, &b, 1e-10, 100);
+/// assert!(result.converged);
+/// assert_close_l2!(&a.dot(&result.solution), &b, 1e-8);
+/// ```
+pub fn cg_preconditioned<A, S, Op, P>(
+    a: &Op,
+    m_inv: &P,
+    b: &ArrayBase<S, Ix1>,
+    tol: A::Real,
+    max_iter: usize,
+) -> IterativeResult<A>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    Op: LinearOperator<Elem = A> + ?Sized,
+    P: Preconditioner<Elem = A> + ?Sized,
+{
+    let threshold = tol * b.norm_l2();
+    let mut x = Array1::<A>::zeros(b.len());
+    let mut r = b.to_owned();
+    let mut z = m_inv.precondition(&r);
+    let mut p = z.clone();
+    let mut rz = r.inner(&z).re();
+    let mut residual_norms = vec![r.norm_l2()];
+    let mut iterations = 0;
+    let mut converged = residual_norms[0] <= threshold;
+    while !converged && iterations < max_iter {
+        let ap = a.apply(&p);
+        let pap = p.inner(&ap).re();
+        if pap <= A::Real::zero() {
+            break;
+        }
+        let alpha = A::from_real(rz / pap);
+        x.scaled_add(alpha, &p);
+        r.scaled_add(-alpha, &ap);
+        z = m_inv.precondition(&r);
+        let rz_new = r.inner(&z).re();
+        let beta = A::from_real(rz_new / rz);
+        rz = rz_new;
+        p.zip_mut_with(&z, |p, &z| *p = z + beta * *p);
+
+        iterations += 1;
+        residual_norms.push(r.norm_l2());
+        converged = residual_norms[iterations] <= threshold;
+    }
+    IterativeResult {
+        solution: x,
+        iterations,
+        residual_norms,
+        converged,
+    }
+}
@@ -0,0 +1,217 @@
+//! Restarted generalized minimal residual method, GMRES(m)
+//!
+//! The `k`-th iterate minimizes the residual norm in the Krylov subspace
+//! `x_0 + M^{-1} K_k(A M^{-1}, r_0)`, whose orthonormal basis `V_k` is built by the [Arnoldi] iteration
+//! with the modified Gram-Schmidt orthogonalizer [MGS]. The small least squares problem
+//! `min |beta e_1 - H_k y|` of the Hessenberg matrix `H_k` is solved progressively by Givens rotations,
+//! which gives the residual norm at each step without forming `x`.
+//! Since the memory and the orthogonalization cost grow linearly in `k`,
+//! the iteration is restarted from the current solution after `m` steps.
+//!
+//! - Y. Saad and M. H. Schultz, "GMRES: A Generalized Minimal Residual Algorithm
+//!   for Solving Nonsymmetric Linear Systems", SIAM J. Sci. Stat. Comput. 7 (3), 1986.
+
+use super::*;
+use crate::{
+    givens::Givens,
+    krylov::{Arnoldi, MGS},
+    norm::Norm,
+};
+use num_traits::Float;
+
+/// Solve `Ax = b` for a general operator `A` by GMRES restarted every `restart` iterations
+///
+/// This is [gmres_preconditioned] without preconditioner.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{iterative::*, *};
+///
+/// // Nonsymmetric and diagonally dominant
+/// let r: Array2<f64> = random((20, 20));
+/// let a = Array2::eye(20) * 10.0 + r;
+/// let b: Array1<f64> = random(20);
+/// let result = gmres(&a, &b, 10, 1e-10, 100);
+/// assert!(result.converged);
+/// assert_close_l2!(&a.dot(&result.solution), &b, 1e-8);
+/// ```
+pub fn gmres<A, S, Op>(
+    a: &Op,
+    b: &ArrayBase<S, Ix1>,
+    restart: usize,
+    tol: A::Real,
+    max_iter: usize,
+) -> IterativeResult<A>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    Op: LinearOperator<Elem = A> + ?Sized,
+{
+    gmres_preconditioned(a, &Identity(PhantomData), b, restart, tol, max_iter)
+}
+
+/// Solve `Ax = b` by GMRES with the right preconditioner `M`
+///
+/// `m_inv` applies `M^{-1}`, which should approximate `A^{-1}` and be cheap to apply,
+/// e.g. [IncompleteLU] or a [LinearOperator] as a [Preconditioner].
+/// GMRES is applied to `A M^{-1} u = b`, and `x = M^{-1} u` is returned.
+/// Since the right preconditioning does not change the residual `b - Ax`,
+/// the convergence is still tested by `|b - Ax| <= tol * |b|`.
+///
+/// `max_iter` is the total number of the Arnoldi steps over all restart cycles.
+/// The residual norms are those of the least squares problems in each cycle,
+/// and the true residual is recomputed at every restart.
+pub fn gmres_preconditioned<A, S, Op, P>(
+    a: &Op,
+    m_inv: &P,
+    b: &ArrayBase<S, Ix1>,
+    restart: usize,
+    tol: A::Real,
+    max_iter: usize,
+) -> IterativeResult<A>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    Op: LinearOperator<Elem = A> + ?Sized,
+    P: Preconditioner<Elem = A> + ?Sized,
+{
+    let n = b.len();
+    let restart = restart.min(n).max(1);
+    let op = RightPreconditioned { a, m_inv };
+    let threshold = tol * b.norm_l2();
+    let mut x = Array1::<A>::zeros(n);
+    let mut r = b.to_owned();
+    let mut beta = r.norm_l2();
+    let mut residual_norms = vec![beta];
+    let mut iterations = 0;
+    let mut converged = beta <= threshold;
+    while !converged && iterations < max_iter {
+        let mut arnoldi = Arnoldi::new(op, r, MGS::new(n, A::Real::epsilon()));
+        let mut lsq = HessenbergLeastSquares::new(beta, restart);
+        let mut breakdown = false;
+        while lsq.len() < restart && iterations < max_iter {
+            match arnoldi.next() {
+                Some(h) => {
+                    iterations += 1;
+                    residual_norms.push(lsq.push(h));
+                    if residual_norms[iterations] <= threshold {
+                        break;
+                    }
+                }
+                None => {
+                    breakdown = true;
+                    break;
+                }
+            }
+        }
+        let (q, h) = arnoldi.into_qh();
+        if breakdown {
+            // The Krylov subspace is invariant, and the last column of `H` has no subdiagonal element
+            let k = h.ncols();
+            let mut last = Array1::zeros(k + 1);
+            last.slice_mut(s![..k]).assign(&h.column(k - 1));
+            iterations += 1;
+            residual_norms.push(lsq.push(last));
+        }
+
+        let y = lsq.solve();
+        let u = q.slice(s![.., ..y.len()]).dot(&y);
+        x += &m_inv.precondition(&u);
+        r = b - &a.apply(&x);
+        beta = r.norm_l2();
+        converged = beta <= threshold;
+    }
+    IterativeResult {
+        solution: x,
+        iterations,
+        residual_norms,
+        converged,
+    }
+}
+
+/// `A M^{-1}`
+struct RightPreconditioned<'a, Op: ?Sized, P: ?Sized> {
+    a: &'a Op,
+    m_inv: &'a P,
+}
+
+impl<'a, Op: ?Sized, P: ?Sized> Clone for RightPreconditioned<'a, Op, P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, Op: ?Sized, P: ?Sized> Copy for RightPreconditioned<'a, Op, P> {}
+
+impl<'a, Op, P> LinearOperator for RightPreconditioned<'a, Op, P>
+where
+    Op: LinearOperator + ?Sized,
+    P: Preconditioner<Elem = Op::Elem> + ?Sized,
+{
+    type Elem = Op::Elem;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<Self::Elem>
+    where
+        S: Data<Elem = Self::Elem>,
+    {
+        self.a.apply(&self.m_inv.precondition(a))
+    }
+}
+
+/// Least squares problem `min |beta e_1 - H y|` for the `(k + 1) x k` Hessenberg matrix `H`
+/// reduced to the upper triangular form by Givens rotations column by column
+struct HessenbergLeastSquares<A: Scalar> {
+    /// Columns of the upper triangular matrix `R`
+    r: Vec<Array1<A>>,
+    /// Rotated right-hand side `G beta e_1`
+    g: Vec<A>,
+    rotations: Vec<Givens<A>>,
+}
+
+impl<A: Scalar> HessenbergLeastSquares<A> {
+    fn new(beta: A::Real, capacity: usize) -> Self {
+        let mut g = Vec::with_capacity(capacity + 1);
+        g.push(A::from_real(beta));
+        HessenbergLeastSquares {
+            r: Vec::with_capacity(capacity),
+            g,
+            rotations: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.r.len()
+    }
+
+    /// Add the `k`-th column of length `k + 2`, and returns the residual norm
+    fn push(&mut self, mut h: Array1<A>) -> A::Real {
+        let k = self.len();
+        for (i, g) in self.rotations.iter().enumerate() {
+            let (x, y) = g.rotate(h[i], h[i + 1]);
+            h[i] = x;
+            h[i + 1] = y;
+        }
+        let g = Givens::new(h[k], h[k + 1]);
+        h[k] = g.rotate(h[k], h[k + 1]).0;
+        let (gk, gk1) = g.rotate(self.g[k], A::zero());
+        self.g[k] = gk;
+        self.g.push(gk1);
+        self.rotations.push(g);
+        self.r.push(h.slice_move(s![..k + 1]));
+        gk1.abs()
+    }
+
+    /// Back substitution `R y = g`
+    fn solve(&self) -> Array1<A> {
+        let k = self.len();
+        let mut y = Array1::zeros(k);
+        for i in (0..k).rev() {
+            let mut t = self.g[i];
+            for j in (i + 1)..k {
+                t -= self.r[j][i] * y[j];
+            }
+            y[i] = t / self.r[i][i];
+        }
+        y
+    }
+}
@@ -0,0 +1,326 @@
+//! LSQR and LSMR for least squares problems
+//!
+//! Both solve the damped least squares problem
+//!
+//! ```text
+//! min |b - Ax|^2 + damp^2 |x|^2
+//! ```
+//!
+//! for a `m x n` [AdjointOperator] `A` using only the products by `A` and `A^H`.
+//! The Golub-Kahan bidiagonalization generates the orthonormal bases of the Krylov subspaces
+//! `K_k(A^H A, A^H b)` and `K_k(A A^H, b)`, and the `k`-th iterate minimizes
+//! `|b - Ax|` (LSQR, equivalent to CG on the normal equations) or
+//! `|A^H (b - Ax)|` (LSMR, equivalent to MINRES on the normal equations) in the former subspace.
+//! `|A^H r|` decreases monotonically in LSMR, so that it is safer to stop it early.
+//! With `damp = 0`, the iteration converges to the minimum norm solution.
+//!
+//! As in the original implementations, the iteration stops when either
+//!
+//! - `|r| <= tol * |b| + tol * |A| |x|`, i.e. `Ax = b` is compatible, or
+//! - `|A^H r| <= tol * |A| |r|`, i.e. `x` solves the least squares problem,
+//!
+//! where `r` is the residual of the damped problem, and `|A|` is the estimated Frobenius norm.
+//! The iteration also stops with `converged == false`
+//! if the estimated condition number of `A` exceeds `1 / eps`.
+//!
+//! - C. C. Paige and M. A. Saunders, "LSQR: An Algorithm for Sparse Linear Equations
+//!   and Sparse Least Squares", ACM Trans. Math. Softw. 8 (1), 1982.
+//! - D. C.-L. Fong and M. A. Saunders, "LSMR: An Iterative Algorithm for Sparse Least-Squares Problems",
+//!   SIAM J. Sci. Comput. 33 (5), 2011.
+
+use super::*;
+use crate::norm::Norm;
+use num_traits::{Float, One, Zero};
+
+/// Solve the damped least squares problem by LSQR
+///
+/// The residual norms are the estimates of `sqrt(|b - Ax|^2 + damp^2 |x|^2)`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{iterative::*, *};
+///
+/// let a: Array2<f64> = random((30, 10));
+/// let b: Array1<f64> = random(30);
+/// let result = lsqr(&a, &b, 0.0, 1e-12, 100);
+/// assert!(result.converged);
+/// assert_close_l2!(&result.solution, &a.least_squares(&b).unwrap().solution, 1e-8);
+/// ```
+pub fn lsqr<A, S, Op>(
+    a: &Op,
+    b: &ArrayBase<S, Ix1>,
+    damp: A::Real,
+    tol: A::Real,
+    max_iter: usize,
+) -> IterativeResult<A>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    Op: AdjointOperator<Elem = A> + ?Sized,
+{
+    let mut bidiag = Bidiagonalization::new(a, b);
+    let (mut alpha, beta) = (bidiag.alpha, bidiag.beta);
+    let b_norm = beta;
+    let mut x = Array1::<A>::zeros(bidiag.v.len());
+    let mut w = bidiag.v.clone();
+    let (mut phibar, mut rhobar) = (beta, alpha);
+    let mut a_norm2 = A::Real::zero();
+    let mut dd_norm = A::Real::zero();
+    let mut psi2 = A::Real::zero();
+    let mut residual_norms = vec![beta];
+    let mut iterations = 0;
+    let mut converged = (alpha * beta).is_zero();
+    while !converged && iterations < max_iter {
+        let (alpha_new, beta) = bidiag.next();
+        a_norm2 = a_norm2 + alpha * alpha + beta * beta + damp * damp;
+        alpha = alpha_new;
+
+        // Eliminate the damping parameter
+        let rhobar1 = Float::hypot(rhobar, damp);
+        let (cs1, sn1) = (rhobar / rhobar1, damp / rhobar1);
+        let psi = sn1 * phibar;
+        phibar = cs1 * phibar;
+
+        // Eliminate the subdiagonal element `beta` of the lower bidiagonal matrix
+        let (cs, sn, rho) = sym_ortho(rhobar1, beta);
+        let theta = sn * alpha;
+        rhobar = -cs * alpha;
+        let phi = cs * phibar;
+        phibar = sn * phibar;
+        let tau = sn * phi;
+
+        let w_norm = w.norm_l2() / rho;
+        dd_norm += w_norm * w_norm;
+        x.scaled_add(A::from_real(phi / rho), &w);
+        w.zip_mut_with(&bidiag.v, |w, &v| *w = v - w.mul_real(theta / rho));
+
+        psi2 += psi * psi;
+        let r_norm = Float::sqrt(phibar * phibar + psi2);
+        let ar_norm = alpha * Float::abs(tau);
+        let a_norm = Float::sqrt(a_norm2);
+        let a_cond = a_norm * Float::sqrt(dd_norm);
+        iterations += 1;
+        residual_norms.push(r_norm);
+        match stopping(tol, b_norm, a_norm, a_cond, x.norm_l2(), r_norm, ar_norm) {
+            Some(c) => {
+                converged = c;
+                break;
+            }
+            None => continue,
+        }
+    }
+    IterativeResult {
+        solution: x,
+        iterations,
+        residual_norms,
+        converged,
+    }
+}
+
+/// Solve the damped least squares problem by LSMR
+///
+/// The residual norms are the estimates of `sqrt(|b - Ax|^2 + damp^2 |x|^2)`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{iterative::*, *};
+///
+/// // Ridge regression
+/// let a: Array2<f64> = random((30, 10));
+/// let b: Array1<f64> = random(30);
+/// let result = lsmr(&a, &b, 0.5, 1e-12, 100);
+/// assert!(result.converged);
+/// let ridge = a.least_squares_ridge(&b, &RidgePenalty::Scalar(0.25)).unwrap();
+/// assert_close_l2!(&result.solution, &ridge.solution, 1e-8);
+/// ```
+pub fn lsmr<A, S, Op>(
+    a: &Op,
+    b: &ArrayBase<S, Ix1>,
+    damp: A::Real,
+    tol: A::Real,
+    max_iter: usize,
+) -> IterativeResult<A>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    Op: AdjointOperator<Elem = A> + ?Sized,
+{
+    let one = A::Real::one();
+    let zero = A::Real::zero();
+    let mut bidiag = Bidiagonalization::new(a, b);
+    let (alpha, beta) = (bidiag.alpha, bidiag.beta);
+    let b_norm = beta;
+    let n = bidiag.v.len();
+    let mut x = Array1::<A>::zeros(n);
+    let mut h = bidiag.v.clone();
+    let mut hbar = Array1::<A>::zeros(n);
+
+    let (mut zetabar, mut alphabar) = (alpha * beta, alpha);
+    let (mut rho, mut rhobar, mut cbar, mut sbar) = (one, one, one, zero);
+    let mut zeta = zero;
+    // For the estimate of |r|
+    let (mut betadd, mut betad, mut rhodold) = (beta, zero, one);
+    let (mut tautildeold, mut thetatilde, mut d) = (zero, zero, zero);
+    // For the estimates of |A| and cond(A)
+    let mut a_norm2 = alpha * alpha;
+    let (mut max_rbar, mut min_rbar) = (zero, A::Real::max_value());
+
+    let mut residual_norms = vec![beta];
+    let mut iterations = 0;
+    let mut converged = zetabar.is_zero();
+    while !converged && iterations < max_iter {
+        let (alpha, beta) = bidiag.next();
+
+        // Eliminate the damping parameter, and then the subdiagonal element `beta`
+        let (chat, shat, alphahat) = sym_ortho(alphabar, damp);
+        let rho_old = rho;
+        let (c, s, rho_new) = sym_ortho(alphahat, beta);
+        rho = rho_new;
+        let thetanew = s * alpha;
+        alphabar = c * alpha;
+
+        // Second QR decomposition to minimize |A^H r|
+        let (rhobar_old, zeta_old) = (rhobar, zeta);
+        let thetabar = sbar * rho;
+        let rhotemp = cbar * rho;
+        let (cbar_new, sbar_new, rhobar_new) = sym_ortho(cbar * rho, thetanew);
+        cbar = cbar_new;
+        sbar = sbar_new;
+        rhobar = rhobar_new;
+        zeta = cbar * zetabar;
+        zetabar = -sbar * zetabar;
+
+        let coef = thetabar * rho / (rho_old * rhobar_old);
+        hbar.zip_mut_with(&h, |hbar, &h| *hbar = h - hbar.mul_real(coef));
+        x.scaled_add(A::from_real(zeta / (rho * rhobar)), &hbar);
+        h.zip_mut_with(&bidiag.v, |h, &v| *h = v - h.mul_real(thetanew / rho));
+
+        // Estimate |r|
+        let betaacute = chat * betadd;
+        let betacheck = -shat * betadd;
+        let betahat = c * betaacute;
+        betadd = -s * betaacute;
+        let thetatilde_old = thetatilde;
+        let (ctilde_old, stilde_old, rhotilde_old) = sym_ortho(rhodold, thetabar);
+        thetatilde = stilde_old * rhobar;
+        rhodold = ctilde_old * rhobar;
+        betad = -stilde_old * betad + ctilde_old * betahat;
+        tautildeold = (zeta_old - thetatilde_old * tautildeold) / rhotilde_old;
+        let taud = (zeta - thetatilde * tautildeold) / rhodold;
+        d += betacheck * betacheck;
+        let r_norm = Float::sqrt(d + (betad - taud) * (betad - taud) + betadd * betadd);
+
+        // Estimate |A| and cond(A)
+        a_norm2 += beta * beta;
+        let a_norm = Float::sqrt(a_norm2);
+        a_norm2 += alpha * alpha;
+        max_rbar = Float::max(max_rbar, rhobar_old);
+        if iterations > 0 {
+            min_rbar = Float::min(min_rbar, rhobar_old);
+        }
+        let a_cond = Float::max(max_rbar, rhotemp) / Float::min(min_rbar, rhotemp);
+
+        iterations += 1;
+        residual_norms.push(r_norm);
+        let ar_norm = Float::abs(zetabar);
+        match stopping(tol, b_norm, a_norm, a_cond, x.norm_l2(), r_norm, ar_norm) {
+            Some(c) => {
+                converged = c;
+                break;
+            }
+            None => continue,
+        }
+    }
+    IterativeResult {
+        solution: x,
+        iterations,
+        residual_norms,
+        converged,
+    }
+}
+
+/// Golub-Kahan bidiagonalization `beta_{k+1} u_{k+1} = A v_k - alpha_k u_k`,
+/// `alpha_{k+1} v_{k+1} = A^H u_{k+1} - beta_{k+1} v_k` starting from `beta_1 u_1 = b`
+struct Bidiagonalization<'a, Op: ?Sized, A: Scalar> {
+    a: &'a Op,
+    u: Array1<A>,
+    v: Array1<A>,
+    alpha: A::Real,
+    beta: A::Real,
+}
+
+impl<'a, A, Op> Bidiagonalization<'a, Op, A>
+where
+    A: Scalar + Lapack,
+    Op: AdjointOperator<Elem = A> + ?Sized,
+{
+    fn new<S: Data<Elem = A>>(a: &'a Op, b: &ArrayBase<S, Ix1>) -> Self {
+        let mut u = b.to_owned();
+        let beta = normalize(&mut u);
+        let mut v = a.apply_adjoint(&u);
+        let alpha = normalize(&mut v);
+        Bidiagonalization {
+            a,
+            u,
+            v,
+            alpha,
+            beta,
+        }
+    }
+
+    /// Proceed one step, and returns `(alpha_{k+1}, beta_{k+1})`
+    fn next(&mut self) -> (A::Real, A::Real) {
+        let mut u = self.a.apply(&self.v);
+        u.scaled_add(A::from_real(-self.alpha), &self.u);
+        self.beta = normalize(&mut u);
+        self.u = u;
+        if !self.beta.is_zero() {
+            let mut v = self.a.apply_adjoint(&self.u);
+            v.scaled_add(A::from_real(-self.beta), &self.v);
+            self.alpha = normalize(&mut v);
+            self.v = v;
+        }
+        (self.alpha, self.beta)
+    }
+}
+
+/// Normalize `v` unless it is zero, and returns the original norm
+fn normalize<A: Scalar + Lapack>(v: &mut Array1<A>) -> A::Real {
+    let norm = v.norm_l2();
+    if !norm.is_zero() {
+        v.mapv_inplace(|v| v.div_real(norm));
+    }
+    norm
+}
+
+/// Givens rotation `(c, s, r)` with `[c s; -s c] [a; b] = [r; 0]` and `r >= 0` for real `a` and `b`
+fn sym_ortho<T: Float>(a: T, b: T) -> (T, T, T) {
+    if b.is_zero() {
+        let c = if a.is_zero() { T::one() } else { a.signum() };
+        (c, T::zero(), a.abs())
+    } else if a.is_zero() {
+        (T::zero(), b.signum(), b.abs())
+    } else {
+        let r = a.hypot(b);
+        (a / r, b / r, r)
+    }
+}
+
+/// `Some(true)` if converged, `Some(false)` if `A` is too ill-conditioned, and `None` to continue
+fn stopping<T: Float>(
+    tol: T,
+    b_norm: T,
+    a_norm: T,
+    a_cond: T,
+    x_norm: T,
+    r_norm: T,
+    ar_norm: T,
+) -> Option<bool> {
+    if r_norm <= tol * b_norm + tol * a_norm * x_norm || ar_norm <= tol * a_norm * r_norm {
+        Some(true)
+    } else if a_cond >= T::epsilon().recip() {
+        Some(false)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,121 @@
+//! Minimal residual method, MINRES
+//!
+//! For a Hermitian operator `A`, the Lanczos iteration reduces `A` to a real symmetric tridiagonal matrix,
+//! and the `k`-th iterate minimizes the residual norm in the Krylov subspace `K_k(A, b)`
+//! as GMRES but with short recurrences. Unlike CG, `A` may be indefinite,
+//! e.g. the saddle-point (KKT) systems `[H B^H; B 0]`. `A` may also be singular
+//! if `b` is in its range.
+//!
+//! - C. C. Paige and M. A. Saunders, "Solution of Sparse Indefinite Systems of Linear Equations",
+//!   SIAM J. Numer. Anal. 12 (4), 1975.
+
+use super::*;
+use crate::{inner::InnerProduct, norm::Norm};
+use num_traits::{Float, One, Zero};
+
+/// Solve `Ax = b` for a Hermitian, possibly indefinite, operator `A` by MINRES
+///
+/// Each iteration costs one product by `A` and `O(n)` operations, and the residual norm
+/// decreases monotonically. The iteration stops when the Lanczos iteration finds an invariant subspace,
+/// where the solution is exact if `A` is nonsingular.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{iterative::*, *};
+///
+/// // Symmetric indefinite
+/// let a: Array2<f64> = array![[2.0, 1.0, 0.0], [1.0, -3.0, 1.0], [0.0, 1.0, 1.0]];
+/// let b: Array1<f64> = array![1.0, 2.0, 3.0];
+/// let result = minres(&a, &b, 1e-12, 10);
+/// assert!(result.converged);
+/// assert_close_l2!(&a.dot(&result.solution), &b, 1e-10);
+/// ```
+pub fn minres<A, S, Op>(
+    a: &Op,
+    b: &ArrayBase<S, Ix1>,
+    tol: A::Real,
+    max_iter: usize,
+) -> IterativeResult<A>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    Op: LinearOperator<Elem = A> + ?Sized,
+{
+    let n = b.len();
+    let threshold = tol * b.norm_l2();
+    let mut x = Array1::<A>::zeros(n);
+    let mut beta = b.norm_l2();
+    let mut residual_norms = vec![beta];
+    let mut iterations = 0;
+    let mut converged = beta <= threshold;
+    if converged {
+        return IterativeResult {
+            solution: x,
+            iterations,
+            residual_norms,
+            converged,
+        };
+    }
+
+    // Lanczos vectors `v_{k-1}` and `v_k`
+    let mut v_old = Array1::<A>::zeros(n);
+    let mut v = b.mapv(|b| b.div_real(beta));
+    let mut beta_old = A::Real::zero();
+    // Search directions `w_{k-2}` and `w_{k-1}`
+    let mut w_old = Array1::<A>::zeros(n);
+    let mut w = Array1::<A>::zeros(n);
+    // Givens rotation of the previous step, and the elements of the tridiagonal matrix rotated by it
+    let (mut cs, mut sn) = (-A::Real::one(), A::Real::zero());
+    let (mut dbar, mut epsilon) = (A::Real::zero(), A::Real::zero());
+    let mut phibar = beta;
+    while !converged && iterations < max_iter {
+        let mut y = a.apply(&v);
+        let alpha = v.inner(&y).re();
+        azip!((y in &mut y, &v in &v, &v_old in &v_old) {
+            *y = *y - v.mul_real(alpha) - v_old.mul_real(beta_old)
+        });
+        beta = y.norm_l2();
+
+        // Apply the previous rotation to the new column `[beta_old; alpha; beta]`,
+        // and eliminate `beta` by a new rotation
+        let epsilon_old = epsilon;
+        let delta = cs * dbar + sn * alpha;
+        let gbar = sn * dbar - cs * alpha;
+        epsilon = sn * beta;
+        dbar = -cs * beta;
+        let gamma = Float::hypot(gbar, beta);
+        if gamma.is_zero() {
+            // `A` is singular, and `b` is not in its range
+            break;
+        }
+        cs = gbar / gamma;
+        sn = beta / gamma;
+        let phi = cs * phibar;
+        phibar = sn * phibar;
+
+        let w_new = Zip::from(&v)
+            .and(&w_old)
+            .and(&w)
+            .map_collect(|&v, &w_old, &w| {
+                (v - w_old.mul_real(epsilon_old) - w.mul_real(delta)).div_real(gamma)
+            });
+        w_old = std::mem::replace(&mut w, w_new);
+        x.zip_mut_with(&w, |x, &w| *x += w.mul_real(phi));
+
+        iterations += 1;
+        residual_norms.push(Float::abs(phibar));
+        converged = residual_norms[iterations] <= threshold;
+        if beta.is_zero() {
+            // Invariant subspace
+            break;
+        }
+        v_old = std::mem::replace(&mut v, y.mapv(|y| y.div_real(beta)));
+        beta_old = beta;
+    }
+    IterativeResult {
+        solution: x,
+        iterations,
+        residual_norms,
+        converged,
+    }
+}
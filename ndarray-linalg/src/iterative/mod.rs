@@ -0,0 +1,62 @@
+//! Iterative solvers of linear equations
+//!
+//! The coefficient matrix `A` is accessed only through [LinearOperator],
+//! i.e. by the products `A v`. Besides the dense matrices (`Array2`),
+//! the matrix-free operators implementing [LinearOperator] can be used
+//! for the problems too large to be factorized.
+//! The convergence is accelerated by a [Preconditioner] approximating `A`.
+//!
+//! Starting from `x = 0`, the iteration stops when the residual norm `|b - Ax|`
+//! becomes not larger than `tol * |b|`, or `max_iter` iterations are done.
+//! The least squares solvers [lsqr()] and [lsmr()] have their own stopping criteria
+//! for the incompatible systems.
+//!
+//! - Y. Saad, "Iterative Methods for Sparse Linear Systems", 2nd ed., SIAM (2003).
+
+use crate::operator::{AdjointOperator, LinearOperator};
+use crate::types::*;
+use ndarray::*;
+use std::marker::PhantomData;
+
+pub mod bicgstab;
+pub mod cg;
+pub mod gmres;
+pub mod lsqr;
+pub mod minres;
+pub mod preconditioner;
+
+pub use bicgstab::{bicgstab, bicgstab_preconditioned};
+pub use cg::{cg, cg_preconditioned};
+pub use gmres::{gmres, gmres_preconditioned};
+pub use lsqr::{lsmr, lsqr};
+pub use minres::minres;
+pub use preconditioner::*;
+
+/// Result of the iterative solvers
+#[derive(Debug, Clone)]
+pub struct IterativeResult<A: Scalar> {
+    /// Approximate solution `x`
+    pub solution: Array1<A>,
+    /// The number of iterations done
+    pub iterations: usize,
+    /// The residual norm `|b - Ax|` of the initial guess and after each iteration
+    ///
+    /// These are the norms of the residuals updated by the recurrence of each method,
+    /// which may deviate from the true residuals by rounding errors.
+    pub residual_norms: Vec<A::Real>,
+    /// Whether the stopping criterion, e.g. `|b - Ax| <= tol * |b|`, is satisfied
+    pub converged: bool,
+}
+
+/// Identity as the trivial preconditioner
+struct Identity<A>(PhantomData<A>);
+
+impl<A: Scalar> LinearOperator for Identity<A> {
+    type Elem = A;
+
+    fn apply_mut<S>(&self, _a: &mut ArrayBase<S, Ix1>)
+    where
+        S: DataMut<Elem = A>,
+    {
+    }
+}
@@ -0,0 +1,436 @@
+//! Preconditioners for the iterative solvers
+//!
+//! A preconditioner `M` approximates `A` so that `M^{-1} A` is better conditioned than `A`,
+//! while `Mz = r` is cheap to solve. The following preconditioners are built
+//! from the dense matrices or the band matrices of [crate::band]:
+//!
+//! - [Jacobi]: the diagonal `M = D`
+//! - [Ssor]: the symmetric successive over-relaxation
+//!   `M = (D / w + L) (D / w)^{-1} (D / w + U) w / (2 - w)` for the splitting `A = L + D + U`
+//! - [IncompleteLU]: `M = LU` keeping the non-zero pattern of `A`, ILU(0)
+//! - [IncompleteCholesky]: `M = L L^H` keeping the non-zero pattern of `A`, IC(0),
+//!   for Hermitian positive definite `A`
+//!
+//! The dense matrices are stored in the band storage of their actual bandwidth,
+//! and zeros inside the band are excluded from the non-zero pattern of ILU(0) and IC(0).
+//! For the band matrices without zeros in the band, no fill-in appears outside the band,
+//! and ILU(0) and IC(0) coincide with the exact LU and Cholesky decompositions.
+//!
+//! Any [LinearOperator] applying `M^{-1}` is also a [Preconditioner].
+//!
+//! - Y. Saad, "Iterative Methods for Sparse Linear Systems", 2nd ed., SIAM (2003), Chapter 10.
+
+use super::*;
+use crate::band::{BandedMatrix, SymmetricBand};
+use crate::error::*;
+use crate::UPLO;
+use num_traits::{Float, Zero};
+
+/// Preconditioner `M` applied as `M^{-1}`
+pub trait Preconditioner {
+    type Elem: Scalar;
+
+    /// Solve `Mz = r` in-place, i.e. overwrite `r` by `M^{-1} r`
+    fn precondition_mut<S>(&self, r: &mut ArrayBase<S, Ix1>)
+    where
+        S: DataMut<Elem = Self::Elem>;
+
+    /// Solve `Mz = r` out-place
+    fn precondition<S>(&self, r: &ArrayBase<S, Ix1>) -> Array1<Self::Elem>
+    where
+        S: Data<Elem = Self::Elem>,
+    {
+        let mut z = r.to_owned();
+        self.precondition_mut(&mut z);
+        z
+    }
+}
+
+impl<Op: LinearOperator + ?Sized> Preconditioner for Op {
+    type Elem = Op::Elem;
+
+    fn precondition_mut<S>(&self, r: &mut ArrayBase<S, Ix1>)
+    where
+        S: DataMut<Elem = Self::Elem>,
+    {
+        self.apply_mut(r)
+    }
+
+    fn precondition<S>(&self, r: &ArrayBase<S, Ix1>) -> Array1<Self::Elem>
+    where
+        S: Data<Elem = Self::Elem>,
+    {
+        self.apply(r)
+    }
+}
+
+/// Jacobi (diagonal) preconditioner
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{iterative::*, *};
+///
+/// let a: Array2<f64> = random_hpd(20);
+/// let b: Array1<f64> = random(20);
+/// let m = Jacobi::from_dense(&a).unwrap();
+/// let result = cg_preconditioned(&a, &m, &b, 1e-10, 100);
+/// assert!(result.converged);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Jacobi<A: Scalar> {
+    inv_diag: Array1<A>,
+}
+
+impl<A: Scalar> Jacobi<A> {
+    /// [LinalgError::Lapack] is returned if a diagonal element is zero
+    pub fn from_dense<S>(a: &ArrayBase<S, Ix2>) -> Result<Self>
+    where
+        S: Data<Elem = A>,
+    {
+        if !a.is_square() {
+            return Err(LinalgError::NotSquare {
+                rows: a.nrows() as i32,
+                cols: a.ncols() as i32,
+            });
+        }
+        Self::from_diag(a.diag())
+    }
+
+    /// [LinalgError::Lapack] is returned if a diagonal element is zero
+    pub fn from_banded(a: &BandedMatrix<A>) -> Result<Self> {
+        Self::from_diag(a.ab.row(a.ku))
+    }
+
+    fn from_diag(diag: ArrayView1<A>) -> Result<Self> {
+        if let Some(i) = diag.iter().position(|d| d.is_zero()) {
+            return Err(zero_pivot(i));
+        }
+        Ok(Jacobi {
+            inv_diag: diag.mapv(|d| A::one() / d),
+        })
+    }
+}
+
+impl<A: Scalar> Preconditioner for Jacobi<A> {
+    type Elem = A;
+
+    fn precondition_mut<S>(&self, r: &mut ArrayBase<S, Ix1>)
+    where
+        S: DataMut<Elem = A>,
+    {
+        azip!((r in r, &d in &self.inv_diag) *r *= d);
+    }
+}
+
+/// Symmetric successive over-relaxation (SSOR) preconditioner
+///
+/// `M` is Hermitian positive definite if `A` is and `0 < w < 2`,
+/// and then SSOR can be used with [cg_preconditioned].
+/// `w = 1` gives the symmetric Gauss-Seidel preconditioner.
+#[derive(Debug, Clone)]
+pub struct Ssor<A: Scalar> {
+    a: BandedMatrix<A>,
+    omega: A::Real,
+}
+
+impl<A: Scalar> Ssor<A> {
+    /// [LinalgError::Lapack] is returned if a diagonal element is zero
+    ///
+    /// # Panics
+    ///
+    /// Panics if `omega` is not in `(0, 2)`.
+    pub fn from_dense<S>(a: &ArrayBase<S, Ix2>, omega: A::Real) -> Result<Self>
+    where
+        S: Data<Elem = A>,
+    {
+        Self::from_banded(&band_of_dense(a)?, omega)
+    }
+
+    /// [LinalgError::Lapack] is returned if a diagonal element is zero
+    ///
+    /// # Panics
+    ///
+    /// Panics if `omega` is not in `(0, 2)`.
+    pub fn from_banded(a: &BandedMatrix<A>, omega: A::Real) -> Result<Self> {
+        assert!(
+            omega > A::Real::zero() && omega < A::real(2.0),
+            "Relaxation parameter must be in (0, 2)"
+        );
+        if let Some(i) = a.ab.row(a.ku).iter().position(|d| d.is_zero()) {
+            return Err(zero_pivot(i));
+        }
+        Ok(Ssor {
+            a: a.clone(),
+            omega,
+        })
+    }
+}
+
+impl<A: Scalar> Preconditioner for Ssor<A> {
+    type Elem = A;
+
+    fn precondition_mut<S>(&self, r: &mut ArrayBase<S, Ix1>)
+    where
+        S: DataMut<Elem = A>,
+    {
+        let BandedMatrix { kl, ku, ab } = &self.a;
+        let (kl, ku) = (*kl, *ku);
+        let n = r.len();
+        let omega = self.omega;
+        // (D / w + L) y = r
+        for i in 0..n {
+            let mut t = r[i];
+            for j in i.saturating_sub(kl)..i {
+                t -= ab[(ku + i - j, j)] * r[j];
+            }
+            r[i] = t.mul_real(omega) / ab[(ku, i)];
+        }
+        // (D / w + U) z = (D / w) y
+        for i in (0..n).rev() {
+            let mut t = r[i] * ab[(ku, i)].div_real(omega);
+            for j in (i + 1)..n.min(i + ku + 1) {
+                t -= ab[(ku + i - j, j)] * r[j];
+            }
+            r[i] = t.mul_real(omega) / ab[(ku, i)];
+        }
+        let scale = (A::real(2.0) - omega) / omega;
+        r.mapv_inplace(|r| r.mul_real(scale));
+    }
+}
+
+/// Incomplete LU decomposition without fill-in, ILU(0)
+///
+/// No pivoting is done, and [LinalgError::Lapack] is returned if a zero pivot appears,
+/// e.g. for the matrices with zeros on the diagonal.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{iterative::*, *};
+///
+/// let r: Array2<f64> = random((20, 20));
+/// let a = Array2::eye(20) * 10.0 + r;
+/// let b: Array1<f64> = random(20);
+/// let m = IncompleteLU::from_dense(&a).unwrap();
+/// let result = gmres_preconditioned(&a, &m, &b, 10, 1e-10, 100);
+/// assert!(result.converged);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IncompleteLU<A: Scalar> {
+    /// Unit lower triangular `L` without the diagonal and upper triangular `U` in the band of `A`
+    lu: BandedMatrix<A>,
+}
+
+impl<A: Scalar> IncompleteLU<A> {
+    /// Decompose with the non-zero pattern of `a`
+    pub fn from_dense<S>(a: &ArrayBase<S, Ix2>) -> Result<Self>
+    where
+        S: Data<Elem = A>,
+    {
+        Self::from_banded(&band_of_dense(a)?)
+    }
+
+    /// Decompose with the non-zero pattern in the band of `a`
+    pub fn from_banded(a: &BandedMatrix<A>) -> Result<Self> {
+        let (kl, ku) = (a.kl, a.ku);
+        let n = a.len();
+        let pattern = a.ab.mapv(|x| !x.is_zero());
+        let mut lu = a.clone();
+        let ab = &mut lu.ab;
+        for i in 0..n {
+            for k in i.saturating_sub(kl)..i {
+                if !pattern[(ku + i - k, k)] {
+                    continue;
+                }
+                let l = ab[(ku + i - k, k)] / ab[(ku, k)];
+                ab[(ku + i - k, k)] = l;
+                // `u[k, j]` is zero outside of the band
+                for j in (k + 1)..n.min(k + ku + 1) {
+                    if pattern[(ku + i - j, j)] {
+                        let u = ab[(ku + k - j, j)];
+                        ab[(ku + i - j, j)] -= l * u;
+                    }
+                }
+            }
+            if ab[(ku, i)].is_zero() {
+                return Err(zero_pivot(i));
+            }
+        }
+        Ok(IncompleteLU { lu })
+    }
+}
+
+impl<A: Scalar> Preconditioner for IncompleteLU<A> {
+    type Elem = A;
+
+    fn precondition_mut<S>(&self, r: &mut ArrayBase<S, Ix1>)
+    where
+        S: DataMut<Elem = A>,
+    {
+        let BandedMatrix { kl, ku, ab } = &self.lu;
+        let (kl, ku) = (*kl, *ku);
+        let n = r.len();
+        for i in 0..n {
+            let mut t = r[i];
+            for j in i.saturating_sub(kl)..i {
+                t -= ab[(ku + i - j, j)] * r[j];
+            }
+            r[i] = t;
+        }
+        for i in (0..n).rev() {
+            let mut t = r[i];
+            for j in (i + 1)..n.min(i + ku + 1) {
+                t -= ab[(ku + i - j, j)] * r[j];
+            }
+            r[i] = t / ab[(ku, i)];
+        }
+    }
+}
+
+/// Incomplete Cholesky decomposition without fill-in, IC(0)
+///
+/// IC(0) may break down even for Hermitian positive definite matrices,
+/// and then [LinalgError::Lapack] is returned.
+/// It always exists for the M-matrices, e.g. the discretized Laplacians.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{iterative::*, *};
+///
+/// let a: Array2<f64> = random_hpd(20);
+/// let b: Array1<f64> = random(20);
+/// let m = IncompleteCholesky::from_dense(&a, UPLO::Lower).unwrap();
+/// let result = cg_preconditioned(&a, &m, &b, 1e-10, 100);
+/// assert!(result.converged);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IncompleteCholesky<A: Scalar> {
+    /// `L` in the lower band storage with the bandwidth of `A`
+    l: SymmetricBand<A>,
+}
+
+impl<A: Scalar> IncompleteCholesky<A> {
+    /// Decompose with the non-zero pattern of `a`, whose `uplo` triangle is only referenced
+    pub fn from_dense<S>(a: &ArrayBase<S, Ix2>, uplo: UPLO) -> Result<Self>
+    where
+        S: Data<Elem = A>,
+    {
+        if !a.is_square() {
+            return Err(LinalgError::NotSquare {
+                rows: a.nrows() as i32,
+                cols: a.ncols() as i32,
+            });
+        }
+        let mut k = 0;
+        for ((i, j), x) in a.indexed_iter() {
+            let stored = match uplo {
+                UPLO::Upper => i <= j,
+                UPLO::Lower => i >= j,
+            };
+            if stored && !x.is_zero() {
+                k = k.max(i.abs_diff(j));
+            }
+        }
+        Self::from_banded(&SymmetricBand::from_dense(a, k, uplo))
+    }
+
+    /// Decompose with the non-zero pattern in the band of `a`
+    pub fn from_banded(a: &SymmetricBand<A>) -> Result<Self> {
+        let k = a.k;
+        let n = a.len();
+        // `A[i, j]` for `i >= j`
+        let lower = |i: usize, j: usize| match a.uplo {
+            UPLO::Upper => a.ab[(k + j - i, i)].conj(),
+            UPLO::Lower => a.ab[(i - j, j)],
+        };
+        let mut l = Array2::<A>::zeros((k + 1, n).f());
+        for j in 0..n {
+            for i in j..n.min(j + k + 1) {
+                let aij = lower(i, j);
+                if i != j && aij.is_zero() {
+                    continue;
+                }
+                let mut t = aij;
+                for p in i.saturating_sub(k)..j {
+                    t -= l[(i - p, p)] * l[(j - p, p)].conj();
+                }
+                if i == j {
+                    let d = t.re();
+                    if d <= A::Real::zero() {
+                        return Err(zero_pivot(j));
+                    }
+                    l[(0, j)] = A::from_real(Float::sqrt(d));
+                } else {
+                    l[(i - j, j)] = t / l[(0, j)];
+                }
+            }
+        }
+        Ok(IncompleteCholesky {
+            l: SymmetricBand {
+                uplo: UPLO::Lower,
+                k,
+                ab: l,
+            },
+        })
+    }
+}
+
+impl<A: Scalar> Preconditioner for IncompleteCholesky<A> {
+    type Elem = A;
+
+    fn precondition_mut<S>(&self, r: &mut ArrayBase<S, Ix1>)
+    where
+        S: DataMut<Elem = A>,
+    {
+        let SymmetricBand { k, ab, .. } = &self.l;
+        let k = *k;
+        let n = r.len();
+        // L y = r
+        for i in 0..n {
+            let mut t = r[i];
+            for j in i.saturating_sub(k)..i {
+                t -= ab[(i - j, j)] * r[j];
+            }
+            r[i] = t / ab[(0, i)];
+        }
+        // L^H z = y
+        for i in (0..n).rev() {
+            let mut t = r[i];
+            for j in (i + 1)..n.min(i + k + 1) {
+                t -= ab[(j - i, i)].conj() * r[j];
+            }
+            r[i] = t / ab[(0, i)];
+        }
+    }
+}
+
+/// Pack a dense matrix into the band storage of its actual bandwidth
+fn band_of_dense<A, S>(a: &ArrayBase<S, Ix2>) -> Result<BandedMatrix<A>>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    if !a.is_square() {
+        return Err(LinalgError::NotSquare {
+            rows: a.nrows() as i32,
+            cols: a.ncols() as i32,
+        });
+    }
+    let (mut kl, mut ku) = (0, 0);
+    for ((i, j), x) in a.indexed_iter() {
+        if !x.is_zero() {
+            if i > j {
+                kl = kl.max(i - j);
+            } else {
+                ku = ku.max(j - i);
+            }
+        }
+    }
+    Ok(BandedMatrix::from_dense(a, kl, ku))
+}
+
+/// Zero (or non-positive) pivot at the `i`-th row
+fn zero_pivot(i: usize) -> LinalgError {
+    LinalgError::Lapack(lax::error::Error::LapackComputationalFailure {
+        return_code: i as i32 + 1,
+    })
+}
@@ -56,6 +56,14 @@ where
     /// Iterate until convergent
     pub fn complete(mut self) -> (Q<A>, H<A>) {
         for _ in &mut self {} // execute iteration until convergent
+        self.into_qh()
+    }
+
+    /// Q-matrix and H-matrix of the current Krylov subspace without further iteration
+    ///
+    /// If the iteration is stopped before convergence, `Q` has one more column than `H`,
+    /// i.e. the last row of the rectangular Hessenberg matrix is dropped from `H`.
+    pub fn into_qh(self) -> (Q<A>, H<A>) {
         let q = self.ortho.get_q();
         let n = self.h.len();
         let mut h = Array2::zeros((n, n).f());
@@ -69,6 +77,50 @@ where
     }
 }
 
+impl<A, F, Ortho> Arnoldi<A, OwnedRepr<A>, F, Ortho>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+    Ortho: Orthogonalizer<Elem = A>,
+{
+    /// Resume Arnoldi iteration from a decomposition `A Q_k = Q_{k+1} H`,
+    /// e.g. truncated by a restart
+    ///
+    /// - `q` is `n x (k+1)` matrix with orthonormal columns
+    /// - `h` is `(k+1) x k` upper Hessenberg matrix
+    ///
+    /// Panics if the shapes mismatch or the columns of `q` are linearly dependent.
+    pub fn from_decomposition<Sq, Sh>(
+        a: F,
+        q: &ArrayBase<Sq, Ix2>,
+        h: &ArrayBase<Sh, Ix2>,
+        mut ortho: Ortho,
+    ) -> Self
+    where
+        Sq: Data<Elem = A>,
+        Sh: Data<Elem = A>,
+    {
+        assert_eq!(ortho.len(), 0);
+        let k = h.ncols();
+        assert_eq!(q.ncols(), k + 1);
+        assert_eq!(h.nrows(), k + 1);
+        // The orthogonalizer stores `q_i / d_i` where `d_i` is the last coefficient,
+        // e.g. a phase factor for Householder reflection
+        let mut d = Vec::with_capacity(k + 1);
+        for col in q.columns() {
+            match ortho.append(col) {
+                AppendResult::Added(coef) => d.push(coef[coef.len() - 1]),
+                AppendResult::Dependent(_) => panic!("Columns of Q are linearly dependent"),
+            }
+        }
+        let h = (0..k)
+            .map(|j| Array1::from_shape_fn(j + 2, |i| d[i] * h[(i, j)] / d[j]))
+            .collect();
+        let v = q.column(k).mapv(|x| x / d[k]);
+        Arnoldi { a, v, ortho, h }
+    }
+}
+
 impl<A, S, F, Ortho> Iterator for Arnoldi<A, S, F, Ortho>
 where
     A: Scalar + Lapack,
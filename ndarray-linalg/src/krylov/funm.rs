@@ -0,0 +1,58 @@
+//! Krylov subspace approximation of matrix functions
+//!
+//! `f(A) b` is approximated by `|b| V_k f(H_k) e_1`, where `V_k` is the orthonormal basis of
+//! the `k`-dimensional Krylov subspace of `b` and `H_k = V_k^H A V_k` is the Hessenberg matrix
+//! computed by the Arnoldi iteration. For Hermitian `A` it reduces to the Lanczos method,
+//! and `H_k` is tridiagonal.
+//!
+//! - N. J. Higham, "Functions of Matrices: Theory and Computation", SIAM, 2008, Chapter 13.
+
+use super::*;
+use crate::{error::*, funm::Funm, norm::Norm, operator::LinearOperator};
+use num_traits::Zero;
+
+/// Approximate `f(A) b` in the Krylov subspace of dimension `k`
+///
+/// `A` is only accessed through [LinearOperator], and `f` is evaluated on the `k x k` matrix `H_k`
+/// by [Funm::funm]. The result is exact if the Krylov subspace becomes invariant within `k` steps
+/// (detected with the residual tolerance `tol` as [arnoldi_mgs]), and in general the error decreases
+/// as `k` grows when `f` is smooth on the spectrum of `A`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// let a: Array2<f64> = random_hpd(20);
+/// let b: Array1<f64> = random(20);
+/// // A^{-1/2} b
+/// let x = krylov_funm(a.view(), b.clone(), 20, 1e-12, |z| z.sqrt().inv()).unwrap();
+/// let ans = a.funm(|z| z.sqrt().inv()).unwrap().dot(&b.mapv(|x| x.as_c()));
+/// assert_close_l2!(&x, &ans, 1e-6);
+/// ```
+pub fn krylov_funm<A, S, F>(
+    a: impl LinearOperator<Elem = A>,
+    b: ArrayBase<S, Ix1>,
+    k: usize,
+    tol: A::Real,
+    f: F,
+) -> Result<Array1<A::Complex>>
+where
+    A: Scalar + Lapack,
+    A::Complex: Lapack,
+    S: DataMut<Elem = A>,
+    F: Fn(A::Complex) -> A::Complex,
+{
+    let n = b.len();
+    let norm = b.norm_l2();
+    if norm.is_zero() || k == 0 {
+        return Ok(Array1::zeros(n));
+    }
+    let mgs = MGS::new(n, tol);
+    let mut arnoldi = Arnoldi::new(a, b, mgs);
+    for _ in (&mut arnoldi).take(k) {}
+    let (q, h) = arnoldi.into_qh();
+    let m = h.nrows();
+    let fh = h.funm(f)?;
+    let y = fh.column(0).mapv(|x| x.mul_real(norm));
+    Ok(q.slice(s![.., ..m]).mapv(|x| x.as_c()).dot(&y))
+}
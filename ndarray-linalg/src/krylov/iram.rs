@@ -0,0 +1,319 @@
+//! Implicitly restarted Arnoldi method
+//!
+//! The Arnoldi iteration of `m` steps approximates the eigenpairs of `A` by the Ritz pairs
+//! of the `m x m` Hessenberg matrix `H`. Instead of increasing `m` until the wanted Ritz pairs converge,
+//! the decomposition is compressed to `k` steps by the shifted QR steps on `H`
+//! with the unwanted Ritz values as the shifts (exact shifts),
+//! which filters the unwanted components out of the starting vector,
+//! and then extended again to `m` steps. The memory usage is bounded by `m` vectors.
+//!
+//! For a real operator, a complex conjugate pair of shifts is applied at once by the double shift
+//! to keep the arithmetic real.
+//!
+//! - D. C. Sorensen, "Implicit application of polynomial filters in a k-step Arnoldi method",
+//!   SIAM J. Matrix Anal. Appl. 13 (1992).
+//! - R. B. Lehoucq, D. C. Sorensen, C. Yang, "ARPACK Users' Guide", SIAM (1998).
+
+use super::*;
+use crate::{
+    eig::*, error::*, generate::*, givens::*, inner::*, norm::Norm, operator::LinearOperator,
+};
+use num_traits::{Float, One, Zero};
+use std::any::TypeId;
+use std::cmp::Ordering;
+
+/// Which eigenvalues are computed by [Iram]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Which {
+    /// Largest absolute value
+    LargestMagnitude,
+    /// Smallest absolute value, which converges slowly without the shift-invert transformation
+    SmallestMagnitude,
+    /// Largest real part
+    LargestReal,
+    /// Smallest real part
+    SmallestReal,
+    /// Largest absolute value of the imaginary part
+    LargestImaginary,
+    /// Smallest absolute value of the imaginary part
+    SmallestImaginary,
+}
+
+impl Which {
+    /// Order of the eigenvalues, the wanted first
+    pub(crate) fn cmp<A: Scalar>(self, x: &A, y: &A) -> Ordering {
+        let (x, y) = match self {
+            Which::LargestMagnitude => (y.abs(), x.abs()),
+            Which::SmallestMagnitude => (x.abs(), y.abs()),
+            Which::LargestReal => (y.re(), x.re()),
+            Which::SmallestReal => (x.re(), y.re()),
+            Which::LargestImaginary => (Float::abs(y.im()), Float::abs(x.im())),
+            Which::SmallestImaginary => (Float::abs(x.im()), Float::abs(y.im())),
+        };
+        x.partial_cmp(&y).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Result of [Iram::solve]
+#[derive(Debug, Clone)]
+pub struct IramResult<A: Scalar> {
+    /// Approximate eigenvalues sorted by [Which]
+    pub eigenvalues: Array1<A::Complex>,
+    /// Corresponding normalized eigenvectors as columns
+    pub eigenvectors: Array2<A::Complex>,
+    /// Estimates of the residual norms `|A x - lambda x|`
+    pub residual_norms: Array1<A::Real>,
+    /// The number of restarts done
+    pub restarts: usize,
+    /// Whether all the residual norms are within the tolerance
+    pub converged: bool,
+}
+
+/// Implicitly restarted Arnoldi method computing `k` eigenpairs of a general operator
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// // Upper bidiagonal matrix with the eigenvalues 1, 2, ..., 100
+/// let mut a: Array2<f64> = Array2::from_diag(&Array1::range(1.0, 101.0, 1.0));
+/// for i in 0..99 {
+///     a[(i, i + 1)] = 1.0;
+/// }
+/// let result = Iram::new(3, Which::LargestReal)
+///     .tol(1e-10)
+///     .solve(&a, &Array1::ones(100))
+///     .unwrap();
+/// assert!(result.converged);
+/// for (lambda, expected) in result.eigenvalues.iter().zip(&[100.0, 99.0, 98.0]) {
+///     assert_rclose!(lambda.re, *expected, 1e-8);
+/// }
+/// ```
+///
+/// The eigenvalues near `sigma` are computed with [crate::ShiftInvert]
+/// by [Which::LargestMagnitude] and [crate::ShiftInvert::back_transform].
+#[derive(Debug, Clone)]
+pub struct Iram<A: Scalar> {
+    k: usize,
+    which: Which,
+    ncv: Option<usize>,
+    tol: A::Real,
+    max_restarts: usize,
+}
+
+impl<A: Scalar + Lapack> Iram<A> {
+    /// Compute `k` eigenpairs selected by `which`
+    pub fn new(k: usize, which: Which) -> Self {
+        Iram {
+            k,
+            which,
+            ncv: None,
+            tol: A::Real::epsilon(),
+            max_restarts: 300,
+        }
+    }
+
+    /// Dimension `m` of the Krylov subspace, `max(2k + 1, 20)` by default
+    ///
+    /// It is clamped into `k < m <= n`.
+    pub fn ncv(mut self, ncv: usize) -> Self {
+        self.ncv = Some(ncv);
+        self
+    }
+
+    /// Relative tolerance of the residual norms, the machine epsilon by default
+    ///
+    /// A Ritz pair `(theta, x)` is accepted if `|A x - theta x| <= tol * max(eps^(2/3), |theta|)`.
+    pub fn tol(mut self, tol: A::Real) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    /// Maximum number of restarts, 300 by default
+    pub fn max_restarts(mut self, max_restarts: usize) -> Self {
+        self.max_restarts = max_restarts;
+        self
+    }
+
+    /// Start Arnoldi iteration from `start`
+    ///
+    /// If the Krylov subspace is invariant under `a`,
+    /// the eigenpairs in the subspace are returned and may be fewer than `k`.
+    /// For `k >= n`, all the `n` eigenpairs are returned without restart.
+    pub fn solve<Op, S>(&self, a: &Op, start: &ArrayBase<S, Ix1>) -> Result<IramResult<A>>
+    where
+        Op: LinearOperator<Elem = A>,
+        S: Data<Elem = A>,
+    {
+        let n = start.len();
+        if matches!(a.shape(), Some(shape) if shape != (n, n)) {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let k = self.k.min(n);
+        let ncv = self.ncv.unwrap_or(20.max(2 * k + 1)).max(k + 1).min(n);
+        let eps = A::Real::epsilon();
+        let real = TypeId::of::<A>() == TypeId::of::<A::Real>();
+
+        let mut arnoldi = Arnoldi::new(a, start.to_owned(), MGS::with_reorthogonalization(n, eps));
+        let mut restarts = 0;
+        loop {
+            let mut beta = A::Real::zero();
+            let mut invariant = false;
+            while arnoldi.dim() <= ncv {
+                match arnoldi.next() {
+                    Some(coef) => beta = coef[coef.len() - 1].abs(),
+                    None => {
+                        invariant = true;
+                        break;
+                    }
+                }
+            }
+            let (q, mut h) = arnoldi.into_qh();
+            let m = h.ncols();
+            let (theta, y) = h.eig()?;
+            let mut order: Vec<usize> = (0..m).collect();
+            order.sort_by(|&i, &j| self.which.cmp(&theta[i], &theta[j]));
+
+            let nev = k.min(m);
+            let residual_norms: Array1<A::Real> = order[..nev]
+                .iter()
+                .map(|&i| {
+                    if invariant {
+                        Zero::zero()
+                    } else {
+                        beta * y[(m - 1, i)].abs()
+                    }
+                })
+                .collect();
+            let threshold = Float::powf(eps, A::real(2.0 / 3.0));
+            let converged = residual_norms
+                .iter()
+                .zip(&order)
+                .all(|(&r, &i)| r <= self.tol * threshold.max(theta[i].abs()));
+            // Nothing is left to filter out if all the Ritz pairs are wanted, e.g. for `k >= n`
+            if converged || invariant || nev == m || restarts == self.max_restarts {
+                let q = q.slice(s![.., ..m]).mapv(|x| x.as_c());
+                let y = y.select(Axis(1), &order[..nev]);
+                return Ok(IramResult {
+                    eigenvalues: order[..nev].iter().map(|&i| theta[i]).collect(),
+                    eigenvectors: q.dot(&y),
+                    residual_norms,
+                    restarts,
+                    converged,
+                });
+            }
+
+            // Keep a complex conjugate pair together for a real operator
+            let mut kk = nev;
+            let (last, next) = (theta[order[kk - 1]], theta[order[kk]]);
+            if real && !last.im().is_zero() && next == last.conj() && kk + 1 < m {
+                kk += 1;
+            }
+
+            // Shifted QR steps with the unwanted Ritz values
+            let mut u = Array2::<A>::eye(m);
+            let shifts: Vec<A::Complex> = order[kk..].iter().map(|&i| theta[i]).collect();
+            let mut i = 0;
+            while i < shifts.len() {
+                let mu = shifts[i];
+                if real && !mu.im().is_zero() && shifts.get(i + 1) == Some(&mu.conj()) {
+                    double_shift_qr_step(&mut h, &mut u, mu);
+                    i += 2;
+                } else {
+                    let mu = if real {
+                        A::from_real(mu.re())
+                    } else {
+                        from_complex(mu)
+                    };
+                    single_shift_qr_step(&mut h, &mut u, mu);
+                    i += 1;
+                }
+            }
+
+            // A V U[:, ..k] = V U[:, ..k] H[..k, ..k] + f e_k^T
+            let v = q.slice(s![.., ..m]).dot(&u.slice(s![.., ..=kk]));
+            let mut f = v.column(kk).mapv(|x| x * h[(kk, kk - 1)]);
+            let b = u[(m - 1, kk - 1)].mul_real(beta);
+            azip!((f in &mut f, &q in &q.column(m)) *f += q * b);
+            let f_norm = f.norm_l2();
+            let mut qk = v;
+            let mut hk = Array2::zeros((kk + 1, kk));
+            hk.slice_mut(s![..kk, ..]).assign(&h.slice(s![..kk, ..kk]));
+            if f_norm > eps {
+                qk.column_mut(kk).assign(&f.mapv(|x| x.div_real(f_norm)));
+                hk[(kk, kk - 1)] = A::from_real(f_norm);
+            } else {
+                // The subspace is invariant. Continue with any vector orthogonal to it
+                let mut r: Array1<A> = random(n);
+                for col in qk.slice(s![.., ..kk]).columns() {
+                    let c = col.inner(&r);
+                    azip!((r in &mut r, &q in &col) *r -= q * c);
+                }
+                let r_norm = r.norm_l2();
+                qk.column_mut(kk).assign(&r.mapv(|x| x.div_real(r_norm)));
+            }
+            arnoldi =
+                Arnoldi::from_decomposition(a, &qk, &hk, MGS::with_reorthogonalization(n, eps));
+            restarts += 1;
+        }
+    }
+}
+
+/// `x + iy` as `A`, which is either `A::Real` or `A::Complex`
+fn from_complex<A: Scalar>(c: A::Complex) -> A {
+    if c.im().is_zero() {
+        A::from_real(c.re())
+    } else {
+        // `sqrt(-1)` is the imaginary unit for complex `A`
+        let i = A::from_real(-A::Real::one()).sqrt();
+        A::from_real(c.re()) + i.mul_real(c.im())
+    }
+}
+
+/// Restore the Hessenberg form of `H` perturbed in the leading `3 x 3` block
+/// by chasing the bulge down with Givens rotations, accumulated into `U`
+fn chase_bulge<A: Scalar>(h: &mut Array2<A>, u: &mut Array2<A>) {
+    let m = h.nrows();
+    for j in 0..m.saturating_sub(2) {
+        for i in (j + 2..(j + 4).min(m)).rev() {
+            if h[(i, j)].is_zero() {
+                continue;
+            }
+            let g = Givens::new(h[(i - 1, j)], h[(i, j)]);
+            rotate(h, u, &g, i - 1);
+            h[(i, j)] = A::zero();
+        }
+    }
+}
+
+/// `H <- G H G^H` and `U <- U G^H` for the rotation of `i`-th and `i+1`-th coordinates
+fn rotate<A: Scalar>(h: &mut Array2<A>, u: &mut Array2<A>, g: &Givens<A>, i: usize) {
+    g.rotate_rows(h, i, i + 1);
+    g.rotate_cols(h, i, i + 1);
+    g.rotate_cols(u, i, i + 1);
+}
+
+/// Implicit QR step on Hessenberg `H` with a shift `mu`
+fn single_shift_qr_step<A: Scalar>(h: &mut Array2<A>, u: &mut Array2<A>, mu: A) {
+    let g = Givens::new(h[(0, 0)] - mu, h[(1, 0)]);
+    rotate(h, u, &g, 0);
+    chase_bulge(h, u);
+}
+
+/// Implicit QR step on real Hessenberg `H` with the shifts `mu` and `conj(mu)`,
+/// i.e. with the first column of `H^2 - 2 Re(mu) H + |mu|^2 I`
+fn double_shift_qr_step<A: Scalar>(h: &mut Array2<A>, u: &mut Array2<A>, mu: A::Complex) {
+    let s = A::from_real(mu.re() + mu.re());
+    let t = A::from_real(mu.square());
+    let x = h[(0, 0)] * h[(0, 0)] + h[(0, 1)] * h[(1, 0)] - s * h[(0, 0)] + t;
+    let mut y = h[(1, 0)] * (h[(0, 0)] + h[(1, 1)] - s);
+    if h.nrows() > 2 {
+        let z = h[(1, 0)] * h[(2, 1)];
+        let g = Givens::new(y, z);
+        y = g.rotate(y, z).0;
+        rotate(h, u, &g, 1);
+    }
+    let g = Givens::new(x, y);
+    rotate(h, u, &g, 0);
+    chase_bulge(h, u);
+}
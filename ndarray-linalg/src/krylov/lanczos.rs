@@ -0,0 +1,309 @@
+//! Lanczos iteration for Hermitian operators
+//!
+//! - [Lanczos algorithm - Wikipedia](https://en.wikipedia.org/wiki/Lanczos_algorithm)
+//! - B. N. Parlett, D. S. Scott, "The Lanczos algorithm with selective orthogonalization",
+//!   Math. Comp. 33 (1979).
+//!
+
+use super::*;
+use crate::{error::*, inner::*, norm::Norm, operator::LinearOperator, tridiagonal::*};
+use num_traits::{One, Zero};
+
+/// Tridiagonalize a Hermitian operator by `k` steps of Lanczos iteration
+///
+/// The operator `A` is given only through `apply(x, y)` which computes `y = A x`
+/// for `x` and `y` of length `n`, i.e. `A` does not need to be stored as a dense matrix.
+/// Starting from the Krylov vector `start`, this computes the diagonal `d` and off-diagonal `e` elements
+/// of the real symmetric tridiagonal matrix `T = V^H A V`
+/// where `V` is the `n x k` orthonormal basis of the Krylov subspace.
+/// The extreme eigenvalues of `T`, e.g. computed by [crate::EighTridiagonal],
+/// approximate the extreme eigenvalues of `A`.
+/// [LanczosEigh] computes the eigenpairs directly until they converge.
+///
+/// Each new basis vector is fully reorthogonalized against all previous ones
+/// to avoid the loss of orthogonality and the resulting spurious copies of eigenvalues,
+/// which costs `O(n k^2)` operations and `O(n k)` memory.
+/// The iteration stops before `k` steps if an invariant subspace is found,
+/// and then `d` is shorter than `k`. `e` always has `d.len() - 1` elements.
+///
+/// Errors
+/// -------
+/// - if the length of `start` is not `n`
+///
+/// Panic
+/// ------
+/// - if `start` is zero
+///
+pub fn lanczos<A: Scalar>(
+    n: usize,
+    apply: impl Fn(&[A], &mut [A]),
+    k: usize,
+    start: &[A],
+) -> Result<(Vec<A::Real>, Vec<A::Real>)> {
+    if start.len() != n {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let k = k.min(n);
+    let mut d = Vec::with_capacity(k);
+    let mut e = Vec::with_capacity(k);
+    if k == 0 {
+        return Ok((d, e));
+    }
+
+    let norm = norm_l2(start);
+    assert!(norm > A::Real::zero(), "Starting vector must be non-zero");
+    let mut v: Vec<A> = start.iter().map(|x| x.div_real(norm)).collect();
+    let mut basis: Vec<Vec<A>> = Vec::with_capacity(k);
+    let mut w = vec![A::zero(); n];
+    let tol: A::Real = num_traits::Float::epsilon();
+    let tol = tol.sqrt();
+    let mut anorm = A::Real::zero();
+    loop {
+        apply(&v, &mut w);
+        let alpha = inner(&v, &w).re();
+        basis.push(v);
+        d.push(alpha);
+        if d.len() == k {
+            break;
+        }
+        // Classical Gram-Schmidt twice against all basis vectors,
+        // which also removes `alpha v` and `beta v_prev` of the three-term recurrence
+        for _ in 0..2 {
+            for q in &basis {
+                let c = inner(q, &w);
+                for (w, q) in w.iter_mut().zip(q) {
+                    *w -= c * *q;
+                }
+            }
+        }
+        let beta = norm_l2(&w);
+        // estimate of the operator norm by the Gershgorin discs of `T`
+        let row = alpha.abs() + beta + e.last().cloned().unwrap_or_else(Zero::zero);
+        if row > anorm {
+            anorm = row;
+        }
+        if beta <= tol * anorm {
+            break;
+        }
+        e.push(beta);
+        let inv = A::Real::one() / beta;
+        v = w.iter().map(|x| x.mul_real(inv)).collect();
+    }
+    Ok((d, e))
+}
+
+/// Reorthogonalization of the Lanczos vectors in [LanczosEigh]
+///
+/// In floating point arithmetic, the Lanczos vectors lose their orthogonality
+/// as soon as a Ritz pair converges, and the converged eigenvalue appears again in later steps.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Reorthogonalization {
+    /// Against all the previous Lanczos vectors, which costs `O(n j)` operations in the `j`-th step
+    Full,
+    /// Only against the converged Ritz vectors, which are the directions where the orthogonality is lost.
+    /// The Lanczos vectors are kept orthogonal to the level of `sqrt(eps)`.
+    Selective,
+}
+
+/// Result of [LanczosEigh::solve]
+#[derive(Debug, Clone)]
+pub struct LanczosEighResult<A: Scalar> {
+    /// Approximate eigenvalues sorted by [Which]
+    pub eigenvalues: Array1<A::Real>,
+    /// Corresponding normalized eigenvectors as columns
+    pub eigenvectors: Array2<A>,
+    /// Estimates of the residual norms `|A x - lambda x|`
+    pub residual_norms: Array1<A::Real>,
+    /// The number of Lanczos steps done, i.e. the dimension of the Krylov subspace
+    pub iterations: usize,
+    /// Whether all the residual norms are within the tolerance
+    pub converged: bool,
+}
+
+/// Lanczos method computing `k` extremal eigenpairs of a Hermitian operator
+///
+/// This is the Hermitian counterpart of [Iram]. The Krylov subspace is extended
+/// without restart until the wanted Ritz pairs, computed from the eigendecomposition
+/// of the tridiagonal matrix `T` by [EighTridiagonal], converge.
+/// All the Lanczos vectors are stored to compute the Ritz vectors.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// // Eigenvalues 1, 2, ..., 100
+/// let a: Array2<f64> = Array2::from_diag(&Array1::range(1.0, 101.0, 1.0));
+/// let result = LanczosEigh::new(3, Which::SmallestReal)
+///     .tol(1e-10)
+///     .solve(&a, &Array1::ones(100))
+///     .unwrap();
+/// assert!(result.converged);
+/// assert!(result.eigenvalues.abs_diff_eq(&array![1.0, 2.0, 3.0], 1e-8));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LanczosEigh<A: Scalar> {
+    k: usize,
+    which: Which,
+    reorthogonalization: Reorthogonalization,
+    tol: A::Real,
+    max_iter: Option<usize>,
+}
+
+impl<A> LanczosEigh<A>
+where
+    A: Scalar + Lapack,
+    A::Real: Lapack,
+{
+    /// Compute `k` eigenpairs selected by `which`,
+    /// e.g. [Which::LargestReal] for the largest eigenvalues
+    pub fn new(k: usize, which: Which) -> Self {
+        LanczosEigh {
+            k,
+            which,
+            reorthogonalization: Reorthogonalization::Selective,
+            tol: num_traits::Float::epsilon(),
+            max_iter: None,
+        }
+    }
+
+    /// Reorthogonalization strategy, [Reorthogonalization::Selective] by default
+    pub fn reorthogonalization(mut self, reorthogonalization: Reorthogonalization) -> Self {
+        self.reorthogonalization = reorthogonalization;
+        self
+    }
+
+    /// Relative tolerance of the residual norms, the machine epsilon by default
+    ///
+    /// A Ritz pair `(theta, x)` is accepted if `|A x - theta x| <= tol * max(eps^(2/3), |theta|)`.
+    pub fn tol(mut self, tol: A::Real) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    /// Maximum number of Lanczos steps, `n` by default
+    pub fn max_iter(mut self, max_iter: usize) -> Self {
+        self.max_iter = Some(max_iter);
+        self
+    }
+
+    /// Start Lanczos iteration from `start`
+    ///
+    /// If the Krylov subspace is invariant under `a`,
+    /// the eigenpairs in the subspace are returned and may be fewer than `k`.
+    ///
+    /// Panics if `start` is zero.
+    pub fn solve<Op, S>(&self, a: &Op, start: &ArrayBase<S, Ix1>) -> Result<LanczosEighResult<A>>
+    where
+        Op: LinearOperator<Elem = A>,
+        S: Data<Elem = A>,
+    {
+        let n = start.len();
+        if matches!(a.shape(), Some(shape) if shape != (n, n)) {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let k = self.k.min(n);
+        let max_iter = self.max_iter.unwrap_or(n).min(n).max(1);
+        let eps: A::Real = num_traits::Float::epsilon();
+        let sqrt_eps = num_traits::Float::sqrt(eps);
+        let threshold = num_traits::Float::powf(eps, A::real(2.0 / 3.0));
+
+        let norm = start.norm_l2();
+        assert!(norm > A::Real::zero(), "Starting vector must be non-zero");
+        let mut v = start.mapv(|x| x.div_real(norm));
+        let mut basis: Vec<Array1<A>> = Vec::new();
+        let mut d = Vec::new();
+        let mut e = Vec::new();
+        let mut beta = A::Real::zero();
+        let mut anorm = A::Real::zero();
+        // Converged Ritz vectors for the selective reorthogonalization
+        let mut good: Vec<Array1<A>> = Vec::new();
+        loop {
+            let mut w = a.apply(&v);
+            let alpha = v.inner(&w).re();
+            azip!((w in &mut w, &v in &v) *w -= v.mul_real(alpha));
+            if let Some(prev) = basis.last() {
+                azip!((w in &mut w, &p in prev) *w -= p.mul_real(beta));
+            }
+            basis.push(v);
+            d.push(alpha);
+            let targets = match self.reorthogonalization {
+                Reorthogonalization::Full => &basis,
+                Reorthogonalization::Selective => &good,
+            };
+            // Classical Gram-Schmidt twice
+            for _ in 0..2 {
+                for q in targets {
+                    let c = q.inner(&w);
+                    azip!((w in &mut w, &q in q) *w -= q * c);
+                }
+            }
+            beta = w.norm_l2();
+            // estimate of the operator norm by the Gershgorin discs of `T`
+            let row = alpha.abs() + beta + e.last().cloned().unwrap_or_else(Zero::zero);
+            anorm = num_traits::Float::max(anorm, row);
+            let invariant = beta <= sqrt_eps * anorm;
+
+            // Ritz pairs
+            let j = d.len();
+            let (theta, s) = (aview1(&d), aview1(&e)).eigh_tridiagonal()?;
+            let mut order: Vec<usize> = (0..j).collect();
+            order.sort_by(|&i, &l| self.which.cmp(&theta[i], &theta[l]));
+            let bounds: Vec<A::Real> = (0..j)
+                .map(|i| {
+                    if invariant {
+                        Zero::zero()
+                    } else {
+                        beta * s[(j - 1, i)].abs()
+                    }
+                })
+                .collect();
+            let nev = k.min(j);
+            let converged = (j >= k || invariant)
+                && order[..nev].iter().all(|&i| {
+                    bounds[i] <= self.tol * num_traits::Float::max(threshold, theta[i].abs())
+                });
+            if converged || invariant || j == max_iter {
+                let x = ritz_vectors(&basis, &s.select(Axis(1), &order[..nev]));
+                return Ok(LanczosEighResult {
+                    eigenvalues: order[..nev].iter().map(|&i| theta[i]).collect(),
+                    eigenvectors: x,
+                    residual_norms: order[..nev].iter().map(|&i| bounds[i]).collect(),
+                    iterations: j,
+                    converged,
+                });
+            }
+
+            if self.reorthogonalization == Reorthogonalization::Selective {
+                let converged: Vec<usize> =
+                    (0..j).filter(|&i| bounds[i] <= sqrt_eps * anorm).collect();
+                good = if converged.is_empty() {
+                    Vec::new()
+                } else {
+                    let y = ritz_vectors(&basis, &s.select(Axis(1), &converged));
+                    y.columns().into_iter().map(|y| y.to_owned()).collect()
+                };
+            }
+            e.push(beta);
+            v = w.mapv(|x| x.div_real(beta));
+        }
+    }
+}
+
+/// `V S` where `V` is the Lanczos vectors and `S` is real
+fn ritz_vectors<A: Scalar>(basis: &[Array1<A>], s: &Array2<A::Real>) -> Array2<A> {
+    let mut x = Array2::zeros((basis[0].len(), s.ncols()));
+    for (v, s) in basis.iter().zip(s.rows()) {
+        for (mut x, &s) in x.columns_mut().into_iter().zip(&s) {
+            azip!((x in &mut x, &v in v) *x += v.mul_real(s));
+        }
+    }
+    x
+}
+
+fn inner<A: Scalar>(x: &[A], y: &[A]) -> A {
+    x.iter().zip(y).map(|(x, y)| x.conj() * *y).sum()
+}
+
+fn norm_l2<A: Scalar>(x: &[A]) -> A::Real {
+    x.iter().map(|x| x.square()).sum::<A::Real>().sqrt()
+}
@@ -14,6 +14,9 @@ pub struct MGS<A: Scalar> {
 
     /// Tolerance
     tol: A::Real,
+
+    /// Repeat the projection if cancellation occurs
+    reorthogonalize: bool,
 }
 
 impl<A: Scalar + Lapack> MGS<A> {
@@ -23,6 +26,36 @@ impl<A: Scalar + Lapack> MGS<A> {
             dim,
             q: Vec::new(),
             tol,
+            reorthogonalize: false,
+        }
+    }
+
+    /// Create an empty orthogonalizer which projects the vector once more
+    /// if its norm is reduced by more than `1/sqrt(2)` in the projection
+    ///
+    /// The basis is kept orthogonal to the working precision
+    /// even if the vectors to be appended are nearly dependent,
+    /// e.g. in the Arnoldi iteration after some Ritz values converge.
+    ///
+    /// - J. W. Daniel, W. B. Gragg, L. Kaufman, G. W. Stewart,
+    ///   "Reorthogonalization and stable algorithms for updating the Gram-Schmidt QR factorization",
+    ///   Math. Comp. 30 (1976).
+    pub fn with_reorthogonalization(dim: usize, tol: A::Real) -> Self {
+        Self {
+            reorthogonalize: true,
+            ..Self::new(dim, tol)
+        }
+    }
+
+    fn project<S>(&self, a: &mut ArrayBase<S, Ix1>, coef: &mut Array1<A>)
+    where
+        S: DataMut<Elem = A>,
+    {
+        for i in 0..self.len() {
+            let q = &self.q[i];
+            let c = q.inner(a);
+            azip!((a in &mut *a, &q in q) *a -= c * q);
+            coef[i] += c;
         }
     }
 }
@@ -48,13 +81,13 @@ impl<A: Scalar + Lapack> Orthogonalizer for MGS<A> {
     {
         assert_eq!(a.len(), self.dim());
         let mut coef = Array1::zeros(self.len() + 1);
-        for i in 0..self.len() {
-            let q = &self.q[i];
-            let c = q.inner(a);
-            azip!((a in &mut *a, &q in q) *a -= c * q);
-            coef[i] = c;
+        let nrm0 = a.norm_l2();
+        self.project(a, &mut coef);
+        let mut nrm = a.norm_l2();
+        if self.reorthogonalize && nrm < nrm0 * A::real(std::f64::consts::FRAC_1_SQRT_2) {
+            self.project(a, &mut coef);
+            nrm = a.norm_l2();
         }
-        let nrm = a.norm_l2();
         coef[self.len()] = A::from_real(nrm);
         coef
     }
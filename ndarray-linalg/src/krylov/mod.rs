@@ -4,11 +4,17 @@ use crate::types::*;
 use ndarray::*;
 
 pub mod arnoldi;
+pub mod funm;
 pub mod householder;
+pub mod iram;
+pub mod lanczos;
 pub mod mgs;
 
 pub use arnoldi::{arnoldi_householder, arnoldi_mgs, Arnoldi};
+pub use funm::krylov_funm;
 pub use householder::{householder, Householder};
+pub use iram::{Iram, IramResult, Which};
+pub use lanczos::{lanczos, LanczosEigh, LanczosEighResult, Reorthogonalization};
 pub use mgs::{mgs, MGS};
 
 /// Q-matrix
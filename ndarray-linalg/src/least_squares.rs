@@ -20,6 +20,18 @@
 //!
 //! All methods use the Lapacke family of methods `*gelsd` which solves the least
 //! squares problem using the SVD with a divide-and-conquer strategy.
+//! `LeastSquaresWithDriver` with the method `least_squares_with_driver` can choose
+//! `*gelsy` using the complete orthogonal factorization or `*gelss` using the SVD
+//! with QR iteration instead, see `LeastSquaresDriver`.
+//!
+//! `LeastSquaresRidge` with the method `least_squares_ridge` solves the ridge
+//! (Tikhonov-regularized) problem by the same driver applied to an augmented system,
+//! and `LeastSquaresWeighted` with the method `least_squares_weighted` solves
+//! the weighted problem after whitening by the weights.
+//!
+//! `total_least_squares` solves the errors-in-variables problem by the SVD of `[A b]`.
+//! `least_squares_constrained` solves the problem with the linear equality constraints by `*gglse`,
+//! and `solve_gauss_markov` solves the general Gauss-Markov linear model by `*ggglm`.
 //!
 //! The traits are implemented for value types `f32`, `f64`, `c32` and `c64`
 //! and vector or matrix right-hand-sides (`ArrayBase<S, Ix1>` or `ArrayBase<S, Ix2>`).
@@ -60,22 +72,33 @@
 //! // `a` and `b` have been moved, no longer valid
 //! ```
 
+pub use lax::LeastSquaresDriver;
 use lax::*;
 use ndarray::*;
+use num_traits::Float;
 
+use crate::cholesky::*;
 use crate::error::*;
 use crate::layout::*;
+use crate::svd::*;
 use crate::types::*;
 
 /// Result of a LeastSquares computation
 ///
 /// Takes two type parameters, `E`, the element type of the matrix
 /// (one of `f32`, `f64`, `c32` or `c64`) and `I`, the dimension of
-/// b in the equation `Ax = b` (one of `Ix1` or `Ix2`). If `I` is `Ix1`,
-/// the  right-hand-side (RHS) is a `n x 1` column vector and the solution
-/// is a `m x 1` column vector. If `I` is `Ix2`, the RHS is a `n x k` matrix
+/// b in the equation `Ax = b` (one of `Ix1` or `Ix2`).
+///
+/// For a `m x n` matrix `A`, the solution always has `n` rows regardless of the shape of `A`:
+///
+/// - `m > n` (overdetermined): `x` minimizes `|b - Ax|`.
+/// - `m == n`: `x` is the exact solution if `A` is full rank.
+/// - `m < n` (underdetermined): `x` is the minimum norm solution among those satisfying `Ax = b`.
+///
+/// If `I` is `Ix1`, the right-hand-side (RHS) is a `m x 1` column vector and the solution
+/// is a `n x 1` column vector. If `I` is `Ix2`, the RHS is a `m x k` matrix
 /// (which can be seen as solving `Ax = b` k times for different b) and
-/// the solution is a `m x k` matrix.
+/// the solution is a `n x k` matrix.
 #[derive(Debug, Clone)]
 pub struct LeastSquaresResult<E: Scalar, I: Dimension> {
     /// The singular values of the matrix A in `Ax = b`
@@ -85,7 +108,12 @@ pub struct LeastSquaresResult<E: Scalar, I: Dimension> {
     pub solution: Array<E, I>,
     /// The rank of the matrix A in `Ax = b`
     pub rank: i32,
-    /// If n < m and rank(A) == n, the sum of squares
+    /// The residual sum of squares `|b - Ax|^2`
+    ///
+    /// This is available only if `m >= n` and `rank(A) == n`, i.e. when the solution is unique.
+    /// It is zero for a square full-rank matrix. Otherwise, e.g. for underdetermined problems,
+    /// this is `None` as `numpy.linalg.lstsq` returns empty residuals.
+    ///
     /// If b is a (m x 1) vector, this is a 0-dimensional array (single value)
     /// If b is a (m x k) matrix, this is a (k x 1) column vector
     pub residual_sum_of_squares: Option<Array<E::Real, I::Smaller>>,
@@ -147,6 +175,41 @@ where
     ) -> Result<LeastSquaresResult<E, I>>;
 }
 
+/// Solve least squares for immutable references using the specified LAPACK driver
+///
+/// [LeastSquaresSvd] always uses [LeastSquaresDriver::Gelsd].
+/// [LeastSquaresDriver::Gelsy] based on the QR decomposition with column pivoting is
+/// usually faster, but the singular values of the result are empty in this case.
+/// The effective rank is reported by all the drivers.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Rank 1 matrix
+/// let a: Array2<f64> = array![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+/// let b: Array1<f64> = array![1.0, 2.0, 3.0];
+/// for driver in [LeastSquaresDriver::Gelsd, LeastSquaresDriver::Gelsy, LeastSquaresDriver::Gelss] {
+///     let result = a.least_squares_with_driver(&b, driver).unwrap();
+///     assert_eq!(result.rank, 1);
+///     assert_close_l2!(&result.solution, &array![0.2, 0.4], 1e-12);
+/// }
+/// ```
+pub trait LeastSquaresWithDriver<D, E, I>
+where
+    D: Data<Elem = E>,
+    E: Scalar + Lapack,
+    I: Dimension,
+{
+    /// Solve a least squares problem of the form `Ax = rhs` by the driver `driver`.
+    /// `A` and `rhs` are unchanged.
+    fn least_squares_with_driver(
+        &self,
+        rhs: &ArrayBase<D, I>,
+        driver: LeastSquaresDriver,
+    ) -> Result<LeastSquaresResult<E, I>>;
+}
+
 /// Solve least squares for immutable references and a single
 /// column vector as a right-hand side.
 /// `E` is one of `f32`, `f64`, `c32`, `c64`. `D1`, `D2` can be any
@@ -195,6 +258,40 @@ where
     }
 }
 
+impl<E, D1, D2> LeastSquaresWithDriver<D2, E, Ix1> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    fn least_squares_with_driver(
+        &self,
+        rhs: &ArrayBase<D2, Ix1>,
+        driver: LeastSquaresDriver,
+    ) -> Result<LeastSquaresResult<E, Ix1>> {
+        let mut a = self.to_owned();
+        let mut b = rhs.to_owned();
+        least_squares_srhs_in_place(&mut a, &mut b, driver)
+    }
+}
+
+impl<E, D1, D2> LeastSquaresWithDriver<D2, E, Ix2> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    fn least_squares_with_driver(
+        &self,
+        rhs: &ArrayBase<D2, Ix2>,
+        driver: LeastSquaresDriver,
+    ) -> Result<LeastSquaresResult<E, Ix2>> {
+        let mut a = self.to_owned();
+        let mut b = rhs.to_owned();
+        least_squares_nrhs_in_place(&mut a, &mut b, driver)
+    }
+}
+
 /// Solve least squares for owned values and a single
 /// column vector as a right-hand side. The matrix and the RHS
 /// vector are consumed.
@@ -272,37 +369,55 @@ where
         &mut self,
         rhs: &mut ArrayBase<D2, Ix1>,
     ) -> Result<LeastSquaresResult<E, Ix1>> {
-        if self.shape()[0] != rhs.shape()[0] {
-            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
-        }
-        let (m, n) = (self.shape()[0], self.shape()[1]);
-        if n > m {
-            // we need a new rhs b/c it will be overwritten with the solution
-            // for which we need `n` entries
-            let mut new_rhs = Array1::<E>::zeros((n,));
-            new_rhs.slice_mut(s![0..m]).assign(rhs);
-            compute_least_squares_srhs(self, &mut new_rhs)
-        } else {
-            compute_least_squares_srhs(self, rhs)
-        }
+        least_squares_srhs_in_place(self, rhs, LeastSquaresDriver::Gelsd)
+    }
+}
+
+fn least_squares_srhs_in_place<E, D1, D2>(
+    a: &mut ArrayBase<D1, Ix2>,
+    rhs: &mut ArrayBase<D2, Ix1>,
+    driver: LeastSquaresDriver,
+) -> Result<LeastSquaresResult<E, Ix1>>
+where
+    E: Scalar + Lapack,
+    D1: DataMut<Elem = E>,
+    D2: DataMut<Elem = E>,
+{
+    if a.shape()[0] != rhs.shape()[0] {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let (m, n) = (a.shape()[0], a.shape()[1]);
+    if n > m {
+        // we need a new rhs b/c it will be overwritten with the solution
+        // for which we need `n` entries
+        let mut new_rhs = Array1::<E>::zeros((n,));
+        new_rhs.slice_mut(s![0..m]).assign(rhs);
+        compute_least_squares_srhs(a, &mut new_rhs, driver)
+    } else {
+        compute_least_squares_srhs(a, rhs, driver)
     }
 }
 
 fn compute_least_squares_srhs<E, D1, D2>(
     a: &mut ArrayBase<D1, Ix2>,
     rhs: &mut ArrayBase<D2, Ix1>,
+    driver: LeastSquaresDriver,
 ) -> Result<LeastSquaresResult<E, Ix1>>
 where
     E: Scalar + Lapack,
     D1: DataMut<Elem = E>,
     D2: DataMut<Elem = E>,
 {
+    let a_layout = a.layout()?;
+    let rhs_layout = a_layout.resized(rhs.len() as i32, 1);
     let LeastSquaresOwned::<E> {
         singular_values,
         rank,
-    } = E::least_squares(
-        a.layout()?,
+    } = lax::least_squares::least_squares_with_driver(
+        driver,
+        a_layout,
         a.as_allocated_mut()?,
+        rhs_layout,
         rhs.as_slice_memory_order_mut()
             .ok_or(LinalgError::MemoryNotCont)?,
     )?;
@@ -355,29 +470,43 @@ where
         &mut self,
         rhs: &mut ArrayBase<D2, Ix2>,
     ) -> Result<LeastSquaresResult<E, Ix2>> {
-        if self.shape()[0] != rhs.shape()[0] {
-            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
-        }
-        let (m, n) = (self.shape()[0], self.shape()[1]);
-        if n > m {
-            // we need a new rhs b/c it will be overwritten with the solution
-            // for which we need `n` entries
-            let k = rhs.shape()[1];
-            let mut new_rhs = match self.layout()? {
-                MatrixLayout::C { .. } => Array2::<E>::zeros((n, k)),
-                MatrixLayout::F { .. } => Array2::<E>::zeros((n, k).f()),
-            };
-            new_rhs.slice_mut(s![0..m, ..]).assign(rhs);
-            compute_least_squares_nrhs(self, &mut new_rhs)
-        } else {
-            compute_least_squares_nrhs(self, rhs)
-        }
+        least_squares_nrhs_in_place(self, rhs, LeastSquaresDriver::Gelsd)
+    }
+}
+
+fn least_squares_nrhs_in_place<E, D1, D2>(
+    a: &mut ArrayBase<D1, Ix2>,
+    rhs: &mut ArrayBase<D2, Ix2>,
+    driver: LeastSquaresDriver,
+) -> Result<LeastSquaresResult<E, Ix2>>
+where
+    E: Scalar + Lapack,
+    D1: DataMut<Elem = E>,
+    D2: DataMut<Elem = E>,
+{
+    if a.shape()[0] != rhs.shape()[0] {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let (m, n) = (a.shape()[0], a.shape()[1]);
+    if n > m {
+        // we need a new rhs b/c it will be overwritten with the solution
+        // for which we need `n` entries
+        let k = rhs.shape()[1];
+        let mut new_rhs = match a.layout()? {
+            MatrixLayout::C { .. } => Array2::<E>::zeros((n, k)),
+            MatrixLayout::F { .. } => Array2::<E>::zeros((n, k).f()),
+        };
+        new_rhs.slice_mut(s![0..m, ..]).assign(rhs);
+        compute_least_squares_nrhs(a, &mut new_rhs, driver)
+    } else {
+        compute_least_squares_nrhs(a, rhs, driver)
     }
 }
 
 fn compute_least_squares_nrhs<E, D1, D2>(
     a: &mut ArrayBase<D1, Ix2>,
     rhs: &mut ArrayBase<D2, Ix2>,
+    driver: LeastSquaresDriver,
 ) -> Result<LeastSquaresResult<E, Ix2>>
 where
     E: Scalar + Lapack,
@@ -389,7 +518,8 @@ where
     let LeastSquaresOwned::<E> {
         singular_values,
         rank,
-    } = E::least_squares_nrhs(
+    } = lax::least_squares::least_squares_with_driver(
+        driver,
         a_layout,
         a.as_allocated_mut()?,
         rhs_layout,
@@ -424,6 +554,408 @@ fn compute_residual_array1<E: Scalar, D: Data<Elem = E>>(
     )
 }
 
+/// Solve least squares problem `|b - Ax|` by the normal equations `A^H A x = A^H b`
+///
+/// `A^H A` is formed by a rank-k update (`*syrk`/`*herk`), `A^H b` by a matrix-vector
+/// product (`*gemv`), and then the normal equations are solved by Cholesky decomposition
+/// (`*posv`). This is faster than [LeastSquaresSvd] for tall matrices,
+/// since only a small `n x n` matrix is factorized.
+///
+/// Note that the condition number of `A^H A` is the square of that of `A`,
+/// i.e. this loses about twice as many digits as the SVD or QR based solvers.
+/// Use this only for well-conditioned problems where speed matters more than
+/// the last few digits. `A` must have full column rank, otherwise the Cholesky
+/// decomposition fails.
+///
+/// If the number of rows of `A` is not equal to the length of `b`,
+/// `IncompatibleShape` error is raised.
+pub fn solve_normal_equations<E, D1, D2>(
+    a: &ArrayBase<D1, Ix2>,
+    b: &ArrayBase<D2, Ix1>,
+) -> Result<Array1<E>>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    if a.nrows() != b.len() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let a = match a.layout() {
+        Ok(_) => CowArray::from(a.view()),
+        Err(_) => a.as_standard_layout(),
+    };
+    let b = b.as_standard_layout();
+    let x = E::solve_normal_equations(a.layout()?, a.as_allocated()?, b.as_slice().unwrap())?;
+    Ok(Array1::from(x))
+}
+
+/// Penalty of the ridge regression for [LeastSquaresRidge]
+#[derive(Debug, Clone)]
+pub enum RidgePenalty<E: Scalar> {
+    /// `lambda |x|^2`
+    Scalar(E::Real),
+    /// `sum_j lambda_j |x_j|^2` with a penalty for each column of `A`
+    PerColumn(Array1<E::Real>),
+    /// `lambda |L x|^2` with a general `p x n` regularization matrix `L`
+    Matrix(E::Real, Array2<E>),
+}
+
+/// Ridge (Tikhonov-regularized) least squares
+///
+/// Minimize `|b - Ax|^2 + |Γ x|^2`, where `Γ` is `sqrt(lambda) I`, `diag(sqrt(lambda_j))`
+/// or `sqrt(lambda) L` for each [RidgePenalty]. This is solved as the ordinary least squares
+/// problem of the augmented system `[A; Γ] x = [b; 0]` by [LeastSquaresSvd].
+/// The penalties must be non-negative.
+///
+/// In the returned [LeastSquaresResult], `singular_values` and `rank` are those of
+/// the augmented matrix `[A; Γ]`, and `residual_sum_of_squares` is `|b - Ax|^2`
+/// without the penalty term, which is `None` if the solution is not unique.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[1.0, 0.0], [0.0, 1.0], [0.0, 0.0]];
+/// let b: Array1<f64> = array![2.0, 4.0, 1.0];
+/// // x_j = b_j / (1 + lambda)
+/// let result = a.least_squares_ridge(&b, &RidgePenalty::Scalar(1.0)).unwrap();
+/// assert_close_l2!(&result.solution, &array![1.0, 2.0], 1e-12);
+/// let result = a
+///     .least_squares_ridge(&b, &RidgePenalty::PerColumn(array![1.0, 3.0]))
+///     .unwrap();
+/// assert_close_l2!(&result.solution, &array![1.0, 1.0], 1e-12);
+/// ```
+pub trait LeastSquaresRidge<D, E>
+where
+    D: Data<Elem = E>,
+    E: Scalar + Lapack,
+{
+    fn least_squares_ridge(
+        &self,
+        rhs: &ArrayBase<D, Ix1>,
+        penalty: &RidgePenalty<E>,
+    ) -> Result<LeastSquaresResult<E, Ix1>>;
+}
+
+impl<E, D1, D2> LeastSquaresRidge<D2, E> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    fn least_squares_ridge(
+        &self,
+        rhs: &ArrayBase<D2, Ix1>,
+        penalty: &RidgePenalty<E>,
+    ) -> Result<LeastSquaresResult<E, Ix1>> {
+        let (m, n) = self.dim();
+        if m != rhs.len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let gamma: Array2<E> = match penalty {
+            RidgePenalty::Scalar(lambda) => {
+                Array2::from_diag_elem(n, E::from_real(Float::sqrt(*lambda)))
+            }
+            RidgePenalty::PerColumn(lambda) => {
+                if lambda.len() != n {
+                    return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+                }
+                Array2::from_diag(&lambda.mapv(|l| E::from_real(Float::sqrt(l))))
+            }
+            RidgePenalty::Matrix(lambda, l) => {
+                if l.ncols() != n {
+                    return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+                }
+                let scale = Float::sqrt(*lambda);
+                l.mapv(|x| x.mul_real(scale))
+            }
+        };
+        let p = gamma.nrows();
+        let mut a_aug = Array2::<E>::zeros((m + p, n));
+        a_aug.slice_mut(s![..m, ..]).assign(self);
+        a_aug.slice_mut(s![m.., ..]).assign(&gamma);
+        let mut b_aug = Array1::<E>::zeros(m + p);
+        b_aug.slice_mut(s![..m]).assign(rhs);
+
+        let mut result = a_aug.least_squares_into(b_aug)?;
+        if result.residual_sum_of_squares.is_some() {
+            let residual = rhs - &self.dot(&result.solution);
+            let mut arr: Array<E::Real, Ix0> = Array::zeros(());
+            arr[()] = residual.mapv(|x| x.square()).sum();
+            result.residual_sum_of_squares = Some(arr);
+        }
+        Ok(result)
+    }
+}
+
+/// Weights of the weighted least squares for [LeastSquaresWeighted]
+#[derive(Debug, Clone)]
+pub enum LeastSquaresWeights<E: Scalar> {
+    /// Non-negative weight `w_i` of each row, i.e. minimize `sum_i w_i |b_i - (Ax)_i|^2`
+    Diagonal(Array1<E::Real>),
+    /// Hermite positive definite weight matrix `W`, i.e. minimize `(b - Ax)^H W (b - Ax)`
+    Matrix(Array2<E>),
+}
+
+/// Weighted least squares
+///
+/// The problem is whitened into the ordinary least squares problem `|C (b - Ax)|`
+/// solved by [LeastSquaresSvd], where `C = diag(sqrt(w_i))` for the row weights,
+/// or `C = U` of the Cholesky decomposition `W = U^H U` for the weight matrix.
+///
+/// In the returned [LeastSquaresResult], `singular_values` are those of `CA`,
+/// and `residual_sum_of_squares` is the weighted residual sum of squares `|C (b - Ax)|^2`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Fit a constant, where the second observation is 3 times as reliable as the first
+/// let a: Array2<f64> = array![[1.0], [1.0]];
+/// let b: Array1<f64> = array![0.0, 4.0];
+/// let w = LeastSquaresWeights::Diagonal(array![1.0, 3.0]);
+/// let result = a.least_squares_weighted(&b, &w).unwrap();
+/// assert_close_l2!(&result.solution, &array![3.0], 1e-12);
+/// // 1 * 3^2 + 3 * 1^2
+/// assert_rclose!(result.residual_sum_of_squares.unwrap()[()], 12.0, 1e-12);
+/// ```
+pub trait LeastSquaresWeighted<D, E>
+where
+    D: Data<Elem = E>,
+    E: Scalar + Lapack,
+{
+    fn least_squares_weighted(
+        &self,
+        rhs: &ArrayBase<D, Ix1>,
+        weights: &LeastSquaresWeights<E>,
+    ) -> Result<LeastSquaresResult<E, Ix1>>;
+}
+
+impl<E, D1, D2> LeastSquaresWeighted<D2, E> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    fn least_squares_weighted(
+        &self,
+        rhs: &ArrayBase<D2, Ix1>,
+        weights: &LeastSquaresWeights<E>,
+    ) -> Result<LeastSquaresResult<E, Ix1>> {
+        let m = self.nrows();
+        if m != rhs.len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let (a, b) = match weights {
+            LeastSquaresWeights::Diagonal(w) => {
+                if w.len() != m {
+                    return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+                }
+                let c = w.mapv(|w| E::from_real(Float::sqrt(w)));
+                let a = self * &c.view().insert_axis(Axis(1));
+                let b = rhs * &c;
+                (a, b)
+            }
+            LeastSquaresWeights::Matrix(w) => {
+                if w.dim() != (m, m) {
+                    return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+                }
+                let c = w.cholesky(UPLO::Upper)?;
+                (c.dot(self), c.dot(rhs))
+            }
+        };
+        a.least_squares_into(b)
+    }
+}
+
+/// Result of [total_least_squares]
+#[derive(Debug, Clone)]
+pub struct TotalLeastSquaresResult<E: Scalar> {
+    /// The singular values of the augmented matrix `[A b]`
+    pub singular_values: Array1<E::Real>,
+    /// The solution `x` satisfying `(A + ΔA) x = b + Δb`
+    pub solution: Array1<E>,
+    /// The correction `ΔA` to the matrix
+    pub correction_a: Array2<E>,
+    /// The correction `Δb` to the right-hand side
+    pub correction_b: Array1<E>,
+}
+
+/// Solve total least squares problem, i.e. least squares with the errors in both `A` and `b`
+///
+/// Find the smallest corrections `[ΔA Δb]` in the Frobenius norm such that
+/// `(A + ΔA) x = b + Δb` is solvable. Let `v` be the right singular vector of
+/// the augmented matrix `[A b]` for the smallest singular value `s_{n+1}`.
+/// The solution is `x = -v_{1..n} / v_{n+1}`, and the corrections are `[ΔA Δb] = -[A b] v v^H`,
+/// whose Frobenius norm is `s_{n+1}`.
+///
+/// Returns [lax::error::Error::LapackComputationalFailure] if `v_{n+1}` is zero,
+/// where the problem has no solution. If `s_n == s_{n+1}`, the solution is not unique,
+/// and one of them is returned.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Fit y = x through the points (0, 0.1), (1, 0.9), (2, 2.1), (3, 2.9)
+/// let a: Array2<f64> = array![[0.0], [1.0], [2.0], [3.0]];
+/// let b: Array1<f64> = array![0.1, 0.9, 2.1, 2.9];
+/// let result = total_least_squares(&a, &b).unwrap();
+/// let x = &result.solution;
+/// assert_close_l2!(&(&a + &result.correction_a).dot(x), &(&b + &result.correction_b), 1e-12);
+/// ```
+///
+/// If the number of rows of `A` is not equal to the length of `b`,
+/// `IncompatibleShape` error is raised.
+pub fn total_least_squares<E, D1, D2>(
+    a: &ArrayBase<D1, Ix2>,
+    b: &ArrayBase<D2, Ix1>,
+) -> Result<TotalLeastSquaresResult<E>>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    let (m, n) = a.dim();
+    if m != b.len() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let mut c = Array2::<E>::zeros((m, n + 1));
+    c.slice_mut(s![.., ..n]).assign(a);
+    c.column_mut(n).assign(b);
+    let (_, singular_values, vt) = c.svd(false, true)?;
+    let v = vt.unwrap().row(n).mapv(|x| x.conj());
+    if v[n].is_zero() {
+        return Err(LinalgError::Lapack(
+            lax::error::Error::LapackComputationalFailure {
+                return_code: n as i32 + 1,
+            },
+        ));
+    }
+    let solution = v.slice(s![..n]).mapv(|x| -x / v[n]);
+    let cv = c.dot(&v);
+    let correction = Array2::from_shape_fn((m, n + 1), |(i, j)| -cv[i] * v[j].conj());
+    Ok(TotalLeastSquaresResult {
+        singular_values,
+        solution,
+        correction_a: correction.slice(s![.., ..n]).to_owned(),
+        correction_b: correction.column(n).to_owned(),
+    })
+}
+
+/// Solve linear equality-constrained least squares problem `min |b - Ax|` subject to `Cx = d`
+///
+/// For a `m x n` matrix `A` and a `p x n` matrix `C`, the problem must satisfy `p <= n <= m + p`,
+/// otherwise `IncompatibleShape` error is raised.
+/// The solution is unique if `C` has full row rank and `[A; C]` has full column rank,
+/// otherwise [lax::error::Error::LapackComputationalFailure] is returned.
+/// This uses the generalized RQ factorization of `(C, A)` by `*gglse`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Fit x to (1, 2, 3) with the constraint x_1 + x_2 + x_3 = 3
+/// let a: Array2<f64> = Array2::eye(3);
+/// let b: Array1<f64> = array![1.0, 2.0, 3.0];
+/// let c: Array2<f64> = array![[1.0, 1.0, 1.0]];
+/// let d: Array1<f64> = array![3.0];
+/// let x = least_squares_constrained(&a, &b, &c, &d).unwrap();
+/// assert_close_l2!(&x, &array![0.0, 1.0, 2.0], 1e-12);
+/// ```
+pub fn least_squares_constrained<E, D1, D2, D3, D4>(
+    a: &ArrayBase<D1, Ix2>,
+    b: &ArrayBase<D2, Ix1>,
+    c: &ArrayBase<D3, Ix2>,
+    d: &ArrayBase<D4, Ix1>,
+) -> Result<Array1<E>>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+    D3: Data<Elem = E>,
+    D4: Data<Elem = E>,
+{
+    let (m, n) = a.dim();
+    let p = c.nrows();
+    if c.ncols() != n || b.len() != m || d.len() != p || p > n || n > m + p {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    // `*gglse` only accepts column-major matrices
+    let mut a_f = Array2::zeros((m, n).f());
+    a_f.assign(a);
+    let mut c_f = Array2::zeros((p, n).f());
+    c_f.assign(c);
+    let mut b = b.to_vec();
+    let mut d = d.to_vec();
+    let x = E::least_squares_constrained(
+        a_f.layout()?,
+        c_f.layout()?,
+        a_f.as_allocated_mut()?,
+        &mut b,
+        c_f.as_allocated_mut()?,
+        &mut d,
+    )?;
+    Ok(Array1::from(x))
+}
+
+/// Solve general Gauss-Markov linear model `min |y|` subject to `b = Ax + Wy`
+///
+/// For a `m x n` matrix `A` and a `m x p` matrix `W`, the model must satisfy `n <= m <= n + p`,
+/// otherwise `IncompatibleShape` error is raised. Returns `(x, y)`.
+/// The solution is unique if `A` has full column rank and `[A W]` has full row rank,
+/// otherwise [lax::error::Error::LapackComputationalFailure] is returned.
+/// This uses the generalized QR factorization of `(A, W)` by `*ggglm`.
+///
+/// If the noise `e = Wy` has the covariance `W W^H`, `x` is the best linear unbiased estimator,
+/// i.e. the generalized least squares solution minimizing `(b - Ax)^H (W W^H)^{-1} (b - Ax)`,
+/// while `W W^H` may be singular and need not be formed.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Estimate the mean of the observations with the standard deviations 1 and 2
+/// let a: Array2<f64> = array![[1.0], [1.0]];
+/// let w: Array2<f64> = array![[1.0, 0.0], [0.0, 2.0]];
+/// let b: Array1<f64> = array![0.0, 5.0];
+/// let (x, y) = solve_gauss_markov(&a, &w, &b).unwrap();
+/// assert_close_l2!(&x, &array![1.0], 1e-12);
+/// assert_close_l2!(&(a.dot(&x) + w.dot(&y)), &b, 1e-12);
+/// ```
+pub fn solve_gauss_markov<E, D1, D2, D3>(
+    a: &ArrayBase<D1, Ix2>,
+    w: &ArrayBase<D2, Ix2>,
+    b: &ArrayBase<D3, Ix1>,
+) -> Result<(Array1<E>, Array1<E>)>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+    D3: Data<Elem = E>,
+{
+    let (m, n) = a.dim();
+    let p = w.ncols();
+    if w.nrows() != m || b.len() != m || n > m || m > n + p {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    // `*ggglm` only accepts column-major matrices
+    let mut a_f = Array2::zeros((m, n).f());
+    a_f.assign(a);
+    let mut w_f = Array2::zeros((m, p).f());
+    w_f.assign(w);
+    let mut b = b.to_vec();
+    let GaussMarkovOwned { x, y } = E::gauss_markov(
+        a_f.layout()?,
+        w_f.layout()?,
+        a_f.as_allocated_mut()?,
+        w_f.as_allocated_mut()?,
+        &mut b,
+    )?;
+    Ok((Array1::from(x), Array1::from(y)))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{error::LinalgError, *};
@@ -15,6 +15,7 @@
 //!    - [Triangular matrices](triangular/index.html)
 //!    - [Hermitian/real symmetric matrices](solveh/index.html)
 //!    - [Tridiagonal matrices](tridiagonal/index.html)
+//!    - [Iterative solvers for large matrices](iterative/index.html)
 //! - [Inverse matrix computation](solve/trait.Inverse.html)
 //!
 //! Naming Convention
@@ -50,50 +51,115 @@
 extern crate ndarray;
 
 pub mod assert;
+pub mod band;
+pub mod bidiagonal;
 pub mod cholesky;
+#[cfg(feature = "rustfft")]
+pub mod circulant;
+pub mod cod;
 pub mod convert;
 pub mod diagonal;
 pub mod eig;
 pub mod eigh;
+pub mod equilibrate;
 pub mod error;
+pub mod expm;
+pub mod funm;
 pub mod generate;
+pub mod givens;
+pub mod gsvd;
+pub mod hessenberg;
 pub mod inner;
+pub mod irls;
+pub mod iterative;
 pub mod krylov;
 pub mod layout;
 pub mod least_squares;
 pub mod lobpcg;
+pub mod logm;
+pub mod lyapunov;
+pub mod nnls;
 pub mod norm;
+pub mod onenormest;
 pub mod operator;
 pub mod opnorm;
+pub mod packed;
+pub mod pinv;
+pub mod polar;
 pub mod qr;
+pub mod recursive_least_squares;
+pub mod riccati;
+pub mod schur;
+pub mod shift_invert;
+pub mod signm;
+pub mod small;
 pub mod solve;
+pub mod solve_expert;
 pub mod solveh;
+pub mod sqrtm;
+pub mod subspace;
 pub mod svd;
+pub mod svd_partial;
+pub mod svd_randomized;
 pub mod svddc;
+pub mod sylvester;
+pub mod toeplitz;
 pub mod trace;
 pub mod triangular;
 pub mod tridiagonal;
 pub mod types;
 
 pub use crate::assert::*;
+pub use crate::band::*;
+pub use crate::bidiagonal::*;
 pub use crate::cholesky::*;
+#[cfg(feature = "rustfft")]
+pub use crate::circulant::*;
+pub use crate::cod::*;
 pub use crate::convert::*;
 pub use crate::diagonal::*;
 pub use crate::eig::*;
 pub use crate::eigh::*;
+pub use crate::equilibrate::*;
+pub use crate::expm::*;
+pub use crate::funm::*;
 pub use crate::generate::*;
+pub use crate::givens::*;
+pub use crate::gsvd::*;
+pub use crate::hessenberg::*;
 pub use crate::inner::*;
+pub use crate::irls::*;
 pub use crate::layout::*;
 pub use crate::least_squares::*;
 pub use crate::lobpcg::{TruncatedEig, TruncatedOrder, TruncatedSvd};
+pub use crate::logm::*;
+pub use crate::lyapunov::*;
+pub use crate::nnls::*;
 pub use crate::norm::*;
+pub use crate::onenormest::*;
 pub use crate::operator::*;
 pub use crate::opnorm::*;
+pub use crate::packed::*;
+pub use crate::pinv::*;
+pub use crate::polar::*;
 pub use crate::qr::*;
+pub use crate::recursive_least_squares::*;
+pub use crate::riccati::*;
+pub use crate::schur::*;
+pub use crate::shift_invert::*;
+pub use crate::signm::*;
+pub use crate::small::*;
 pub use crate::solve::*;
+pub use crate::solve_expert::*;
 pub use crate::solveh::*;
+pub use crate::sqrtm::*;
+pub use crate::subspace::*;
 pub use crate::svd::*;
+pub use crate::svd_partial::*;
+pub use crate::svd_randomized::*;
 pub use crate::svddc::*;
+pub use crate::sylvester::*;
+pub use crate::toeplitz::*;
 pub use crate::trace::*;
 pub use crate::triangular::*;
 pub use crate::tridiagonal::*;
@@ -0,0 +1,103 @@
+//! Matrix logarithm
+//!
+//! The principal logarithm `log(A)` is computed by the inverse scaling and squaring method on the Schur form:
+//! `A = Z T Z^H` is reduced into the complex upper triangular `T`,
+//! whose square roots are taken `k` times until `T^(1/2^k)` is close to the identity,
+//! and then `log(A) = 2^k Z log(T^(1/2^k)) Z^H` where the last logarithm is evaluated by
+//! Gauss-Legendre quadrature of `log(I + X) = int_0^1 X (I + t X)^{-1} dt`,
+//! which is equivalent to the diagonal Padé approximant.
+//!
+//! - N. J. Higham, "Evaluating Padé approximants of the matrix logarithm",
+//!   SIAM J. Matrix Anal. Appl. 22 (4), 2001.
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::opnorm::*;
+use crate::schur::*;
+use crate::sqrtm::sqrtm_triangular;
+use crate::triangular::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// Maximum 1-norm of `X` for which the 8-point quadrature of `log(I + X)` is accurate in double precision
+const THETA_8: f64 = 0.367;
+
+/// Upper limit of the number of square roots, which is only reached for non-finite matrices
+const MAX_SQRT: usize = 64;
+
+/// Nodes on `[0, 1]` and weights of the 8-point Gauss-Legendre quadrature
+const GAUSS_LEGENDRE_8: [(f64, f64); 8] = [
+    (0.019_855_071_751_231_8, 0.050_614_268_145_188_1),
+    (0.101_666_761_293_186_6, 0.111_190_517_226_687_2),
+    (0.237_233_795_041_835_5, 0.156_853_322_938_943_7),
+    (0.408_282_678_752_175_1, 0.181_341_891_689_181),
+    (0.591_717_321_247_825, 0.181_341_891_689_181),
+    (0.762_766_204_958_164_5, 0.156_853_322_938_943_7),
+    (0.898_333_238_706_813_4, 0.111_190_517_226_687_2),
+    (0.980_144_928_248_768_2, 0.050_614_268_145_188_1),
+];
+
+/// Principal logarithm of a square matrix
+///
+/// The result is complex even for real matrices, since the logarithm of a real matrix
+/// with negative eigenvalues is not real.
+/// [LinalgError::Lapack] is returned for singular matrices, whose logarithm does not exist.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[2.0, 1.0], [0.0, 2.0]];
+/// let l = a.logm().unwrap();
+/// assert_close_l2!(&l.expm().unwrap(), &a.mapv(|x| x.as_c()), 1e-12);
+/// ```
+pub trait Logm {
+    type Output;
+    fn logm(&self) -> Result<Self::Output>;
+}
+
+impl<A, S> Logm for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    A::Complex: Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A::Complex>;
+
+    fn logm(&self) -> Result<Self::Output> {
+        let (t, z) = complex_schur(self)?;
+        let l = logm_triangular(&t)?;
+        Ok(z.dot(&l).dot(&conjugate::<_, _, OwnedRepr<_>>(&z)))
+    }
+}
+
+/// Principal logarithm of an upper triangular matrix
+fn logm_triangular<T: Scalar + Lapack>(t: &Array2<T>) -> Result<Array2<T>> {
+    let n = t.nrows();
+    if let Some(i) = (0..n).find(|&i| t[(i, i)].is_zero()) {
+        return Err(LinalgError::Lapack(
+            lax::error::Error::LapackComputationalFailure {
+                return_code: i as i32 + 1,
+            },
+        ));
+    }
+    let eye = Array2::<T>::eye(n);
+    let mut t = t.to_owned();
+    let mut k = 0;
+    while k < MAX_SQRT && (&t - &eye).opnorm_one()? > T::real(THETA_8) {
+        t = sqrtm_triangular(&t)?;
+        k += 1;
+    }
+    let x = t - &eye;
+    let mut l = Array2::zeros((n, n));
+    for (node, weight) in GAUSS_LEGENDRE_8 {
+        let mut d = eye.clone();
+        d.scaled_add(T::from_real(T::real(node)), &x);
+        let y = d.solve_triangular(UPLO::Upper, Diag::NonUnit, &x)?;
+        l.scaled_add(T::from_real(T::real(weight)), &y);
+    }
+    let scale = T::from_real(T::real(2.0_f64.powi(k as i32)));
+    Ok(l.mapv(|x| x * scale))
+}
@@ -0,0 +1,213 @@
+//! Lyapunov equations
+//!
+//! The continuous-time Lyapunov equation `AX + XA^H + Q = 0` is the Sylvester equation with `B = A^H`,
+//! and solved by the Bartels-Stewart algorithm using a single Schur decomposition `A = U T U^H`:
+//! `T Y + Y T^H = -U^H Q U` is solved by `*trsyl`, and `X = U Y U^H`.
+//!
+//! The discrete-time Lyapunov (Stein) equation `AXA^H - X + Q = 0` is reduced into the continuous-time one
+//! by the bilinear transformation `A_c = (A + I)^{-1} (A - I)` and `Q_c = 2 (A + I)^{-1} Q (A + I)^{-H}`.
+//!
+//! The controllability and observability Gramians of a stable linear system `x' = Ax + Bu, y = Cx`
+//! are the solutions of `A W_c + W_c A^H + B B^H = 0` and `A^H W_o + W_o A + C^H C = 0`.
+//!
+//! - [Wikipedia article on Lyapunov equation](https://en.wikipedia.org/wiki/Lyapunov_equation)
+
+use ndarray::*;
+use num_traits::Zero;
+
+use crate::cholesky::*;
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::layout::*;
+use crate::schur::*;
+use crate::solve::*;
+use crate::sylvester::solve_sylvester_schur;
+use crate::types::*;
+
+/// Solve the continuous-time Lyapunov equation `AX + XA^H + Q = 0`
+///
+/// The solution is unique if no pair of eigenvalues of `A` satisfies `λ_i + conj(λ_j) = 0`,
+/// and [LinalgError::Lapack] is returned otherwise.
+/// If `Q` is Hermitian, `X` is also Hermitian and returned exactly Hermitian,
+/// e.g. it can be passed to [Cholesky] as is when `A` is stable and `Q` is positive definite.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = random((4, 4));
+/// let q: Array2<f64> = random_hpd(4);
+/// let x = solve_lyapunov(&a, &q).unwrap();
+/// assert_close_l2!(&(a.dot(&x) + x.dot(&a.t())), &(-&q), 1e-9);
+/// ```
+pub fn solve_lyapunov<A, Sa, Sq>(
+    a: &ArrayBase<Sa, Ix2>,
+    q: &ArrayBase<Sq, Ix2>,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sq: Data<Elem = A>,
+{
+    a.square_layout()?;
+    if q.dim() != a.dim() {
+        return Err(LinalgError::Shape(ShapeError::from_kind(
+            ErrorKind::IncompatibleShape,
+        )));
+    }
+    let (t, u, _) = a.schur()?;
+    let uh: Array2<A> = conjugate(&u);
+    let f = uh.dot(q).dot(&u).mapv_into(|x| -x);
+    let y = solve_sylvester_schur(&t, Transpose::No, &t, Transpose::Hermite, 1, &f)?;
+    let x = u.dot(&y).dot(&uh);
+    Ok(if is_hermitian(q, A::Real::zero()) {
+        hermitian_part(&x)
+    } else {
+        x
+    })
+}
+
+/// Solve the discrete-time Lyapunov equation `AXA^H - X + Q = 0`
+///
+/// The solution is unique if no pair of eigenvalues of `A` satisfies `λ_i conj(λ_j) = 1`,
+/// and [LinalgError::Lapack] is returned otherwise.
+/// If `Q` is Hermitian, `X` is returned exactly Hermitian as [solve_lyapunov].
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = random((4, 4));
+/// let q: Array2<f64> = random_hpd(4);
+/// let x = solve_discrete_lyapunov(&a, &q).unwrap();
+/// assert_close_l2!(&(a.dot(&x).dot(&a.t()) - &x), &(-&q), 1e-9);
+/// ```
+pub fn solve_discrete_lyapunov<A, Sa, Sq>(
+    a: &ArrayBase<Sa, Ix2>,
+    q: &ArrayBase<Sq, Ix2>,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sq: Data<Elem = A>,
+{
+    a.square_layout()?;
+    if q.dim() != a.dim() {
+        return Err(LinalgError::Shape(ShapeError::from_kind(
+            ErrorKind::IncompatibleShape,
+        )));
+    }
+    let eye = Array2::<A>::eye(a.nrows());
+    // `A + I` is singular only if `A` has the eigenvalue `-1`, where the solution is not unique
+    let p = (a + &eye).inv()?;
+    let ac = p.dot(&(a - &eye));
+    let two = A::from_real(A::real(2.0));
+    let ph: Array2<A> = conjugate(&p);
+    let qc = p.dot(q).dot(&ph).mapv_into(|x| x * two);
+    let x = solve_lyapunov(&ac, &qc)?;
+    Ok(if is_hermitian(q, A::Real::zero()) {
+        hermitian_part(&x)
+    } else {
+        x
+    })
+}
+
+/// Controllability Gramian `W_c` of `(A, B)`, i.e. the solution of `A W_c + W_c A^H + B B^H = 0`
+///
+/// `A` must be stable, i.e. all eigenvalues have negative real parts.
+/// If `calc_factor` is true, the lower triangular Cholesky factor `L` of `W_c = L L^H` is also returned,
+/// which is used e.g. in the balanced truncation by the SVD of `L_o^H L_c`.
+/// [LinalgError::Lapack] is returned by the Cholesky decomposition
+/// if `W_c` is not positive definite, i.e. `(A, B)` is not controllable.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[-1.0, 1.0], [0.0, -2.0]];
+/// let b: Array2<f64> = array![[0.0], [1.0]];
+/// let (w, l) = gramian_controllability(&a, &b, true).unwrap();
+/// assert_close_l2!(&(a.dot(&w) + w.dot(&a.t()) + b.dot(&b.t())), &Array2::zeros((2, 2)), 1e-12);
+/// let l = l.unwrap();
+/// assert_close_l2!(&l.dot(&l.t()), &w, 1e-12);
+/// ```
+pub fn gramian_controllability<A, Sa, Sb>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    calc_factor: bool,
+) -> Result<(Array2<A>, Option<Array2<A>>)>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    if b.nrows() != a.nrows() {
+        return Err(LinalgError::Shape(ShapeError::from_kind(
+            ErrorKind::IncompatibleShape,
+        )));
+    }
+    let bh: Array2<A> = conjugate(b);
+    gramian(a, &b.dot(&bh), calc_factor)
+}
+
+/// Observability Gramian `W_o` of `(A, C)`, i.e. the solution of `A^H W_o + W_o A + C^H C = 0`
+///
+/// This is the controllability Gramian of `(A^H, C^H)`, see [gramian_controllability].
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[-1.0, 1.0], [0.0, -2.0]];
+/// let c: Array2<f64> = array![[1.0, 0.0]];
+/// let (w, _) = gramian_observability(&a, &c, false).unwrap();
+/// assert_close_l2!(&(a.t().dot(&w) + w.dot(&a) + c.t().dot(&c)), &Array2::zeros((2, 2)), 1e-12);
+/// ```
+pub fn gramian_observability<A, Sa, Sc>(
+    a: &ArrayBase<Sa, Ix2>,
+    c: &ArrayBase<Sc, Ix2>,
+    calc_factor: bool,
+) -> Result<(Array2<A>, Option<Array2<A>>)>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sc: Data<Elem = A>,
+{
+    if c.ncols() != a.ncols() {
+        return Err(LinalgError::Shape(ShapeError::from_kind(
+            ErrorKind::IncompatibleShape,
+        )));
+    }
+    let ah: Array2<A> = conjugate(a);
+    let ch: Array2<A> = conjugate(c);
+    gramian(&ah, &ch.dot(c), calc_factor)
+}
+
+/// Solve `A W + W A^H + Q = 0` with the optional Cholesky factor of `W`
+fn gramian<A, S>(
+    a: &ArrayBase<S, Ix2>,
+    q: &Array2<A>,
+    calc_factor: bool,
+) -> Result<(Array2<A>, Option<Array2<A>>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let w = solve_lyapunov(a, q)?;
+    let l = if calc_factor {
+        Some(w.cholesky(UPLO::Lower)?)
+    } else {
+        None
+    };
+    Ok((w, l))
+}
+
+/// `(X + X^H) / 2`
+fn hermitian_part<A: Scalar>(x: &Array2<A>) -> Array2<A> {
+    let half = A::from_real(A::real(0.5));
+    let mut h = x.to_owned();
+    Zip::from(&mut h)
+        .and(&x.t())
+        .for_each(|h, &xt| *h = (*h + xt.conj()) * half);
+    h
+}
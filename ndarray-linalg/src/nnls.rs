@@ -0,0 +1,324 @@
+//! Non-negative and bounded-variable least squares
+//!
+//! Minimize `|b - Ax|_2` subject to `x >= 0` for a real `m x n` matrix `A`
+//! by the active-set algorithm of Lawson and Hanson.
+//! The variables are split into the passive set, which are free and positive,
+//! and the active set, which are fixed to zero.
+//! Starting from `x = 0`, the variable with the largest gradient `w = A^T (b - Ax)`
+//! is moved to the passive set, and the least squares problem for the passive variables is solved,
+//! where the variables becoming non-positive are moved back to the active set.
+//! The iteration stops when all the gradients of the active variables are not positive,
+//! i.e. the Karush-Kuhn-Tucker conditions hold.
+//!
+//! [bvls] generalizes this to the box constraints `lower <= x <= upper`,
+//! where the non-free variables are fixed at either bound.
+//!
+//! - C. L. Lawson and R. J. Hanson, "Solving Least Squares Problems", SIAM (1995), Chapter 23.
+//! - [P. B. Stark and R. L. Parker, Bounded-Variable Least-Squares: an Algorithm and Applications (1995)](https://www.stat.berkeley.edu/~stark/Preprints/bvls.pdf)
+
+use ndarray::*;
+use num_traits::Float;
+
+use crate::error::*;
+use crate::least_squares::*;
+use crate::types::*;
+
+/// Result of [nnls] and [bvls]
+#[derive(Debug, Clone)]
+pub struct NnlsResult<A> {
+    /// Feasible solution `x`
+    pub solution: Array1<A>,
+    /// The residual sum of squares `|b - Ax|^2`
+    pub residual_sum_of_squares: A,
+    /// The number of the least squares problems solved for the free variables
+    pub iterations: usize,
+    /// Whether the optimality conditions hold,
+    /// i.e. the iteration has stopped before reaching the limit
+    pub converged: bool,
+}
+
+/// Non-negative least squares with the default tolerance and iteration limit
+///
+/// The tolerance for the gradient is `10 * max(m, n) * eps * |A^T b|_inf`,
+/// and the iteration limit is `3 n` as SciPy. See [nnls_with] for detail.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[1.0, 0.0], [0.0, 1.0]];
+/// let b: Array1<f64> = array![2.0, -1.0];
+/// let result = nnls(&a, &b).unwrap();
+/// assert!(result.converged);
+/// assert_close_l2!(&result.solution, &array![2.0, 0.0], 1e-12);
+/// assert_rclose!(result.residual_sum_of_squares, 1.0, 1e-12);
+/// ```
+pub fn nnls<A, S1, S2>(a: &ArrayBase<S1, Ix2>, b: &ArrayBase<S2, Ix1>) -> Result<NnlsResult<A>>
+where
+    A: Scalar<Real = A> + Float + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    let n = a.ncols();
+    let lower = Array1::zeros(n);
+    let upper = Array1::from_elem(n, A::infinity());
+    active_set(a, b, lower.view(), upper.view(), None, 3 * n)
+}
+
+/// Non-negative least squares with the tolerance `tol` for the gradient
+/// and the limit `max_iter` of the number of the least squares problems to be solved
+///
+/// A variable is moved to the passive set only if its gradient is larger than `tol`.
+/// If the limit is reached, the feasible solution at that time is returned
+/// with `converged == false`.
+pub fn nnls_with<A, S1, S2>(
+    a: &ArrayBase<S1, Ix2>,
+    b: &ArrayBase<S2, Ix1>,
+    tol: A,
+    max_iter: usize,
+) -> Result<NnlsResult<A>>
+where
+    A: Scalar<Real = A> + Float + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    let n = a.ncols();
+    let lower = Array1::zeros(n);
+    let upper = Array1::from_elem(n, A::infinity());
+    active_set(a, b, lower.view(), upper.view(), Some(tol), max_iter)
+}
+
+/// Bounded-variable least squares with the default tolerance and iteration limit
+///
+/// Minimize `|b - Ax|_2` subject to `lower <= x <= upper`,
+/// where the infinite bounds can be used for the unbounded variables.
+/// This is the algorithm of Stark and Parker, which extends [nnls] to the variables fixed
+/// at either the lower or the upper bound. The iteration starts from the lower bounds,
+/// or the upper bounds for the variables without the finite lower bound,
+/// and the gradient tolerance is `10 * max(m, n) * eps` times the largest initial gradient.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = Array2::eye(3);
+/// let b: Array1<f64> = array![-2.0, 0.5, 3.0];
+/// let lower = array![-1.0, -1.0, f64::NEG_INFINITY];
+/// let upper = array![1.0, 1.0, 2.0];
+/// let result = bvls(&a, &b, &lower, &upper).unwrap();
+/// assert_close_l2!(&result.solution, &array![-1.0, 0.5, 2.0], 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `lower[j] > upper[j]` for some `j`.
+pub fn bvls<A, S1, S2, S3, S4>(
+    a: &ArrayBase<S1, Ix2>,
+    b: &ArrayBase<S2, Ix1>,
+    lower: &ArrayBase<S3, Ix1>,
+    upper: &ArrayBase<S4, Ix1>,
+) -> Result<NnlsResult<A>>
+where
+    A: Scalar<Real = A> + Float + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    S3: Data<Elem = A>,
+    S4: Data<Elem = A>,
+{
+    active_set(a, b, lower.view(), upper.view(), None, 3 * a.ncols())
+}
+
+/// Bounded-variable least squares with the tolerance `tol` for the gradient
+/// and the limit `max_iter` of the number of the least squares problems to be solved
+///
+/// See [bvls] and [nnls_with] for detail.
+///
+/// # Panics
+///
+/// Panics if `lower[j] > upper[j]` for some `j`.
+pub fn bvls_with<A, S1, S2, S3, S4>(
+    a: &ArrayBase<S1, Ix2>,
+    b: &ArrayBase<S2, Ix1>,
+    lower: &ArrayBase<S3, Ix1>,
+    upper: &ArrayBase<S4, Ix1>,
+    tol: A,
+    max_iter: usize,
+) -> Result<NnlsResult<A>>
+where
+    A: Scalar<Real = A> + Float + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    S3: Data<Elem = A>,
+    S4: Data<Elem = A>,
+{
+    active_set(a, b, lower.view(), upper.view(), Some(tol), max_iter)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Free,
+    Lower,
+    Upper,
+}
+
+/// Active-set iteration shared by [nnls] and [bvls]
+///
+/// The tolerance defaults to `10 * max(m, n) * eps` times the largest initial gradient.
+fn active_set<A, S1, S2>(
+    a: &ArrayBase<S1, Ix2>,
+    b: &ArrayBase<S2, Ix1>,
+    lower: ArrayView1<A>,
+    upper: ArrayView1<A>,
+    tol: Option<A>,
+    max_iter: usize,
+) -> Result<NnlsResult<A>>
+where
+    A: Scalar<Real = A> + Float + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    let (m, n) = a.dim();
+    if m != b.len() || lower.len() != n || upper.len() != n {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    assert!(
+        lower.iter().zip(upper.iter()).all(|(l, u)| l <= u),
+        "Lower bound must not be larger than upper bound"
+    );
+
+    let mut state: Vec<State> = lower
+        .iter()
+        .zip(upper.iter())
+        .map(|(l, u)| {
+            if l.is_finite() {
+                State::Lower
+            } else if u.is_finite() {
+                State::Upper
+            } else {
+                State::Free
+            }
+        })
+        .collect();
+    let mut x = Array1::from_shape_fn(n, |j| match state[j] {
+        State::Lower => lower[j],
+        State::Upper => upper[j],
+        State::Free => A::zero(),
+    });
+    let tol = match tol {
+        Some(tol) => tol,
+        None => {
+            let w = a.t().dot(&(b - &a.dot(&x)));
+            let w_max = w.iter().fold(A::zero(), |acc, v| acc.max(Float::abs(*v)));
+            A::real(10 * m.max(n)) * A::epsilon() * w_max
+        }
+    };
+
+    let mut iterations = 0;
+    let mut converged = false;
+    let mut need_solve = state.contains(&State::Free);
+    'outer: loop {
+        while need_solve {
+            if iterations >= max_iter {
+                break 'outer;
+            }
+            iterations += 1;
+            let free: Vec<usize> = (0..n).filter(|&j| state[j] == State::Free).collect();
+            let z = solve_free(a, b, &x, &free)?;
+            if free.iter().all(|&j| lower[j] < z[j] && z[j] < upper[j]) {
+                x = z;
+                need_solve = false;
+                continue;
+            }
+            // Move from x toward z as far as x stays feasible,
+            // and the variable reaching its bound first leaves the free set
+            let (j_min, alpha) = free
+                .iter()
+                .filter_map(|&j| {
+                    if x[j] == z[j] {
+                        // Already at the bound
+                        Some((j, A::zero()))
+                    } else if z[j] <= lower[j] {
+                        Some((j, (x[j] - lower[j]) / (x[j] - z[j])))
+                    } else if z[j] >= upper[j] {
+                        Some((j, (upper[j] - x[j]) / (z[j] - x[j])))
+                    } else {
+                        None
+                    }
+                })
+                .fold(
+                    (n, A::infinity()),
+                    |acc, v| if v.1 < acc.1 { v } else { acc },
+                );
+            x.zip_mut_with(&z, |x, &z| *x += alpha * (z - *x));
+            for &j in &free {
+                if x[j] <= lower[j] || (j == j_min && z[j] <= lower[j]) {
+                    x[j] = lower[j];
+                    state[j] = State::Lower;
+                } else if x[j] >= upper[j] || j == j_min {
+                    x[j] = upper[j];
+                    state[j] = State::Upper;
+                }
+            }
+        }
+
+        // Free the variable violating the optimality condition most,
+        // i.e. whose objective decreases most by leaving its bound
+        let w = a.t().dot(&(b - &a.dot(&x)));
+        let t = (0..n)
+            .filter_map(|j| match state[j] {
+                State::Lower => Some((j, w[j])),
+                State::Upper => Some((j, -w[j])),
+                State::Free => None,
+            })
+            .fold(None, |acc: Option<(usize, A)>, v| match acc {
+                Some(acc) if acc.1 >= v.1 => Some(acc),
+                _ => Some(v),
+            });
+        match t {
+            Some((t, wt)) if wt > tol => {
+                state[t] = State::Free;
+                need_solve = true;
+            }
+            _ => {
+                converged = true;
+                break;
+            }
+        }
+    }
+    let residual_sum_of_squares = (b - &a.dot(&x)).iter().map(|r| r.square()).sum();
+    Ok(NnlsResult {
+        solution: x,
+        residual_sum_of_squares,
+        iterations,
+        converged,
+    })
+}
+
+/// Least squares solution `min |b - A_B x_B - A_F z_F|` for the free variables `F`,
+/// where the other variables `B` are fixed to `x_B`
+fn solve_free<A, S1, S2>(
+    a: &ArrayBase<S1, Ix2>,
+    b: &ArrayBase<S2, Ix1>,
+    x: &Array1<A>,
+    free: &[usize],
+) -> Result<Array1<A>>
+where
+    A: Scalar + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    let mut z = x.clone();
+    if free.is_empty() {
+        return Ok(z);
+    }
+    for &j in free {
+        z[j] = A::zero();
+    }
+    let rhs = b - &a.dot(&z);
+    let a_free = a.select(Axis(1), free);
+    let z_free = a_free.least_squares_into(rhs)?.solution;
+    for (&j, &v) in free.iter().zip(z_free.iter()) {
+        z[j] = v;
+    }
+    Ok(z)
+}
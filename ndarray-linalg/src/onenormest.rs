@@ -0,0 +1,240 @@
+//! Estimation of the 1-norm of linear operators
+//!
+//! The 1-norm `|A|_1 = max_j sum_i |A_ij|` of a `n x n` operator `A` is estimated
+//! by the block algorithm of Higham and Tisseur, a generalization of Hager's method used in `*gecon`.
+//! `A` is accessed only through the products `A X` and `A^H X` with `n x t` blocks `X`,
+//! and usually a few iterations are enough, so that e.g. `|A^{-1}|_1` or `|A^k|_1`
+//! is estimated in `O(n^2)` operations without forming the matrix.
+//! The estimate is always a lower bound of the exact 1-norm, and larger `t` gives a better estimate.
+//!
+//! - [N. J. Higham and F. Tisseur, A Block Algorithm for Matrix 1-Norm Estimation,
+//!   with an Application to 1-Norm Pseudospectra (2000)](https://doi.org/10.1137/S0895479899356080)
+
+use ndarray::*;
+use num_traits::{Float, One, Zero};
+use rand::{thread_rng, Rng};
+
+use crate::error::*;
+use crate::solve::*;
+use crate::types::*;
+
+/// Maximum number of iterations of [onenormest_using]
+const ITER_MAX: usize = 5;
+
+/// Estimate the 1-norm of a `n x n` operator `A` with `t` columns per block
+///
+/// `apply(X)` and `apply_h(X)` should return `A X` and `A^H X` for `n x t` blocks `X`.
+///
+/// - This function uses [rand::thread_rng] for the starting block.
+///   See [onenormest_using] for using another RNG.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Estimate |A^3|_1 without forming A^3
+/// let a: Array2<f64> = random((10, 10));
+/// let ah = a.t().to_owned();
+/// let est = onenormest(
+///     10,
+///     2,
+///     |x: &Array2<f64>| Ok(a.dot(&a.dot(&a.dot(x)))),
+///     |x: &Array2<f64>| Ok(ah.dot(&ah.dot(&ah.dot(x)))),
+/// )
+/// .unwrap();
+/// let exact = a.dot(&a).dot(&a).opnorm_one().unwrap();
+/// assert!(est <= exact * (1.0 + 1e-12));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `t` is zero.
+pub fn onenormest<A, F, G>(n: usize, t: usize, apply: F, apply_h: G) -> Result<A::Real>
+where
+    A: Scalar,
+    F: FnMut(&Array2<A>) -> Result<Array2<A>>,
+    G: FnMut(&Array2<A>) -> Result<Array2<A>>,
+{
+    let mut rng = thread_rng();
+    onenormest_using(n, t, apply, apply_h, &mut rng)
+}
+
+/// Estimate the 1-norm of a `n x n` operator `A` with `t` columns per block with given RNG
+///
+/// See [onenormest] for detail. `t = 2` is a good default,
+/// and `t` larger than `n` is reduced to `n`.
+///
+/// # Panics
+///
+/// Panics if `t` is zero.
+pub fn onenormest_using<A, F, G, R>(
+    n: usize,
+    t: usize,
+    mut apply: F,
+    mut apply_h: G,
+    rng: &mut R,
+) -> Result<A::Real>
+where
+    A: Scalar,
+    F: FnMut(&Array2<A>) -> Result<Array2<A>>,
+    G: FnMut(&Array2<A>) -> Result<Array2<A>>,
+    R: Rng,
+{
+    assert!(t > 0, "Block size must be positive");
+    if n == 0 {
+        return Ok(A::Real::zero());
+    }
+    let t = t.min(n);
+
+    // Starting block: the first column is (1, ..., 1)^T and the others are random vectors of +-1,
+    // all normalized to have the unit 1-norm
+    let mut x = Array2::<A>::ones((n, t));
+    resample_parallel(&mut x, None, rng);
+    let scale = A::Real::one() / A::real(n);
+    x.mapv_inplace(|v| v.mul_real(scale));
+
+    let mut est_old = A::Real::zero();
+    let mut ind_best = 0;
+    let mut ind_hist: Vec<usize> = Vec::new();
+    let mut ind: Vec<usize> = (0..n).collect();
+    let mut s_old: Option<Array2<A>> = None;
+    for k in 1.. {
+        let y = apply(&x)?;
+        let mut j_best = 0;
+        let mut est = A::Real::zero();
+        for (j, col) in y.columns().into_iter().enumerate() {
+            let norm = col.iter().fold(A::Real::zero(), |acc, v| acc + v.abs());
+            if norm > est {
+                j_best = j;
+                est = norm;
+            }
+        }
+        if k == 2 || (k > 2 && est > est_old) {
+            ind_best = ind[j_best];
+        }
+        if k >= 2 && est <= est_old {
+            break;
+        }
+        est_old = est;
+        if k > ITER_MAX {
+            break;
+        }
+
+        let mut s = y.mapv(sign);
+        if let Some(s_old) = &s_old {
+            // No new information if all the columns are the same as the previous ones
+            if s.columns()
+                .into_iter()
+                .all(|sj| s_old.columns().into_iter().any(|si| parallel(&sj, &si)))
+            {
+                break;
+            }
+        }
+        if t > 1 {
+            resample_parallel(&mut s, s_old.as_ref(), rng);
+        }
+
+        let z = apply_h(&s)?;
+        let h: Vec<A::Real> = z
+            .rows()
+            .into_iter()
+            .map(|row| row.iter().fold(A::Real::zero(), |acc, v| acc.max(v.abs())))
+            .collect();
+        let h_max = h.iter().fold(A::Real::zero(), |acc, &v| acc.max(v));
+        if k >= 2 && h_max == h[ind_best] {
+            break;
+        }
+        ind.sort_by(|&i, &j| h[j].partial_cmp(&h[i]).unwrap());
+        if t > 1 {
+            if ind[..t].iter().all(|i| ind_hist.contains(i)) {
+                break;
+            }
+            // Use the unit vectors not visited yet first
+            let (fresh, visited): (Vec<usize>, Vec<usize>) =
+                ind.iter().partition(|i| !ind_hist.contains(i));
+            ind = fresh.into_iter().chain(visited).collect();
+        }
+        x.fill(A::zero());
+        for (j, &i) in ind[..t].iter().enumerate() {
+            x[(i, j)] = A::one();
+        }
+        ind_hist.extend_from_slice(&ind[..t]);
+        s_old = Some(s);
+    }
+    Ok(est_old)
+}
+
+impl<A, S> LUFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+{
+    /// Estimate the 1-norm of the inverse matrix `|A^{-1}|_1` by [onenormest]
+    ///
+    /// The condition number in 1-norm is estimated by `|A|_1 |A^{-1}|_1`,
+    /// which is more reliable than [crate::ReciprocalConditionNum] for `t > 1`.
+    pub fn inv_onenormest(&self, t: usize) -> Result<A::Real> {
+        let n = self.a.nrows();
+        let solve_columns = |x: &Array2<A>, hermite: bool| -> Result<Array2<A>> {
+            let mut y = x.to_owned();
+            for mut col in y.columns_mut() {
+                let mut c = col.to_owned();
+                if hermite {
+                    self.solve_h_inplace(&mut c)?;
+                } else {
+                    self.solve_inplace(&mut c)?;
+                }
+                col.assign(&c);
+            }
+            Ok(y)
+        };
+        onenormest(
+            n,
+            t,
+            |x: &Array2<A>| solve_columns(x, false),
+            |x: &Array2<A>| solve_columns(x, true),
+        )
+    }
+}
+
+/// `x / |x|`, or 1 if `x` is zero
+fn sign<A: Scalar>(x: A) -> A {
+    let r = x.abs();
+    if r.is_zero() {
+        A::one()
+    } else {
+        x.mul_real(A::Real::one() / r)
+    }
+}
+
+/// Whether the vectors of unit-modulus elements are parallel, i.e. `|a^H b| = n`
+fn parallel<A: Scalar>(a: &ArrayView1<A>, b: &ArrayView1<A>) -> bool {
+    let dot: A = a.iter().zip(b).map(|(a, b)| a.conj() * *b).sum();
+    dot.abs() >= A::real(a.len())
+}
+
+/// Replace the columns of `s` parallel to an earlier column or a column of `s_old`
+/// by random vectors of +-1
+///
+/// The number of retries is limited since there are only `2^(n-1)` such vectors.
+fn resample_parallel<A, R>(s: &mut Array2<A>, s_old: Option<&Array2<A>>, rng: &mut R)
+where
+    A: Scalar,
+    R: Rng,
+{
+    const RETRY_MAX: usize = 8;
+    for j in 1..s.ncols() {
+        for _ in 0..RETRY_MAX {
+            let sj = s.column(j);
+            let duplicated = (0..j).any(|i| parallel(&sj, &s.column(i)))
+                || s_old
+                    .is_some_and(|s_old| s_old.columns().into_iter().any(|si| parallel(&sj, &si)));
+            if !duplicated {
+                break;
+            }
+            for v in s.column_mut(j) {
+                *v = if rng.gen() { A::one() } else { -A::one() };
+            }
+        }
+    }
+}
@@ -1,14 +1,26 @@
 //! Linear operator algebra
 
+use crate::band::*;
+use crate::cholesky::*;
 use crate::generate::hstack;
+use crate::solve::*;
+use crate::solveh::*;
 use crate::types::*;
 use ndarray::*;
+use std::marker::PhantomData;
 
 /// Abstracted linear operator as an action to vector (`ArrayBase<S, Ix1>`) and matrix
 /// (`ArrayBase<S, Ix2`)
 pub trait LinearOperator {
     type Elem: Scalar;
 
+    /// Shape `(m, n)` as a `m x n` matrix
+    ///
+    /// `None` if the operator does not know its shape, e.g. when it acts on vectors of any length.
+    fn shape(&self) -> Option<(usize, usize)> {
+        None
+    }
+
     /// Apply operator out-place
     fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<S::Elem>
     where
@@ -73,6 +85,10 @@ where
 {
     type Elem = A;
 
+    fn shape(&self) -> Option<(usize, usize)> {
+        Some(self.dim())
+    }
+
     fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
     where
         S: Data<Elem = A>,
@@ -80,3 +96,388 @@ where
         self.dot(a)
     }
 }
+
+/// Linear operator which can also apply its adjoint `A^H`
+pub trait AdjointOperator: LinearOperator {
+    /// Apply the adjoint operator `A^H` out-place
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<Self::Elem>
+    where
+        S: Data<Elem = Self::Elem>;
+}
+
+impl<A, Sa> AdjointOperator for ArrayBase<Sa, Ix2>
+where
+    A: Scalar,
+    Sa: Data<Elem = A>,
+{
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        // A^H a = conj(A^T conj(a))
+        let mut b = self.t().dot(&a.mapv(|x| x.conj()));
+        b.mapv_inplace(|x| x.conj());
+        b
+    }
+}
+
+impl<T: LinearOperator + ?Sized> LinearOperator for &T {
+    type Elem = T::Elem;
+
+    fn shape(&self) -> Option<(usize, usize)> {
+        (**self).shape()
+    }
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<Self::Elem>
+    where
+        S: Data<Elem = Self::Elem>,
+    {
+        (**self).apply(a)
+    }
+
+    fn apply_mut<S>(&self, a: &mut ArrayBase<S, Ix1>)
+    where
+        S: DataMut<Elem = Self::Elem>,
+    {
+        (**self).apply_mut(a)
+    }
+}
+
+impl<T: AdjointOperator + ?Sized> AdjointOperator for &T {
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<Self::Elem>
+    where
+        S: Data<Elem = Self::Elem>,
+    {
+        (**self).apply_adjoint(a)
+    }
+}
+
+/// Matrix-free operator defined by closures, e.g. for FFT-based or sparse matrices
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Cumulative sum and its adjoint, the reversed cumulative sum
+/// let op = FnOperator::with_adjoint(
+///     (4, 4),
+///     |x: ArrayView1<f64>| {
+///         let mut y = x.to_owned();
+///         y.accumulate_axis_inplace(Axis(0), |&prev, cur| *cur += prev);
+///         y
+///     },
+///     |x: ArrayView1<f64>| {
+///         let mut y = x.to_owned();
+///         y.slice_mut(s![..;-1])
+///             .accumulate_axis_inplace(Axis(0), |&prev, cur| *cur += prev);
+///         y
+///     },
+/// );
+/// let x = array![1.0, 2.0, 3.0, 4.0];
+/// assert_eq!(op.apply(&x), array![1.0, 3.0, 6.0, 10.0]);
+/// assert_eq!(op.apply_adjoint(&x), array![10.0, 9.0, 7.0, 4.0]);
+/// ```
+pub struct FnOperator<A, F, G = ()> {
+    shape: (usize, usize),
+    apply: F,
+    adjoint: G,
+    phantom: PhantomData<A>,
+}
+
+impl<A, F> FnOperator<A, F>
+where
+    A: Scalar,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+{
+    /// `m x n` operator applied by `apply`
+    pub fn new(shape: (usize, usize), apply: F) -> Self {
+        FnOperator {
+            shape,
+            apply,
+            adjoint: (),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, F, G> FnOperator<A, F, G>
+where
+    A: Scalar,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+    G: Fn(ArrayView1<A>) -> Array1<A>,
+{
+    /// `m x n` operator applied by `apply`, and its adjoint applied by `adjoint`
+    pub fn with_adjoint(shape: (usize, usize), apply: F, adjoint: G) -> Self {
+        FnOperator {
+            shape,
+            apply,
+            adjoint,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, F, G> LinearOperator for FnOperator<A, F, G>
+where
+    A: Scalar,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+{
+    type Elem = A;
+
+    fn shape(&self) -> Option<(usize, usize)> {
+        Some(self.shape)
+    }
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        (self.apply)(a.view())
+    }
+}
+
+impl<A, F, G> AdjointOperator for FnOperator<A, F, G>
+where
+    A: Scalar,
+    F: Fn(ArrayView1<A>) -> Array1<A>,
+    G: Fn(ArrayView1<A>) -> Array1<A>,
+{
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        (self.adjoint)(a.view())
+    }
+}
+
+/// Scalar multiple `alpha A` of an operator
+pub struct ScaledOperator<Op: LinearOperator> {
+    alpha: Op::Elem,
+    op: Op,
+}
+
+impl<Op: LinearOperator> ScaledOperator<Op> {
+    pub fn new(alpha: Op::Elem, op: Op) -> Self {
+        ScaledOperator { alpha, op }
+    }
+}
+
+impl<Op: LinearOperator> LinearOperator for ScaledOperator<Op> {
+    type Elem = Op::Elem;
+
+    fn shape(&self) -> Option<(usize, usize)> {
+        self.op.shape()
+    }
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<Self::Elem>
+    where
+        S: Data<Elem = Self::Elem>,
+    {
+        self.op.apply(a).mapv_into(|x| self.alpha * x)
+    }
+}
+
+impl<Op: AdjointOperator> AdjointOperator for ScaledOperator<Op> {
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<Self::Elem>
+    where
+        S: Data<Elem = Self::Elem>,
+    {
+        let alpha = self.alpha.conj();
+        self.op.apply_adjoint(a).mapv_into(|x| alpha * x)
+    }
+}
+
+/// Sum `A + B` of operators of the same shape
+pub struct OperatorSum<L, R> {
+    lhs: L,
+    rhs: R,
+}
+
+impl<L, R> OperatorSum<L, R>
+where
+    L: LinearOperator,
+    R: LinearOperator<Elem = L::Elem>,
+{
+    pub fn new(lhs: L, rhs: R) -> Self {
+        OperatorSum { lhs, rhs }
+    }
+}
+
+impl<L, R> LinearOperator for OperatorSum<L, R>
+where
+    L: LinearOperator,
+    R: LinearOperator<Elem = L::Elem>,
+{
+    type Elem = L::Elem;
+
+    fn shape(&self) -> Option<(usize, usize)> {
+        self.lhs.shape().or_else(|| self.rhs.shape())
+    }
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<Self::Elem>
+    where
+        S: Data<Elem = Self::Elem>,
+    {
+        self.lhs.apply(a) + self.rhs.apply(a)
+    }
+}
+
+impl<L, R> AdjointOperator for OperatorSum<L, R>
+where
+    L: AdjointOperator,
+    R: AdjointOperator<Elem = L::Elem>,
+{
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<Self::Elem>
+    where
+        S: Data<Elem = Self::Elem>,
+    {
+        self.lhs.apply_adjoint(a) + self.rhs.apply_adjoint(a)
+    }
+}
+
+/// Product `AB` of operators, i.e. `B` is applied first
+pub struct OperatorProduct<L, R> {
+    lhs: L,
+    rhs: R,
+}
+
+impl<L, R> OperatorProduct<L, R>
+where
+    L: LinearOperator,
+    R: LinearOperator<Elem = L::Elem>,
+{
+    pub fn new(lhs: L, rhs: R) -> Self {
+        OperatorProduct { lhs, rhs }
+    }
+}
+
+impl<L, R> LinearOperator for OperatorProduct<L, R>
+where
+    L: LinearOperator,
+    R: LinearOperator<Elem = L::Elem>,
+{
+    type Elem = L::Elem;
+
+    fn shape(&self) -> Option<(usize, usize)> {
+        match (self.lhs.shape(), self.rhs.shape()) {
+            (Some((m, _)), Some((_, n))) => Some((m, n)),
+            _ => None,
+        }
+    }
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<Self::Elem>
+    where
+        S: Data<Elem = Self::Elem>,
+    {
+        self.lhs.apply(&self.rhs.apply(a))
+    }
+}
+
+impl<L, R> AdjointOperator for OperatorProduct<L, R>
+where
+    L: AdjointOperator,
+    R: AdjointOperator<Elem = L::Elem>,
+{
+    fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<Self::Elem>
+    where
+        S: Data<Elem = Self::Elem>,
+    {
+        self.rhs.apply_adjoint(&self.lhs.apply_adjoint(a))
+    }
+}
+
+/// Inverse `A^{-1}` of a factorized matrix as an operator
+///
+/// This is implemented for [LUFactorized], [CholeskyFactorized], [BKFactorized],
+/// [LUFactorizedBanded] and [CholeskyFactorizedBanded].
+/// Each application solves the linear equation with the factors,
+/// and panics if the length of the vector does not match.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = random((3, 3));
+/// let a_inv = InverseOperator::new(a.factorize().unwrap());
+/// let b: Array1<f64> = random(3);
+/// assert_close_l2!(&a_inv.apply(&b), &a.solve(&b).unwrap(), 1e-9);
+/// ```
+pub struct InverseOperator<F> {
+    factorized: F,
+}
+
+impl<F> InverseOperator<F> {
+    pub fn new(factorized: F) -> Self {
+        InverseOperator { factorized }
+    }
+}
+
+macro_rules! impl_inverse_operator {
+    ($factorized:ty, $elem:ty, [$($bounds:tt)*], |$f:ident| $n:expr, $solve:ident, $solve_adjoint:ident) => {
+        impl<$($bounds)*> LinearOperator for InverseOperator<$factorized> {
+            type Elem = $elem;
+
+            fn shape(&self) -> Option<(usize, usize)> {
+                let $f = &self.factorized;
+                Some(($n, $n))
+            }
+
+            fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<$elem>
+            where
+                S: Data<Elem = $elem>,
+            {
+                self.factorized.$solve(a).unwrap()
+            }
+        }
+
+        impl<$($bounds)*> AdjointOperator for InverseOperator<$factorized> {
+            fn apply_adjoint<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<$elem>
+            where
+                S: Data<Elem = $elem>,
+            {
+                self.factorized.$solve_adjoint(a).unwrap()
+            }
+        }
+    };
+}
+
+impl_inverse_operator!(
+    LUFactorized<Sa>,
+    A,
+    [A: Scalar + Lapack, Sa: Data<Elem = A> + RawDataClone],
+    |f| f.a.nrows(),
+    solve,
+    solve_h
+);
+impl_inverse_operator!(
+    CholeskyFactorized<Sa>,
+    A,
+    [A: Scalar + Lapack, Sa: Data<Elem = A>],
+    |f| f.factor.nrows(),
+    solvec,
+    solvec
+);
+impl_inverse_operator!(
+    BKFactorized<Sa>,
+    A,
+    [A: Scalar + Lapack, Sa: Data<Elem = A>],
+    |f| f.a.nrows(),
+    solveh,
+    solveh
+);
+impl_inverse_operator!(
+    LUFactorizedBanded<A>,
+    A,
+    [A: Scalar + Lapack],
+    |f| f.len(),
+    solve,
+    solve_h
+);
+impl_inverse_operator!(
+    CholeskyFactorizedBanded<A>,
+    A,
+    [A: Scalar + Lapack],
+    |f| f.factor.len(),
+    solvec,
+    solvec
+);
@@ -0,0 +1,270 @@
+//! Symmetric/Hermitian matrices in the packed storage
+//!
+//! The upper or lower triangular part of a symmetric/Hermitian `n x n` matrix `A`
+//! is stored column by column in the array `AP` of length `n (n + 1) / 2`:
+//!
+//! - [UPLO::Upper]: `AP[i + j (j + 1) / 2] = A[i, j]` for `i <= j`
+//! - [UPLO::Lower]: `AP[i + (2n - j - 1) j / 2] = A[i, j]` for `j <= i`
+//!
+//! This is the packed storage of LAPACK, and halves the memory of the dense matrix.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a = arr2(&[
+//!     [4.0, 1.0, 2.0],
+//!     [1.0, 5.0, 3.0],
+//!     [2.0, 3.0, 6.0],
+//! ]);
+//! let p = PackedMatrix::from_dense(&a, UPLO::Upper);
+//! assert_eq!(p.ap, arr1(&[4.0, 1.0, 5.0, 2.0, 3.0, 6.0]));
+//! assert_eq!(p.to_dense(), a);
+//! ```
+
+use ndarray::*;
+
+use crate::cholesky::*;
+use crate::error::*;
+use crate::solveh::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// Symmetric/Hermitian matrix in the packed storage of LAPACK
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackedMatrix<A: Scalar> {
+    /// Which triangular part is stored in `ap`
+    pub uplo: UPLO,
+    /// Size of the matrix
+    pub n: usize,
+    /// Array of length `n (n + 1) / 2`
+    pub ap: Array1<A>,
+}
+
+impl<A: Scalar> PackedMatrix<A> {
+    /// Pack the upper or lower triangular part of a symmetric/Hermitian matrix
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` is not square.
+    pub fn from_dense<S>(a: &ArrayBase<S, Ix2>, uplo: UPLO) -> Self
+    where
+        S: Data<Elem = A>,
+    {
+        assert!(a.is_square(), "Packed matrix must be square");
+        let n = a.nrows();
+        let mut ap = Array1::zeros(n * (n + 1) / 2);
+        for j in 0..n {
+            for i in Self::rows(uplo, n, j) {
+                ap[Self::index(uplo, n, i, j)] = a[(i, j)];
+            }
+        }
+        PackedMatrix { uplo, n, ap }
+    }
+
+    /// Size of the matrix
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Returns true if the matrix is `0 x 0`
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Unpack into the dense symmetric/Hermitian matrix
+    pub fn to_dense(&self) -> Array2<A> {
+        let n = self.n;
+        let mut a = Array2::zeros((n, n));
+        for j in 0..n {
+            for i in Self::rows(self.uplo, n, j) {
+                let x = self.ap[Self::index(self.uplo, n, i, j)];
+                a[(j, i)] = x.conj();
+                a[(i, j)] = x;
+            }
+        }
+        a
+    }
+
+    /// Rows of the `j`-th column kept in the storage
+    fn rows(uplo: UPLO, n: usize, j: usize) -> std::ops::Range<usize> {
+        match uplo {
+            UPLO::Upper => 0..j + 1,
+            UPLO::Lower => j..n,
+        }
+    }
+
+    fn index(uplo: UPLO, n: usize, i: usize, j: usize) -> usize {
+        match uplo {
+            UPLO::Upper => i + j * (j + 1) / 2,
+            UPLO::Lower => i + (2 * n - j - 1) * j / 2,
+        }
+    }
+}
+
+/// Cholesky decomposition of a Hermitian (or real symmetric) positive definite packed matrix
+#[derive(Debug, Clone, PartialEq)]
+pub struct CholeskyFactorizedPacked<A: Scalar> {
+    /// `U` from the decomposition `A = U^H U` if `factor.uplo` is [UPLO::Upper],
+    /// or `L` from `A = L L^H` if [UPLO::Lower], in the packed storage
+    pub factor: PackedMatrix<A>,
+}
+
+impl<A> SolveC<A> for CholeskyFactorizedPacked<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solvec_inplace<'a, Sb>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let f = &self.factor;
+        A::solve_cholesky_packed(
+            f.uplo,
+            f.n as i32,
+            f.ap.as_slice().unwrap(),
+            b.as_slice_mut().unwrap(),
+        )?;
+        Ok(b)
+    }
+}
+
+/// Cholesky decomposition of Hermitian (or real symmetric) positive definite packed matrix
+pub trait CholeskyPacked<A: Scalar> {
+    /// Computes the Cholesky decomposition using `*pptrf`
+    /// in the storage part specified by `self.uplo`
+    ///
+    /// Returns [LinalgError::Lapack] with a positive return code
+    /// if the matrix is not positive definite.
+    fn cholesky_packed(&self) -> Result<CholeskyFactorizedPacked<A>>;
+}
+
+impl<A> CholeskyPacked<A> for PackedMatrix<A>
+where
+    A: Scalar + Lapack,
+{
+    fn cholesky_packed(&self) -> Result<CholeskyFactorizedPacked<A>> {
+        let mut factor = self.clone();
+        A::cholesky_packed(
+            factor.uplo,
+            factor.n as i32,
+            factor.ap.as_slice_mut().unwrap(),
+        )?;
+        Ok(CholeskyFactorizedPacked { factor })
+    }
+}
+
+impl<A> SolveC<A> for PackedMatrix<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solvec_inplace<'a, Sb>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.cholesky_packed()?.solvec_inplace(b)
+    }
+}
+
+/// Bunch-Kaufman factorization of a symmetric/Hermitian packed matrix
+#[derive(Debug, Clone, PartialEq)]
+pub struct BKFactorizedPacked<A: Scalar> {
+    a: PackedMatrix<A>,
+    ipiv: Pivot,
+}
+
+/// Bunch-Kaufman factorization of symmetric/Hermitian (not necessarily definite) packed matrix
+pub trait FactorizeHPacked<A: Scalar> {
+    /// Computes the factorization using `*sptrf`/`*hptrf`
+    ///
+    /// Returns [LinalgError::Lapack] with a positive return code if the matrix is singular.
+    fn factorizeh_packed(&self) -> Result<BKFactorizedPacked<A>>;
+}
+
+impl<A> FactorizeHPacked<A> for PackedMatrix<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorizeh_packed(&self) -> Result<BKFactorizedPacked<A>> {
+        let mut a = self.clone();
+        let ipiv = A::bk_packed(a.uplo, a.n as i32, a.ap.as_slice_mut().unwrap())?;
+        Ok(BKFactorizedPacked { a, ipiv })
+    }
+}
+
+impl<A> SolveH<A> for BKFactorizedPacked<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solveh_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        assert_eq!(
+            rhs.len(),
+            self.a.n,
+            "The length of `rhs` must be compatible with the shape of the factored matrix.",
+        );
+        A::solveh_packed(
+            self.a.uplo,
+            self.a.n as i32,
+            self.a.ap.as_slice().unwrap(),
+            &self.ipiv,
+            rhs.as_slice_mut().unwrap(),
+        )?;
+        Ok(rhs)
+    }
+}
+
+impl<A> SolveH<A> for PackedMatrix<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solveh_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.factorizeh_packed()?.solveh_inplace(rhs)
+    }
+}
+
+/// Eigenvalue problem for symmetric/Hermitian packed matrices using `*spev`/`*hpev`
+pub trait EighPacked<A: Scalar> {
+    /// Compute eigenvalues in ascending order and orthonormal eigenvectors
+    fn eigh_packed(&self) -> Result<(Array1<A::Real>, Array2<A>)>;
+    /// Compute eigenvalues in ascending order
+    fn eigvalsh_packed(&self) -> Result<Array1<A::Real>>;
+}
+
+impl<A> EighPacked<A> for PackedMatrix<A>
+where
+    A: Scalar + Lapack,
+{
+    fn eigh_packed(&self) -> Result<(Array1<A::Real>, Array2<A>)> {
+        let n = self.n;
+        let mut ap = self.ap.clone();
+        let lax::EighPackedOwned { eigs, z } =
+            A::eigh_packed(true, self.uplo, n as i32, ap.as_slice_mut().unwrap())?;
+        let z = Array2::from_shape_vec((n, n).f(), z.unwrap()).unwrap();
+        Ok((Array1::from(eigs), z))
+    }
+
+    fn eigvalsh_packed(&self) -> Result<Array1<A::Real>> {
+        let mut ap = self.ap.clone();
+        let lax::EighPackedOwned { eigs, .. } =
+            A::eigh_packed(false, self.uplo, self.n as i32, ap.as_slice_mut().unwrap())?;
+        Ok(Array1::from(eigs))
+    }
+}
@@ -0,0 +1,121 @@
+//! Moore-Penrose pseudo-inverse
+//!
+//! The pseudo-inverse of a `m x n` matrix `A` with the thin SVD `A = U S V^H`
+//! is the `n x m` matrix `A^+ = V S^+ U^H`, where `S^+` inverts the singular values
+//! larger than the cutoff `rcond * s_max` and replaces the others by zero.
+//! `x = A^+ b` is the minimum-norm solution of the least squares problem `min |A x - b|_2`.
+//!
+//! As [NumPy](https://numpy.org/doc/stable/reference/generated/numpy.linalg.pinv.html),
+//! the relative tolerance `rcond` is scaled by the largest singular value,
+//! and the default is `max(m, n) * eps`.
+//!
+//! - [Wikipedia article on Moore-Penrose inverse](https://en.wikipedia.org/wiki/Moore%E2%80%93Penrose_inverse)
+
+use ndarray::*;
+use num_traits::Float;
+
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::svd::*;
+use crate::types::*;
+use lax::svd::{SvdWork, SvdWorkImpl};
+
+/// Pseudo-inverse of matrix reference
+///
+/// The SVD workspace is allocated on each call; use [pinv_with_work] to reuse it.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Rank 1 matrix
+/// let a: Array2<f64> = array![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+/// let ap = a.pinv().unwrap();
+/// assert_eq!(ap.dim(), (2, 3));
+/// assert_close_l2!(&a.dot(&ap).dot(&a), &a, 1e-12);
+/// assert_close_l2!(&ap.dot(&array![1.0, 2.0, 3.0]), &array![0.2, 0.4], 1e-12);
+/// ```
+pub trait Pinv {
+    type Output;
+    type Real;
+    /// Pseudo-inverse with the default relative tolerance `max(m, n) * eps`
+    fn pinv(&self) -> Result<Self::Output>;
+    /// Pseudo-inverse regarding the singular values not larger than `rcond * s_max` as zero
+    fn pinv_with_tol(&self, rcond: Self::Real) -> Result<Self::Output>;
+}
+
+impl<A, S> Pinv for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+    type Real = A::Real;
+
+    fn pinv(&self) -> Result<Self::Output> {
+        let (m, n) = self.dim();
+        self.pinv_with_tol(A::real(m.max(n)) * A::Real::epsilon())
+    }
+
+    fn pinv_with_tol(&self, rcond: A::Real) -> Result<Self::Output> {
+        let (m, n) = self.dim();
+        if m == 0 || n == 0 {
+            return Ok(Array2::zeros((n, m)));
+        }
+        let (u, s, vt) = self.svd_thin(true, true)?;
+        Ok(pinv_from_svd(
+            u.unwrap().view(),
+            s.view(),
+            vt.unwrap().view(),
+            rcond,
+        ))
+    }
+}
+
+/// Pseudo-inverse regarding the singular values not larger than `rcond * s_max` as zero,
+/// reusing the SVD workspace for the matrices of the same shape
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let mut work = SvdThinWork::new(3, 2).unwrap();
+/// for _ in 0..3 {
+///     let a: Array2<f64> = random((3, 2));
+///     let ap = pinv_with_work(&a, 1e-12, &mut work).unwrap();
+///     assert_close_l2!(&ap, &a.pinv_with_tol(1e-12).unwrap(), 1e-12);
+/// }
+/// ```
+pub fn pinv_with_work<A, S>(
+    a: &ArrayBase<S, Ix2>,
+    rcond: A::Real,
+    work: &mut SvdThinWork<A>,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    SvdWork<A>: SvdWorkImpl<Elem = A>,
+{
+    let (u, s, vt) = work.calc(a)?;
+    Ok(pinv_from_svd(u, s, vt, rcond))
+}
+
+/// `V S^+ U^H` from the thin SVD `A = U S V^H`
+fn pinv_from_svd<A: Scalar>(
+    u: ArrayView2<A>,
+    s: ArrayView1<A::Real>,
+    vt: ArrayView2<A>,
+    rcond: A::Real,
+) -> Array2<A> {
+    let (m, n) = (u.nrows(), vt.ncols());
+    // Singular values are in descending order
+    let cutoff = rcond * s[0];
+    let rank = rank_from_singular_values(s.as_slice().unwrap(), cutoff, false);
+    if rank == 0 {
+        return Array2::zeros((n, m));
+    }
+    let v: Array2<A> = conjugate(&vt.slice(s![..rank, ..]));
+    let uh: Array2<A> = conjugate(&u.slice(s![.., ..rank]));
+    let s_inv = s.slice(s![..rank]).mapv(|s| A::from_real(s.recip()));
+    (&v * &s_inv).dot(&uh)
+}
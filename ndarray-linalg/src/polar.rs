@@ -0,0 +1,103 @@
+//! Polar decomposition
+//!
+//! A `m x n` matrix `A` is decomposed as `A = U P` (right polar decomposition)
+//! or `A = P U` (left polar decomposition),
+//! where `U` has orthonormal columns (rows if `m < n`) and `P` is Hermitian positive semi-definite.
+//! Both are computed from the thin SVD `A = W S V^H` as `U = W V^H`, and `P = V S V^H` or `P = W S W^H`.
+//! `U` is the nearest matrix with orthonormal columns to `A` in the Frobenius norm.
+//!
+//! - [Wikipedia article on polar decomposition](https://en.wikipedia.org/wiki/Polar_decomposition)
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::svd::*;
+use crate::types::*;
+use lax::svd::{SvdWork, SvdWorkImpl};
+
+/// Polar decomposition of matrix reference
+///
+/// The SVD workspace is allocated on each call; use [polar_with_work] to reuse it.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = random((3, 3));
+/// let (u, p) = a.polar().unwrap();
+/// assert_close_l2!(&u.dot(&p), &a, 1e-9);
+/// let (p, u) = a.polar_left().unwrap();
+/// assert_close_l2!(&p.dot(&u), &a, 1e-9);
+/// ```
+pub trait Polar {
+    type U;
+    type P;
+    /// Right polar decomposition `A = U P`, where `P` is `n x n`
+    fn polar(&self) -> Result<(Self::U, Self::P)>;
+    /// Left polar decomposition `A = P U`, where `P` is `m x m`
+    fn polar_left(&self) -> Result<(Self::P, Self::U)>;
+}
+
+impl<A, S> Polar for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type U = Array2<A>;
+    type P = Array2<A>;
+
+    fn polar(&self) -> Result<(Self::U, Self::P)> {
+        let (w, s, vt) = self.svd_thin(true, true)?;
+        Ok(polar_from_svd(
+            w.unwrap().view(),
+            s.view(),
+            vt.unwrap().view(),
+        ))
+    }
+
+    fn polar_left(&self) -> Result<(Self::P, Self::U)> {
+        let (w, s, vt) = self.svd_thin(true, true)?;
+        let (w, vt) = (w.unwrap(), vt.unwrap());
+        let wh: Array2<A> = conjugate(&w);
+        let p = (&w * &s.mapv(A::from_real)).dot(&wh);
+        Ok((p, w.dot(&vt)))
+    }
+}
+
+/// Right polar decomposition `A = U P` reusing the SVD workspace for the matrices of the same shape
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let mut work = SvdThinWork::new(3, 3).unwrap();
+/// for _ in 0..3 {
+///     let a: Array2<f64> = random((3, 3));
+///     let (u, p) = polar_with_work(&a, &mut work).unwrap();
+///     assert_close_l2!(&u.dot(&p), &a, 1e-9);
+/// }
+/// ```
+pub fn polar_with_work<A, S>(
+    a: &ArrayBase<S, Ix2>,
+    work: &mut SvdThinWork<A>,
+) -> Result<(Array2<A>, Array2<A>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    SvdWork<A>: SvdWorkImpl<Elem = A>,
+{
+    let (w, s, vt) = work.calc(a)?;
+    Ok(polar_from_svd(w, s, vt))
+}
+
+/// `U = W V^H` and `P = V S V^H` from the thin SVD `A = W S V^H`
+fn polar_from_svd<A: Scalar>(
+    w: ArrayView2<A>,
+    s: ArrayView1<A::Real>,
+    vt: ArrayView2<A>,
+) -> (Array2<A>, Array2<A>) {
+    let v: Array2<A> = conjugate(&vt);
+    let p = (&v * &s.mapv(A::from_real)).dot(&vt);
+    (w.dot(&vt), p)
+}
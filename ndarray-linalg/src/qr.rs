@@ -3,15 +3,19 @@
 //! [Wikipedia article on QR decomposition](https://en.wikipedia.org/wiki/QR_decomposition)
 
 use ndarray::*;
-use num_traits::Zero;
+use num_traits::{One, Zero};
 
 use crate::convert::*;
 use crate::error::*;
 use crate::layout::*;
+use crate::svd::rank_from_singular_values;
 use crate::triangular::*;
 use crate::types::*;
 
+use lax::qr::OrthogonalFactorization;
+pub use lax::qr::{apply_householder, householder_vectors};
 pub use lax::UPLO;
+use lax::{Side, Transpose};
 
 /// QR decomposition for matrix reference
 ///
@@ -128,6 +132,250 @@ where
     }
 }
 
+/// LQ decomposition `A = L Q` for matrix reference
+///
+/// For `m x n` matrix `A` and `k = min(m, n)`, `L` is `m x k` lower trapezoidal
+/// and `Q` is `k x n` with orthonormal rows.
+/// This is computed by `*gelqf` and `*orglq` (or `*unglq`).
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = random((3, 5));
+/// let (l, q) = a.lq().unwrap();
+/// assert_close_l2!(&l.dot(&q), &a, 1e-9);
+/// assert_close_l2!(&q.dot(&q.t()), &Array2::eye(3), 1e-9);
+/// ```
+pub trait LQ {
+    type L;
+    type Q;
+    fn lq(&self) -> Result<(Self::L, Self::Q)>;
+}
+
+/// QL decomposition `A = Q L` for matrix reference
+///
+/// For `m x n` matrix `A` and `k = min(m, n)`, `Q` is `m x k` with orthonormal columns
+/// and `L` is `k x n` lower trapezoidal, i.e. `L[(i, j)] = 0` for `j > i + n - k`.
+/// This is computed by `*geqlf` and `*orgql` (or `*ungql`).
+pub trait QL {
+    type Q;
+    type L;
+    fn ql(&self) -> Result<(Self::Q, Self::L)>;
+}
+
+/// RQ decomposition `A = R Q` for matrix reference
+///
+/// For `m x n` matrix `A` and `k = min(m, n)`, `R` is `m x k` upper trapezoidal,
+/// i.e. `R[(i, j)] = 0` for `i > j + m - k`, and `Q` is `k x n` with orthonormal rows.
+/// This is computed by `*gerqf` and `*orgrq` (or `*ungrq`).
+pub trait RQ {
+    type R;
+    type Q;
+    fn rq(&self) -> Result<(Self::R, Self::Q)>;
+}
+
+impl<A, S> LQ for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type L = Array2<A>;
+    type Q = Array2<A>;
+
+    fn lq(&self) -> Result<(Self::L, Self::Q)> {
+        let (m, n) = self.dim();
+        let k = m.min(n);
+        let a = factorize_orthogonal(self, OrthogonalFactorization::LQ)?;
+        let l = take_trapezoid(&a.factor.slice(s![.., ..k]), |i, j| j <= i);
+        let q = replicate(&a.q.slice(s![..k, ..]));
+        Ok((l, q))
+    }
+}
+
+impl<A, S> QL for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Q = Array2<A>;
+    type L = Array2<A>;
+
+    fn ql(&self) -> Result<(Self::Q, Self::L)> {
+        let (m, n) = self.dim();
+        let k = m.min(n);
+        let a = factorize_orthogonal(self, OrthogonalFactorization::QL)?;
+        let l = take_trapezoid(&a.factor.slice(s![m - k.., ..]), |i, j| j <= i + n - k);
+        let q = replicate(&a.q.slice(s![.., n - k..]));
+        Ok((q, l))
+    }
+}
+
+impl<A, S> RQ for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type R = Array2<A>;
+    type Q = Array2<A>;
+
+    fn rq(&self) -> Result<(Self::R, Self::Q)> {
+        let (m, n) = self.dim();
+        let k = m.min(n);
+        let a = factorize_orthogonal(self, OrthogonalFactorization::RQ)?;
+        let r = take_trapezoid(&a.factor.slice(s![.., n - k..]), |i, j| i <= j + m - k);
+        let q = replicate(&a.q.slice(s![m - k.., ..]));
+        Ok((r, q))
+    }
+}
+
+/// Triangular factor and orthogonal factor of [OrthogonalFactorization]
+/// stored in the Fortran-order arrays of the same shape as the input
+struct OrthogonalFactors<A> {
+    factor: Array2<A>,
+    q: Array2<A>,
+}
+
+fn factorize_orthogonal<A, S>(
+    a: &ArrayBase<S, Ix2>,
+    kind: OrthogonalFactorization,
+) -> Result<OrthogonalFactors<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let mut factor = fortran_copy(a);
+    if factor.is_empty() {
+        let q = factor.clone();
+        return Ok(OrthogonalFactors { factor, q });
+    }
+    let l = factor.layout()?;
+    let tau = A::householder_orthogonal(kind, l, factor.as_allocated_mut()?)?;
+    let mut q = factor.clone();
+    A::q_orthogonal(kind, l, q.as_allocated_mut()?, &tau)?;
+    Ok(OrthogonalFactors { factor, q })
+}
+
+/// Copy of `a` where the elements `(i, j)` are replaced by zero unless `keep(i, j)`
+fn take_trapezoid<A, S>(a: &ArrayBase<S, Ix2>, keep: impl Fn(usize, usize) -> bool) -> Array2<A>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let mut a: Array2<A> = replicate(a);
+    Zip::indexed(&mut a).for_each(|(i, j), elt| {
+        if !keep(i, j) {
+            *elt = A::zero()
+        }
+    });
+    a
+}
+
+/// Represents the QR decomposition `A = Q R` of a `m x n` matrix
+/// with `Q` kept as the product of `k = min(m, n)` Householder reflectors
+///
+/// `Q B` and `Q^H B` are computed by `*ormqr` (or `*unmqr`) in `O(m n k)` operations
+/// without forming `Q`, and `Q` is formed only when [QRFactorized::generate_q] is called.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = random((5, 3));
+/// let b: Array2<f64> = random((5, 2));
+/// let f = a.factorize_qr().unwrap();
+/// let q = f.generate_q().unwrap();
+/// let qtb = f.apply_qh(&b).unwrap();
+/// assert_close_l2!(&qtb.slice(s![..3, ..]), &q.t().dot(&b), 1e-9);
+/// assert_close_l2!(&q.dot(&f.r()), &a, 1e-9);
+/// ```
+#[derive(Debug, Clone)]
+pub struct QRFactorized<A: Scalar> {
+    /// `R` in the upper triangle and the Householder vectors below the diagonal, in Fortran order
+    a: Array2<A>,
+    /// The scalar factors of the Householder reflectors
+    tau: Vec<A>,
+}
+
+/// An interface for computing the QR decomposition with the implicit `Q`
+pub trait FactorizeQR {
+    type Elem: Scalar;
+    /// Computes the Householder QR decomposition by `*geqrf`
+    fn factorize_qr(&self) -> Result<QRFactorized<Self::Elem>>;
+}
+
+impl<A, S> FactorizeQR for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn factorize_qr(&self) -> Result<QRFactorized<A>> {
+        let mut a = fortran_copy(self);
+        let tau = A::householder(a.layout()?, a.as_allocated_mut()?)?;
+        Ok(QRFactorized { a, tau })
+    }
+}
+
+impl<A: Scalar + Lapack> QRFactorized<A> {
+    /// `k x n` upper trapezoidal factor `R`
+    pub fn r(&self) -> Array2<A> {
+        let (m, n) = self.a.dim();
+        take_slice_upper(&self.a, m.min(n), n)
+    }
+
+    /// `m x k` matrix `Q` with orthonormal columns formed by `*orgqr` (or `*ungqr`)
+    pub fn generate_q(&self) -> Result<Array2<A>> {
+        let (m, n) = self.a.dim();
+        let mut q = self.a.clone();
+        A::q(q.layout()?, q.as_allocated_mut()?, &self.tau)?;
+        Ok(take_slice(&q, m, m.min(n)))
+    }
+
+    /// Computes `Q B` for the `m x m` unitary `Q`, where `B` has `m` rows
+    pub fn apply_q<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix2>) -> Result<Array2<A>> {
+        self.apply(Transpose::No, b)
+    }
+
+    /// Computes `Q^H B` for the `m x m` unitary `Q`, where `B` has `m` rows
+    ///
+    /// The first `k` rows are `Q^H B` for the `m x k` matrix returned by [QRFactorized::generate_q].
+    pub fn apply_qh<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix2>) -> Result<Array2<A>> {
+        self.apply(Transpose::Hermite, b)
+    }
+
+    fn apply<S: Data<Elem = A>>(
+        &self,
+        trans: Transpose,
+        b: &ArrayBase<S, Ix2>,
+    ) -> Result<Array2<A>> {
+        if b.nrows() != self.a.nrows() {
+            return Err(LinalgError::Shape(ShapeError::from_kind(
+                ErrorKind::IncompatibleShape,
+            )));
+        }
+        let mut c = fortran_copy(b);
+        A::apply_q(
+            self.a.layout()?,
+            Side::Left,
+            trans,
+            self.a.as_allocated()?,
+            &self.tau,
+            c.layout()?,
+            c.as_allocated_mut()?,
+        )?;
+        Ok(c)
+    }
+}
+
+/// Copy into a Fortran-order array
+fn fortran_copy<A: Scalar, S: Data<Elem = A>>(a: &ArrayBase<S, Ix2>) -> Array2<A> {
+    let mut f = Array2::zeros(a.dim().f());
+    f.assign(a);
+    f
+}
+
 fn take_slice<A, S1, S2>(a: &ArrayBase<S1, Ix2>, n: usize, m: usize) -> ArrayBase<S2, Ix2>
 where
     A: Copy,
@@ -153,3 +401,221 @@ where
     });
     a
 }
+
+/// Pivot selection rule for [qr_pivoted]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PivotRule<R> {
+    /// Pick the column with the largest remaining norm as `*geqp3` does
+    ///
+    /// Columns whose norms are nearly tied may be picked differently
+    /// depending on the rounding errors in the norm updates.
+    Largest,
+    /// Pick the lowest-index column among those whose remaining norm is
+    /// within the relative tolerance from the largest one
+    ///
+    /// This makes the permutation reproducible across platforms and LAPACK builds
+    /// as long as the rounding errors are smaller than the tolerance.
+    LowestIndex { rtol: R },
+}
+
+/// Result of [qr_pivoted]
+#[derive(Debug, Clone)]
+pub struct QRPivoted<A: Scalar> {
+    /// `m x k` matrix with orthonormal columns where `k = min(m, n)`
+    pub q: Array2<A>,
+    /// `k x n` upper triangular matrix whose diagonal elements are non-increasing in magnitude
+    pub r: Array2<A>,
+    /// The `j`-th column of `A P` is the `perm[j]`-th column of `A`
+    pub perm: Vec<usize>,
+}
+
+impl<A: Scalar> QRPivoted<A> {
+    /// Numerical rank from the diagonal elements of `R`
+    ///
+    /// Counts the diagonal elements whose magnitude is strictly greater than `tol`
+    /// as [rank_from_singular_values] does for singular values.
+    /// The leading `rank` columns of `A P` are the well-conditioned subset of the columns of `A`.
+    pub fn rank(&self, tol: A::Real) -> usize {
+        let d: Vec<A::Real> = self.r.diag().iter().map(|x| x.abs()).collect();
+        rank_from_singular_values(&d, tol, false)
+    }
+}
+
+/// QR decomposition with column pivoting `A P = Q R`
+///
+/// [PivotRule::Largest] is computed by `*geqp3`.
+/// [PivotRule::LowestIndex] is not supported by LAPACK, and is computed by the Businger-Golub algorithm
+/// implemented by Householder reflections in Rust, where the column norms of the trailing submatrix
+/// are downdated at every step and recomputed when cancellation is detected as in `*laqp2`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // The 2nd and 3rd columns have the same norm
+/// let a = arr2(&[[1.0, 0.0, 3.0], [0.0, 3.0, 0.0], [0.0, 4.0, 4.0]]);
+/// let qrp = qr_pivoted(&a, PivotRule::LowestIndex { rtol: 1e-8 }).unwrap();
+/// assert_eq!(qrp.perm[0], 1);
+/// ```
+pub fn qr_pivoted<A, S>(a: &ArrayBase<S, Ix2>, rule: PivotRule<A::Real>) -> Result<QRPivoted<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    match rule {
+        PivotRule::Largest => qr_pivoted_lapack(a),
+        PivotRule::LowestIndex { .. } => Ok(businger_golub(a, rule)),
+    }
+}
+
+fn qr_pivoted_lapack<A, S>(a: &ArrayBase<S, Ix2>) -> Result<QRPivoted<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    if k == 0 {
+        return Ok(QRPivoted {
+            q: Array2::zeros((m, 0)),
+            r: Array2::zeros((0, n)),
+            perm: (0..n).collect(),
+        });
+    }
+    let mut a = fortran_copy(a);
+    let l = a.layout()?;
+    let (tau, jpvt) = A::householder_pivoted(l, a.as_allocated_mut()?)?;
+    let r = take_slice_upper(&a, k, n);
+    A::q(l, a.as_allocated_mut()?, &tau)?;
+    let q = take_slice(&a, m, k);
+    let perm = jpvt.iter().map(|&j| j as usize - 1).collect();
+    Ok(QRPivoted { q, r, perm })
+}
+
+fn businger_golub<A, S>(a: &ArrayBase<S, Ix2>, rule: PivotRule<A::Real>) -> QRPivoted<A>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    let mut r = a.to_owned();
+    let mut perm: Vec<usize> = (0..n).collect();
+    let zero = A::Real::zero();
+    let one = A::Real::one();
+    let tol: A::Real = num_traits::Float::epsilon();
+    let tol = tol.sqrt();
+
+    // `norms` are the downdated norms of the trailing columns,
+    // and `exact` keep the norms at the last time they are computed directly
+    let mut norms: Vec<A::Real> = r.columns().into_iter().map(column_norm).collect();
+    let mut exact = norms.clone();
+    let mut reflectors = Vec::with_capacity(k);
+    for i in 0..k {
+        let p = i + select_pivot(&norms[i..], rule);
+        if p != i {
+            for row in 0..m {
+                r.swap((row, i), (row, p));
+            }
+            norms.swap(i, p);
+            exact.swap(i, p);
+            perm.swap(i, p);
+        }
+
+        // The reflection is skipped if the pivot column is already reduced,
+        // but the norms of the trailing columns have to be downdated anyway
+        let mut col = r.slice_mut(s![i.., i]);
+        let v = householder_reflector(&mut col);
+        if let Some(v) = &v {
+            for mut col in r.slice_mut(s![i.., i + 1..]).columns_mut() {
+                let mut x = col.to_vec();
+                apply_householder(v, A::from_real(one + one), &mut x);
+                col.assign(&aview1(&x));
+            }
+        }
+        for j in i + 1..n {
+            if norms[j] == zero {
+                continue;
+            }
+            let t = r[(i, j)].abs() / norms[j];
+            let t = one - t * t;
+            let t = if t < zero { zero } else { t };
+            let ratio = norms[j] / exact[j];
+            if t * ratio * ratio <= tol {
+                norms[j] = column_norm(r.slice(s![i + 1.., j]));
+                exact[j] = norms[j];
+            } else {
+                norms[j] *= t.sqrt();
+            }
+        }
+        if let Some(v) = v {
+            reflectors.push((i, v));
+        }
+    }
+
+    let mut q = Array2::zeros((m, k));
+    q.diag_mut().fill(A::one());
+    for (i, v) in reflectors.iter().rev() {
+        for mut col in q.slice_mut(s![*i.., ..]).columns_mut() {
+            let mut x = col.to_vec();
+            apply_householder(v, A::from_real(one + one), &mut x);
+            col.assign(&aview1(&x));
+        }
+    }
+    let r = take_slice_upper(&r, k, n);
+    QRPivoted { q, r, perm }
+}
+
+fn column_norm<A: Scalar>(x: ArrayView1<A>) -> A::Real {
+    x.iter().map(|x| x.square()).sum::<A::Real>().sqrt()
+}
+
+/// Index of the pivot column in `norms` according to `rule`
+fn select_pivot<R: Scalar<Real = R> + PartialOrd>(norms: &[R], rule: PivotRule<R>) -> usize {
+    let (imax, max) =
+        norms.iter().enumerate().fold(
+            (0, R::zero()),
+            |(i, max), (j, &x)| {
+                if x > max {
+                    (j, x)
+                } else {
+                    (i, max)
+                }
+            },
+        );
+    match rule {
+        PivotRule::Largest => imax,
+        PivotRule::LowestIndex { rtol } => {
+            let threshold = max * (R::one() - rtol);
+            norms.iter().position(|&x| x >= threshold).unwrap_or(imax)
+        }
+    }
+}
+
+/// Overwrite `x` by `H x = (alpha, 0, ..., 0)` and returns the unit vector `v` of `H = I - 2 v v^H`
+///
+/// Returns `None` if `x` is already in this form.
+fn householder_reflector<A: Scalar, S: DataMut<Elem = A>>(
+    x: &mut ArrayBase<S, Ix1>,
+) -> Option<Vec<A>> {
+    let tail = column_norm(x.slice(s![1..]));
+    if tail == A::Real::zero() {
+        return None;
+    }
+    let norm = column_norm(x.view());
+    let x0 = x[0];
+    let alpha = if x0.abs() == A::Real::zero() {
+        -A::from_real(norm)
+    } else {
+        -x0.mul_real(norm / x0.abs())
+    };
+    let mut v = x.to_vec();
+    v[0] -= alpha;
+    let vnorm = column_norm(aview1(&v));
+    for v in v.iter_mut() {
+        *v = v.div_real(vnorm);
+    }
+    x.fill(A::zero());
+    x[0] = alpha;
+    Some(v)
+}
@@ -0,0 +1,187 @@
+//! Recursive least squares by updating the QR decomposition
+//!
+//! For the least squares problem `min |b - Ax|` of a `m x n` matrix `A`,
+//! only the `n x n` upper triangular factor `R` of the QR decomposition `A = QR`,
+//! the first `n` elements `z` of `Q^H b`, and the residual sum of squares are kept.
+//! The solution is obtained by the back substitution `Rx = z`.
+//!
+//! When a row is added, it is eliminated against `R` by `n` Givens rotations in `O(n^2)` operations
+//! without refactorizing the whole `A`.
+//! A row is removed by the orthogonal downdating algorithm of LINPACK `*chdd`,
+//! which is stable as long as the remaining rows still determine the solution well.
+//!
+//! - G. H. Golub and C. F. Van Loan, "Matrix Computations", 4th ed., Section 6.5.
+//! - J. J. Dongarra et al., "LINPACK Users' Guide", SIAM (1979), Chapter 10.
+
+use ndarray::*;
+use num_traits::{Float, One, Zero};
+
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::givens::*;
+use crate::triangular::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// Least squares problem updated row by row
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Fit y = 1 + 2 t
+/// let mut rls = RecursiveLeastSquares::<f64>::new(2);
+/// for t in 0..5 {
+///     let t = t as f64;
+///     rls.add_row(&array![1.0, t], 1.0 + 2.0 * t).unwrap();
+/// }
+/// // Wrong observation, and its removal
+/// rls.add_row(&array![1.0, 5.0], 100.0).unwrap();
+/// rls.remove_row(&array![1.0, 5.0], 100.0).unwrap();
+/// assert_close_l2!(&rls.solve().unwrap(), &array![1.0, 2.0], 1e-12);
+/// assert_eq!(rls.nrows(), 5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RecursiveLeastSquares<A: Scalar> {
+    r: Array2<A>,
+    z: Array1<A>,
+    residual_sum_of_squares: A::Real,
+    nrows: usize,
+}
+
+impl<A> RecursiveLeastSquares<A>
+where
+    A: Scalar + Lapack,
+{
+    /// Empty problem with `n` unknowns
+    pub fn new(n: usize) -> Self {
+        RecursiveLeastSquares {
+            r: Array2::zeros((n, n)),
+            z: Array1::zeros(n),
+            residual_sum_of_squares: A::Real::zero(),
+            nrows: 0,
+        }
+    }
+
+    /// The number of unknowns `n`
+    pub fn len(&self) -> usize {
+        self.z.len()
+    }
+
+    /// Whether there are no unknowns
+    pub fn is_empty(&self) -> bool {
+        self.z.is_empty()
+    }
+
+    /// The number of rows `m` added and not removed yet
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    /// Upper triangular factor `R` satisfying `R^H R = A^H A`
+    pub fn r(&self) -> &Array2<A> {
+        &self.r
+    }
+
+    /// The residual sum of squares `|b - Ax|^2` for the least squares solution `x`
+    pub fn residual_sum_of_squares(&self) -> A::Real {
+        self.residual_sum_of_squares
+    }
+
+    /// Adds the equation `row . x = rhs`, i.e. a row to `A` and an element to `b`
+    pub fn add_row<S>(&mut self, row: &ArrayBase<S, Ix1>, rhs: A) -> Result<()>
+    where
+        S: Data<Elem = A>,
+    {
+        let n = self.len();
+        if row.len() != n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let mut v = row.to_owned();
+        let mut w = rhs;
+        for k in 0..n {
+            let g = Givens::new(self.r[(k, k)], v[k]);
+            for j in k..n {
+                let (r, v_new) = g.rotate(self.r[(k, j)], v[j]);
+                self.r[(k, j)] = r;
+                v[j] = v_new;
+            }
+            let (z, w_new) = g.rotate(self.z[k], w);
+            self.z[k] = z;
+            w = w_new;
+        }
+        self.residual_sum_of_squares += w.square();
+        self.nrows += 1;
+        Ok(())
+    }
+
+    /// Removes the equation `row . x = rhs` added before
+    ///
+    /// Unlike [RecursiveLeastSquares::add_row], this requires `R` to be nonsingular.
+    /// [LinalgError::NotPositiveDefinite] is returned if `A^H A` would not be positive definite
+    /// after the removal, e.g. when the row has not been added,
+    /// and then the state is left unchanged.
+    pub fn remove_row<S>(&mut self, row: &ArrayBase<S, Ix1>, rhs: A) -> Result<()>
+    where
+        S: Data<Elem = A>,
+    {
+        let n = self.len();
+        if row.len() != n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        if n == 0 {
+            self.residual_sum_of_squares =
+                Float::max(self.residual_sum_of_squares - rhs.square(), A::Real::zero());
+            self.nrows = self.nrows.saturating_sub(1);
+            return Ok(());
+        }
+        // The rotations mapping `[p; alpha]` to the last unit vector, where `R^H p = conj(row)`,
+        // also map `[R; 0]` to `[R_new; row]`
+        let rh: Array2<A> = conjugate(&self.r);
+        let p = rh.solve_triangular(UPLO::Lower, Diag::NonUnit, &row.mapv(|x| x.conj()))?;
+        let alpha2 = A::Real::one() - p.iter().map(|x| x.square()).sum::<A::Real>();
+        if alpha2 <= A::Real::zero() {
+            return Err(LinalgError::NotPositiveDefinite);
+        }
+        let mut alpha = A::from_real(Float::sqrt(alpha2));
+        let mut rotations = Vec::with_capacity(n);
+        for k in (0..n).rev() {
+            let g = Givens::new(alpha, p[k]);
+            alpha = g.rotate(alpha, p[k]).0;
+            rotations.push(g);
+        }
+        rotations.reverse();
+
+        let mut e = Array1::<A>::zeros(n);
+        for k in (0..n).rev() {
+            let g = &rotations[k];
+            for j in k..n {
+                let (e_new, r) = g.rotate(e[j], self.r[(k, j)]);
+                e[j] = e_new;
+                self.r[(k, j)] = r;
+            }
+        }
+        // Solve `G [zeta_old; z_old] = [zeta; z_new]` for `zeta_old` and `z_new`
+        // starting from `zeta = rhs` in the reverse order of the rotations
+        let mut zeta = rhs;
+        for (k, g) in rotations.iter().enumerate() {
+            let z = (self.z[k] - g.s.conj() * zeta).mul_real(g.c.recip());
+            zeta = zeta.mul_real(g.c) - g.s * z;
+            self.z[k] = z;
+        }
+        self.residual_sum_of_squares = Float::max(
+            self.residual_sum_of_squares - zeta.square(),
+            A::Real::zero(),
+        );
+        self.nrows -= 1;
+        Ok(())
+    }
+
+    /// Least squares solution `x` of the rows added so far
+    ///
+    /// [LinalgError::Lapack] is returned if `R` is singular,
+    /// e.g. when less than `n` linearly independent rows have been added.
+    pub fn solve(&self) -> Result<Array1<A>> {
+        self.r.solve_triangular(UPLO::Upper, Diag::NonUnit, &self.z)
+    }
+}
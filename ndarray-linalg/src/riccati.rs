@@ -0,0 +1,220 @@
+//! Algebraic Riccati equations
+//!
+//! The continuous-time algebraic Riccati equation (CARE)
+//!
+//! ```text
+//! A^H X + X A - X B R^{-1} B^H X + Q = 0
+//! ```
+//!
+//! is solved by the Schur method: the stable invariant subspace `[U_1; U_2]` of the Hamiltonian matrix
+//! `[[A, -G], [-Q, -A^H]]` with `G = B R^{-1} B^H` is obtained by reordering its Schur form,
+//! and the stabilizing solution is `X = U_2 U_1^{-1}`, i.e. `A - G X` is stable.
+//!
+//! The discrete-time algebraic Riccati equation (DARE)
+//!
+//! ```text
+//! A^H X A - X - A^H X B (R + B^H X B)^{-1} B^H X A + Q = 0
+//! ```
+//!
+//! is solved in the same way using the stable deflating subspace of the symplectic pencil
+//! `[[A, 0], [-Q, I]] - λ [[I, G], [0, A^H]]` computed by the reordered generalized Schur form.
+//! `(I + G X)^{-1} A` has eigenvalues inside the unit circle for the stabilizing solution `X`.
+//!
+//! - A. J. Laub, "A Schur method for solving algebraic Riccati equations",
+//!   IEEE Trans. Automat. Control 24 (6), 1979.
+//! - T. Pappas, A. J. Laub, and N. R. Sandell, "On the numerical solution of the discrete-time
+//!   algebraic Riccati equation", IEEE Trans. Automat. Control 25 (4), 1980.
+
+use ndarray::*;
+use num_traits::Zero;
+
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::layout::*;
+use crate::schur::*;
+use crate::solve::*;
+use crate::types::*;
+
+/// Solve the continuous-time algebraic Riccati equation `A^H X + X A - X B R^{-1} B^H X + Q = 0`
+///
+/// `A` is `n x n`, `B` is `n x m`, `Q` is `n x n` Hermitian, and `R` is `m x m` Hermitian positive definite.
+/// The stabilizing solution `X` is returned as an exactly Hermitian matrix,
+/// and [LinalgError::Lapack] is returned if it does not exist,
+/// e.g. the Hamiltonian matrix has eigenvalues on the imaginary axis.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = random((4, 4));
+/// let b: Array2<f64> = random((4, 2));
+/// let q = Array2::<f64>::eye(4);
+/// let r = Array2::<f64>::eye(2);
+/// let x = solve_care(&a, &b, &q, &r).unwrap();
+/// let xb = x.dot(&b);
+/// let res = a.t().dot(&x) + x.dot(&a) - xb.dot(&xb.t()) + &q;
+/// assert_close_l2!(&res, &Array2::zeros((4, 4)), 1e-9);
+/// ```
+pub fn solve_care<A, Sa, Sb, Sq, Sr>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    q: &ArrayBase<Sq, Ix2>,
+    r: &ArrayBase<Sr, Ix2>,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sq: Data<Elem = A>,
+    Sr: Data<Elem = A>,
+{
+    let g = riccati_gain(a, b, q, r)?;
+    let n = a.nrows();
+    let ah: Array2<A> = conjugate(a);
+    let mut h = Array2::zeros((2 * n, 2 * n));
+    h.slice_mut(s![..n, ..n]).assign(a);
+    h.slice_mut(s![..n, n..]).assign(&g.mapv(|x| -x));
+    h.slice_mut(s![n.., ..n]).assign(&q.mapv(|x| -x));
+    h.slice_mut(s![n.., n..]).assign(&ah.mapv(|x| -x));
+    let (mut t, mut u, _) = h.schur_into()?;
+
+    // Move the eigenvalues in the left half plane to the top,
+    // where both eigenvalues of a 2x2 block of a real Schur form have the real part `t[(i, i)]`
+    let mut k = 0;
+    let mut i = 0;
+    while i < 2 * n {
+        let size = block_size(&t, i);
+        if t[(i, i)].re() < A::Real::zero() {
+            if i != k {
+                reorder_schur(&mut t, Some(&mut u), i, k)?;
+            }
+            k += size;
+        }
+        i += size;
+    }
+    if k != n {
+        return Err(no_stabilizing_solution());
+    }
+    stabilizing_solution(&u, n)
+}
+
+/// Solve the discrete-time algebraic Riccati equation
+/// `A^H X A - X - A^H X B (R + B^H X B)^{-1} B^H X A + Q = 0`
+///
+/// The shapes and the requirements of the arguments are the same as [solve_care].
+/// The stabilizing solution `X` is returned as an exactly Hermitian matrix,
+/// and [LinalgError::Lapack] is returned if it does not exist,
+/// e.g. the symplectic pencil has eigenvalues on the unit circle.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = random((4, 4));
+/// let b: Array2<f64> = random((4, 2));
+/// let q = Array2::<f64>::eye(4);
+/// let r = Array2::<f64>::eye(2);
+/// let x = solve_dare(&a, &b, &q, &r).unwrap();
+/// let bxa = b.t().dot(&x).dot(&a);
+/// let k = (&r + &b.t().dot(&x).dot(&b)).inv().unwrap();
+/// let res = a.t().dot(&x).dot(&a) - &x - bxa.t().dot(&k).dot(&bxa) + &q;
+/// assert_close_l2!(&res, &Array2::zeros((4, 4)), 1e-9);
+/// ```
+pub fn solve_dare<A, Sa, Sb, Sq, Sr>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    q: &ArrayBase<Sq, Ix2>,
+    r: &ArrayBase<Sr, Ix2>,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sq: Data<Elem = A>,
+    Sr: Data<Elem = A>,
+{
+    let g = riccati_gain(a, b, q, r)?;
+    let n = a.nrows();
+    let ah: Array2<A> = conjugate(a);
+    let eye = Array2::<A>::eye(n);
+    let mut m = Array2::zeros((2 * n, 2 * n));
+    m.slice_mut(s![..n, ..n]).assign(a);
+    m.slice_mut(s![n.., ..n]).assign(&q.mapv(|x| -x));
+    m.slice_mut(s![n.., n..]).assign(&eye);
+    let mut l = Array2::zeros((2 * n, 2 * n));
+    l.slice_mut(s![..n, ..n]).assign(&eye);
+    l.slice_mut(s![..n, n..]).assign(&g);
+    l.slice_mut(s![n.., n..]).assign(&ah);
+    let mut gs = m.qz(&l)?;
+
+    // Move the eigenvalues inside the unit circle to the top,
+    // where both eigenvalues of a 2x2 block of a real Schur form have the same modulus
+    let mut k = 0;
+    let mut i = 0;
+    while i < 2 * n {
+        let size = block_size(&gs.s, i);
+        if gs.alpha[i].abs() < gs.beta[i].abs() {
+            if i != k {
+                gs.reorder(i, k)?;
+            }
+            k += size;
+        }
+        i += size;
+    }
+    if k != n {
+        return Err(no_stabilizing_solution());
+    }
+    stabilizing_solution(&gs.z, n)
+}
+
+/// Check the shapes, and compute `G = B R^{-1} B^H`
+fn riccati_gain<A, Sa, Sb, Sq, Sr>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    q: &ArrayBase<Sq, Ix2>,
+    r: &ArrayBase<Sr, Ix2>,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sq: Data<Elem = A>,
+    Sr: Data<Elem = A>,
+{
+    a.square_layout()?;
+    r.square_layout()?;
+    let (n, m) = b.dim();
+    if a.nrows() != n || q.dim() != (n, n) || r.nrows() != m {
+        return Err(LinalgError::Shape(ShapeError::from_kind(
+            ErrorKind::IncompatibleShape,
+        )));
+    }
+    let bh: Array2<A> = conjugate(b);
+    Ok(b.dot(&r.inv()?).dot(&bh))
+}
+
+/// Size of the diagonal block of a (quasi-)triangular Schur form starting at the row `i`
+fn block_size<A: Scalar>(t: &Array2<A>, i: usize) -> usize {
+    if i + 1 < t.nrows() && !t[(i + 1, i)].is_zero() {
+        2
+    } else {
+        1
+    }
+}
+
+/// `X = U_2 U_1^{-1}` from the basis `[U_1; U_2]` of the stable subspace in the first `n` columns of `u`
+fn stabilizing_solution<A: Scalar + Lapack>(u: &Array2<A>, n: usize) -> Result<Array2<A>> {
+    let f = u.slice(s![..n, ..n]).to_owned().factorize_into()?;
+    // X U_1 = U_2 is solved row by row as U_1^T x_i = u_i
+    let mut x = u.slice(s![n.., ..n]).to_owned();
+    for mut row in x.axis_iter_mut(Axis(0)) {
+        f.solve_t_inplace(&mut row)?;
+    }
+    let half = A::from_real(A::real(0.5));
+    let xh: Array2<A> = conjugate(&x);
+    Ok((x + xh).mapv_into(|x| x * half))
+}
+
+fn no_stabilizing_solution() -> LinalgError {
+    LinalgError::Lapack(lax::error::Error::LapackComputationalFailure { return_code: 1 })
+}
@@ -0,0 +1,271 @@
+//! Schur form of general matrices
+
+use ndarray::*;
+
+use crate::convert::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+/// Schur decomposition `A = Z T Z^H` of a general matrix using `*gees`
+///
+/// Returns the Schur form `T`, the Schur vectors `Z` and the eigenvalues in the order of the diagonal of `T`.
+/// For real matrices, `T` is quasi-triangular with 2x2 blocks for complex conjugate pairs of eigenvalues,
+/// and `Z` is orthogonal. For complex matrices, `T` is upper triangular and `Z` is unitary.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = random((4, 4));
+/// let (t, z, eigs) = a.schur().unwrap();
+/// assert_close_l2!(&z.dot(&t).dot(&z.t()), &a, 1e-9);
+/// # let _ = eigs;
+/// ```
+pub trait Schur {
+    type T;
+    type Z;
+    type Eigs;
+    fn schur(&self) -> Result<(Self::T, Self::Z, Self::Eigs)>;
+}
+
+/// Schur decomposition of a general matrix, consuming it to store `T`
+pub trait SchurInto {
+    type T;
+    type Z;
+    type Eigs;
+    fn schur_into(self) -> Result<(Self::T, Self::Z, Self::Eigs)>;
+}
+
+impl<A, S> SchurInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type T = Self;
+    type Z = Array2<A>;
+    type Eigs = Array1<A::Complex>;
+
+    fn schur_into(mut self) -> Result<(Self::T, Self::Z, Self::Eigs)> {
+        let l = self.square_layout()?;
+        let res = A::schur(l, true, self.as_allocated_mut()?)?;
+        let z = into_matrix(l, res.z.unwrap())?;
+        Ok((self, z, ArrayBase::from(res.eigs)))
+    }
+}
+
+impl<A, S> Schur for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type T = Array2<A>;
+    type Z = Array2<A>;
+    type Eigs = Array1<A::Complex>;
+
+    fn schur(&self) -> Result<(Self::T, Self::Z, Self::Eigs)> {
+        self.to_owned().schur_into()
+    }
+}
+
+/// Result of [QZ::qz]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneralizedSchur<A: Scalar> {
+    /// Generalized Schur form of `A`, quasi-triangular for real matrices and triangular for complex matrices
+    pub s: Array2<A>,
+    /// Generalized Schur form of `B`, upper triangular
+    pub t: Array2<A>,
+    /// Left Schur vectors
+    pub q: Array2<A>,
+    /// Right Schur vectors
+    pub z: Array2<A>,
+    /// Numerators of the generalized eigenvalues `alpha[i] / beta[i]`
+    pub alpha: Array1<A::Complex>,
+    /// Denominators of the generalized eigenvalues, real and non-negative for real matrices
+    pub beta: Array1<A>,
+}
+
+impl<A: Scalar + Lapack> GeneralizedSchur<A> {
+    /// Move a diagonal block of `(S, T)` from the row `ifst` to the row `ilst` using `*tgexc`
+    ///
+    /// `Q` and `Z` are updated so that `A = Q S Z^H` and `B = Q T Z^H` hold after reordering,
+    /// and `alpha` and `beta` are permuted accordingly.
+    /// Indices are adjusted for 2x2 blocks of real matrices as [reorder_schur],
+    /// and the actual `(ifst, ilst)` is returned.
+    pub fn reorder(&mut self, ifst: usize, ilst: usize) -> Result<(usize, usize)> {
+        let l = self.s.square_layout()?;
+        let mut t = same_order(l, &self.t);
+        let mut q = same_order(l, &self.q);
+        let mut z = same_order(l, &self.z);
+        let (ifst, ilst) = A::reorder_generalized_schur(
+            l,
+            self.s.as_allocated_mut()?,
+            t.as_allocated_mut()?,
+            Some(q.as_allocated_mut()?),
+            Some(z.as_allocated_mut()?),
+            ifst,
+            ilst,
+        )?;
+        self.t.assign(&t);
+        self.q.assign(&q);
+        self.z.assign(&z);
+        // The moved block has the same size as the block now at `ilst`
+        let n = self.s.nrows();
+        let size = if ilst + 1 < n && !self.s[(ilst + 1, ilst)].is_zero() {
+            2
+        } else {
+            1
+        };
+        let alpha = self.alpha.as_slice_mut().unwrap();
+        let beta = self.beta.as_slice_mut().unwrap();
+        if ifst < ilst {
+            alpha[ifst..ilst + size].rotate_left(size);
+            beta[ifst..ilst + size].rotate_left(size);
+        } else {
+            alpha[ilst..ifst + size].rotate_right(size);
+            beta[ilst..ifst + size].rotate_right(size);
+        }
+        Ok((ifst, ilst))
+    }
+}
+
+/// Copy of `a` in the same memory order as `l`
+fn same_order<A: Scalar>(l: MatrixLayout, a: &Array2<A>) -> Array2<A> {
+    let mut b = match l {
+        MatrixLayout::C { .. } => Array2::zeros(a.dim()),
+        MatrixLayout::F { .. } => Array2::zeros(a.dim().f()),
+    };
+    b.assign(a);
+    b
+}
+
+/// Generalized Schur (QZ) decomposition `A = Q S Z^H`, `B = Q T Z^H` of a matrix pencil `(A, B)` using `*gges`
+///
+/// The generalized eigenvalues `alpha[i] / beta[i]` are returned as pairs,
+/// since `beta[i]` is zero for infinite eigenvalues of a singular `B`.
+pub trait QZ<A, S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    /// # Panics
+    ///
+    /// Panics if the shapes of `self` and `b` are different.
+    fn qz(&self, b: &ArrayBase<S, Ix2>) -> Result<GeneralizedSchur<A>>;
+}
+
+impl<A, Sa, Sb> QZ<A, Sb> for ArrayBase<Sa, Ix2>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    fn qz(&self, b: &ArrayBase<Sb, Ix2>) -> Result<GeneralizedSchur<A>> {
+        assert_eq!(
+            self.shape(),
+            b.shape(),
+            "The shapes of A and B must be identical"
+        );
+        let mut s = self.to_owned();
+        let l = s.square_layout()?;
+        // `*gges` requires `A` and `B` in the same memory order
+        let mut t = match l {
+            MatrixLayout::C { .. } => Array2::zeros(b.dim()),
+            MatrixLayout::F { .. } => Array2::zeros(b.dim().f()),
+        };
+        t.assign(b);
+        let res =
+            A::generalized_schur(l, true, true, s.as_allocated_mut()?, t.as_allocated_mut()?)?;
+        Ok(GeneralizedSchur {
+            s,
+            t,
+            q: into_matrix(l, res.q.unwrap())?,
+            z: into_matrix(l, res.z.unwrap())?,
+            alpha: ArrayBase::from(res.alpha),
+            beta: ArrayBase::from(res.beta),
+        })
+    }
+}
+
+/// Move a diagonal block of the Schur form `T` from the row `ifst` to the row `ilst`
+///
+/// This swaps adjacent diagonal blocks of `T` step by step using `*trexc`,
+/// and accumulates the transformations into the Schur vectors `z` if given,
+/// i.e. `A = Z T Z^H` holds after reordering if it holds before.
+///
+/// For real matrices, `T` is quasi-triangular with 2x2 blocks for complex conjugate pairs.
+/// If `ifst` points the second row of a 2x2 block, it is adjusted to the first row,
+/// and `ilst` may be also adjusted not to split a 2x2 block.
+/// The actual `(ifst, ilst)` is returned.
+///
+/// # Panics
+///
+/// Panics if the shapes of `t` and `z` are different.
+pub fn reorder_schur<A, S>(
+    t: &mut ArrayBase<S, Ix2>,
+    z: Option<&mut ArrayBase<S, Ix2>>,
+    ifst: usize,
+    ilst: usize,
+) -> Result<(usize, usize)>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    let l = t.square_layout()?;
+    let z = match z {
+        Some(z) => {
+            assert_eq!(
+                t.shape(),
+                z.shape(),
+                "The shapes of T and Z must be identical"
+            );
+            z
+        }
+        None => {
+            return Ok(A::reorder_schur(
+                l,
+                t.as_allocated_mut()?,
+                None,
+                ifst,
+                ilst,
+            )?)
+        }
+    };
+    if z.layout()?.same_order(&l) {
+        return Ok(A::reorder_schur(
+            l,
+            t.as_allocated_mut()?,
+            Some(z.as_allocated_mut()?),
+            ifst,
+            ilst,
+        )?);
+    }
+    // Work on a copy of Z in the same memory order as T
+    let mut zc = match l {
+        MatrixLayout::C { .. } => Array2::zeros(z.dim()),
+        MatrixLayout::F { .. } => Array2::zeros(z.dim().f()),
+    };
+    zc.assign(z);
+    let res = A::reorder_schur(
+        l,
+        t.as_allocated_mut()?,
+        Some(zc.as_allocated_mut()?),
+        ifst,
+        ilst,
+    )?;
+    z.assign(&zc);
+    Ok(res)
+}
+
+/// Complex Schur decomposition `A = Z T Z^H`, where `T` is upper triangular even for real matrices
+pub(crate) fn complex_schur<A, S>(
+    a: &ArrayBase<S, Ix2>,
+) -> Result<(Array2<A::Complex>, Array2<A::Complex>)>
+where
+    A: Scalar,
+    A::Complex: Lapack,
+    S: Data<Elem = A>,
+{
+    let (t, z, _) = a.mapv(|x| x.as_c()).schur_into()?;
+    Ok((t, z))
+}
@@ -0,0 +1,217 @@
+//! Shift-invert spectral transformation
+//!
+//! The Krylov subspace methods converge first to the eigenvalues of the largest magnitude.
+//! To compute the interior eigenvalues `lambda` of `Ax = lambda Bx` near a shift `sigma`,
+//! the operator `(A - sigma B)^{-1} B` is used instead, which has the same eigenvectors
+//! and the eigenvalues `theta = 1 / (lambda - sigma)`.
+//! The eigenvalues closest to `sigma` become the largest in magnitude and well separated,
+//! and are recovered by `lambda = sigma + 1 / theta`.
+//! Each application costs one solve with the factorization of `A - sigma B` computed once.
+//!
+//! For Hermitian `A`, real `sigma` and `B = I`, the operator is also Hermitian,
+//! and can be used with [crate::krylov::lanczos()] or [crate::lobpcg::lobpcg].
+//! Otherwise the operator is not Hermitian, and [crate::krylov::Iram] or [crate::krylov::Arnoldi] should be used.
+
+use ndarray::*;
+use num_traits::{Float, One};
+
+use crate::eig::*;
+use crate::error::*;
+use crate::krylov::{Arnoldi, MGS};
+use crate::operator::*;
+use crate::solve::*;
+use crate::types::*;
+
+/// Shift-inverted operator `(A - sigma B)^{-1} B`
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Eigenvalues 1, 2, ..., 100
+/// let a: Array2<f64> = Array2::from_diag(&Array1::from_shape_fn(100, |i| (i + 1) as f64));
+/// let op = ShiftInvert::new(&a, 50.3).unwrap();
+/// let (lambda, _x) = op.eigs(&Array1::ones(100), 20).unwrap();
+/// // The closest eigenvalues to 50.3 first
+/// assert_rclose!(lambda[0].re, 50.0, 1e-9);
+/// assert_rclose!(lambda[1].re, 51.0, 1e-9);
+/// ```
+pub struct ShiftInvert<A: Scalar, F> {
+    inverse: InverseOperator<F>,
+    b: Option<Array2<A>>,
+    sigma: A,
+    n: usize,
+}
+
+impl<A> ShiftInvert<A, LUFactorized<OwnedRepr<A>>>
+where
+    A: Scalar + Lapack,
+{
+    /// Factorize `A - sigma I` by the LU decomposition
+    pub fn new<S>(a: &ArrayBase<S, Ix2>, sigma: A) -> Result<Self>
+    where
+        S: Data<Elem = A>,
+    {
+        if !a.is_square() {
+            return Err(LinalgError::NotSquare {
+                rows: a.nrows() as i32,
+                cols: a.ncols() as i32,
+            });
+        }
+        let mut shifted = a.to_owned();
+        shifted.diag_mut().mapv_inplace(|x| x - sigma);
+        Ok(Self::from_factorized(
+            shifted.factorize_into()?,
+            sigma,
+            None,
+        ))
+    }
+
+    /// Factorize `A - sigma B` by the LU decomposition for the generalized eigenvalue problem
+    pub fn generalized<S1, S2>(
+        a: &ArrayBase<S1, Ix2>,
+        b: &ArrayBase<S2, Ix2>,
+        sigma: A,
+    ) -> Result<Self>
+    where
+        S1: Data<Elem = A>,
+        S2: Data<Elem = A>,
+    {
+        if !a.is_square() {
+            return Err(LinalgError::NotSquare {
+                rows: a.nrows() as i32,
+                cols: a.ncols() as i32,
+            });
+        }
+        if a.shape() != b.shape() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let shifted = Zip::from(a).and(b).map_collect(|&a, &b| a - sigma * b);
+        Ok(Self::from_factorized(
+            shifted.factorize_into()?,
+            sigma,
+            Some(b.to_owned()),
+        ))
+    }
+}
+
+impl<A, F> ShiftInvert<A, F>
+where
+    A: Scalar + Lapack,
+    InverseOperator<F>: LinearOperator<Elem = A>,
+{
+    /// Wrap a factorization of `A - sigma B`, e.g. [crate::band::LUFactorizedBanded],
+    /// or of `A - sigma I` if `b` is `None`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the factorization is unknown, or does not match `b`.
+    pub fn from_factorized(factorized: F, sigma: A, b: Option<Array2<A>>) -> Self {
+        let inverse = InverseOperator::new(factorized);
+        let (n, _) = inverse
+            .shape()
+            .expect("Size of the factorization is unknown");
+        if let Some(b) = &b {
+            assert_eq!(b.dim(), (n, n), "Shape of B mismatches");
+        }
+        ShiftInvert {
+            inverse,
+            b,
+            sigma,
+            n,
+        }
+    }
+
+    /// The shift `sigma`
+    pub fn sigma(&self) -> A {
+        self.sigma
+    }
+
+    /// Size of the problem
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Returns true if the problem is `0 x 0`
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Eigenvalue `lambda = sigma + 1 / theta` of the original problem
+    /// from an eigenvalue `theta` of the operator
+    pub fn back_transform(&self, theta: A::Complex) -> A::Complex {
+        self.sigma.as_c() + A::Complex::one() / theta
+    }
+
+    /// [ShiftInvert::back_transform] for a real eigenvalue `theta`, e.g. computed by Lanczos iteration
+    pub fn back_transform_real(&self, theta: A::Real) -> A {
+        self.sigma + A::from_real(theta.recip())
+    }
+
+    /// Approximate eigenpairs near `sigma` by `k` steps of Arnoldi iteration from `start`
+    ///
+    /// The Ritz values of the operator are transformed back into the eigenvalues `lambda`
+    /// of the original problem, and sorted from the closest to `sigma`.
+    /// The columns of the returned matrix are the corresponding normalized Ritz vectors.
+    /// The iteration stops before `k` steps if an invariant subspace is found.
+    /// [LinalgError::NonFinite] is returned if a Ritz value is NaN,
+    /// e.g. when the operator returns infinite or NaN values.
+    pub fn eigs<S>(
+        &self,
+        start: &ArrayBase<S, Ix1>,
+        k: usize,
+    ) -> Result<(Array1<A::Complex>, Array2<A::Complex>)>
+    where
+        S: Data<Elem = A>,
+    {
+        if start.len() != self.n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let mut arnoldi =
+            Arnoldi::new(self, start.to_owned(), MGS::new(self.n, A::Real::epsilon()));
+        for _ in 0..k.min(self.n) {
+            if arnoldi.next().is_none() {
+                break;
+            }
+        }
+        let (q, h) = arnoldi.into_qh();
+        let m = h.ncols();
+        let (theta, y) = h.eig()?;
+        let x = q.slice(s![.., ..m]).mapv(|x| x.as_c()).dot(&y);
+
+        let abs: Vec<A::Real> = theta.iter().map(|t| t.abs()).collect();
+        if abs.iter().any(|t| t.is_nan()) {
+            return Err(LinalgError::NonFinite);
+        }
+        let mut order: Vec<usize> = (0..m).collect();
+        order.sort_by(|&i, &j| abs[j].partial_cmp(&abs[i]).unwrap());
+        let lambda = order
+            .iter()
+            .map(|&i| self.back_transform(theta[i]))
+            .collect();
+        let x = x.select(Axis(1), &order);
+        Ok((lambda, x))
+    }
+}
+
+impl<A, F> LinearOperator for ShiftInvert<A, F>
+where
+    A: Scalar + Lapack,
+    InverseOperator<F>: LinearOperator<Elem = A>,
+{
+    type Elem = A;
+
+    fn shape(&self) -> Option<(usize, usize)> {
+        Some((self.n, self.n))
+    }
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        match &self.b {
+            Some(b) => self.inverse.apply_into(b.dot(a)),
+            None => self.inverse.apply(a),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! Matrix sign function
+//!
+//! For a matrix `A` without eigenvalues on the imaginary axis, the sign function `sign(A) = A (A^2)^{-1/2}`
+//! has eigenvalues `+1` and `-1` for the eigenvalues of `A` in the right and left half planes.
+//! `(I + sign(A)) / 2` is the spectral projector onto the invariant subspace of the right half plane.
+//!
+//! It is computed by the Newton iteration `X <- (mu X + (mu X)^{-1}) / 2` starting from `X = A`,
+//! where the scaling `mu = sqrt(|X^{-1}|_1 / |X|_1)` accelerates the initial phase.
+//!
+//! - N. J. Higham, "Functions of Matrices: Theory and Computation", SIAM, 2008, Chapter 5.
+
+use ndarray::*;
+use num_traits::{Float, One};
+
+use crate::error::*;
+use crate::layout::*;
+use crate::opnorm::*;
+use crate::solve::*;
+use crate::types::*;
+
+/// Maximum number of the Newton iterations
+const MAX_ITER: usize = 100;
+
+/// Matrix sign function of a square matrix
+///
+/// [LinalgError::Lapack] is returned if the iteration does not converge,
+/// which happens when `A` has eigenvalues on or very close to the imaginary axis.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[2.0, 1.0], [0.0, -3.0]];
+/// let s = a.signm().unwrap();
+/// assert_close_l2!(&s.dot(&s), &Array2::eye(2), 1e-12);
+/// assert_close_l2!(&s.diag().to_owned(), &array![1.0, -1.0], 1e-12);
+/// ```
+pub trait Signm {
+    type Output;
+    fn signm(&self) -> Result<Self::Output>;
+}
+
+impl<A, S> Signm for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn signm(&self) -> Result<Self::Output> {
+        let n = self.square_layout()?.len();
+        let tol = A::real(n) * A::Real::epsilon();
+        let half = A::from_real(A::real(0.5));
+        let mut x = self.to_owned();
+        let mut scaling = true;
+        for _ in 0..MAX_ITER {
+            let x_inv = x.inv()?;
+            let mu = if scaling {
+                Float::sqrt(x_inv.opnorm_one()? / x.opnorm_one()?)
+            } else {
+                A::Real::one()
+            };
+            let (mu, mu_inv) = (A::from_real(mu), A::from_real(mu.recip()));
+            let next = Zip::from(&x)
+                .and(&x_inv)
+                .map_collect(|&x, &y| (x * mu + y * mu_inv) * half);
+            let diff = (&next - &x).opnorm_one()?;
+            let norm = next.opnorm_one()?;
+            x = next;
+            if diff <= tol * norm {
+                return Ok(x);
+            }
+            // Scaling disturbs the quadratic convergence in the final phase
+            if diff <= A::real(1e-2) * norm {
+                scaling = false;
+            }
+        }
+        Err(LinalgError::Lapack(
+            lax::error::Error::LapackComputationalFailure {
+                return_code: MAX_ITER as i32,
+            },
+        ))
+    }
+}
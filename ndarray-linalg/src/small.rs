@@ -0,0 +1,258 @@
+//! Closed-form determinant, inverse, and solver for 2x2, 3x3, and 4x4 matrices
+//!
+//! For tiny matrices, the overhead of calling LAPACK dominates the computation.
+//! These functions compute the result from the adjugate matrix (Cramer's rule)
+//! without calling LAPACK and without heap allocation.
+//!
+//! Cramer's rule is not backward stable, so the inverse and solution are not computed
+//! and `None` is returned if the matrix is nearly singular,
+//! i.e. `|det A| <= sqrt(eps) * prod_i |a_i|` where `a_i` are the rows of `A`.
+//! By Hadamard's inequality, the right hand side is an upper bound of `|det A|`.
+//! [solve_small] uses these functions for `n <= 4`, and falls back to LAPACK in this case.
+//! [crate::Solve] also uses them for `n <= small_solve_threshold()`,
+//! which is `4` by default and can be changed by [set_small_solve_threshold].
+//!
+//! ```
+//! use ndarray_linalg::*;
+//!
+//! let a = [[3.0, 2.0, -1.0], [2.0, -2.0, 4.0], [-2.0, 1.0, -2.0]];
+//! let x = solve_3x3(&a, &[1.0, -2.0, 0.0]).unwrap();
+//! assert!((x[0] - 1.0).abs() < 1e-9);
+//! assert!((x[1] + 2.0).abs() < 1e-9);
+//! assert!((x[2] + 2.0).abs() < 1e-9);
+//! ```
+
+use ndarray::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::error::*;
+use crate::solve::*;
+use crate::types::*;
+
+fn adjugate_2x2<A: Scalar>(a: &[[A; 2]; 2]) -> [[A; 2]; 2] {
+    [[a[1][1], -a[0][1]], [-a[1][0], a[0][0]]]
+}
+
+fn adjugate_3x3<A: Scalar>(a: &[[A; 3]; 3]) -> [[A; 3]; 3] {
+    [
+        [
+            a[1][1] * a[2][2] - a[1][2] * a[2][1],
+            a[0][2] * a[2][1] - a[0][1] * a[2][2],
+            a[0][1] * a[1][2] - a[0][2] * a[1][1],
+        ],
+        [
+            a[1][2] * a[2][0] - a[1][0] * a[2][2],
+            a[0][0] * a[2][2] - a[0][2] * a[2][0],
+            a[0][2] * a[1][0] - a[0][0] * a[1][2],
+        ],
+        [
+            a[1][0] * a[2][1] - a[1][1] * a[2][0],
+            a[0][1] * a[2][0] - a[0][0] * a[2][1],
+            a[0][0] * a[1][1] - a[0][1] * a[1][0],
+        ],
+    ]
+}
+
+fn adjugate_4x4<A: Scalar>(a: &[[A; 4]; 4]) -> [[A; 4]; 4] {
+    // 2x2 minors of the upper two rows
+    let s0 = a[0][0] * a[1][1] - a[1][0] * a[0][1];
+    let s1 = a[0][0] * a[1][2] - a[1][0] * a[0][2];
+    let s2 = a[0][0] * a[1][3] - a[1][0] * a[0][3];
+    let s3 = a[0][1] * a[1][2] - a[1][1] * a[0][2];
+    let s4 = a[0][1] * a[1][3] - a[1][1] * a[0][3];
+    let s5 = a[0][2] * a[1][3] - a[1][2] * a[0][3];
+    // 2x2 minors of the lower two rows
+    let c0 = a[2][0] * a[3][1] - a[3][0] * a[2][1];
+    let c1 = a[2][0] * a[3][2] - a[3][0] * a[2][2];
+    let c2 = a[2][0] * a[3][3] - a[3][0] * a[2][3];
+    let c3 = a[2][1] * a[3][2] - a[3][1] * a[2][2];
+    let c4 = a[2][1] * a[3][3] - a[3][1] * a[2][3];
+    let c5 = a[2][2] * a[3][3] - a[3][2] * a[2][3];
+    [
+        [
+            a[1][1] * c5 - a[1][2] * c4 + a[1][3] * c3,
+            -a[0][1] * c5 + a[0][2] * c4 - a[0][3] * c3,
+            a[3][1] * s5 - a[3][2] * s4 + a[3][3] * s3,
+            -a[2][1] * s5 + a[2][2] * s4 - a[2][3] * s3,
+        ],
+        [
+            -a[1][0] * c5 + a[1][2] * c2 - a[1][3] * c1,
+            a[0][0] * c5 - a[0][2] * c2 + a[0][3] * c1,
+            -a[3][0] * s5 + a[3][2] * s2 - a[3][3] * s1,
+            a[2][0] * s5 - a[2][2] * s2 + a[2][3] * s1,
+        ],
+        [
+            a[1][0] * c4 - a[1][1] * c2 + a[1][3] * c0,
+            -a[0][0] * c4 + a[0][1] * c2 - a[0][3] * c0,
+            a[3][0] * s4 - a[3][1] * s2 + a[3][3] * s0,
+            -a[2][0] * s4 + a[2][1] * s2 - a[2][3] * s0,
+        ],
+        [
+            -a[1][0] * c3 + a[1][1] * c1 - a[1][2] * c0,
+            a[0][0] * c3 - a[0][1] * c1 + a[0][2] * c0,
+            -a[3][0] * s3 + a[3][1] * s1 - a[3][2] * s0,
+            a[2][0] * s3 - a[2][1] * s1 + a[2][2] * s0,
+        ],
+    ]
+}
+
+/// Determinant by the cofactor expansion along the first row
+fn det_from_adjugate<A: Scalar, const N: usize>(a: &[[A; N]; N], adj: &[[A; N]; N]) -> A {
+    (0..N).map(|j| a[0][j] * adj[j][0]).sum()
+}
+
+/// Check `|det A| > sqrt(eps) * prod_i |a_i|`
+fn is_well_conditioned<A: Scalar, const N: usize>(a: &[[A; N]; N], det: A) -> bool {
+    let eps: A::Real = num_traits::Float::epsilon();
+    let bound: A::Real = a
+        .iter()
+        .map(|row| row.iter().map(|x| x.square()).sum::<A::Real>().sqrt())
+        .product();
+    det.abs() > eps.sqrt() * bound
+}
+
+macro_rules! impl_small {
+    ($n:expr, $adj:ident, $det:ident, $inv:ident, $solve:ident) => {
+        #[doc = concat!("Determinant of ", stringify!($n), "x", stringify!($n), " matrix")]
+        pub fn $det<A: Scalar>(a: &[[A; $n]; $n]) -> A {
+            det_from_adjugate(a, &$adj(a))
+        }
+
+        #[doc = concat!("Inverse of ", stringify!($n), "x", stringify!($n), " matrix")]
+        ///
+        /// `None` is returned if the matrix is nearly singular.
+        pub fn $inv<A: Scalar>(a: &[[A; $n]; $n]) -> Option<[[A; $n]; $n]> {
+            let mut adj = $adj(a);
+            let det = det_from_adjugate(a, &adj);
+            if !is_well_conditioned(a, det) {
+                return None;
+            }
+            for row in adj.iter_mut() {
+                for x in row.iter_mut() {
+                    *x /= det;
+                }
+            }
+            Some(adj)
+        }
+
+        #[doc = concat!("Solve `A * x = b` for ", stringify!($n), "x", stringify!($n), " matrix `A`")]
+        ///
+        /// `None` is returned if the matrix is nearly singular.
+        pub fn $solve<A: Scalar>(a: &[[A; $n]; $n], b: &[A; $n]) -> Option<[A; $n]> {
+            let adj = $adj(a);
+            let det = det_from_adjugate(a, &adj);
+            if !is_well_conditioned(a, det) {
+                return None;
+            }
+            let mut x = [A::zero(); $n];
+            for (xi, row) in x.iter_mut().zip(adj.iter()) {
+                *xi = row.iter().zip(b.iter()).map(|(&r, &b)| r * b).sum::<A>() / det;
+            }
+            Some(x)
+        }
+    };
+}
+
+impl_small!(2, adjugate_2x2, det_2x2, inv_2x2, solve_2x2);
+impl_small!(3, adjugate_3x3, det_3x3, inv_3x3, solve_3x3);
+impl_small!(4, adjugate_4x4, det_4x4, inv_4x4, solve_4x4);
+
+static SMALL_SOLVE_THRESHOLD: AtomicUsize = AtomicUsize::new(4);
+
+/// The largest size of `A` for which [crate::Solve::solve] uses the closed-form solvers
+///
+/// This is `4` by default.
+pub fn small_solve_threshold() -> usize {
+    SMALL_SOLVE_THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Set the largest size of `A` for which [crate::Solve::solve] uses the closed-form solvers
+///
+/// Sizes larger than `4` have no closed-form solver and are same as `4`.
+/// Set `0` to always solve by the LU decomposition, e.g. to get results
+/// which do not depend on the size of the matrix.
+/// This is a process-wide setting.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[3.0, 2.0], [1.0, 2.0]];
+/// let b = array![5.0, 3.0];
+/// set_small_solve_threshold(0);
+/// assert_eq!(a.solve(&b).unwrap(), a.factorize().unwrap().solve(&b).unwrap());
+/// set_small_solve_threshold(4);
+/// ```
+pub fn set_small_solve_threshold(n: usize) {
+    SMALL_SOLVE_THRESHOLD.store(n, Ordering::Relaxed);
+}
+
+/// Solve `A * x = b` in-place by the closed-form solvers if `A` is 2x2, 3x3, or 4x4
+///
+/// Returns `false` without modifying `b` if `A` has another shape or is nearly singular.
+pub(crate) fn solve_small_inplace<A, S, Sb>(
+    a: &ArrayBase<S, Ix2>,
+    b: &mut ArrayBase<Sb, Ix1>,
+) -> bool
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+    Sb: DataMut<Elem = A>,
+{
+    fn to_array<A: Scalar, S: Data<Elem = A>, const N: usize>(
+        a: &ArrayBase<S, Ix2>,
+    ) -> [[A; N]; N] {
+        let mut m = [[A::zero(); N]; N];
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                *x = a[(i, j)];
+            }
+        }
+        m
+    }
+    fn to_vec<A: Scalar, S: Data<Elem = A>, const N: usize>(b: &ArrayBase<S, Ix1>) -> [A; N] {
+        let mut v = [A::zero(); N];
+        for (i, x) in v.iter_mut().enumerate() {
+            *x = b[i];
+        }
+        v
+    }
+
+    if !a.is_square() || a.nrows() != b.len() {
+        return false;
+    }
+    let solved = match a.nrows() {
+        2 => solve_2x2(&to_array(a), &to_vec(b)).map(|x| b.assign(&aview1(&x))),
+        3 => solve_3x3(&to_array(a), &to_vec(b)).map(|x| b.assign(&aview1(&x))),
+        4 => solve_4x4(&to_array(a), &to_vec(b)).map(|x| b.assign(&aview1(&x))),
+        _ => None,
+    };
+    solved.is_some()
+}
+
+/// Solve `A * x = b` by the closed-form solvers if `A` is 2x2, 3x3, or 4x4,
+/// and by the LU decomposition otherwise
+///
+/// This also falls back to the LU decomposition if `A` is nearly singular.
+/// Different from [crate::Solve], this does not depend on [small_solve_threshold].
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[3.0, 2.0], [1.0, 2.0]];
+/// let x = solve_small(&a, &array![5.0, 3.0]).unwrap();
+/// assert_close_l2!(&x, &array![1.0, 1.0], 1e-12);
+/// ```
+pub fn solve_small<A, S, Sb>(a: &ArrayBase<S, Ix2>, b: &ArrayBase<Sb, Ix1>) -> Result<Array1<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    let mut x = b.to_owned();
+    if !solve_small_inplace(a, &mut x) {
+        a.factorize()?.solve_inplace(&mut x)?;
+    }
+    Ok(x)
+}
@@ -43,7 +43,10 @@ use crate::convert::*;
 use crate::error::*;
 use crate::layout::*;
 use crate::opnorm::OperationNorm;
+use crate::small::{small_solve_threshold, solve_small_inplace};
+use crate::triangular::*;
 use crate::types::*;
+use crate::UPLO;
 
 pub use lax::{Pivot, Transpose};
 
@@ -64,6 +67,10 @@ pub use lax::{Pivot, Transpose};
 /// If you plan to solve many equations with the same `A` matrix but different
 /// `b` vectors, it's faster to factor the `A` matrix once using the
 /// `Factorize` trait, and then solve using the `LUFactorized` struct.
+///
+/// For 2x2, 3x3, and 4x4 matrices, `A.solve*` bypasses LAPACK
+/// and uses the closed-form solvers in [crate::small] unless `A` is nearly singular.
+/// This is limited to `n <= small_solve_threshold()`, see [crate::small::set_small_solve_threshold].
 pub trait Solve<A: Scalar> {
     /// Solves a system of linear equations `A * x = b` where `A` is `self`, `b`
     /// is the argument, and `x` is the successful result.
@@ -189,9 +196,9 @@ pub trait Solve<A: Scalar> {
 pub struct LUFactorized<S: Data + RawDataClone> {
     /// The factors `L` and `U`; the unit diagonal elements of `L` are not
     /// stored.
-    a: ArrayBase<S, Ix2>,
+    pub(crate) a: ArrayBase<S, Ix2>,
     /// The pivot indices that define the permutation matrix `P`.
-    ipiv: Pivot,
+    pub(crate) ipiv: Pivot,
 }
 
 impl<A, S> Solve<A> for LUFactorized<S>
@@ -276,6 +283,10 @@ where
     where
         Sb: DataMut<Elem = A>,
     {
+        // Closed-form solution for tiny matrices, see the `small` module
+        if self.nrows() <= small_solve_threshold() && solve_small_inplace(self, rhs) {
+            return Ok(rhs);
+        }
         let f = self.factorize()?;
         f.solve_inplace(rhs)
     }
@@ -338,6 +349,79 @@ where
     }
 }
 
+/// Solve `(A + U V^H) x = b` reusing the LU factorization of `A`
+///
+/// The Sherman-Morrison-Woodbury formula
+///
+/// ```text
+/// (A + U V^H)^{-1} = A^{-1} - A^{-1} U (I + V^H A^{-1} U)^{-1} V^H A^{-1}
+/// ```
+///
+/// reduces the rank-`k` updated system to `k + 1` solves with the factorized `A`
+/// and a dense `k x k` solve of the capacitance matrix `C = I + V^H A^{-1} U`,
+/// where `U` and `V` are `n x k` matrices.
+/// Since `det(A + U V^H) = det(A) det(C)`, the updated matrix is singular
+/// if and only if `C` is, and then an error is returned from the `k x k` solve.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[4.0, 1.0], [1.0, 3.0]];
+/// let u: Array2<f64> = array![[1.0], [0.0]];
+/// let v: Array2<f64> = array![[0.0], [2.0]];
+/// let b: Array1<f64> = array![1.0, 2.0];
+/// let f = a.factorize().unwrap();
+/// let x = woodbury_solve(&f, &u, &v, &b).unwrap();
+/// // A + U V^H = [[4, 3], [1, 3]]
+/// assert!(x.abs_diff_eq(&array![-1.0 / 3.0, 7.0 / 9.0], 1e-12));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `U` and `V` do not have the same shape,
+/// or their number of rows or the length of `b` is not equal to the size of `A`.
+pub fn woodbury_solve<A, S, Su, Sv, Sb>(
+    factored: &LUFactorized<S>,
+    u: &ArrayBase<Su, Ix2>,
+    v: &ArrayBase<Sv, Ix2>,
+    b: &ArrayBase<Sb, Ix1>,
+) -> Result<Array1<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+    Su: Data<Elem = A>,
+    Sv: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    let n = factored.a.nrows();
+    assert_eq!(u.dim(), v.dim(), "`u` and `v` must have the same shape");
+    assert_eq!(
+        u.nrows(),
+        n,
+        "The number of rows of `u` must be equal to the size of `A`"
+    );
+    assert_eq!(
+        b.len(),
+        n,
+        "The length of `b` must be equal to the size of `A`"
+    );
+    let k = u.ncols();
+
+    let y = factored.solve(b)?;
+    // Z = A^{-1} U, column by column
+    let mut z = Array2::<A>::zeros((n, k).f());
+    for (mut zj, uj) in z.axis_iter_mut(Axis(1)).zip(u.axis_iter(Axis(1))) {
+        zj.assign(&uj);
+        factored.solve_inplace(&mut zj)?;
+    }
+    let vh = v.t().mapv(|x| x.conj());
+    let mut c = vh.dot(&z);
+    c.diag_mut().map_inplace(|x| *x += A::one());
+    let w = c.solve_into(vh.dot(&y))?;
+    Ok(y - z.dot(&w))
+}
+
 /// An interface for inverting matrix refs.
 pub trait Inverse {
     type Output;
@@ -468,7 +552,7 @@ pub trait DeterminantInto<A: Scalar>: Sized {
     fn sln_det_into(self) -> Result<(A, A::Real)>;
 }
 
-fn lu_sln_det<'a, A, P, U>(ipiv_iter: P, u_diag_iter: U) -> (A, A::Real)
+pub(crate) fn lu_sln_det<'a, A, P, U>(ipiv_iter: P, u_diag_iter: U) -> (A, A::Real)
 where
     A: Scalar + Lapack,
     P: Iterator<Item = i32>,
@@ -631,3 +715,212 @@ where
         self.factorize_into()?.rcond_into()
     }
 }
+
+/// Structure of a coefficient matrix used to select the linear solver
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixStructure {
+    /// General square matrix, solved by LU decomposition (`*getrf`/`*getrs`)
+    General,
+    /// Triangular matrix, solved by substitution (`*trtrs`) without factorization
+    Triangular { uplo: UPLO, diag: Diag },
+    /// Hermitian (real symmetric) matrix, solved by Bunch-Kaufman decomposition (`*sytrf`/`*hetrf`)
+    /// referring only to the `uplo` triangle
+    Hermitian { uplo: UPLO },
+    /// Hermitian positive definite matrix, solved by Cholesky decomposition (`*potrf`/`*potrs`)
+    /// referring only to the `uplo` triangle
+    PositiveDefinite { uplo: UPLO },
+}
+
+/// Detect the structure of a square matrix
+///
+/// A matrix is regarded as upper (lower) triangular if all elements
+/// below (above) the diagonal are smaller than `tol` in absolute value.
+/// Its diagonal is regarded as unit if all diagonal elements are
+/// within `tol` from one.
+/// Otherwise, it is regarded as Hermitian if `|a_ij - conj(a_ji)| <= tol` for all `i, j`.
+/// Positive definiteness is not detected since it cannot be checked without factorization.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[1.0, 2.0], [0.0, 1.0]];
+/// assert_eq!(
+///     detect_structure(&a, 0.0),
+///     MatrixStructure::Triangular { uplo: UPLO::Upper, diag: Diag::Unit }
+/// );
+/// ```
+pub fn detect_structure<A, S>(a: &ArrayBase<S, Ix2>, tol: A::Real) -> MatrixStructure
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let is_zero = |i: usize, j: usize| a[(i, j)].abs() <= tol;
+    let n = a.nrows();
+    let lower_zero = (0..n).all(|i| (0..i.min(a.ncols())).all(|j| is_zero(i, j)));
+    let upper_zero = (0..n).all(|i| (i + 1..a.ncols()).all(|j| is_zero(i, j)));
+    let uplo = if lower_zero {
+        UPLO::Upper
+    } else if upper_zero {
+        UPLO::Lower
+    } else if is_hermitian(a, tol) {
+        return MatrixStructure::Hermitian { uplo: UPLO::Upper };
+    } else {
+        return MatrixStructure::General;
+    };
+    let diag = if a.diag().iter().all(|d| (*d - A::one()).abs() <= tol) {
+        Diag::Unit
+    } else {
+        Diag::NonUnit
+    };
+    MatrixStructure::Triangular { uplo, diag }
+}
+
+pub(crate) fn is_hermitian<A, S>(a: &ArrayBase<S, Ix2>, tol: A::Real) -> bool
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    a.is_square()
+        && (0..a.nrows())
+            .all(|i| (i..a.ncols()).all(|j| (a[(i, j)] - a[(j, i)].conj()).abs() <= tol))
+}
+
+/// Solve systems of linear equations exploiting the structure of the coefficient matrix
+pub trait SolveStructured<A: Scalar> {
+    /// Solves a system of linear equations `A * x = b` where `A` is `self`
+    ///
+    /// If `structure` is `None`, it is detected by [detect_structure]
+    /// with zero tolerance, i.e. exact triangularity or symmetry is required.
+    /// Triangular matrices are solved by `*trtrs` without LU decomposition.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` is not the equal to the number of columns
+    /// of `A`.
+    fn solve_structured<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+        structure: Option<MatrixStructure>,
+    ) -> Result<Array1<A>>;
+}
+
+impl<A, S> SolveStructured<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solve_structured<Sb: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+        structure: Option<MatrixStructure>,
+    ) -> Result<Array1<A>> {
+        self.ensure_square()?;
+        let structure = structure.unwrap_or_else(|| detect_structure(self, A::Real::zero()));
+        match structure {
+            MatrixStructure::General => self.solve(b),
+            MatrixStructure::Triangular { uplo, diag } => {
+                self.solve_triangular_into(uplo, diag, b.to_owned())
+            }
+            MatrixStructure::Hermitian { .. } | MatrixStructure::PositiveDefinite { .. } => {
+                let mut a: Array2<A> = replicate(self);
+                let mut x: Array1<A> = replicate(b);
+                solve_auto(&mut a, &mut x, Some(structure))?;
+                Ok(x)
+            }
+        }
+    }
+}
+
+/// Solve `A * x = b` in-place by the solver suitable for the structure of `A`
+///
+/// This works like the LAPACK driver routines such as `*gesv`:
+/// `a` is overwritten by its factorization and `b` by the solution `x`.
+/// The solver is selected by `hint`:
+///
+/// | structure                            | routines                        |
+/// |:-------------------------------------|:--------------------------------|
+/// | [MatrixStructure::General]           | `*getrf` + `*getrs` (`*gesv`)   |
+/// | [MatrixStructure::Triangular]        | `*trtrs`                        |
+/// | [MatrixStructure::Hermitian]         | `*sytrf` + `*sytrs` (`*sysv`)   |
+/// | [MatrixStructure::PositiveDefinite]  | `*potrf` + `*potrs` (`*posv`)   |
+///
+/// If `hint` is `None`, the structure is detected by [detect_structure] with zero tolerance.
+/// A Hermitian matrix with positive diagonal elements is tried by Cholesky decomposition first,
+/// and falls back to Bunch-Kaufman decomposition if it turns out not to be positive definite.
+/// The structure actually used is returned.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let mut a: Array2<f64> = array![[4.0, 1.0], [1.0, 3.0]];
+/// let mut b: Array1<f64> = array![1.0, 2.0];
+/// let structure = solve_auto(&mut a, &mut b, None).unwrap();
+/// assert_eq!(structure, MatrixStructure::PositiveDefinite { uplo: UPLO::Upper });
+/// assert!(b.abs_diff_eq(&array![1.0 / 11.0, 7.0 / 11.0], 1e-12));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the length of `b` is not equal to the size of `A`.
+pub fn solve_auto<A, S, Sb>(
+    a: &mut ArrayBase<S, Ix2>,
+    b: &mut ArrayBase<Sb, Ix1>,
+    hint: Option<MatrixStructure>,
+) -> Result<MatrixStructure>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+    Sb: DataMut<Elem = A>,
+{
+    a.ensure_square()?;
+    let n = a.nrows();
+    assert_eq!(
+        b.len(),
+        n,
+        "The length of `b` must be equal to the size of `A`."
+    );
+    let l = a.square_layout()?;
+    let structure = match hint {
+        Some(structure) => structure,
+        None => match detect_structure(a, A::Real::zero()) {
+            MatrixStructure::Hermitian { uplo }
+                if a.diag().iter().all(|d| d.re() > A::Real::zero()) =>
+            {
+                // Cholesky decomposition may fail halfway, so keep the original for the fallback
+                let original = a.to_owned();
+                if A::cholesky(l, uplo, a.as_allocated_mut()?).is_ok() {
+                    A::solve_cholesky(l, uplo, a.as_allocated()?, b.as_slice_mut().unwrap())?;
+                    return Ok(MatrixStructure::PositiveDefinite { uplo });
+                }
+                a.assign(&original);
+                MatrixStructure::Hermitian { uplo }
+            }
+            structure => structure,
+        },
+    };
+    let x = b.as_slice_mut().unwrap();
+    match structure {
+        MatrixStructure::General => {
+            let ipiv = A::lu(l, a.as_allocated_mut()?)?;
+            A::solve(l, Transpose::No, a.as_allocated()?, &ipiv, x)?;
+        }
+        MatrixStructure::Triangular { uplo, diag } => {
+            let lb = MatrixLayout::F {
+                col: 1,
+                lda: n as i32,
+            };
+            A::solve_triangular(l, lb, uplo, diag, a.as_allocated()?, x)?;
+        }
+        MatrixStructure::Hermitian { uplo } => {
+            let ipiv = A::bk(l, uplo, a.as_allocated_mut()?)?;
+            A::solveh(l, uplo, a.as_allocated()?, &ipiv, x)?;
+        }
+        MatrixStructure::PositiveDefinite { uplo } => {
+            A::cholesky(l, uplo, a.as_allocated_mut()?)?;
+            A::solve_cholesky(l, uplo, a.as_allocated()?, x)?;
+        }
+    }
+    Ok(structure)
+}
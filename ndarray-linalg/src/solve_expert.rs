@@ -0,0 +1,316 @@
+//! Solve linear equations accurately using the expert driver of LAPACK
+//!
+//! An existing factorization can also improve its solution by iterative refinement with [SolveRefined].
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![[3., 2., -1.], [2., -2., 4.], [-2., 1., -2.]];
+//! let b: Array1<f64> = array![1., -2., 0.];
+//! let (x, diag) = solve_accurate(&a, &b).unwrap();
+//! assert!(x.abs_diff_eq(&array![1., -2., -2.], 1e-9));
+//! assert!(diag.rcond > 0.0);
+//! ```
+
+use ndarray::*;
+
+use crate::cholesky::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::solve::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// Diagnostics of the linear solver returned with the solution
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolveDiagnostics<A: Scalar> {
+    /// Estimate of the reciprocal condition number of the (equilibrated) matrix in 1-norm
+    pub rcond: A::Real,
+    /// Estimated bound of the relative forward error `max|x - x_true| / max|x|`
+    pub forward_error: A::Real,
+    /// Componentwise relative backward error of the solution
+    pub backward_error: A::Real,
+    /// Whether the matrix has been equilibrated before factorization
+    pub equilibrated: bool,
+}
+
+/// Solve `A * x = b` as accurately as possible
+///
+/// This runs the expert driver of LAPACK (`*gesvx`), which equilibrates `A` if it is badly scaled,
+/// computes its LU factorization, solves the system,
+/// and then improves the solution by iterative refinement.
+/// It is slower than [crate::Solve], but the solution is returned
+/// with the estimated condition number and error bounds as [SolveDiagnostics].
+///
+/// # Panics
+///
+/// Panics if the length of `b` is not the equal to the number of columns of `A`.
+pub fn solve_accurate<A, Sa, Sb>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix1>,
+) -> Result<(Array1<A>, SolveDiagnostics<A>)>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    let mut a = a.to_owned();
+    let mut b = b.to_owned();
+    assert_eq!(a.ncols(), b.len());
+    let l = a.square_layout()?;
+    let lax::SolveExpertOwned {
+        x,
+        rcond,
+        ferr,
+        berr,
+        equilibrated,
+    } = A::solve_expert(
+        l,
+        a.as_allocated_mut()?,
+        b.as_slice_memory_order_mut().unwrap(),
+    )?;
+    Ok((
+        Array1::from(x),
+        SolveDiagnostics {
+            rcond,
+            forward_error: ferr,
+            backward_error: berr,
+            equilibrated,
+        },
+    ))
+}
+
+/// Solve `A * x = b` for a Hermitian (or real symmetric) positive definite `A` as accurately as possible
+///
+/// This is [solve_accurate] using the Cholesky decomposition (`*posvx`),
+/// where only the `uplo` part of `A` is referenced.
+/// [LinalgError::Lapack] is returned if `A` is not positive definite.
+///
+/// # Panics
+///
+/// Panics if the length of `b` is not the equal to the number of columns of `A`.
+pub fn solvec_accurate<A, Sa, Sb>(
+    a: &ArrayBase<Sa, Ix2>,
+    uplo: UPLO,
+    b: &ArrayBase<Sb, Ix1>,
+) -> Result<(Array1<A>, SolveDiagnostics<A>)>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    assert_eq!(a.ncols(), b.len());
+    let n = a.square_layout()?.len();
+    // `*posvx` requires the column-major order
+    let mut af = Array2::zeros(a.dim().f());
+    af.assign(a);
+    let mut b = b.to_owned();
+    let lax::SolveExpertOwned {
+        x,
+        rcond,
+        ferr,
+        berr,
+        equilibrated,
+    } = A::solvec_expert(
+        uplo,
+        n,
+        af.as_allocated_mut()?,
+        b.as_slice_memory_order_mut().unwrap(),
+    )?;
+    Ok((
+        Array1::from(x),
+        SolveDiagnostics {
+            rcond,
+            forward_error: ferr,
+            backward_error: berr,
+            equilibrated,
+        },
+    ))
+}
+
+/// Solve `A * x = b` by the LU decomposition in single precision refined to double precision
+///
+/// This runs the mixed precision driver of LAPACK (`dsgesv` or `zcgesv`),
+/// which is about twice as fast as [crate::Solve] for large well-conditioned matrices.
+/// The number of the refinement iterations is returned with the solution,
+/// or `None` if the refinement did not converge and the system has been solved in double precision.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[3., 2., -1.], [2., -2., 4.], [-2., 1., -2.]];
+/// let b: Array1<f64> = array![1., -2., 0.];
+/// let (x, _iter) = solve_mixed_precision(&a, &b).unwrap();
+/// assert_close_l2!(&x, &array![1., -2., -2.], 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the length of `b` is not the equal to the number of columns of `A`.
+pub fn solve_mixed_precision<A, Sa, Sb>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix1>,
+) -> Result<(Array1<A>, Option<usize>)>
+where
+    A: Scalar + Lapack + lax::solve_mixed::SolveMixedPrecisionImpl,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    assert_eq!(a.ncols(), b.len());
+    let n = a.square_layout()?.len();
+    // `*sgesv` requires the column-major order
+    let mut af = Array2::zeros(a.dim().f());
+    af.assign(a);
+    let b: Vec<A> = b.iter().cloned().collect();
+    let (x, iter) = A::solve_mixed_precision(n, af.as_allocated_mut()?, &b)?;
+    Ok((Array1::from(x), (iter >= 0).then_some(iter as usize)))
+}
+
+/// Error estimates of the solution improved by iterative refinement
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ErrorBounds<A: Scalar> {
+    /// Estimated bound of the relative forward error `max|x - x_true| / max|x|`
+    pub forward_error: A::Real,
+    /// Componentwise relative backward error of the solution
+    pub backward_error: A::Real,
+}
+
+/// Solve linear equations with a stored factorization followed by iterative refinement
+///
+/// The residual `b - A x` is computed with the original matrix `A`,
+/// and the correction is solved with the factorization (`*gerfs` or `*porfs`).
+/// This costs only `O(n^2)` operations per iteration in addition to the plain solve,
+/// and substantially improves the accuracy for ill-conditioned systems.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[4., 1.], [1., 3.]];
+/// let f = a.factorize().unwrap();
+/// let b = array![1., 2.];
+/// let (x, err) = f.solve_refined(&a, &b).unwrap();
+/// assert_close_l2!(&a.dot(&x), &b, 1e-12);
+/// assert!(err.backward_error < 1e-12);
+/// ```
+pub trait SolveRefined<A: Scalar> {
+    /// Solves `A x = b` and refines `x`, where `a` is the matrix factorized into `self`
+    fn solve_refined<Sa, Sb>(
+        &self,
+        a: &ArrayBase<Sa, Ix2>,
+        b: &ArrayBase<Sb, Ix1>,
+    ) -> Result<(Array1<A>, ErrorBounds<A>)>
+    where
+        Sa: Data<Elem = A>,
+        Sb: Data<Elem = A>;
+}
+
+impl<A, S> SolveRefined<A> for LUFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+{
+    fn solve_refined<Sa, Sb>(
+        &self,
+        a: &ArrayBase<Sa, Ix2>,
+        b: &ArrayBase<Sb, Ix1>,
+    ) -> Result<(Array1<A>, ErrorBounds<A>)>
+    where
+        Sa: Data<Elem = A>,
+        Sb: Data<Elem = A>,
+    {
+        let l = self.a.square_layout()?;
+        check_shape(&self.a, a, b)?;
+        let a = copy_with_layout(a, l);
+        let b: Array1<A> = b.iter().cloned().collect();
+        let mut x = b.clone();
+        let af = self.a.as_allocated()?;
+        A::solve(l, Transpose::No, af, &self.ipiv, x.as_slice_mut().unwrap())?;
+        let (forward_error, backward_error) = A::refine(
+            l,
+            Transpose::No,
+            a.as_allocated()?,
+            af,
+            &self.ipiv,
+            b.as_slice().unwrap(),
+            x.as_slice_mut().unwrap(),
+        )?;
+        Ok((
+            x,
+            ErrorBounds {
+                forward_error,
+                backward_error,
+            },
+        ))
+    }
+}
+
+impl<A, S> SolveRefined<A> for CholeskyFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    /// Solves `A x = b` and refines `x`, where only the `uplo` part of `a` is referenced
+    fn solve_refined<Sa, Sb>(
+        &self,
+        a: &ArrayBase<Sa, Ix2>,
+        b: &ArrayBase<Sb, Ix1>,
+    ) -> Result<(Array1<A>, ErrorBounds<A>)>
+    where
+        Sa: Data<Elem = A>,
+        Sb: Data<Elem = A>,
+    {
+        let l = self.factor.square_layout()?;
+        check_shape(&self.factor, a, b)?;
+        let a = copy_with_layout(a, l);
+        let b: Array1<A> = b.iter().cloned().collect();
+        let mut x = b.clone();
+        self.solvec_inplace(&mut x)?;
+        let (forward_error, backward_error) = A::refine_cholesky(
+            l,
+            self.uplo,
+            a.as_allocated()?,
+            self.factor.as_allocated()?,
+            b.as_slice().unwrap(),
+            x.as_slice_mut().unwrap(),
+        )?;
+        Ok((
+            x,
+            ErrorBounds {
+                forward_error,
+                backward_error,
+            },
+        ))
+    }
+}
+
+fn check_shape<A, Sf, Sa, Sb>(
+    factor: &ArrayBase<Sf, Ix2>,
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix1>,
+) -> Result<()>
+where
+    Sf: Data<Elem = A>,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    if a.dim() != factor.dim() || b.len() != factor.nrows() {
+        return Err(LinalgError::Shape(ShapeError::from_kind(
+            ErrorKind::IncompatibleShape,
+        )));
+    }
+    Ok(())
+}
+
+/// Copy of `a` in the same memory order as the factorization
+fn copy_with_layout<A, S>(a: &ArrayBase<S, Ix2>, l: MatrixLayout) -> Array2<A>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let mut c = Array2::zeros(a.dim().set_f(matches!(l, MatrixLayout::F { .. })));
+    c.assign(a);
+    c
+}
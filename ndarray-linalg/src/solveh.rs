@@ -347,16 +347,7 @@ where
             debug_assert_eq!(lower_diag.im(), Zero::zero());
 
             // Off-diagonal elements, can be complex.
-            let off_diag = match layout {
-                MatrixLayout::C { .. } => match uplo {
-                    UPLO::Upper => unsafe { a.uget((k + 1, k)) },
-                    UPLO::Lower => unsafe { a.uget((k, k + 1)) },
-                },
-                MatrixLayout::F { .. } => match uplo {
-                    UPLO::Upper => unsafe { a.uget((k, k + 1)) },
-                    UPLO::Lower => unsafe { a.uget((k + 1, k)) },
-                },
-            };
+            let off_diag = bk_off_diag(uplo, layout, a, k);
 
             // Determinant of 2x2 block.
             let block_det = upper_diag * lower_diag - off_diag.square();
@@ -370,6 +361,67 @@ where
     (sign, ln_det)
 }
 
+/// Off-diagonal element of the 2x2 block of `D` at `k..k+2` in the Bunch-Kaufman factorization
+fn bk_off_diag<S, A>(uplo: UPLO, layout: MatrixLayout, a: &ArrayBase<S, Ix2>, k: usize) -> A
+where
+    S: Data<Elem = A>,
+    A: Scalar,
+{
+    match layout {
+        MatrixLayout::C { .. } => match uplo {
+            UPLO::Upper => a[(k + 1, k)],
+            UPLO::Lower => a[(k, k + 1)],
+        },
+        MatrixLayout::F { .. } => match uplo {
+            UPLO::Upper => a[(k, k + 1)],
+            UPLO::Lower => a[(k + 1, k)],
+        },
+    }
+}
+
+/// Returns the numbers of positive, negative, and zero eigenvalues from the diagonal blocks of `D`
+fn bk_inertia<S, A>(uplo: UPLO, ipiv: &[i32], a: &ArrayBase<S, Ix2>) -> (usize, usize, usize)
+where
+    S: Data<Elem = A>,
+    A: Scalar + Lapack,
+{
+    let layout = a.layout().unwrap();
+    let (mut pos, mut neg, mut zero) = (0, 0, 0);
+    let mut count = |x: A::Real| {
+        if x > A::Real::zero() {
+            pos += 1;
+        } else if x < A::Real::zero() {
+            neg += 1;
+        } else {
+            zero += 1;
+        }
+    };
+    let mut k = 0;
+    while k < ipiv.len() {
+        if ipiv[k] > 0 {
+            count(a[(k, k)].re());
+            k += 1;
+        } else {
+            // The eigenvalues of the 2x2 block have the signs determined by its determinant and trace
+            let (d1, d2) = (a[(k, k)].re(), a[(k + 1, k + 1)].re());
+            let det = d1 * d2 - bk_off_diag(uplo, layout, a, k).square();
+            if det < A::Real::zero() {
+                count(A::Real::one());
+                count(-A::Real::one());
+            } else {
+                count(d1 + d2);
+                count(if det > A::Real::zero() {
+                    d1 + d2
+                } else {
+                    A::Real::zero()
+                });
+            }
+            k += 2;
+        }
+    }
+    (pos, neg, zero)
+}
+
 impl<A, S> BKFactorized<S>
 where
     A: Scalar + Lapack,
@@ -399,6 +451,17 @@ where
         bk_sln_det(UPLO::Upper, self.ipiv.iter().cloned(), &self.a)
     }
 
+    /// Computes the inertia of the factorized Hermitian (or real symmetric)
+    /// matrix, i.e. the numbers of positive, negative, and zero eigenvalues.
+    ///
+    /// By Sylvester's law of inertia, they are counted from the 1x1 and 2x2
+    /// diagonal blocks of `D` without computing the eigenvalues.
+    /// Zero eigenvalues are counted only if the corresponding pivots are
+    /// exactly zero.
+    pub fn inertia(&self) -> (usize, usize, usize) {
+        bk_inertia(UPLO::Upper, &self.ipiv, &self.a)
+    }
+
     /// Computes the determinant of the factorized Hermitian (or real
     /// symmetric) matrix.
     pub fn deth_into(self) -> A::Real {
@@ -0,0 +1,94 @@
+//! Matrix square root
+//!
+//! The principal square root `A^(1/2)`, whose eigenvalues have non-negative real parts,
+//! is computed by the Schur method of Björck and Hammarling:
+//! `A = Z T Z^H` is reduced into the complex upper triangular `T`,
+//! and the upper triangular `U` satisfying `U^2 = T` is determined element by element.
+//! Hermitian matrices are diagonalized by [Eigh] instead.
+//!
+//! - N. J. Higham, "Computing real square roots of a real matrix",
+//!   Linear Algebra Appl. 88/89, 1987.
+
+use ndarray::*;
+use num_traits::Zero;
+
+use crate::eigh::*;
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::schur::complex_schur;
+use crate::solve::is_hermitian;
+use crate::types::*;
+use crate::UPLO;
+
+/// Principal square root of a square matrix
+///
+/// The result is complex even for real matrices, since the square root of a real matrix
+/// with negative eigenvalues is not real.
+/// For positive definite matrices [SymmetricSqrt] returns a real result.
+/// [LinalgError::Lapack] is returned if the square root does not exist,
+/// e.g. for a nilpotent Jordan block.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[4.0, 1.0], [0.0, 9.0]];
+/// let s = a.sqrtm().unwrap();
+/// assert_close_l2!(&s.dot(&s), &a.mapv(|x| x.as_c()), 1e-12);
+/// ```
+pub trait Sqrtm {
+    type Output;
+    fn sqrtm(&self) -> Result<Self::Output>;
+}
+
+impl<A, S> Sqrtm for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    A::Complex: Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A::Complex>;
+
+    fn sqrtm(&self) -> Result<Self::Output> {
+        if is_hermitian(self, A::Real::zero()) {
+            let (e, v) = self.eigh(UPLO::Lower)?;
+            let v = v.mapv(|x| x.as_c());
+            let e_sqrt = e.mapv(|e| A::complex(e, A::Real::zero()).sqrt());
+            let vh: Array2<A::Complex> = conjugate(&v);
+            return Ok((&v * &e_sqrt).dot(&vh));
+        }
+        let (t, z) = complex_schur(self)?;
+        let u = sqrtm_triangular(&t)?;
+        Ok(z.dot(&u).dot(&conjugate::<_, _, OwnedRepr<_>>(&z)))
+    }
+}
+
+/// Principal square root of an upper triangular matrix (Björck-Hammarling method)
+///
+/// Fails if two diagonal elements are zero and the element between them cannot be eliminated.
+pub(crate) fn sqrtm_triangular<T: Scalar>(t: &Array2<T>) -> Result<Array2<T>> {
+    let n = t.nrows();
+    let mut u = Array2::zeros((n, n));
+    for j in 0..n {
+        u[(j, j)] = t[(j, j)].sqrt();
+        for i in (0..j).rev() {
+            let mut s = t[(i, j)];
+            for k in (i + 1)..j {
+                s -= u[(i, k)] * u[(k, j)];
+            }
+            let d = u[(i, i)] + u[(j, j)];
+            u[(i, j)] = if !d.is_zero() {
+                s / d
+            } else if s.is_zero() {
+                T::zero()
+            } else {
+                return Err(LinalgError::Lapack(
+                    lax::error::Error::LapackComputationalFailure {
+                        return_code: j as i32 + 1,
+                    },
+                ));
+            };
+        }
+    }
+    Ok(u)
+}
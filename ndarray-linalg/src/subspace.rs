@@ -0,0 +1,212 @@
+//! Numerical rank, and orthonormal bases of the null space and the column space
+//!
+//! For a `m x n` matrix `A` with the SVD `A = U S V^H` and the numerical rank `r`,
+//! i.e. the number of singular values larger than `tol`,
+//! the leading `r` columns of `U` span the column space (range) of `A`,
+//! and the trailing `n - r` columns of `V` span the null space of `A`,
+//! which consists of the solutions of the homogeneous equation `A x = 0`.
+//! The column space is also obtained by the QR decomposition with column pivoting,
+//! which is faster but less reliable to determine the rank.
+//!
+//! [orth] returns an orthonormal basis of the column space by the QR decomposition without pivoting
+//! if `A` clearly has full column rank, and falls back to the SVD otherwise.
+//!
+//! The default tolerance of [Rank] and [orth] is `max(m, n) * eps * s_max` as MATLAB and NumPy,
+//! where `s_max` is the largest singular value.
+//!
+//! - G. H. Golub and C. F. Van Loan, "Matrix Computations", 4th ed., Section 2.4.
+
+use ndarray::*;
+use num_traits::{Float, Zero};
+
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::qr::*;
+use crate::svd::*;
+use crate::types::*;
+
+/// Null space and column space of matrix reference
+///
+/// The bases are returned with the numerical rank of `A`,
+/// where the singular values (or the diagonal elements of `R` for [Subspace::column_space_qr])
+/// not larger than `tol` are regarded as zero.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Rank 1 matrix
+/// let a: Array2<f64> = array![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+/// let (ns, rank) = a.null_space(1e-10).unwrap();
+/// assert_eq!(rank, 1);
+/// assert_eq!(ns.dim(), (2, 1));
+/// assert!(a.dot(&ns).iter().all(|x| x.abs() < 1e-12));
+/// let (cs, _) = a.column_space(1e-10).unwrap();
+/// assert_eq!(cs.dim(), (3, 1));
+/// ```
+pub trait Subspace {
+    type Elem: Scalar;
+    /// `n x (n - rank)` matrix whose columns are an orthonormal basis of the null space
+    fn null_space(&self, tol: <Self::Elem as Scalar>::Real) -> Result<(Array2<Self::Elem>, usize)>;
+    /// `m x rank` matrix whose columns are an orthonormal basis of the column space using the SVD
+    fn column_space(
+        &self,
+        tol: <Self::Elem as Scalar>::Real,
+    ) -> Result<(Array2<Self::Elem>, usize)>;
+    /// `m x rank` matrix whose columns are an orthonormal basis of the column space
+    /// using the QR decomposition with column pivoting
+    fn column_space_qr(
+        &self,
+        tol: <Self::Elem as Scalar>::Real,
+    ) -> Result<(Array2<Self::Elem>, usize)>;
+}
+
+impl<A, S> Subspace for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn null_space(&self, tol: A::Real) -> Result<(Array2<A>, usize)> {
+        let (m, n) = self.dim();
+        if m == 0 || n == 0 {
+            return Ok((Array2::eye(n), 0));
+        }
+        let (_, s, vt) = self.svd(false, true)?;
+        let rank = rank_from_singular_values(s.as_slice().unwrap(), tol, false);
+        let vt = vt.unwrap();
+        Ok((conjugate(&vt.slice(s![rank.., ..])), rank))
+    }
+
+    fn column_space(&self, tol: A::Real) -> Result<(Array2<A>, usize)> {
+        let (m, n) = self.dim();
+        if m == 0 || n == 0 {
+            return Ok((Array2::zeros((m, 0)), 0));
+        }
+        let (u, s, _) = self.svd_thin(true, false)?;
+        let rank = rank_from_singular_values(s.as_slice().unwrap(), tol, false);
+        Ok((u.unwrap().slice(s![.., ..rank]).to_owned(), rank))
+    }
+
+    fn column_space_qr(&self, tol: A::Real) -> Result<(Array2<A>, usize)> {
+        let qrp = qr_pivoted(self, PivotRule::Largest)?;
+        let rank = qrp.rank(tol);
+        Ok((qrp.q.slice(s![.., ..rank]).to_owned(), rank))
+    }
+}
+
+/// Numerical rank of matrix reference
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+/// assert_eq!(a.rank().unwrap(), 1);
+/// assert_eq!(a.rank_qr().unwrap(), 1);
+/// assert_eq!(Array2::<f64>::eye(3).rank_with_tol(0.5).unwrap(), 3);
+/// ```
+pub trait Rank {
+    type Real;
+    /// Number of the singular values larger than `max(m, n) * eps * s_max`
+    fn rank(&self) -> Result<usize>;
+    /// Number of the singular values larger than `tol`
+    fn rank_with_tol(&self, tol: Self::Real) -> Result<usize>;
+    /// Number of the diagonal elements of `R` of the QR decomposition with column pivoting
+    /// larger than `max(m, n) * eps * |R_11|` in magnitude
+    ///
+    /// This is faster than [Rank::rank] but may overestimate the rank of nearly rank-deficient matrices.
+    fn rank_qr(&self) -> Result<usize>;
+}
+
+impl<A, S> Rank for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Real = A::Real;
+
+    fn rank(&self) -> Result<usize> {
+        let s = singular_values(self)?;
+        let tol = default_tol::<A>(self.dim(), s.first().cloned());
+        Ok(rank_from_singular_values(&s, tol, false))
+    }
+
+    fn rank_with_tol(&self, tol: A::Real) -> Result<usize> {
+        let s = singular_values(self)?;
+        Ok(rank_from_singular_values(&s, tol, false))
+    }
+
+    fn rank_qr(&self) -> Result<usize> {
+        let qrp = qr_pivoted(self, PivotRule::Largest)?;
+        let r11 = qrp.r.diag().first().map(|x| x.abs());
+        Ok(qrp.rank(default_tol::<A>(self.dim(), r11)))
+    }
+}
+
+/// Orthonormal basis of the column space of `A`
+///
+/// If `A` has at least as many rows as columns and all the diagonal elements of `R`
+/// of the QR decomposition `A = Q R` are larger than `sqrt(eps) * max|R_ii|` in magnitude,
+/// `A` clearly has full column rank and `Q` is returned.
+/// Otherwise, since the QR decomposition without pivoting does not reveal the rank reliably,
+/// the leading columns of `U` of the SVD are returned
+/// for the singular values larger than `max(m, n) * eps * s_max`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[1.0, 1.0], [1.0, 0.0], [0.0, 1.0]];
+/// let q = orth(&a).unwrap();
+/// assert_eq!(q.dim(), (3, 2));
+/// assert_close_l2!(&q.t().dot(&q), &Array2::eye(2), 1e-12);
+///
+/// // Rank deficient
+/// let a: Array2<f64> = array![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+/// assert_eq!(orth(&a).unwrap().dim(), (3, 1));
+/// ```
+pub fn orth<A, S>(a: &ArrayBase<S, Ix2>) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let (m, n) = a.dim();
+    if m == 0 || n == 0 {
+        return Ok(Array2::zeros((m, 0)));
+    }
+    if m >= n {
+        let (q, r) = a.qr()?;
+        let d: Vec<A::Real> = r.diag().iter().map(|x| x.abs()).collect();
+        let d_max = d.iter().fold(A::Real::zero(), |acc, &x| acc.max(x));
+        let tol = Float::sqrt(A::Real::epsilon()) * d_max;
+        if d.iter().all(|&x| x > tol) {
+            return Ok(q);
+        }
+    }
+    let (u, s, _) = a.svd_thin(true, false)?;
+    let tol = default_tol::<A>((m, n), s.first().cloned());
+    let rank = rank_from_singular_values(s.as_slice().unwrap(), tol, false);
+    Ok(u.unwrap().slice(s![.., ..rank]).to_owned())
+}
+
+/// Singular values in descending order
+fn singular_values<A, S>(a: &ArrayBase<S, Ix2>) -> Result<Vec<A::Real>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let (m, n) = a.dim();
+    if m == 0 || n == 0 {
+        return Ok(Vec::new());
+    }
+    let (_, s, _) = a.svd(false, false)?;
+    Ok(s.to_vec())
+}
+
+/// `max(m, n) * eps * s_max`
+fn default_tol<A: Scalar>((m, n): (usize, usize), s_max: Option<A::Real>) -> A::Real {
+    let s_max = s_max.unwrap_or_else(A::Real::zero);
+    A::real(m.max(n)) * A::Real::epsilon() * s_max
+}
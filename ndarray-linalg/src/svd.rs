@@ -1,10 +1,20 @@
 //! Singular-value decomposition (SVD)
 //!
 //! [Wikipedia article on SVD](https://en.wikipedia.org/wiki/Singular_value_decomposition)
+//!
+//! The traits in this module use the QR iteration driver `*gesvd`.
+//! The divide-and-conquer driver `*gesdd`, which is much faster for large matrices,
+//! is available through [SVDDC](crate::svddc::SVDDC),
+//! and [svd_auto] selects one of them by the size of the matrix.
 
-use crate::{convert::*, error::*, layout::*, types::*};
+use crate::{convert::*, error::*, generate::conjugate, layout::*, types::*};
+use lax::svd::{SvdWork, SvdWorkImpl};
+use lax::JobSvd;
 use ndarray::*;
 
+pub use lax::svd::{svd_auto, svd_with_driver, SvdDriver};
+pub use lax::SingularRange;
+
 /// singular-value decomposition of matrix reference
 pub trait SVD {
     type U;
@@ -104,3 +114,270 @@ where
         Ok((u, s, vt))
     }
 }
+
+/// Thin singular-value decomposition of matrix reference
+///
+/// For a `m x n` matrix, `U` is `m x k` and `V^T` is `k x n` where `k = min(m, n)`,
+/// instead of the square matrices returned by [SVD::svd].
+/// This saves memory and time for tall-skinny or short-wide matrices.
+pub trait SVDThin {
+    type U;
+    type VT;
+    type Sigma;
+    fn svd_thin(
+        &self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)>;
+}
+
+impl<A, S> SVDThin for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type U = Array2<A>;
+    type VT = Array2<A>;
+    type Sigma = Array1<A::Real>;
+
+    fn svd_thin(
+        &self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        let mut a = self.to_owned();
+        let l = a.layout()?;
+        let svd_res = A::svd_thin(l, calc_u, calc_vt, a.as_allocated_mut()?)?;
+        let (m, n) = l.size();
+        let k = m.min(n);
+
+        let u = svd_res.u.map(|u| into_matrix(l.resized(m, k), u).unwrap());
+        let vt = svd_res
+            .vt
+            .map(|vt| into_matrix(l.resized(k, n), vt).unwrap());
+        let s = ArrayBase::from(svd_res.s);
+        Ok((u, s, vt))
+    }
+}
+
+/// Workspace of the thin SVD reused for `m x n` matrices of the same shape
+///
+/// The copy of the input, the LAPACK workspace, and the factors `U`, `S`, `V^H`
+/// are allocated once in [SvdThinWork::new], and overwritten by each [SvdThinWork::calc].
+/// This is used by [crate::pinv_with_work] and [crate::polar_with_work].
+pub struct SvdThinWork<A: Scalar> {
+    a: Array2<A>,
+    svd: SvdWork<A>,
+}
+
+impl<A> SvdThinWork<A>
+where
+    A: Scalar + Lapack,
+    SvdWork<A>: SvdWorkImpl<Elem = A>,
+{
+    pub fn new(m: usize, n: usize) -> Result<Self> {
+        let a = Array2::zeros((m, n).f());
+        let svd = SvdWork::new_with_job(a.layout()?, JobSvd::Some, JobSvd::Some)?;
+        Ok(SvdThinWork { a, svd })
+    }
+
+    /// Shape `(m, n)` of the matrices this workspace accepts
+    pub fn dim(&self) -> (usize, usize) {
+        self.a.dim()
+    }
+
+    /// Compute `U` (`m x k`), `S`, and `V^H` (`k x n`) for `k = min(m, n)`
+    /// as the views into the workspace
+    pub fn calc<S>(
+        &mut self,
+        a: &ArrayBase<S, Ix2>,
+    ) -> Result<(
+        ArrayView2<'_, A>,
+        ArrayView1<'_, A::Real>,
+        ArrayView2<'_, A>,
+    )>
+    where
+        S: Data<Elem = A>,
+    {
+        if a.dim() != self.a.dim() {
+            return Err(LinalgError::Shape(ShapeError::from_kind(
+                ErrorKind::IncompatibleShape,
+            )));
+        }
+        let (m, n) = a.dim();
+        let k = m.min(n);
+        self.a.assign(a);
+        let res = self.svd.calc(self.a.as_slice_memory_order_mut().unwrap())?;
+        let u = ArrayView2::from_shape((m, k).f(), res.u.unwrap())?;
+        let vt = ArrayView2::from_shape((k, n).f(), res.vt.unwrap())?;
+        Ok((u, ArrayView1::from(res.s), vt))
+    }
+}
+
+/// Thin singular-value decomposition of matrix reference by one-sided Jacobi method (`*gesvj`)
+///
+/// The output is same as [SVDThin::svd_thin], i.e. `U` is `m x k` and `V^T` is `k x n` where `k = min(m, n)`.
+/// This is slower than [SVD::svd], but small singular values are computed
+/// with high relative accuracy even for badly scaled matrices,
+/// e.g. `A = B D` for a well-conditioned `B` and a diagonal `D` with widely varying entries.
+pub trait SVDJacobi {
+    type U;
+    type VT;
+    type Sigma;
+    fn svd_jacobi(
+        &self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)>;
+}
+
+impl<A, S> SVDJacobi for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type U = Array2<A>;
+    type VT = Array2<A>;
+    type Sigma = Array1<A::Real>;
+
+    fn svd_jacobi(
+        &self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        let (m, n) = self.dim();
+        if m < n {
+            // `*gesvj` requires `m >= n`, so decompose `A^H = V S U^H` instead
+            let ah: Array2<A> = conjugate(self);
+            let (v, s, uh) = ah.svd_jacobi(calc_vt, calc_u)?;
+            return Ok((uh.map(|uh| conjugate(&uh)), s, v.map(|v| conjugate(&v))));
+        }
+        let mut a = Array2::zeros((m, n).f());
+        a.assign(self);
+        let l = a.layout()?;
+        let svd_res = A::svd_jacobi(l, calc_u, calc_vt, a.as_allocated_mut()?)?;
+        let u = svd_res.u.map(|u| into_matrix(l, u).unwrap());
+        let vt = svd_res
+            .vt
+            .map(|vt| into_matrix(l.resized(n as i32, n as i32), vt).unwrap());
+        Ok((u, ArrayBase::from(svd_res.s), vt))
+    }
+}
+
+/// Singular-value decomposition only for a subset of singular values (`*gesvdx`)
+///
+/// Computing only a few singular values, e.g. the largest ones by [SingularRange::Indices],
+/// is cheaper than the full decomposition by [SVD::svd].
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = random((100, 80));
+/// // Ten largest singular values
+/// let s = a.svd_select(SingularRange::Indices(0..10)).unwrap();
+/// assert_eq!(s.len(), 10);
+/// // Singular values in (1.0, 2.0] and corresponding left singular vectors
+/// let (u, s, _) = a
+///     .svd_select_with_vectors(true, false, SingularRange::Values { lower: 1.0, upper: 2.0 })
+///     .unwrap();
+/// assert_eq!(u.unwrap().dim(), (100, s.len()));
+/// ```
+pub trait SVDSelect {
+    type U;
+    type VT;
+    type Sigma;
+    type Real;
+    /// Compute the singular values in `range` in descending order
+    fn svd_select(&self, range: SingularRange<Self::Real>) -> Result<Self::Sigma>;
+    /// Compute the singular values in `range`, and corresponding singular vectors if requested
+    ///
+    /// For `ns` singular values found in `range`, `U` is `m x ns` and `V^T` is `ns x n`.
+    fn svd_select_with_vectors(
+        &self,
+        calc_u: bool,
+        calc_vt: bool,
+        range: SingularRange<Self::Real>,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)>;
+}
+
+impl<A, S> SVDSelect for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type U = Array2<A>;
+    type VT = Array2<A>;
+    type Sigma = Array1<A::Real>;
+    type Real = A::Real;
+
+    fn svd_select(&self, range: SingularRange<A::Real>) -> Result<Self::Sigma> {
+        let (_, s, _) = self.svd_select_with_vectors(false, false, range)?;
+        Ok(s)
+    }
+
+    fn svd_select_with_vectors(
+        &self,
+        calc_u: bool,
+        calc_vt: bool,
+        range: SingularRange<A::Real>,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        let mut a = self.to_owned();
+        let l = a.layout()?;
+        let svd_res = A::svd_select(l, calc_u, calc_vt, &range, a.as_allocated_mut()?)?;
+        let (m, n) = l.size();
+        let ns = svd_res.s.len() as i32;
+
+        let u = svd_res.u.map(|u| into_matrix(l.resized(m, ns), u).unwrap());
+        let vt = svd_res
+            .vt
+            .map(|vt| into_matrix(l.resized(ns, n), vt).unwrap());
+        Ok((u, ArrayBase::from(svd_res.s), vt))
+    }
+}
+
+/// Principal angles between two subspaces
+///
+/// For matrices `q1` and `q2` whose columns are orthonormal bases of subspaces,
+/// this returns the principal angles between them in ascending order,
+/// computed as `acos` of the singular values of `q1^H q2`.
+/// The number of angles is the smaller of the dimensions of the subspaces.
+///
+/// # Panics
+///
+/// Panics if the number of rows of `q1` and `q2` mismatch.
+pub fn principal_angles<A, S1, S2>(
+    q1: &ArrayBase<S1, Ix2>,
+    q2: &ArrayBase<S2, Ix2>,
+) -> Result<Array1<A::Real>>
+where
+    A: Scalar + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    assert_eq!(q1.nrows(), q2.nrows());
+    let c = q1.t().mapv(|x| x.conj()).dot(q2);
+    let (_, s, _) = c.svd_into(false, false)?;
+    // Singular values may slightly exceed one due to rounding
+    let one = A::real(1.0);
+    Ok(s.mapv(|s| if s > one { one } else { s }.acos()))
+}
+
+/// Numerical rank from singular values
+///
+/// Counts the singular values `s` which are strictly greater than `tol`,
+/// i.e. a singular value exactly equal to `tol` is regarded as zero.
+/// This convention does not depend on the platform as long as `tol` is computed
+/// in the same way. If `inclusive` is true, singular values equal to `tol` are also counted.
+///
+/// ```
+/// use ndarray_linalg::rank_from_singular_values;
+/// let s = [3.0, 2.0, 1.0, 0.5];
+/// assert_eq!(rank_from_singular_values(&s, 1.0, false), 2);
+/// assert_eq!(rank_from_singular_values(&s, 1.0, true), 3);
+/// ```
+pub fn rank_from_singular_values<T: PartialOrd>(s: &[T], tol: T, inclusive: bool) -> usize {
+    s.iter()
+        .filter(|s| if inclusive { **s >= tol } else { **s > tol })
+        .count()
+}
@@ -0,0 +1,226 @@
+//! Partial singular value decomposition by Golub-Kahan-Lanczos bidiagonalization
+//!
+//! The `k` largest singular triplets of a `m x n` matrix `A` are computed
+//! only through the products `A v` and `A^H u`, in a similar way as `svds` of ARPACK:
+//!
+//! 1. Build orthonormal bases `U` and `V` of `p` dimensional Krylov subspaces by Golub-Kahan-Lanczos bidiagonalization,
+//!    where `A V = U B` and `A^H U = V B^H + f e_p^H` with the `p x p` upper triangular matrix `B`
+//! 2. Approximate the singular triplets of `A` by those of the small matrix `B`
+//! 3. Restart the bidiagonalization keeping the `k` wanted Ritz vectors (thick restart) until they converge
+//!
+//! - [J. Baglama and L. Reichel, Augmented implicitly restarted Lanczos bidiagonalization methods (2005)](https://doi.org/10.1137/04060593X)
+
+use ndarray::*;
+use num_traits::Zero;
+use rand::{thread_rng, Rng};
+
+use crate::error::*;
+use crate::generate::*;
+use crate::svd::*;
+use crate::types::*;
+
+/// Result of [svd_partial] and [svd_partial_using]
+#[derive(Debug, Clone)]
+pub struct SvdPartial<A: Scalar> {
+    /// `m x k` left singular vectors
+    pub u: Array2<A>,
+    /// `k` largest singular values in descending order
+    pub s: Array1<A::Real>,
+    /// `k x n` right singular vectors
+    pub vt: Array2<A>,
+    /// Residual norms `|A^H u_i - s_i v_i|` of each singular triplet,
+    /// while `A v_i = s_i u_i` holds up to rounding errors
+    pub residuals: Array1<A::Real>,
+    /// Number of the restarts actually performed
+    pub restarts: usize,
+}
+
+/// Compute the `k` largest singular values and vectors by restarted Golub-Kahan-Lanczos bidiagonalization
+///
+/// - This function uses [rand::thread_rng] for the starting vector.
+///   See [svd_partial_using] for using another RNG
+///
+/// # Panics
+///
+/// Panics if `k` is zero or larger than `min(m, n)`.
+pub fn svd_partial<A, S>(
+    a: &ArrayBase<S, Ix2>,
+    k: usize,
+    tol: A::Real,
+    max_restarts: usize,
+) -> Result<SvdPartial<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let mut rng = thread_rng();
+    svd_partial_using(a, k, tol, max_restarts, &mut rng)
+}
+
+/// Compute the `k` largest singular values and vectors by restarted Golub-Kahan-Lanczos bidiagonalization
+/// with given RNG
+///
+/// The iteration stops when the residuals of all `k` triplets become smaller than `tol * s_0`
+/// where `s_0` is the largest singular value, or after `max_restarts` restarts.
+/// The residuals are returned in either case, and should be checked by the caller
+/// if `max_restarts` may be reached.
+///
+/// The Krylov subspaces have `p = min(max(2k, k + 8), m, n)` dimensions,
+/// and the basis vectors are fully reorthogonalized, which costs `O((m + n) p^2)` operations per restart.
+///
+/// # Panics
+///
+/// Panics if `k` is zero or larger than `min(m, n)`.
+pub fn svd_partial_using<A, S, R>(
+    a: &ArrayBase<S, Ix2>,
+    k: usize,
+    tol: A::Real,
+    max_restarts: usize,
+    rng: &mut R,
+) -> Result<SvdPartial<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    R: Rng,
+{
+    let (m, n) = a.dim();
+    assert!(
+        k > 0 && k <= m.min(n),
+        "k must be in 1..=min(m, n), but k = {}",
+        k
+    );
+    let p = (2 * k).max(k + 8).min(m).min(n);
+    // Number of the Ritz vectors kept in the restart
+    let kept = k.min(p - 1);
+    let eps: A::Real = num_traits::Float::epsilon();
+
+    let mut u = Array2::<A>::zeros((m, p));
+    let mut v = Array2::<A>::zeros((n, p));
+    let mut b = Array2::<A>::zeros((p, p));
+    let mut next = random_orthonormal(&v.slice(s![.., ..0]), rng);
+    let mut start = 0;
+    let mut restarts = 0;
+    // Estimate of the operator norm to detect the breakdown of the bidiagonalization
+    let mut anorm = A::Real::zero();
+    loop {
+        let mut beta = A::Real::zero();
+        for j in start..p {
+            v.column_mut(j).assign(&next);
+            let mut w = a.dot(&next);
+            let c = orthogonalize(&u.slice(s![.., ..j]), &mut w);
+            b.slice_mut(s![..j, j]).assign(&c);
+            let alpha = norm(&w);
+            anorm = c.iter().map(|x| x.abs()).fold(max(anorm, alpha), max);
+            if alpha > eps * anorm {
+                u.column_mut(j).assign(&w.mapv(|x| x.div_real(alpha)));
+                b[(j, j)] = A::from_real(alpha);
+            } else {
+                // `A v_j` is in the span of `U`, so `u_j` is taken as an arbitrary orthogonal vector
+                let w = random_orthonormal(&u.slice(s![.., ..j]), rng);
+                u.column_mut(j).assign(&w);
+                b[(j, j)] = A::zero();
+            }
+
+            let mut z = conjugate_dot(a, &u.column(j));
+            orthogonalize(&v.slice(s![.., ..=j]), &mut z);
+            beta = norm(&z);
+            if j + 1 == n {
+                // `V` spans the whole space, and there is no vector to continue
+                beta = A::Real::zero();
+                break;
+            }
+            anorm = max(anorm, beta);
+            next = if beta > eps * anorm {
+                z.mapv(|x| x.div_real(beta))
+            } else {
+                beta = A::Real::zero();
+                random_orthonormal(&v.slice(s![.., ..=j]), rng)
+            };
+        }
+
+        let (x, sigma, yh) = b.svd(true, true)?;
+        let (x, yh) = (x.unwrap(), yh.unwrap());
+        let residuals: Array1<A::Real> = x.row(p - 1).mapv(|x| beta * x.abs());
+        let converged = residuals.iter().take(k).all(|&r| r <= tol * sigma[0]);
+
+        if converged || restarts == max_restarts {
+            let y = conjugate::<A, _, OwnedRepr<A>>(&yh.slice(s![..k, ..]));
+            let vt = conjugate(&v.dot(&y));
+            return Ok(SvdPartial {
+                u: u.dot(&x.slice(s![.., ..k])),
+                s: sigma.slice(s![..k]).to_owned(),
+                vt,
+                residuals: residuals.slice(s![..k]).to_owned(),
+                restarts,
+            });
+        }
+
+        // Thick restart: `A v_i = s_i u_i` for the kept Ritz vectors,
+        // and the next column of `B` is filled by the projection of `A v` onto them
+        let y = conjugate::<A, _, OwnedRepr<A>>(&yh.slice(s![..kept, ..]));
+        let u_kept = u.dot(&x.slice(s![.., ..kept]));
+        let v_kept = v.dot(&y);
+        u.slice_mut(s![.., ..kept]).assign(&u_kept);
+        v.slice_mut(s![.., ..kept]).assign(&v_kept);
+        b.fill(A::zero());
+        for i in 0..kept {
+            b[(i, i)] = A::from_real(sigma[i]);
+        }
+        if beta == A::Real::zero() {
+            next = random_orthonormal(&v.slice(s![.., ..kept]), rng);
+        }
+        start = kept;
+        restarts += 1;
+    }
+}
+
+/// Orthogonalize `w` against the orthonormal columns of `q` by classical Gram-Schmidt twice,
+/// and returns the coefficients `q^H w` of the removed components
+fn orthogonalize<A, S>(q: &ArrayBase<S, Ix2>, w: &mut Array1<A>) -> Array1<A>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let mut c = Array1::zeros(q.ncols());
+    for _ in 0..2 {
+        let d = conjugate_dot(q, w);
+        *w -= &q.dot(&d);
+        c += &d;
+    }
+    c
+}
+
+/// Random unit vector orthogonal to the orthonormal columns of `q`
+fn random_orthonormal<A, S, R>(q: &ArrayBase<S, Ix2>, rng: &mut R) -> Array1<A>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+    R: Rng,
+{
+    let mut w: Array1<A> = random_using(q.nrows(), rng);
+    orthogonalize(q, &mut w);
+    let nrm = norm(&w);
+    w.mapv(|x| x.div_real(nrm))
+}
+
+/// `a^H x`
+fn conjugate_dot<A, Sa, Sx>(a: &ArrayBase<Sa, Ix2>, x: &ArrayBase<Sx, Ix1>) -> Array1<A>
+where
+    A: Scalar,
+    Sa: Data<Elem = A>,
+    Sx: Data<Elem = A>,
+{
+    a.t().dot(&x.mapv(|x| x.conj())).mapv(|x| x.conj())
+}
+
+fn norm<A: Scalar>(x: &Array1<A>) -> A::Real {
+    x.iter().map(|x| x.square()).sum::<A::Real>().sqrt()
+}
+
+fn max<R: PartialOrd>(x: R, y: R) -> R {
+    if x > y {
+        x
+    } else {
+        y
+    }
+}
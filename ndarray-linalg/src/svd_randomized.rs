@@ -0,0 +1,153 @@
+//! Randomized singular value decomposition
+//!
+//! The `k` largest singular values and vectors of a `m x n` matrix `A` are approximated by
+//! the randomized algorithm of Halko, Martinsson, and Tropp:
+//!
+//! 1. Sample the range of `A` by `Y = A Ω` with a random `n x l` matrix `Ω` where `l = k + oversampling`
+//! 2. Refine `Y` by the power iterations `Y = (A A^H)^q Y`, re-orthonormalized at every step
+//! 3. Compute the SVD of the small `l x n` matrix `B = Q^H A` where `Q` is the orthonormal basis of `Y`
+//!
+//! - [N. Halko, P. G. Martinsson, and J. A. Tropp, Finding structure with randomness (2011)](https://doi.org/10.1137/090771806)
+
+use ndarray::*;
+use rand::{thread_rng, Rng};
+
+use crate::error::*;
+use crate::generate::*;
+use crate::qr::*;
+use crate::svd::*;
+use crate::types::*;
+
+/// Number of the power iterations in [svd_randomized_using]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PowerIteration<R> {
+    /// Run the given number of iterations
+    Fixed(usize),
+    /// Stop when the estimated `k` largest singular values change less than `tol`
+    /// relative to the largest one between successive iterations, or after `max_iters` iterations
+    ///
+    /// This requires the SVD of the small `l x n` matrix at every iteration.
+    Adaptive { tol: R, max_iters: usize },
+}
+
+/// Result of [svd_randomized] and [svd_randomized_using]
+#[derive(Debug, Clone)]
+pub struct SvdRandomized<A: Scalar> {
+    /// `m x k` left singular vectors
+    pub u: Array2<A>,
+    /// `k` largest singular values in descending order
+    pub s: Array1<A::Real>,
+    /// `k x n` right singular vectors
+    pub vt: Array2<A>,
+    /// Number of the power iterations actually performed
+    pub iterations: usize,
+}
+
+/// Compute the `k` largest singular values and vectors by the randomized algorithm
+/// with `n_iter` power iterations
+///
+/// - This function uses [rand::thread_rng].
+///   See [svd_randomized_using] for using another RNG or choosing the number of iterations adaptively
+///
+/// # Panics
+///
+/// Panics if `k` is zero or larger than `min(m, n)`.
+pub fn svd_randomized<A, S>(
+    a: &ArrayBase<S, Ix2>,
+    k: usize,
+    oversampling: usize,
+    n_iter: usize,
+) -> Result<SvdRandomized<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let mut rng = thread_rng();
+    svd_randomized_using(a, k, oversampling, PowerIteration::Fixed(n_iter), &mut rng)
+}
+
+/// Compute the `k` largest singular values and vectors by the randomized algorithm
+/// with given RNG
+///
+/// `oversampling` additional random vectors improve the accuracy,
+/// and a few (e.g. 5-10) is usually enough.
+/// Matrices with slowly decaying singular values need more power iterations,
+/// which can be chosen automatically by [PowerIteration::Adaptive].
+///
+/// # Panics
+///
+/// Panics if `k` is zero or larger than `min(m, n)`.
+pub fn svd_randomized_using<A, S, R>(
+    a: &ArrayBase<S, Ix2>,
+    k: usize,
+    oversampling: usize,
+    power: PowerIteration<A::Real>,
+    rng: &mut R,
+) -> Result<SvdRandomized<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    R: Rng,
+{
+    let (m, n) = a.dim();
+    assert!(
+        k > 0 && k <= m.min(n),
+        "k must be in 1..=min(m, n), but k = {}",
+        k
+    );
+    let l = (k + oversampling).min(m).min(n);
+    let ah = conjugate::<A, _, OwnedRepr<A>>(a);
+
+    let omega: Array2<A> = random_using((n, l), rng);
+    let (mut q, _) = a.dot(&omega).qr_into()?;
+    let (max_iters, tol) = match power {
+        PowerIteration::Fixed(iters) => (iters, None),
+        PowerIteration::Adaptive { tol, max_iters } => (max_iters, Some(tol)),
+    };
+    let mut s_prev = match tol {
+        Some(_) => Some(singular_values(&ah, &q, k)?),
+        None => None,
+    };
+    let mut iterations = 0;
+    while iterations < max_iters {
+        let (z, _) = ah.dot(&q).qr_into()?;
+        let (y, _) = a.dot(&z).qr_into()?;
+        q = y;
+        iterations += 1;
+        if let (Some(tol), Some(prev)) = (tol, s_prev.as_ref()) {
+            let s = singular_values(&ah, &q, k)?;
+            let converged = s
+                .iter()
+                .zip(prev.iter())
+                .all(|(&x, &y): (&A::Real, &A::Real)| (x - y).abs() <= tol * s[0]);
+            s_prev = Some(s);
+            if converged {
+                break;
+            }
+        }
+    }
+
+    // `B^H = A^H Q` is computed instead of `B = Q^H A` to avoid the conjugate of `Q`,
+    // and its thin SVD gives the `n x l` matrix `V` instead of `n x n`
+    let bh = ah.dot(&q);
+    let (v, s, uh) = bh.svd_thin(true, true)?;
+    let (v, uh) = (v.unwrap(), uh.unwrap());
+    let u = q.dot(&conjugate::<A, _, OwnedRepr<A>>(&uh.slice(s![..k, ..])));
+    let vt = conjugate(&v.slice(s![.., ..k]));
+    Ok(SvdRandomized {
+        u,
+        s: s.slice(s![..k]).to_owned(),
+        vt,
+        iterations,
+    })
+}
+
+/// `k` largest singular values of `B = Q^H A` given `A^H`
+fn singular_values<A, S>(ah: &ArrayBase<S, Ix2>, q: &Array2<A>, k: usize) -> Result<Array1<A::Real>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let (_, s, _) = ah.dot(q).svd(false, false)?;
+    Ok(s.slice(s![..k]).to_owned())
+}
@@ -0,0 +1,98 @@
+//! Sylvester equation
+//!
+//! The Sylvester equation `AX + XB = C` for `m x m` matrix `A`, `n x n` matrix `B`, and `m x n` matrix `C`
+//! is solved by the Bartels-Stewart algorithm:
+//! `A = U T U^H` and `B = V S V^H` are reduced into the Schur forms,
+//! the transformed equation `T Y + Y S = U^H C V` is solved by substitution using `*trsyl`,
+//! and `X = U Y V^H`. This requires `O(m^3 + n^3)` operations instead of `O(m^3 n^3)`
+//! for the linear equation of the size `mn` obtained by the Kronecker product.
+//!
+//! The solution is unique if and only if `A` and `-B` have no common eigenvalues.
+//!
+//! - [Wikipedia article on Sylvester equation](https://en.wikipedia.org/wiki/Sylvester_equation)
+
+use ndarray::*;
+use num_traits::One;
+
+use crate::error::*;
+use crate::generate::conjugate;
+use crate::layout::*;
+use crate::schur::*;
+use crate::solve::Transpose;
+use crate::types::*;
+
+/// Solve the Sylvester equation `AX + XB = C`
+///
+/// [LinalgError::Lapack] is returned if `A` and `-B` have common or very close eigenvalues.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = random((4, 4));
+/// let b: Array2<f64> = random((3, 3));
+/// let c: Array2<f64> = random((4, 3));
+/// let x = solve_sylvester(&a, &b, &c).unwrap();
+/// assert_close_l2!(&(a.dot(&x) + x.dot(&b)), &c, 1e-9);
+/// ```
+pub fn solve_sylvester<A, Sa, Sb, Sc>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    c: &ArrayBase<Sc, Ix2>,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sc: Data<Elem = A>,
+{
+    a.square_layout()?;
+    b.square_layout()?;
+    if c.dim() != (a.nrows(), b.nrows()) {
+        return Err(LinalgError::Shape(ShapeError::from_kind(
+            ErrorKind::IncompatibleShape,
+        )));
+    }
+    let (t, u, _) = a.schur()?;
+    let (s, v, _) = b.schur()?;
+    let uh: Array2<A> = conjugate(&u);
+    let vh: Array2<A> = conjugate(&v);
+    let f = uh.dot(c).dot(&v);
+    let y = solve_sylvester_schur(&t, Transpose::No, &s, Transpose::No, 1, &f)?;
+    Ok(u.dot(&y).dot(&vh))
+}
+
+/// Solve `op(T) Y + s Y op(S) = F` for `T` and `S` in Schur form, see [lax::sylvester::SylvesterImpl]
+pub(crate) fn solve_sylvester_schur<A: Scalar + Lapack>(
+    t: &Array2<A>,
+    trant: Transpose,
+    s: &Array2<A>,
+    trans: Transpose,
+    isgn: i32,
+    f: &Array2<A>,
+) -> Result<Array2<A>> {
+    let t = fortran(t);
+    let s = fortran(s);
+    let mut y = fortran(f);
+    let l = y.layout()?;
+    let scale = A::sylvester(
+        l,
+        trant,
+        trans,
+        isgn,
+        t.as_allocated()?,
+        s.as_allocated()?,
+        y.as_allocated_mut()?,
+    )?;
+    if scale != A::Real::one() {
+        let inv = A::from_real(A::Real::one() / scale);
+        y.mapv_inplace(|x| x * inv);
+    }
+    Ok(y)
+}
+
+fn fortran<A: Scalar>(a: &Array2<A>) -> Array2<A> {
+    let mut f = Array2::zeros(a.dim().f());
+    f.assign(a);
+    f
+}
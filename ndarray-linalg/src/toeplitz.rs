@@ -0,0 +1,224 @@
+//! Toeplitz matrices
+//!
+//! A `n x n` Toeplitz matrix `T[i, j] = t_{i - j}` is determined by its first column `c = (t_0, t_1, ...)`
+//! and first row `r = (t_0, t_{-1}, ...)`, and the linear equation `T x = y` is solved
+//! in `O(n^2)` operations by the Levinson recursion without forming the dense matrix.
+//! The recursion requires all the leading principal submatrices of `T` to be non-singular,
+//! which holds e.g. for positive definite matrices, and is not backward stable in general.
+//!
+//! - N. Levinson, "The Wiener RMS error criterion in filter design and prediction", J. Math. Phys. 25, 1946.
+//! - G. H. Golub and C. F. Van Loan, "Matrix Computations", 4th ed., Section 4.7.
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::solve::*;
+use crate::types::*;
+
+/// Toeplitz matrix given by the first column and the first row
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let t = ToeplitzMatrix::new(array![4.0, 1.0, 0.5], array![4.0, 2.0, 1.0]);
+/// assert_eq!(
+///     t.to_dense(),
+///     array![[4.0, 2.0, 1.0], [1.0, 4.0, 2.0], [0.5, 1.0, 4.0]]
+/// );
+/// let b = array![1.0, 2.0, 3.0];
+/// let x = t.solve(&b).unwrap();
+/// assert_close_l2!(&t.to_dense().dot(&x), &b, 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToeplitzMatrix<A: Scalar> {
+    /// The first column `(t_0, t_1, ..., t_{n-1})`
+    pub c: Array1<A>,
+    /// The first row `(t_0, t_{-1}, ..., t_{1-n})`
+    pub r: Array1<A>,
+}
+
+impl<A: Scalar> ToeplitzMatrix<A> {
+    /// Toeplitz matrix with the first column `c` and the first row `r`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths of `c` and `r` mismatch, or `c[0] != r[0]`.
+    pub fn new(c: Array1<A>, r: Array1<A>) -> Self {
+        assert_eq!(
+            c.len(),
+            r.len(),
+            "The first column and row must have the same length"
+        );
+        assert!(
+            c.is_empty() || c[0] == r[0],
+            "c[0] and r[0] must be identical"
+        );
+        ToeplitzMatrix { c, r }
+    }
+
+    /// Hermitian (or real symmetric) Toeplitz matrix with the first column `c`
+    ///
+    /// The imaginary part of `c[0]` is ignored.
+    pub fn hermitian(mut c: Array1<A>) -> Self {
+        if let Some(t0) = c.first_mut() {
+            *t0 = A::from_real(t0.re());
+        }
+        let r = c.mapv(|x| x.conj());
+        ToeplitzMatrix { c, r }
+    }
+
+    /// Size of the matrix
+    pub fn len(&self) -> usize {
+        self.c.len()
+    }
+
+    /// Returns true if the matrix is `0 x 0`
+    pub fn is_empty(&self) -> bool {
+        self.c.is_empty()
+    }
+
+    /// Construct the dense matrix
+    pub fn to_dense(&self) -> Array2<A> {
+        let n = self.len();
+        Array2::from_shape_fn((n, n), |(i, j)| self.get(i, j))
+    }
+
+    /// Transposed matrix, which is also Toeplitz
+    pub fn t(&self) -> Self {
+        ToeplitzMatrix {
+            c: self.r.clone(),
+            r: self.c.clone(),
+        }
+    }
+
+    /// `T[i, j]`
+    fn get(&self, i: usize, j: usize) -> A {
+        if i >= j {
+            self.c[i - j]
+        } else {
+            self.r[j - i]
+        }
+    }
+
+    /// Solve `T x = y` by the Levinson recursion
+    ///
+    /// The forward and backward vectors `f`, `b` of the `k x k` leading submatrix `T_k`
+    /// satisfy `T_k f = e_1` and `T_k b = e_k`, and are extended to `k + 1` with `O(k)` operations.
+    fn levinson(&self, y: &[A]) -> Result<Vec<A>> {
+        let n = self.len();
+        if y.len() != n {
+            return Err(LinalgError::Shape(ShapeError::from_kind(
+                ErrorKind::IncompatibleShape,
+            )));
+        }
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+        let singular = |k: usize| {
+            LinalgError::Lapack(lax::error::Error::LapackComputationalFailure {
+                return_code: k as i32,
+            })
+        };
+        let t0 = self.c[0];
+        if t0.is_zero() {
+            return Err(singular(1));
+        }
+        let mut f = vec![A::one() / t0];
+        let mut b = vec![A::one() / t0];
+        let mut x = vec![y[0] / t0];
+        for (k, &yk) in y.iter().enumerate().skip(1) {
+            // Errors of the extended vectors `[f; 0]` and `[0; b]` in the last and first rows of `T_{k+1}`
+            let ef: A = (0..k).map(|i| self.c[k - i] * f[i]).sum();
+            let eb: A = (0..k).map(|i| self.r[i + 1] * b[i]).sum();
+            let denom = A::one() - ef * eb;
+            if denom.is_zero() {
+                return Err(singular(k + 1));
+            }
+            let mut f_next = Vec::with_capacity(k + 1);
+            let mut b_next = Vec::with_capacity(k + 1);
+            for i in 0..=k {
+                let fi = if i < k { f[i] } else { A::zero() };
+                let bi = if i > 0 { b[i - 1] } else { A::zero() };
+                f_next.push((fi - ef * bi) / denom);
+                b_next.push((bi - eb * fi) / denom);
+            }
+            f = f_next;
+            b = b_next;
+            let ex: A = (0..k).map(|i| self.c[k - i] * x[i]).sum();
+            let coef = yk - ex;
+            x.push(A::zero());
+            for (xi, bi) in x.iter_mut().zip(&b) {
+                *xi += coef * *bi;
+            }
+        }
+        Ok(x)
+    }
+}
+
+impl<A: Scalar> Solve<A> for ToeplitzMatrix<A> {
+    fn solve_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let x = self.levinson(&rhs.to_vec())?;
+        rhs.assign(&ArrayView1::from(&x));
+        Ok(rhs)
+    }
+
+    fn solve_t_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.t().solve_inplace(rhs)
+    }
+
+    fn solve_h_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        ToeplitzMatrix {
+            c: self.r.mapv(|x| x.conj()),
+            r: self.c.mapv(|x| x.conj()),
+        }
+        .solve_inplace(rhs)
+    }
+}
+
+/// Solve the Yule-Walker equations for the coefficients of an autoregressive model
+///
+/// For the autocorrelation `acf = (r_0, r_1, ..., r_p)` of a stationary process,
+/// this returns `a = (a_1, ..., a_p)` of the AR(p) model `x_t = a_1 x_{t-1} + ... + a_p x_{t-p} + e_t`,
+/// i.e. the solution of `R a = (r_1, ..., r_p)` with the Hermitian Toeplitz matrix `R[i, j] = r_{i-j}`,
+/// in `O(p^2)` operations by the Levinson recursion.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Autocorrelation of the AR(1) process x_t = 0.5 x_{t-1} + e_t
+/// let acf = array![1.0, 0.5, 0.25, 0.125];
+/// let a = solve_yule_walker(&acf).unwrap();
+/// assert_close_l2!(&a, &array![0.5, 0.0, 0.0], 1e-12);
+/// ```
+pub fn solve_yule_walker<A, S>(acf: &ArrayBase<S, Ix1>) -> Result<Array1<A>>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let p = acf.len().saturating_sub(1);
+    if p == 0 {
+        return Ok(Array1::zeros(0));
+    }
+    let r = ToeplitzMatrix::hermitian(acf.slice(s![..p]).to_owned());
+    r.solve_into(acf.slice(s![1..]).to_owned())
+}
@@ -10,7 +10,7 @@ use lax::*;
 use ndarray::*;
 use num_traits::One;
 
-pub use lax::{LUFactorizedTridiagonal, Tridiagonal};
+pub use lax::{EighRange, LUFactorizedTridiagonal, Tridiagonal};
 
 /// An interface for making a Tridiagonal struct.
 pub trait ExtractTridiagonal<A: Scalar> {
@@ -693,3 +693,124 @@ where
         self.factorize_tridiagonal()?.rcond_tridiagonal_into()
     }
 }
+
+/// An interface for eigenvalue problem of real symmetric tridiagonal matrices.
+///
+/// This is implemented for a pair `(d, e)` of the diagonal elements `d`
+/// and the off-diagonal elements `e`, so that the matrix need not be built as a dense one.
+/// It is also implemented for [Tridiagonal], where only `d` and `du` are referenced.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // 1D Laplacian with the Dirichlet boundary condition
+/// let d: Array1<f64> = Array1::from_elem(3, 2.0);
+/// let e: Array1<f64> = Array1::from_elem(2, -1.0);
+/// let w = (d, e).eigvalsh_tridiagonal().unwrap();
+/// let sqrt2 = 2.0_f64.sqrt();
+/// assert!(w.abs_diff_eq(&array![2.0 - sqrt2, 2.0, 2.0 + sqrt2], 1e-9));
+/// ```
+///
+/// A few of eigenvalues are computed by [EighTridiagonal::eigh_tridiagonal_select]:
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let d: Array1<f64> = Array1::from_elem(100, 2.0);
+/// let e: Array1<f64> = Array1::from_elem(99, -1.0);
+/// // Two smallest eigenvalues and corresponding eigenvectors
+/// let (w, v) = (d, e)
+///     .eigh_tridiagonal_select(true, EighRange::Indices(0..2))
+///     .unwrap();
+/// assert_eq!(w.len(), 2);
+/// assert_eq!(v.unwrap().dim(), (100, 2));
+/// ```
+pub trait EighTridiagonal<A: Scalar> {
+    /// Computes eigenvalues in ascending order and eigenvectors as columns of a matrix,
+    /// using the LAPACK `*stevd` routines.
+    fn eigh_tridiagonal(&self) -> Result<(Array1<A>, Array2<A>)>;
+
+    /// Computes eigenvalues in ascending order without eigenvectors.
+    fn eigvalsh_tridiagonal(&self) -> Result<Array1<A>>;
+
+    /// Computes `m` eigenvalues in `range` in ascending order,
+    /// and `n x m` eigenvectors if `calc_eigenvec` is true, using the LAPACK `*stevr` routines.
+    fn eigh_tridiagonal_select(
+        &self,
+        calc_eigenvec: bool,
+        range: EighRange<A>,
+    ) -> Result<(Array1<A>, Option<Array2<A>>)>;
+}
+
+impl<A, S, S2> EighTridiagonal<A> for (ArrayBase<S, Ix1>, ArrayBase<S2, Ix1>)
+where
+    A: Scalar<Real = A> + Lapack,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    /// # Panics
+    ///
+    /// Panics if the length of the off-diagonal elements is not `n - 1`.
+    fn eigh_tridiagonal(&self) -> Result<(Array1<A>, Array2<A>)> {
+        let n = self.0.len();
+        let mut d = self.0.to_vec();
+        let e = self.1.to_vec();
+        let z = A::eigh_tridiagonal(true, &mut d, &e)?.unwrap();
+        Ok((
+            Array1::from(d),
+            Array2::from_shape_vec((n, n).f(), z).unwrap(),
+        ))
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the length of the off-diagonal elements is not `n - 1`.
+    fn eigvalsh_tridiagonal(&self) -> Result<Array1<A>> {
+        let mut d = self.0.to_vec();
+        let e = self.1.to_vec();
+        A::eigh_tridiagonal(false, &mut d, &e)?;
+        Ok(Array1::from(d))
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the length of the off-diagonal elements is not `n - 1`.
+    fn eigh_tridiagonal_select(
+        &self,
+        calc_eigenvec: bool,
+        range: EighRange<A>,
+    ) -> Result<(Array1<A>, Option<Array2<A>>)> {
+        let n = self.0.len();
+        let d = self.0.to_vec();
+        let e = self.1.to_vec();
+        let EighTridiagonalOwned { eigs, z } =
+            A::eigh_tridiagonal_select(calc_eigenvec, &range, &d, &e)?;
+        let m = eigs.len();
+        let z = z.map(|z| Array2::from_shape_vec((n, m).f(), z).unwrap());
+        Ok((Array1::from(eigs), z))
+    }
+}
+
+impl<A> EighTridiagonal<A> for Tridiagonal<A>
+where
+    A: Scalar<Real = A> + Lapack,
+{
+    fn eigh_tridiagonal(&self) -> Result<(Array1<A>, Array2<A>)> {
+        (ArrayView1::from(&self.d), ArrayView1::from(&self.du)).eigh_tridiagonal()
+    }
+
+    fn eigvalsh_tridiagonal(&self) -> Result<Array1<A>> {
+        (ArrayView1::from(&self.d), ArrayView1::from(&self.du)).eigvalsh_tridiagonal()
+    }
+
+    fn eigh_tridiagonal_select(
+        &self,
+        calc_eigenvec: bool,
+        range: EighRange<A>,
+    ) -> Result<(Array1<A>, Option<Array2<A>>)> {
+        (ArrayView1::from(&self.d), ArrayView1::from(&self.du))
+            .eigh_tridiagonal_select(calc_eigenvec, range)
+    }
+}
@@ -0,0 +1,231 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn tridiagonal<T: Scalar>(d: &[T], e: &[T]) -> Array2<T> {
+    let n = d.len();
+    let mut a = Array2::from_diag(&arr1(d));
+    for i in 0..n - 1 {
+        a[(i, i + 1)] = e[i];
+        a[(i + 1, i)] = e[i].conj();
+    }
+    a
+}
+
+#[test]
+fn band_pack_unpack() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hermite_using(5, &mut rng);
+    for uplo in [UPLO::Upper, UPLO::Lower] {
+        let full = SymmetricBand::from_dense(&a, 4, uplo);
+        assert_close_l2!(&full.to_dense(), &a, 1e-12);
+        let band = SymmetricBand::from_dense(&a, 1, uplo);
+        assert_eq!(band.ab.dim(), (2, 5));
+        let dense = band.to_dense();
+        for i in 0..5usize {
+            for j in 0..5usize {
+                if i.abs_diff(j) <= 1 {
+                    assert_eq!(dense[(i, j)], a[(i, j)]);
+                } else {
+                    assert_eq!(dense[(i, j)], c64::new(0.0, 0.0));
+                }
+            }
+        }
+    }
+}
+
+macro_rules! test_eigh_generalized_banded {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<eigh_generalized_banded_ $type>]() {
+                // stiffness and mass matrices of the 1D finite element method with linear elements
+                let n = 8;
+                let two = <$type>::from_real(2.0);
+                let one = <$type>::from_real(1.0);
+                let four = <$type>::from_real(4.0);
+                let k = tridiagonal(&vec![two; n], &vec![-one; n - 1]);
+                let m = tridiagonal(&vec![four; n], &vec![one; n - 1]);
+
+                let ans = (k.clone(), m.clone()).eigvalsh(UPLO::Upper).unwrap();
+                let pair = (
+                    SymmetricBand::from_dense(&k, 1, UPLO::Upper),
+                    SymmetricBand::from_dense(&m, 1, UPLO::Lower),
+                );
+                let w = pair.eigvalsh_generalized_banded().unwrap();
+                assert_close_l2!(&w, &ans, $rtol);
+
+                let (w, v) = pair.eigh_generalized_banded().unwrap();
+                assert_close_l2!(&w, &ans, $rtol);
+                let w = w.mapv(<$type>::from_real);
+                assert_close_l2!(&k.dot(&v), &(m.dot(&v) * &w), $rtol);
+                let vh = v.t().mapv(|x| x.conj());
+                assert_close_l2!(&vh.dot(&m).dot(&v), &Array2::eye(n), $rtol);
+            }
+        }
+    };
+}
+
+test_eigh_generalized_banded!(f64, 1e-7);
+test_eigh_generalized_banded!(f32, 1e-3);
+test_eigh_generalized_banded!(c64, 1e-7);
+test_eigh_generalized_banded!(c32, 1e-3);
+
+#[test]
+fn eigh_generalized_banded_wider_b() {
+    // `*sbgv` requires `k_A >= k_B`, so `A` is repacked with the bandwidth of `B`
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a = tridiagonal(&[1.0, 2.0, 3.0, 4.0], &[0.5, -0.5, 0.5]);
+    let b: Array2<f64> = random_hpd_using(4, &mut rng);
+    let ans = (a.clone(), b.clone()).eigvalsh(UPLO::Lower).unwrap();
+    let w = (
+        SymmetricBand::from_dense(&a, 1, UPLO::Lower),
+        SymmetricBand::from_dense(&b, 3, UPLO::Lower),
+    )
+        .eigvalsh_generalized_banded()
+        .unwrap();
+    assert_close_l2!(&w, &ans, 1e-7);
+}
+
+macro_rules! test_cholesky_banded {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<cholesky_banded_ $type>]() {
+                // Hermitian positive definite pentadiagonal matrix
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let n = 8;
+                let a: Array2<$type> = random_hpd_using(n, &mut rng);
+                let a = Array2::from_shape_fn((n, n), |(i, j)| {
+                    if i.abs_diff(j) <= 2 {
+                        a[(i, j)]
+                    } else {
+                        <$type>::from_real(0.0)
+                    }
+                }) + Array2::<$type>::eye(n).mapv(|x| x * <$type>::from_real(n as _));
+                let x: Array1<$type> = random_using(n, &mut rng);
+                let b = a.dot(&x);
+                let ans = a.solvec(&b).unwrap();
+                for uplo in [UPLO::Upper, UPLO::Lower] {
+                    let band = SymmetricBand::from_dense(&a, 2, uplo);
+                    let f = band.cholesky_banded().unwrap();
+                    assert_close_l2!(&f.solvec(&b).unwrap(), &ans, $rtol);
+                    assert_close_l2!(&band.solvec(&b).unwrap(), &x, $rtol);
+                }
+            }
+        }
+    };
+}
+
+test_cholesky_banded!(f64, 1e-9);
+test_cholesky_banded!(f32, 1e-4);
+test_cholesky_banded!(c64, 1e-9);
+test_cholesky_banded!(c32, 1e-4);
+
+#[test]
+fn cholesky_banded_not_positive_definite() {
+    let a = arr2(&[[1.0, 2.0, 0.0], [2.0, 1.0, 2.0], [0.0, 2.0, 1.0]]);
+    let band = SymmetricBand::from_dense(&a, 1, UPLO::Upper);
+    assert!(band.cholesky_banded().is_err());
+}
+
+#[test]
+fn banded_pack_unpack() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((5, 5), &mut rng);
+    let full = BandedMatrix::from_dense(&a, 4, 4);
+    assert_eq!(full.to_dense(), a);
+    let band = BandedMatrix::from_dense(&a, 2, 1);
+    assert_eq!(band.ab.dim(), (4, 5));
+    let dense = band.to_dense();
+    for i in 0..5usize {
+        for j in 0..5usize {
+            if i <= j + 2 && j <= i + 1 {
+                assert_eq!(dense[(i, j)], a[(i, j)]);
+            } else {
+                assert_eq!(dense[(i, j)], 0.0);
+            }
+        }
+    }
+}
+
+macro_rules! test_lu_banded {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<lu_banded_ $type>]() {
+                // Non-symmetric band matrix with kl = 2 and ku = 1
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let n = 8;
+                let a: Array2<$type> = random_using((n, n), &mut rng);
+                let a = Array2::from_shape_fn((n, n), |(i, j)| {
+                    if i <= j + 2 && j <= i + 1 {
+                        a[(i, j)]
+                    } else {
+                        <$type>::from_real(0.0)
+                    }
+                });
+                let band = BandedMatrix::from_dense(&a, 2, 1);
+                let x: Array1<$type> = random_using(n, &mut rng);
+                let b = a.dot(&x);
+                let f = band.factorize_banded().unwrap();
+                assert_close_l2!(&f.solve(&b).unwrap(), &x, $rtol);
+                assert_close_l2!(&band.solve(&b).unwrap(), &x, $rtol);
+                assert_close_l2!(&f.solve_t(&b).unwrap(), &a.solve_t(&b).unwrap(), $rtol);
+                assert_close_l2!(&f.solve_h(&b).unwrap(), &a.solve_h(&b).unwrap(), $rtol);
+
+                assert_rclose!(band.det().unwrap(), a.det().unwrap(), $rtol);
+                let rcond = 1. / (a.opnorm_one().unwrap() * a.inv().unwrap().opnorm_one().unwrap());
+                assert_aclose!(f.rcond().unwrap(), rcond, 0.2);
+            }
+        }
+    };
+}
+
+test_lu_banded!(f64, 1e-9);
+test_lu_banded!(f32, 1e-3);
+test_lu_banded!(c64, 1e-9);
+test_lu_banded!(c32, 1e-3);
+
+#[test]
+fn lu_banded_singular() {
+    let a = arr2(&[[1.0, 2.0, 0.0], [2.0, 4.0, 0.0], [0.0, 1.0, 0.0]]);
+    let band = BandedMatrix::from_dense(&a, 1, 1);
+    assert!(band.factorize_banded().is_err());
+    assert_eq!(band.det().unwrap(), 0.0);
+}
+
+macro_rules! test_eigh_banded {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<eigh_banded_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let n = 8;
+                let a: Array2<$type> = random_hermite_using(n, &mut rng);
+                let a = Array2::from_shape_fn((n, n), |(i, j)| {
+                    if i.abs_diff(j) <= 2 {
+                        a[(i, j)]
+                    } else {
+                        <$type>::from_real(0.0)
+                    }
+                });
+                let ans = a.eigvalsh(UPLO::Upper).unwrap();
+                for uplo in [UPLO::Upper, UPLO::Lower] {
+                    let band = SymmetricBand::from_dense(&a, 2, uplo);
+                    assert_close_l2!(&band.eigvalsh_banded().unwrap(), &ans, $rtol);
+                    let (w, v) = band.eigh_banded().unwrap();
+                    assert_close_l2!(&w, &ans, $rtol);
+                    let w = w.mapv(<$type>::from_real);
+                    assert_close_l2!(&a.dot(&v), &(&v * &w), $rtol);
+                    let vh = v.t().mapv(|x| x.conj());
+                    assert_close_l2!(&vh.dot(&v), &Array2::eye(n), $rtol);
+                }
+            }
+        }
+    };
+}
+
+test_eigh_banded!(f64, 1e-9);
+test_eigh_banded!(f32, 1e-4);
+test_eigh_banded!(c64, 1e-9);
+test_eigh_banded!(c32, 1e-4);
@@ -0,0 +1,91 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test<T: Scalar + Lapack>(a: &Array2<T>)
+where
+    T::Real: Lapack,
+{
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    let (u, s, vt, b) = svd_with_bidiagonal(a).unwrap();
+    assert_eq!(u.dim(), (m, k));
+    assert_eq!(vt.dim(), (k, n));
+    assert_eq!(b.uplo, if m >= n { UPLO::Upper } else { UPLO::Lower });
+
+    let mut sm = Array2::<T>::zeros((k, k));
+    for i in 0..k {
+        sm[(i, i)] = T::from_real(s[i]);
+    }
+    assert_close_l2!(&u.dot(&sm).dot(&vt), a, T::real(1e-7));
+
+    // singular values of the bidiagonal matrix are those of the original matrix
+    let mut d = b.d.to_vec();
+    let mut e = b.e.to_vec();
+    T::svd_bidiagonal(b.uplo, &mut d, &mut e, None, None, None).unwrap();
+    assert_close_l2!(&arr1(&d), &s, T::real(1e-7));
+    let (_, s_svd, _) = a.svd(false, false).unwrap();
+    assert_close_l2!(&s, &s_svd, T::real(1e-7));
+}
+
+macro_rules! test_svd_with_bidiagonal {
+    ($type:ty, $n:expr, $m:expr) => {
+        paste::item! {
+            #[test]
+            fn [<svd_with_bidiagonal_ $type _ $n x $m>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a = random_using(($n, $m), &mut rng);
+                test::<$type>(&a);
+            }
+
+            #[test]
+            fn [<svd_with_bidiagonal_ $type _ $n x $m _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a = random_using(($n, $m).f(), &mut rng);
+                test::<$type>(&a);
+            }
+        }
+    };
+}
+
+test_svd_with_bidiagonal!(f64, 3, 3);
+test_svd_with_bidiagonal!(f64, 5, 3);
+test_svd_with_bidiagonal!(f64, 3, 5);
+test_svd_with_bidiagonal!(c64, 3, 3);
+test_svd_with_bidiagonal!(c64, 5, 3);
+test_svd_with_bidiagonal!(c64, 3, 5);
+
+fn test_bidiagonal_svd(uplo: UPLO) {
+    let b = Bidiagonal::<f64> {
+        uplo,
+        d: arr1(&[1.0, 2.0, 3.0, 4.0]),
+        e: arr1(&[0.5, -1.0, 2.0]),
+    };
+    let mut dense = Array2::from_diag(&b.d);
+    for i in 0..3 {
+        match uplo {
+            UPLO::Upper => dense[(i, i + 1)] = b.e[i],
+            UPLO::Lower => dense[(i + 1, i)] = b.e[i],
+        }
+    }
+    let (_, s_dense, _) = dense.svd(false, false).unwrap();
+    let (u, s, vt) = b.svd(true, true).unwrap();
+    assert_close_l2!(&s, &s_dense, 1e-7);
+    let (u, vt) = (u.unwrap(), vt.unwrap());
+    assert_close_l2!(&u.dot(&Array2::from_diag(&s)).dot(&vt), &dense, 1e-7);
+
+    // rotations applied to C-layout matrix
+    let mut c = Array2::<f64>::eye(4);
+    let s_c = b.svd_apply(None, None, Some(&mut c)).unwrap();
+    assert_close_l2!(&s_c, &s, 1e-7);
+    assert_close_l2!(&c, &u.t(), 1e-7);
+}
+
+#[test]
+fn bidiagonal_svd_upper() {
+    test_bidiagonal_svd(UPLO::Upper);
+}
+
+#[test]
+fn bidiagonal_svd_lower() {
+    test_bidiagonal_svd(UPLO::Lower);
+}
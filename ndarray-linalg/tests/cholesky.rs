@@ -231,3 +231,102 @@ cholesky_solve!(f64, 1e-9);
 cholesky_solve!(f32, 1e-3);
 cholesky_solve!(c64, 1e-9);
 cholesky_solve!(c32, 1e-3);
+
+macro_rules! cholesky_pivoted {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<cholesky_pivoted_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                // Positive semi-definite matrix of rank 3
+                let x: Array2<$elem> = random_using((5, 3), &mut rng);
+                let a = x.dot(&x.t().mapv(|elem| elem.conj()));
+                let a_t = a.clone().reversed_axes();
+                for a in [a.clone(), a_t] {
+                    for uplo in [UPLO::Upper, UPLO::Lower] {
+                        let c = cholesky_pivoted(&a, uplo, -1.0).unwrap();
+                        assert_eq!(c.rank, 3);
+                        let f = c.factor.view();
+                        let fh = c.factor.t().mapv(|elem| elem.conj());
+                        let pa = a.select(Axis(0), &c.perm).select(Axis(1), &c.perm);
+                        match uplo {
+                            UPLO::Upper => assert_close_l2!(&fh.dot(&f), &pa, $rtol),
+                            UPLO::Lower => assert_close_l2!(&f.dot(&fh), &pa, $rtol),
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+cholesky_pivoted!(f64, 1e-9);
+cholesky_pivoted!(c64, 1e-9);
+
+macro_rules! cholesky_update {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<cholesky_update_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hpd_using(4, &mut rng);
+                let x: Array1<$elem> = random_using(4, &mut rng);
+                let xh = x.mapv(|elem| elem.conj());
+                let a_up = &a + &x.view().insert_axis(Axis(1)).dot(&xh.view().insert_axis(Axis(0)));
+                for uplo in [UPLO::Upper, UPLO::Lower] {
+                    let mut f = a.factorizec(uplo).unwrap();
+                    f.update(&x).unwrap();
+                    assert_close_l2!(&f.factor, &a_up.cholesky(uplo).unwrap(), $rtol);
+                    f.downdate(&x).unwrap();
+                    assert_close_l2!(&f.factor, &a.cholesky(uplo).unwrap(), $rtol);
+                    // I - y y^H is not positive definite for |y| > 1
+                    let y = x.mapv(|elem| elem * <$elem>::from(2.0 / x.norm_l2()));
+                    let mut f = Array2::<$elem>::eye(4).factorizec(uplo).unwrap();
+                    assert!(f.downdate(&y).is_err());
+                }
+            }
+        }
+    };
+}
+cholesky_update!(f64, 1e-9);
+cholesky_update!(c64, 1e-9);
+
+fn test_triangular_product<A: Scalar + Lapack>(l: Array2<A>) {
+    let l = l.into_triangular(UPLO::Lower);
+    let lhl = l.t().mapv(|x| x.conj()).dot(&l);
+    let mut p = l.clone();
+    A::triangular_product(
+        p.layout().unwrap(),
+        UPLO::Lower,
+        p.as_allocated_mut().unwrap(),
+    )
+    .unwrap();
+    assert_close_l2!(
+        &p.into_triangular(UPLO::Lower),
+        &lhl.into_triangular(UPLO::Lower),
+        A::real(1e-9)
+    );
+
+    let u = l.t().mapv(|x| x.conj());
+    let uuh = u.dot(&u.t().mapv(|x| x.conj()));
+    let mut p = u.clone();
+    A::triangular_product(
+        p.layout().unwrap(),
+        UPLO::Upper,
+        p.as_allocated_mut().unwrap(),
+    )
+    .unwrap();
+    assert_close_l2!(
+        &p.into_triangular(UPLO::Upper),
+        &uuh.into_triangular(UPLO::Upper),
+        A::real(1e-9)
+    );
+}
+
+#[test]
+fn triangular_product() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    test_triangular_product::<f64>(random_using((4, 4), &mut rng));
+    test_triangular_product::<f64>(random_using((4, 4).f(), &mut rng));
+    test_triangular_product::<c64>(random_using((4, 4), &mut rng));
+    test_triangular_product::<c64>(random_using((4, 4).f(), &mut rng));
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "rustfft")]
+
+use ndarray::*;
+use ndarray_linalg::*;
+
+macro_rules! test_circulant {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<circulant_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                for n in [1, 5, 8] {
+                    let c: Array1<$type> = random_using(n, &mut rng);
+                    let c = CirculantMatrix::new(c);
+                    let a = c.to_dense();
+                    let b: Array1<$type> = random_using(n, &mut rng);
+                    assert_close_l2!(&c.solve(&b).unwrap(), &a.solve(&b).unwrap(), $rtol);
+                    assert_rclose!(c.det(), a.det().unwrap(), $rtol);
+
+                    // Eigenvalues are in the order of the frequencies
+                    let w = c.eigvals();
+                    let omega = <$type>::from_real(<$type>::real(2.0 * std::f64::consts::PI / n as f64))
+                        .mul_complex(<$type>::complex(0, 1));
+                    for k in 0..n {
+                        let v = Array1::from_shape_fn(n, |j| omega.mul_real((j * k) as _).exp());
+                        assert_close_l2!(&a.dot(&v), &v.mapv(|x| x * w[k]), $rtol);
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_circulant!(c64, 1e-9);
+test_circulant!(c32, 1e-4);
+
+#[test]
+fn circulant_real() {
+    // Periodic 1D Laplacian shifted to be non-singular
+    let n = 6;
+    let mut c = Array1::zeros(n);
+    c[0] = 3.0;
+    c[1] = -1.0;
+    c[n - 1] = -1.0;
+    let c = CirculantMatrix::new(c);
+    let a = c.to_dense();
+    let b: Array1<f64> = Array1::from_shape_fn(n, |i| i as f64);
+    let x = c.solve(&b).unwrap();
+    assert_close_l2!(&x, &a.solve(&b).unwrap().mapv(c64::from), 1e-12);
+    assert_rclose!(c.det(), c64::from(a.det().unwrap()), 1e-12);
+}
+
+#[test]
+fn circulant_singular() {
+    let c = CirculantMatrix::new(array![2.0, -1.0, 0.0, -1.0]);
+    assert!(c.solve(&array![1.0, 0.0, 0.0, -1.0]).is_err());
+}
@@ -0,0 +1,69 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_cod<T: Scalar + Lapack>(m: usize, n: usize, r: usize) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let x: Array2<T> = random_using((m, r), &mut rng);
+    let y: Array2<T> = random_using((r, n), &mut rng);
+    let a = x.dot(&y);
+    let cod = complete_orthogonal(&a, T::real(1e-7)).unwrap();
+    assert_eq!(cod.rank, r);
+
+    let mut ap = Array2::zeros((m, n));
+    for (j, &p) in cod.perm.iter().enumerate() {
+        ap.column_mut(j).assign(&a.column(p));
+    }
+    assert!(cod.z.is_standard_layout());
+    let zh: Array2<T> = conjugate(&cod.z);
+    assert_close_l2!(&cod.q.dot(&cod.t).dot(&zh), &ap, T::real(1e-7));
+    assert_close_l2!(&zh.dot(&cod.z), &Array2::eye(r), T::real(1e-7));
+    assert_close_l2!(
+        &cod.t.clone().into_triangular(UPLO::Upper),
+        &cod.t,
+        T::real(1e-7)
+    );
+
+    let b: Array1<T> = random_using(m, &mut rng);
+    let sol = cod.solve_min_norm(&b).unwrap();
+    // Normal equation A^H (A x - b) = 0
+    let ah: Array2<T> = conjugate(&a);
+    let res = ah.dot(&(a.dot(&sol) - &b));
+    assert!(res.norm_l2() < T::real(1e-7) * ah.dot(&b).norm_l2());
+    // Minimum norm, i.e. orthogonal to the null space of A = X Y
+    let yh: Array2<T> = conjugate(&y);
+    let proj = yh.dot(&y.dot(&yh).inv().unwrap().dot(&y.dot(&sol)));
+    assert_close_l2!(&proj, &sol, T::real(1e-7));
+}
+
+#[test]
+fn cod_rank_deficient() {
+    test_cod::<f64>(5, 4, 2);
+    test_cod::<f64>(3, 6, 2);
+    test_cod::<c64>(5, 4, 2);
+    test_cod::<c64>(3, 6, 2);
+}
+
+#[test]
+fn cod_full_rank() {
+    test_cod::<f64>(5, 3, 3);
+    test_cod::<c64>(3, 5, 3);
+}
+
+#[test]
+fn cod_reduced_column() {
+    // The 2nd column vanishes after the 1st step of the pivoted QR decomposition
+    let a = array![[10.0, 9.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, 1.0]];
+    let cod = complete_orthogonal(&a, 1e-10).unwrap();
+    assert_eq!(cod.rank, 2);
+    let x = cod.solve_min_norm(&array![19.0, 1.0, 1.0]).unwrap();
+    assert_close_l2!(&x, &array![190.0 / 181.0, 171.0 / 181.0, 1.0], 1e-12);
+}
+
+#[test]
+fn cod_zero() {
+    let a = Array2::<f64>::zeros((3, 2));
+    let cod = complete_orthogonal(&a, 1e-10).unwrap();
+    assert_eq!(cod.rank, 0);
+    let x = cod.solve_min_norm(&array![1.0, 2.0, 3.0]).unwrap();
+    assert_eq!(x, Array1::zeros(2));
+}
@@ -1,3 +1,4 @@
+use lax::qr::max_orthogonality_error;
 use ndarray::*;
 use ndarray_linalg::*;
 
@@ -330,3 +331,91 @@ macro_rules! impl_test_complex {
 
 impl_test_complex!(c32);
 impl_test_complex!(c64);
+
+/// `beta_i A v_i = alpha_i B v_i` and `beta_i u_i^H A = alpha_i u_i^H B`
+/// The eigenvectors of a normal matrix with distinct eigenvalues are orthonormal
+fn test_eig_normal<T>(rtol: T::Real)
+where
+    T: Scalar + Lapack,
+    T::Complex: Lapack<Real = T::Real>,
+{
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<T> = random_hermite_using(5, &mut rng);
+    let (_, v) = a.eig().unwrap();
+    assert!(max_orthogonality_error(v.as_allocated().unwrap(), v.layout().unwrap()) < rtol);
+}
+
+#[test]
+fn eig_normal_f64() {
+    test_eig_normal::<f64>(1e-9);
+}
+
+#[test]
+fn eig_normal_c64() {
+    test_eig_normal::<c64>(1e-9);
+}
+
+fn test_eig_generalized<T: Scalar + Lapack>(a: &Array2<T>, b: &Array2<T>, rtol: T::Real)
+where
+    T::Complex: Lapack,
+{
+    let res = a.eig_generalized_with_left(b).unwrap();
+    let a = a.map(|x| x.as_c());
+    let b = b.map(|x| x.as_c());
+    let vl = res.vl.unwrap();
+    for i in 0..a.nrows() {
+        let (alpha, beta) = (res.alpha[i], res.beta[i].as_c());
+        let v = res.vr.column(i);
+        assert_close_l2!(
+            &a.dot(&v).mapv(|x| x * beta),
+            &b.dot(&v).mapv(|x| x * alpha),
+            rtol
+        );
+        let uh = vl.column(i).mapv(|x| x.conj());
+        assert_close_l2!(
+            &uh.dot(&a).mapv(|x| x * beta),
+            &uh.dot(&b).mapv(|x| x * alpha),
+            rtol
+        );
+    }
+}
+
+macro_rules! impl_test_eig_generalized {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<$type _eig_generalized>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((4, 4), &mut rng);
+                let b: Array2<$type> = random_using((4, 4), &mut rng);
+                test_eig_generalized(&a, &b, $rtol);
+            }
+
+            #[test]
+            fn [<$type _eig_generalized_t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((4, 4).f(), &mut rng);
+                let b: Array2<$type> = random_using((4, 4), &mut rng);
+                test_eig_generalized(&a, &b, $rtol);
+            }
+        }
+    };
+}
+
+impl_test_eig_generalized!(f64, 1e-9);
+impl_test_eig_generalized!(c64, 1e-9);
+
+#[test]
+fn eig_generalized_identity() {
+    // `B = I` reduces to the standard eigenvalue problem
+    let a = test_matrix_real::<f64>();
+    let b = Array2::<f64>::eye(a.nrows());
+    let res = a.eig_generalized(&b).unwrap();
+    assert!(res.vl.is_none());
+    let eigs = res.alpha.clone() / res.beta.mapv(|b| b.as_c());
+    assert_close_l2!(
+        &sorted_eigvals(eigs.view()),
+        &sorted_eigvals(answer_eig_real::<f64>().view()),
+        1e-9
+    );
+}
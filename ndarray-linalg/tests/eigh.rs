@@ -132,3 +132,179 @@ fn ssqrt_t_lower() {
     println!("ss = {:?}", &ss);
     assert_close_l2!(&ss, &ans, 1e-7);
 }
+
+#[test]
+fn eigenvalue_clusters() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let d = arr1(&[1.0, 2.0, 2.0 + 1e-10, 5.0]);
+    let q: Array2<f64> = random_unitary_using(4, &mut rng);
+    let a = q.dot(&Array2::from_diag(&d)).dot(&q.t());
+    let e = a.eigvalsh(UPLO::Upper).unwrap();
+    let clusters = detect_eigenvalue_clusters(e.as_slice().unwrap(), 1e-8);
+    assert_eq!(clusters, vec![1..3]);
+}
+
+#[test]
+fn eigenvalue_clusters_degenerate() {
+    assert!(detect_eigenvalue_clusters(&[1.0_f64], 1e-8).is_empty());
+    assert!(detect_eigenvalue_clusters(&[1.0_f64, 2.0, 3.0], 1e-8).is_empty());
+    assert_eq!(
+        detect_eigenvalue_clusters(&[1.0_f64, 1.0, 1.0], 1e-8),
+        vec![0..3]
+    );
+}
+
+#[test]
+fn generalized_eigvalsh() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hpd_using(4, &mut rng);
+    let b: Array2<f64> = random_hpd_using(4, &mut rng);
+    let (e, _) = (a.clone(), b.clone()).eigh(UPLO::Upper).unwrap();
+    let e_only = (a, b).eigvalsh(UPLO::Upper).unwrap();
+    assert_close_l2!(&e_only, &e, 1e-7);
+}
+
+#[test]
+fn generalized_eigvalsh_c() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hpd_using(4, &mut rng);
+    let b: Array2<c64> = random_hpd_using(4, &mut rng).reversed_axes();
+    let (e, _) = (a.clone(), b.clone()).eigh(UPLO::Lower).unwrap();
+    let e_only = (a, b).eigvalsh(UPLO::Lower).unwrap();
+    assert_close_l2!(&e_only, &e, 1e-7);
+}
+
+#[should_panic]
+#[test]
+fn generalized_eigvalsh_shape_mismatch() {
+    let a = Array2::<f64>::eye(3);
+    let b = Array2::<f64>::eye(2);
+    let _ = (a, b).eigvalsh_inplace(UPLO::Upper);
+}
+
+fn test_reduce_generalized<T: Scalar + Lapack>(a: &Array2<T>, b: &Array2<T>, uplo: UPLO)
+where
+    T::Real: Lapack,
+{
+    let ans = (a.clone(), b.clone()).eigvalsh(uplo).unwrap();
+    let b_chol = b.cholesky(uplo).unwrap();
+    let mut c = a.clone();
+    assert_eq!(c.layout().unwrap(), b_chol.layout().unwrap());
+    T::reduce_generalized(
+        c.layout().unwrap(),
+        lax::GeneralizedEigType::AxLambdaBx,
+        uplo,
+        c.as_allocated_mut().unwrap(),
+        b_chol.as_allocated().unwrap(),
+    )
+    .unwrap();
+    let w = c.eigvalsh(uplo).unwrap();
+    assert_close_l2!(&w, &ans, T::real(1e-7));
+}
+
+#[test]
+fn reduce_generalized() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    for uplo in [UPLO::Upper, UPLO::Lower] {
+        let a: Array2<f64> = random_hpd_using(4, &mut rng);
+        let b: Array2<f64> = random_hpd_using(4, &mut rng);
+        test_reduce_generalized(&a, &b, uplo);
+        let a: Array2<c64> = random_hermite_using(4, &mut rng);
+        let b: Array2<c64> = random_hpd_using(4, &mut rng);
+        test_reduce_generalized(&a, &b, uplo);
+    }
+}
+
+#[test]
+fn reduce_generalized_t() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    for uplo in [UPLO::Upper, UPLO::Lower] {
+        let a: Array2<c64> = random_hermite_using(4, &mut rng);
+        let b: Array2<c64> = random_hpd_using(4, &mut rng);
+        let mut a_t = Array2::zeros((4, 4).f());
+        let mut b_t = Array2::zeros((4, 4).f());
+        a_t.assign(&a);
+        b_t.assign(&b);
+        test_reduce_generalized(&a_t, &b_t, uplo);
+    }
+}
+
+#[test]
+fn reduce_generalized_abx() {
+    // `A B x = lambda x` reduces into `L^H A L` with `B = L L^H`
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hpd_using(4, &mut rng);
+    let b: Array2<f64> = random_hpd_using(4, &mut rng);
+    let l = b.cholesky(UPLO::Lower).unwrap();
+    let mut c = a.clone();
+    f64::reduce_generalized(
+        c.layout().unwrap(),
+        lax::GeneralizedEigType::ABxLambdaX,
+        UPLO::Lower,
+        c.as_allocated_mut().unwrap(),
+        l.as_allocated().unwrap(),
+    )
+    .unwrap();
+    let w = c.eigvalsh(UPLO::Lower).unwrap();
+    let ans = l.t().dot(&a).dot(&l).eigvalsh(UPLO::Lower).unwrap();
+    assert_close_l2!(&w, &ans, 1e-7);
+}
+
+fn test_eigh_generalized<T: Scalar + Lapack>(a: &Array2<T>, b: &Array2<T>, uplo: UPLO) {
+    use GeneralizedEigType::*;
+    for itype in [AxLambdaBx, ABxLambdaX, BAxLambdaX] {
+        let (e, v) = a.eigh_generalized(b, uplo, itype).unwrap();
+        let ve = v.dot(&Array2::from_diag(&e.mapv(T::from_real)));
+        let vh: Array2<T> = conjugate(&v);
+        let (lhs, rhs, norm) = match itype {
+            AxLambdaBx => (a.dot(&v), b.dot(&ve), vh.dot(b).dot(&v)),
+            ABxLambdaX => (a.dot(b).dot(&v), ve, vh.dot(b).dot(&v)),
+            BAxLambdaX => (b.dot(a).dot(&v), ve, vh.dot(&b.inv().unwrap()).dot(&v)),
+        };
+        assert_close_l2!(&lhs, &rhs, T::real(1e-7));
+        assert_close_l2!(&norm, &Array2::eye(a.nrows()), T::real(1e-7));
+        let e_only = a.eigvalsh_generalized(b, uplo, itype).unwrap();
+        assert_close_l2!(
+            &e_only.mapv(T::from_real),
+            &e.mapv(T::from_real),
+            T::real(1e-7)
+        );
+    }
+}
+
+#[test]
+fn eigh_generalized() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    for uplo in [UPLO::Upper, UPLO::Lower] {
+        let a: Array2<f64> = random_hermite_using(4, &mut rng);
+        let b: Array2<f64> = random_hpd_using(4, &mut rng);
+        test_eigh_generalized(&a, &b, uplo);
+        let a: Array2<c64> = random_hermite_using(4, &mut rng);
+        let b: Array2<c64> = random_hpd_using(4, &mut rng);
+        test_eigh_generalized(&a, &b, uplo);
+    }
+}
+
+#[test]
+fn eigh_generalized_t() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    for uplo in [UPLO::Upper, UPLO::Lower] {
+        let a: Array2<c64> = random_hermite_using(4, &mut rng);
+        let b: Array2<c64> = random_hpd_using(4, &mut rng);
+        let mut a_t = Array2::zeros((4, 4).f());
+        let mut b_t = Array2::zeros((4, 4).f());
+        a_t.assign(&a);
+        b_t.assign(&b);
+        test_eigh_generalized(&a_t, &b_t, uplo);
+        // `B` in the other memory order than `A`
+        test_eigh_generalized(&a, &b_t, uplo);
+    }
+}
+
+#[should_panic]
+#[test]
+fn eigh_generalized_itype_shape_mismatch() {
+    let a = Array2::<f64>::eye(3);
+    let b = Array2::<f64>::eye(2);
+    let _ = a.eigh_generalized(&b, UPLO::Upper, GeneralizedEigType::AxLambdaBx);
+}
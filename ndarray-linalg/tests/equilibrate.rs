@@ -0,0 +1,85 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Scale rows and columns from `1e-6` to `1e6` keeping the memory layout
+fn badly_scaled<A: Scalar>(a: &Array2<A>) -> Array2<A> {
+    let mut a = a.clone();
+    for ((i, j), x) in a.indexed_iter_mut() {
+        let s = 10f64.powi(3 * i as i32 - 6) * 10f64.powi(6 - 3 * j as i32);
+        *x = x.mul_real(A::real(s));
+    }
+    a
+}
+
+macro_rules! test_equilibrate {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<equilibrate_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a_c: Array2<$elem> = random_using((5, 4), &mut rng);
+                let a_f: Array2<$elem> = random_using((5, 4).f(), &mut rng);
+                for a in [a_c, a_f] {
+                    let a = badly_scaled(&a);
+                    let (eq, scaled) = a.equilibrate().unwrap();
+                    assert_eq!(eq.r.len(), 5);
+                    assert_eq!(eq.c.len(), 4);
+                    assert_eq!(scaled, eq.scale_matrix(&a));
+                    // The largest element of each column is 1 in absolute value
+                    for col in scaled.columns() {
+                        let amax = col.iter().fold(0.0, |m, x| x.abs().max(m));
+                        assert_rclose!(amax, 1.0, $rtol);
+                    }
+                    let amax = a.iter().fold(0.0, |m, x| x.abs().max(m));
+                    assert_rclose!(eq.amax, amax, $rtol);
+                }
+            }
+
+            #[test]
+            fn [<solve_equilibrated_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = badly_scaled(&random_using((5, 5), &mut rng));
+                let x: Array1<$elem> = random_using(5, &mut rng);
+                let b = a.dot(&x);
+                let y = a.solve_equilibrated(&b).unwrap();
+                assert_close_l2!(&y, &x, $rtol);
+            }
+
+            #[test]
+            fn [<solvec_equilibrated_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let n = 5;
+                let a: Array2<$elem> = random_hpd_using(n, &mut rng);
+                let d = Array1::from_shape_fn(n, |i| <$elem>::real(10f64.powi(2 * i as i32 - 4)));
+                let a = Array2::from_shape_fn((n, n), |(i, j)| a[(i, j)].mul_real(d[i] * d[j]));
+                let (eq, scaled) = a.equilibrateh().unwrap();
+                assert_eq!(eq.r, eq.c);
+                for i in 0..n {
+                    assert_rclose!(scaled[(i, i)].re(), 1.0, $rtol);
+                }
+                let x: Array1<$elem> = random_using(n, &mut rng);
+                let b = a.dot(&x);
+                let y = a.solvec_equilibrated(&b).unwrap();
+                assert_close_l2!(&y, &x, $rtol);
+            }
+        }
+    };
+}
+
+test_equilibrate!(f64, 1e-7);
+test_equilibrate!(f32, 1e-3);
+test_equilibrate!(c64, 1e-7);
+test_equilibrate!(c32, 1e-3);
+
+#[test]
+fn equilibrate_zero_row() {
+    let a = array![[1.0, 2.0], [0.0, 0.0]];
+    assert!(a.equilibrate().is_err());
+    assert!(a.solve_equilibrated(&array![1.0, 1.0]).is_err());
+}
+
+#[test]
+fn equilibrateh_not_positive() {
+    let a = array![[1.0, 2.0], [2.0, -1.0]];
+    assert!(a.equilibrateh().is_err());
+}
@@ -0,0 +1,169 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn expm_zero() {
+    let a = Array2::<f64>::zeros((3, 3));
+    assert_close_l2!(&a.expm().unwrap(), &Array2::eye(3), 1e-15);
+}
+
+#[test]
+fn expm_non_finite() {
+    for x in [f64::INFINITY, f64::NAN] {
+        let mut a = Array2::<f64>::eye(3);
+        a[(1, 2)] = x;
+        assert!(matches!(a.expm(), Err(error::LinalgError::NonFinite)));
+    }
+}
+
+#[test]
+fn expm_nilpotent() {
+    // exp(N) = I + N + N^2 / 2 for N^3 = 0
+    let a: Array2<f64> = array![[0.0, 1.0, 2.0], [0.0, 0.0, 3.0], [0.0, 0.0, 0.0]];
+    let ans = array![[1.0, 1.0, 3.5], [0.0, 1.0, 3.0], [0.0, 0.0, 1.0]];
+    assert_close_l2!(&a.expm().unwrap(), &ans, 1e-12);
+}
+
+#[test]
+fn expm_rotation() {
+    // Large angles require scaling and squaring
+    for t in [0.01, 0.1, 1.0, 10.0, 100.0_f64] {
+        let a: Array2<f64> = array![[0.0, -t], [t, 0.0]];
+        let ans = array![[t.cos(), -t.sin()], [t.sin(), t.cos()]];
+        assert_close_l2!(&a.expm().unwrap(), &ans, 1e-10);
+    }
+}
+
+fn test_expm_hermite<T: Scalar + Lapack>(scale: f64, rtol: T::Real) {
+    // exp(A) = V exp(D) V^H for Hermitian A = V D V^H
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<T> = random_hermite_using(5, &mut rng);
+    let a = a.mapv(|x| x * T::from_real(T::real(scale)));
+    let (e, v) = a.eigh(UPLO::Lower).unwrap();
+    let vh = v.t().mapv(|x| x.conj());
+    let ans = v
+        .dot(&Array2::from_diag(&e.mapv(|e| T::from_real(e.exp()))))
+        .dot(&vh);
+    assert_close_l2!(&a.expm().unwrap(), &ans, rtol);
+
+    let mut a_t = Array2::zeros((5, 5).f());
+    a_t.assign(&a);
+    assert_close_l2!(&a_t.expm().unwrap(), &ans, rtol);
+}
+
+macro_rules! test_expm_hermite {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<expm_hermite_ $type>]() {
+                // covers each degree of Padé approximant and the scaling
+                for scale in [1e-3, 1e-2, 1e-1, 0.3, 1.0, 10.0] {
+                    test_expm_hermite::<$type>(scale, $rtol);
+                }
+            }
+        }
+    };
+}
+
+test_expm_hermite!(f64, 1e-9);
+test_expm_hermite!(c64, 1e-9);
+
+#[test]
+fn expm_inverse() {
+    // exp(A) exp(-A) = I
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_using((4, 4), &mut rng);
+    let a = a * c64::new(3.0, 0.0);
+    let e = a.expm().unwrap();
+    let e_inv = a.mapv(|x| -x).expm().unwrap();
+    assert_close_l2!(&e.dot(&e_inv), &Array2::eye(4), 1e-9);
+}
+
+#[test]
+fn expm_not_square() {
+    let a = Array2::<f64>::zeros((2, 3));
+    assert!(a.expm().is_err());
+}
+
+fn test_expm_multiply<T: Scalar + Lapack>(scale: f64, rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<T> = random_using((6, 6), &mut rng);
+    let b: Array2<T> = random_using((6, 2), &mut rng);
+    let t = T::from_real(T::real(scale));
+    let e = a.mapv(|x| x * t).expm().unwrap();
+    let x = expm_multiply(&a, &b, t).unwrap();
+    assert_close_l2!(&x, &e.dot(&b), rtol);
+    let x = expm_multiply(&a, &b.column(0), t).unwrap();
+    assert_close_l2!(&x, &e.dot(&b.column(0)), rtol);
+}
+
+macro_rules! test_expm_multiply {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<expm_multiply_ $type>]() {
+                for scale in [1e-3, 0.1, 1.0, 10.0, -10.0] {
+                    test_expm_multiply::<$type>(scale, $rtol);
+                }
+            }
+        }
+    };
+}
+
+test_expm_multiply!(f64, 1e-9);
+test_expm_multiply!(c64, 1e-9);
+
+#[test]
+fn expm_multiply_rotation() {
+    // Imaginary time step of the Hermitian generator
+    let a: Array2<c64> = array![
+        [c64::new(0.0, 0.0), c64::new(1.0, 0.0)],
+        [c64::new(1.0, 0.0), c64::new(0.0, 0.0)]
+    ];
+    let b = array![c64::new(1.0, 0.0), c64::new(0.0, 0.0)];
+    let t = 20.0;
+    let x = expm_multiply(&a, &b, c64::new(0.0, -t)).unwrap();
+    let ans = array![c64::new(t.cos(), 0.0), c64::new(0.0, -t.sin())];
+    assert_close_l2!(&x, &ans, 1e-9);
+}
+
+#[test]
+fn expm_multiply_shape_mismatch() {
+    let a = Array2::<f64>::eye(3);
+    let b = Array1::<f64>::ones(4);
+    assert!(expm_multiply(&a, &b, 1.0).is_err());
+}
+
+#[test]
+fn expm_multiply_non_finite() {
+    let b = Array1::<f64>::ones(3);
+    for x in [f64::INFINITY, f64::NAN] {
+        let mut a = Array2::<f64>::eye(3);
+        a[(1, 2)] = x;
+        assert!(matches!(
+            expm_multiply(&a, &b, 1.0),
+            Err(error::LinalgError::NonFinite)
+        ));
+        let a = Array2::<f64>::eye(3);
+        assert!(matches!(
+            expm_multiply(&a, &b, x),
+            Err(error::LinalgError::NonFinite)
+        ));
+    }
+}
+
+#[test]
+fn expm_with_work_reuse() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let mut work = ExpmWork::<c64>::new(4);
+    // Both the low degree Padé approximant and the scaling and squaring are used
+    for scale in [0.01, 1.0, 10.0, 0.01] {
+        let a: Array2<c64> = random_using((4, 4), &mut rng);
+        let a = a.mapv(|x| x * scale);
+        let r = expm_with_work(&a, &mut work).unwrap();
+        assert_close_l2!(&r, &a.expm().unwrap(), 1e-12);
+        assert_eq!(expm_with_work(&a, &mut work).unwrap(), r);
+    }
+    let a: Array2<c64> = random_using((3, 3), &mut rng);
+    assert!(expm_with_work(&a, &mut work).is_err());
+}
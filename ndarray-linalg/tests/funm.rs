@@ -0,0 +1,109 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_funm_exp<T: Scalar + Lapack>(a: &Array2<T>, rtol: T::Real)
+where
+    T::Complex: Lapack,
+{
+    let f = a.funm(|z| z.exp()).unwrap();
+    let ans = a.mapv(|x| x.as_c()).expm().unwrap();
+    assert_close_l2!(&f, &ans, rtol);
+}
+
+macro_rules! test_funm {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<funm_exp_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((5, 5), &mut rng);
+                test_funm_exp(&a, $rtol);
+            }
+
+            #[test]
+            fn [<funm_exp_ $type _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((5, 5).f(), &mut rng);
+                test_funm_exp(&a, $rtol);
+            }
+        }
+    };
+}
+
+test_funm!(f64, 1e-9);
+test_funm!(c64, 1e-9);
+
+#[test]
+fn funm_jordan_block() {
+    // f(J) = [[f, f', f''/2], [0, f, f'], [0, 0, f]] for the Jordan block J
+    let a: Array2<f64> = array![[2.0, 1.0, 0.0], [0.0, 2.0, 1.0], [0.0, 0.0, 2.0]];
+    let e = 2.0_f64.exp();
+    let ans = array![[e, e, e / 2.0], [0.0, e, e], [0.0, 0.0, e]];
+    let f = a.funm(|z| z.exp()).unwrap();
+    assert_close_l2!(&f, &ans.mapv(|x| x.as_c()), 1e-12);
+}
+
+#[test]
+fn funm_clustered_eigenvalues() {
+    // Nonnormal matrix with clusters {1, 1.05, 1.02} and isolated eigenvalues {3, 5}
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let mut t: Array2<f64> = random_using((5, 5), &mut rng);
+    for (i, d) in [1.0, 3.0, 1.05, 5.0, 1.02].iter().enumerate() {
+        t[(i, i)] = *d;
+        for j in 0..i {
+            t[(i, j)] = 0.0;
+        }
+    }
+    let q: Array2<f64> = random_unitary_using(5, &mut rng);
+    let a = q.dot(&t).dot(&q.t());
+    test_funm_exp(&a, 1e-9);
+    let f = a.funm(|z| z.sqrt()).unwrap();
+    assert_close_l2!(&f, &a.sqrtm().unwrap(), 1e-9);
+}
+
+/// Upper triangular matrix with the given eigenvalues, transformed by a random unitary matrix
+fn nonnormal(eigs: &[f64], scale: f64) -> Array2<f64> {
+    let n = eigs.len();
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let mut t: Array2<f64> = random_using((n, n), &mut rng);
+    t *= scale;
+    for (i, d) in eigs.iter().enumerate() {
+        t[(i, i)] = *d;
+        for j in 0..i {
+            t[(i, j)] = 0.0;
+        }
+    }
+    let q: Array2<f64> = random_unitary_using(n, &mut rng);
+    q.dot(&t).dot(&q.t())
+}
+
+#[test]
+fn funm_cluster_near_branch_point() {
+    // The default circle around the cluster {0.01, 0.05, 0.03} contains the branch point 0 of sqrt
+    let a = nonnormal(&[0.01, 2.0, 0.05, 0.03], 0.01);
+    let f = a.funm(|z| z.sqrt()).unwrap();
+    assert_close_l2!(&f, &a.sqrtm().unwrap(), 1e-9);
+    assert_close_l2!(&f.dot(&f), &a.mapv(|x| x.as_c()), 1e-9);
+}
+
+#[test]
+fn funm_not_analytic() {
+    // The pole of 1/z is at the center of the cluster {0.01, -0.01}
+    let a = nonnormal(&[0.01, 2.0, -0.01], 0.01);
+    assert!(matches!(
+        a.funm(|z| z.inv()),
+        Err(error::LinalgError::NotAnalytic)
+    ));
+}
+
+#[test]
+fn powm() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((4, 4), &mut rng);
+    let ac = a.mapv(|x| x.as_c());
+    assert_close_l2!(&a.powm(2.0).unwrap(), &ac.dot(&ac), 1e-9);
+    assert_close_l2!(&a.powm(-1.0).unwrap(), &ac.inv().unwrap(), 1e-9);
+    assert_close_l2!(&a.powm(0.5).unwrap(), &a.sqrtm().unwrap(), 1e-9);
+    let r = a.powm(1.0 / 3.0).unwrap();
+    assert_close_l2!(&r.dot(&r).dot(&r), &ac, 1e-9);
+}
@@ -0,0 +1,55 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_givens<T: Scalar + Lapack>(f: T, g: T) {
+    let rot = Givens::new(f, g);
+    let (r, z) = rot.rotate(f, g);
+    assert!(z.abs() <= T::real(1e-12) * r.abs());
+    assert!((r.abs() - (f.square() + g.square()).sqrt()).abs() <= T::real(1e-12) * r.abs());
+    assert!(rot.c >= T::real(0.0));
+    assert!((rot.c * rot.c + rot.s.square() - T::real(1.0)).abs() < T::real(1e-12));
+}
+
+#[test]
+fn givens_new() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    for _ in 0..10 {
+        let v: Array1<f64> = random_using(2, &mut rng);
+        test_givens(v[0] - 0.5, v[1] - 0.5);
+        let v: Array1<c64> = random_using(2, &mut rng);
+        test_givens(v[0], v[1]);
+    }
+    test_givens(1.0, 0.0);
+    test_givens(0.0, -2.0);
+    test_givens(c64::new(0.0, 0.0), c64::new(0.0, 3.0));
+    // No overflow in the intermediate values
+    let (r, _) = Givens::new(1e300, 1e300).rotate(1e300, 1e300);
+    assert!((r / 1e300 - 2.0_f64.sqrt()).abs() < 1e-12);
+    // `r` has the same phase as `f`
+    let (r, _) = Givens::new(-3.0, 4.0).rotate(-3.0, 4.0);
+    assert!((r + 5.0).abs() < 1e-12);
+}
+
+fn test_rotate_matrix<T: Scalar + Lapack>() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<T> = random_using((4, 4), &mut rng);
+    let rot = Givens::new(a[(1, 0)], a[(3, 0)]);
+    let g = rot.to_matrix(4, 1, 3);
+    let gh = g.t().mapv(|x| x.conj());
+    assert_close_l2!(&g.dot(&gh), &Array2::eye(4), T::real(1e-12));
+
+    let mut b = a.clone();
+    rot.rotate_rows(&mut b, 1, 3);
+    assert_close_l2!(&b, &g.dot(&a), T::real(1e-12));
+    assert!(b[(3, 0)].abs() < T::real(1e-12));
+
+    let mut c = a.clone();
+    rot.rotate_cols(&mut c.view_mut(), 1, 3);
+    assert_close_l2!(&c, &a.dot(&gh), T::real(1e-12));
+}
+
+#[test]
+fn givens_rotate_matrix() {
+    test_rotate_matrix::<f64>();
+    test_rotate_matrix::<c64>();
+}
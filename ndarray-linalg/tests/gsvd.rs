@@ -0,0 +1,58 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test<T: Scalar + Lapack>(a: &Array2<T>, b: &Array2<T>) {
+    let (m, n) = a.dim();
+    let p = b.nrows();
+    let res = a.gsvd(b).unwrap();
+    assert_eq!(res.u.dim(), (m, m));
+    assert_eq!(res.v.dim(), (p, p));
+    assert_eq!(res.q.dim(), (n, n));
+    let kl = res.k + res.l;
+    assert_eq!(res.r.dim(), (kl, kl));
+    for i in 0..kl {
+        let norm = res.alpha[i] * res.alpha[i] + res.beta[i] * res.beta[i];
+        assert!((norm - T::real(1.0)).abs() < T::real(1e-12));
+    }
+    let qh = res.q.t().mapv(|x| x.conj());
+    let zr = res.zero_r();
+    assert_close_l2!(&res.u.dot(&res.d1()).dot(&zr).dot(&qh), a, T::real(1e-12));
+    assert_close_l2!(&res.v.dot(&res.d2()).dot(&zr).dot(&qh), b, T::real(1e-12));
+}
+
+macro_rules! test_gsvd {
+    ($type:ty, $m:expr, $n:expr, $p:expr) => {
+        paste::item! {
+            #[test]
+            fn [<gsvd_ $type _ $m x $n x $p>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using(($m, $n), &mut rng);
+                let b: Array2<$type> = random_using(($p, $n), &mut rng);
+                test(&a, &b);
+                test(&a.t().to_owned().reversed_axes(), &b);
+            }
+        }
+    };
+}
+
+test_gsvd!(f64, 5, 4, 3);
+test_gsvd!(f64, 2, 4, 3);
+test_gsvd!(f64, 3, 6, 2);
+test_gsvd!(c64, 5, 4, 3);
+test_gsvd!(c64, 2, 4, 3);
+test_gsvd!(c64, 3, 6, 2);
+
+#[test]
+fn gsvd_square_nonsingular_b() {
+    // Generalized singular values are the singular values of `A B^{-1}`
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((4, 4), &mut rng);
+    let b: Array2<f64> = random_hpd_using(4, &mut rng);
+    let res = a.gsvd(&b).unwrap();
+    assert_eq!(res.k, 0);
+    assert_eq!(res.l, 4);
+    let mut sigma: Vec<f64> = (0..4).map(|i| res.alpha[i] / res.beta[i]).collect();
+    sigma.sort_by(|x, y| y.partial_cmp(x).unwrap());
+    let (_, s, _) = a.dot(&b.inv().unwrap()).svd(false, false).unwrap();
+    assert_close_l2!(&arr1(&sigma), &s, 1e-10);
+}
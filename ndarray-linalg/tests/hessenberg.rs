@@ -0,0 +1,60 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// `A = Q H Q^H` where `Q` is unitary and `H` is upper Hessenberg
+fn test_hessenberg<T: Scalar + Lapack>(a: &Array2<T>, rtol: T::Real) {
+    let n = a.nrows();
+    let (h, q) = a.hessenberg().unwrap();
+    let qh = q.t().mapv(|x| x.conj());
+    assert_close_l2!(&q.dot(&h).dot(&qh), a, rtol);
+    assert_close_l2!(&qh.dot(&q), &Array2::eye(n), rtol);
+    for i in 0..n {
+        for j in 0..i.saturating_sub(1) {
+            assert_eq!(h[(i, j)], T::zero());
+        }
+    }
+    // The first row and column are not affected by the reflections
+    assert_close_l2!(
+        &q.column(0).to_owned(),
+        &Array2::<T>::eye(n).column(0),
+        rtol
+    );
+}
+
+macro_rules! test_hessenberg {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<hessenberg_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((5, 5), &mut rng);
+                test_hessenberg(&a, $rtol);
+            }
+
+            #[test]
+            fn [<hessenberg_ $type _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((5, 5).f(), &mut rng);
+                test_hessenberg(&a, $rtol);
+            }
+        }
+    };
+}
+
+test_hessenberg!(f32, 1e-4);
+test_hessenberg!(f64, 1e-9);
+test_hessenberg!(c32, 1e-4);
+test_hessenberg!(c64, 1e-9);
+
+#[test]
+fn hessenberg_eigenvalues() {
+    // Similarity transform keeps the eigenvalues
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hermite_using(5, &mut rng);
+    let (h, _) = a.hessenberg().unwrap();
+    // `H` of a symmetric matrix is symmetric tridiagonal
+    assert_close_l2!(&h, &h.t(), 1e-9);
+    let e = a.eigvalsh(UPLO::Lower).unwrap();
+    let e_h = h.eigvalsh(UPLO::Lower).unwrap();
+    assert_close_l2!(&e_h, &e, 1e-9);
+}
@@ -0,0 +1,159 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+/// Matrix with the eigenvalues near `1, 2, ..., n`
+fn separated<T: Scalar + Lapack>(n: usize) -> Array2<T> {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let mut a: Array2<T> = random_using((n, n), &mut rng);
+    a.mapv_inplace(|x| x.mul_real(T::real(0.01)));
+    for i in 0..n {
+        a[(i, i)] += T::from_real(T::real(i + 1));
+    }
+    a
+}
+
+fn key<T: Scalar>(which: Which, x: T::Complex) -> T::Real {
+    match which {
+        Which::LargestMagnitude => -x.abs(),
+        Which::SmallestReal => x.re(),
+        Which::LargestReal => -x.re(),
+        _ => unreachable!(),
+    }
+}
+
+fn test_iram<T: Scalar + Lapack>(which: Which, rtol: T::Real) {
+    let n = 60;
+    let k = 4;
+    let a: Array2<T> = separated(n);
+    let mut w = a.eig().unwrap().0.to_vec();
+    w.sort_by(|&x, &y| key::<T>(which, x).partial_cmp(&key::<T>(which, y)).unwrap());
+
+    let result = Iram::new(k, which)
+        .ncv(12)
+        .tol(rtol)
+        .solve(&a, &Array1::ones(n))
+        .unwrap();
+    assert!(result.converged);
+    assert_eq!(result.eigenvalues.len(), k);
+    assert_eq!(result.eigenvectors.dim(), (n, k));
+    let a = a.mapv(|x| x.as_c());
+    for (i, (&lambda, expected)) in result.eigenvalues.iter().zip(&w).enumerate() {
+        assert_rclose!(lambda.re(), expected.re(), rtol * T::real(10));
+        // A x = lambda x
+        let x = result.eigenvectors.column(i);
+        let ax = a.dot(&x);
+        let res: T::Real = Zip::from(&ax).and(&x).fold(T::real(0.0), |acc, &ax, &x| {
+            acc + (ax - x * lambda).square()
+        });
+        assert!(res.sqrt() <= rtol * T::real(10) * lambda.abs());
+    }
+}
+
+macro_rules! impl_test {
+    ($which:ident, $name:ident) => {
+        impl_test!($which, $name, f32, 1e-4);
+        impl_test!($which, $name, f64, 1e-10);
+        impl_test!($which, $name, c32, 1e-4);
+        impl_test!($which, $name, c64, 1e-10);
+    };
+    ($which:ident, $name:ident, $scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<iram_ $name _ $scalar>]() {
+                test_iram::<$scalar>(Which::$which, $rtol);
+            }
+        }
+    };
+}
+
+impl_test!(LargestMagnitude, lm);
+impl_test!(LargestReal, lr);
+impl_test!(SmallestReal, sr);
+
+#[test]
+fn iram_complex_pair() {
+    // Rotation blocks with the eigenvalues `r (cos t +- i sin t)`
+    let n = 40;
+    let mut a = Array2::<f64>::zeros((n, n));
+    for b in 0..n / 2 {
+        let r = (b + 1) as f64;
+        let t = 0.1 * (b + 1) as f64;
+        a[(2 * b, 2 * b)] = r * t.cos();
+        a[(2 * b, 2 * b + 1)] = -r * t.sin();
+        a[(2 * b + 1, 2 * b)] = r * t.sin();
+        a[(2 * b + 1, 2 * b + 1)] = r * t.cos();
+    }
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let start: Array1<f64> = random_using(n, &mut rng);
+    let result = Iram::new(2, Which::LargestMagnitude)
+        .tol(1e-10)
+        .solve(&a, &start)
+        .unwrap();
+    assert!(result.converged);
+    for lambda in &result.eigenvalues {
+        assert_rclose!(lambda.norm(), 20.0, 1e-9);
+    }
+    let lambda = result.eigenvalues[0];
+    assert_rclose!(lambda.re, 20.0 * 2.0_f64.cos(), 1e-9);
+    assert_rclose!(lambda.im.abs(), 20.0 * 2.0_f64.sin(), 1e-9);
+}
+
+#[test]
+fn iram_shift_invert() {
+    let n = 60;
+    let a: Array2<f64> = separated(n);
+    let (w, _) = a.eig().unwrap();
+    let sigma = 30.2;
+    let op = ShiftInvert::new(&a, sigma).unwrap();
+    let result = Iram::new(2, Which::LargestMagnitude)
+        .tol(1e-12)
+        .solve(&op, &Array1::ones(n))
+        .unwrap();
+    assert!(result.converged);
+    for (theta, expected) in result.eigenvalues.iter().zip(&[30.0, 31.0]) {
+        let lambda = op.back_transform(*theta);
+        let closest = w
+            .iter()
+            .min_by(|x, y| {
+                (**x - lambda)
+                    .norm()
+                    .partial_cmp(&(**y - lambda).norm())
+                    .unwrap()
+            })
+            .unwrap();
+        assert_rclose!(lambda.re, closest.re, 1e-9);
+        assert_rclose!(lambda.re, *expected, 0.1);
+    }
+}
+
+#[test]
+fn iram_invariant_subspace() {
+    // The starting vector lies in the invariant subspace of the first 3 coordinates
+    let a: Array2<f64> = Array2::from_diag(&array![3.0, 2.0, 1.0, 4.0, 5.0]);
+    let result = Iram::new(2, Which::LargestMagnitude)
+        .solve(&a, &array![1.0, 1.0, 1.0, 0.0, 0.0])
+        .unwrap();
+    assert!(result.converged);
+    assert_eq!(result.restarts, 0);
+    assert_rclose!(result.eigenvalues[0].re, 3.0, 1e-12);
+    assert_rclose!(result.eigenvalues[1].re, 2.0, 1e-12);
+}
+
+#[test]
+fn iram_all_eigenvalues() {
+    // The Krylov subspace of dimension `n` is the whole space for `k >= n`
+    let a: Array2<f64> = separated(5);
+    let mut w = a.eig().unwrap().0.to_vec();
+    w.sort_by(|x, y| y.re.partial_cmp(&x.re).unwrap());
+    for &k in &[5, 8] {
+        let result = Iram::new(k, Which::LargestReal)
+            .tol(0.0)
+            .solve(&a, &Array1::ones(5))
+            .unwrap();
+        assert_eq!(result.restarts, 0);
+        assert_eq!(result.eigenvalues.len(), 5);
+        for (lambda, expected) in result.eigenvalues.iter().zip(&w) {
+            assert_rclose!(lambda.re, expected.re, 1e-12);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// `b = 1 + 2 t` with small noise and large outliers at every 7th point
+fn contaminated<A: Scalar<Real = A>>(n: usize) -> (Array2<A>, Array1<A>) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let noise: Array1<A> = random_using(n, &mut rng);
+    let t = Array1::from_shape_fn(n, |i| A::real(i) / A::real(n));
+    let mut b = Array1::from_shape_fn(n, |i| {
+        A::real(1.0) + A::real(2.0) * t[i] + A::real(0.01) * noise[i]
+    });
+    for i in (0..n).step_by(7) {
+        b[i] += A::real(10.0);
+    }
+    let a = Array2::from_shape_fn((n, 2), |(i, j)| if j == 0 { A::one() } else { t[i] });
+    (a, b)
+}
+
+macro_rules! test_irls {
+    ($elem:ty, $atol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<irls_huber_ $elem>]() {
+                let (a, b) = contaminated::<$elem>(50);
+                let result = irls(&a, &b, &Huber::default(), 1e-5, 100).unwrap();
+                assert!(result.converged);
+                assert!(result.weights.iter().all(|&w| 0.0 < w && w <= 1.0));
+                // Much closer to the truth than the ordinary least squares
+                let ols = a.least_squares(&b).unwrap().solution;
+                let truth = array![1.0, 2.0];
+                assert!((&result.solution - &truth).norm_l2() < 0.2 * (&ols - &truth).norm_l2());
+            }
+
+            #[test]
+            fn [<irls_bisquare_ $elem>]() {
+                let (a, b) = contaminated::<$elem>(50);
+                let result = irls(&a, &b, &Bisquare::default(), 1e-5, 100).unwrap();
+                assert!(result.converged);
+                assert_close_l2!(&result.solution, &array![1.0, 2.0], $atol);
+                for i in (0..50).step_by(7) {
+                    assert_eq!(result.weights[i], 0.0);
+                }
+            }
+        }
+    };
+}
+
+test_irls!(f64, 0.05);
+test_irls!(f32, 0.05);
+
+#[test]
+fn irls_weights() {
+    let huber = Huber::<f64>::default();
+    assert_eq!(huber.weight(1.0), 1.0);
+    assert_eq!(huber.weight(-1.345), 1.0);
+    assert_rclose!(huber.weight(-2.69), 0.5, 1e-12);
+    let bisquare = Bisquare { c: 2.0 };
+    assert_eq!(bisquare.weight(0.0), 1.0);
+    assert_rclose!(bisquare.weight(1.0), 0.5625, 1e-12);
+    assert_eq!(bisquare.weight(-2.0), 0.0);
+}
+
+#[test]
+fn irls_iteration_limit() {
+    let (a, b) = contaminated::<f64>(50);
+    let result = irls(&a, &b, &Huber::default(), 0.0, 2).unwrap();
+    assert!(!result.converged);
+    assert_eq!(result.iterations, 2);
+}
+
+#[test]
+fn irls_shape_error() {
+    let a: Array2<f64> = Array2::ones((3, 2));
+    assert!(irls(&a, &Array1::ones(2), &Huber::default(), 1e-8, 10).is_err());
+}
+
+#[test]
+fn irls_nan() {
+    let (a, mut b) = contaminated::<f64>(50);
+    b[7] = f64::NAN;
+    assert!(irls(&a, &b, &Huber::default(), 1e-8, 10).is_err());
+}
@@ -0,0 +1,553 @@
+use ndarray::*;
+use ndarray_linalg::{iterative::*, *};
+
+/// Matrix-free 1D Laplacian `tridiag(-1, 2, -1)` of size `n`
+struct Laplacian {
+    n: usize,
+}
+
+impl LinearOperator for Laplacian {
+    type Elem = f64;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<f64>
+    where
+        S: Data<Elem = f64>,
+    {
+        Array1::from_shape_fn(self.n, |i| {
+            let left = if i > 0 { a[i - 1] } else { 0.0 };
+            let right = if i + 1 < self.n { a[i + 1] } else { 0.0 };
+            2.0 * a[i] - left - right
+        })
+    }
+}
+
+fn laplacian(n: usize) -> Array2<f64> {
+    Array2::from_shape_fn((n, n), |(i, j)| {
+        if i == j {
+            2.0
+        } else if i + 1 == j || j + 1 == i {
+            -1.0
+        } else {
+            0.0
+        }
+    })
+}
+
+fn test_cg<T: Scalar + Lapack>(rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<T> = random_hpd_using(10, &mut rng);
+    let b: Array1<T> = random_using(10, &mut rng);
+    let result = cg(&a, &b, rtol, 100);
+    assert!(result.converged);
+    assert_eq!(result.residual_norms.len(), result.iterations + 1);
+    assert!(*result.residual_norms.last().unwrap() <= rtol * b.norm_l2());
+    let x = a.solve(&b).unwrap();
+    assert_close_l2!(&result.solution, &x, rtol * T::real(1e3));
+}
+
+#[test]
+fn cg_f32() {
+    test_cg::<f32>(1e-4);
+}
+
+#[test]
+fn cg_f64() {
+    test_cg::<f64>(1e-10);
+}
+
+#[test]
+fn cg_c32() {
+    test_cg::<c32>(1e-4);
+}
+
+#[test]
+fn cg_c64() {
+    test_cg::<c64>(1e-10);
+}
+
+#[test]
+fn cg_matrix_free() {
+    let n = 50;
+    let b = Array1::from_shape_fn(n, |i| (i as f64).sin());
+    let result = cg(&Laplacian { n }, &b, 1e-10, 2 * n);
+    assert!(result.converged);
+    // Exact in at most `n` iterations in exact arithmetic
+    assert!(result.iterations <= n + 5);
+    let x = laplacian(n).solve(&b).unwrap();
+    assert_close_l2!(&result.solution, &x, 1e-8);
+}
+
+#[test]
+fn cg_not_converged() {
+    let n = 50;
+    let b = Array1::ones(n);
+    let result = cg(&Laplacian { n }, &b, 1e-10, 3);
+    assert!(!result.converged);
+    assert_eq!(result.iterations, 3);
+    assert_eq!(result.residual_norms.len(), 4);
+}
+
+#[test]
+fn cg_zero_rhs() {
+    let b = Array1::zeros(5);
+    let result = cg(&laplacian(5), &b, 1e-10, 10);
+    assert!(result.converged);
+    assert_eq!(result.iterations, 0);
+    assert_eq!(result.solution, Array1::zeros(5));
+}
+
+fn test_gmres<T: Scalar + Lapack>(restart: usize, rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    // Nonsymmetric and diagonally dominant
+    let r: Array2<T> = random_using((20, 20), &mut rng);
+    let a = Array2::<T>::eye(20).mapv(|x| x.mul_real(T::real(10.0))) + r;
+    let b: Array1<T> = random_using(20, &mut rng);
+    let result = gmres(&a, &b, restart, rtol, 200);
+    assert!(result.converged);
+    assert_eq!(result.residual_norms.len(), result.iterations + 1);
+    let x = a.solve(&b).unwrap();
+    assert_close_l2!(&result.solution, &x, rtol * T::real(1e2));
+
+    // Jacobi preconditioner
+    let m_inv = a.diag().mapv(|x| T::one() / x).into_diagonal();
+    let result = gmres_preconditioned(&a, &m_inv, &b, restart, rtol, 200);
+    assert!(result.converged);
+    assert_close_l2!(&result.solution, &x, rtol * T::real(1e2));
+}
+
+macro_rules! impl_gmres {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<gmres_ $scalar>]() {
+                test_gmres::<$scalar>(20, $rtol);
+            }
+
+            #[test]
+            fn [<gmres_ $scalar _restarted>]() {
+                test_gmres::<$scalar>(5, $rtol);
+            }
+        }
+    };
+}
+
+impl_gmres!(f32, 1e-4);
+impl_gmres!(f64, 1e-10);
+impl_gmres!(c32, 1e-4);
+impl_gmres!(c64, 1e-10);
+
+/// Matrix-free upwind discretization of the 1D convection-diffusion operator `-u'' + c u'`
+struct ConvectionDiffusion {
+    n: usize,
+    c: f64,
+}
+
+impl LinearOperator for ConvectionDiffusion {
+    type Elem = f64;
+
+    fn apply<S>(&self, a: &ArrayBase<S, Ix1>) -> Array1<f64>
+    where
+        S: Data<Elem = f64>,
+    {
+        Array1::from_shape_fn(self.n, |i| {
+            let left = if i > 0 { a[i - 1] } else { 0.0 };
+            let right = if i + 1 < self.n { a[i + 1] } else { 0.0 };
+            (2.0 + self.c) * a[i] - (1.0 + self.c) * left - right
+        })
+    }
+}
+
+#[test]
+fn gmres_matrix_free() {
+    let n = 40;
+    let op = ConvectionDiffusion { n, c: 2.0 };
+    let b = Array1::from_shape_fn(n, |i| (i as f64 * 0.3).cos());
+    let result = gmres(&op, &b, n, 1e-10, 2 * n);
+    assert!(result.converged);
+    let res = &b - &op.apply(&result.solution);
+    assert!(res.norm_l2() <= 1e-10 * b.norm_l2() * 10.0);
+}
+
+#[test]
+fn gmres_identity_breakdown() {
+    // The Krylov subspace is invariant after one step
+    let a: Array2<f64> = Array2::eye(5) * 2.0;
+    let b: Array1<f64> = array![1.0, 2.0, 3.0, 4.0, 5.0];
+    let result = gmres(&a, &b, 5, 1e-12, 10);
+    assert!(result.converged);
+    assert_eq!(result.iterations, 1);
+    assert_close_l2!(&result.solution, &(&b / 2.0), 1e-12);
+}
+
+#[test]
+fn gmres_not_converged() {
+    let n = 40;
+    let op = ConvectionDiffusion { n, c: 2.0 };
+    let b = Array1::ones(n);
+    let result = gmres(&op, &b, 3, 1e-12, 6);
+    assert!(!result.converged);
+    assert_eq!(result.iterations, 6);
+    // The residual norm does not increase
+    for w in result.residual_norms.windows(2) {
+        assert!(w[1] <= w[0] * (1.0 + 1e-12));
+    }
+}
+
+fn test_bicgstab<T: Scalar + Lapack>(rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let r: Array2<T> = random_using((20, 20), &mut rng);
+    let a = Array2::<T>::eye(20).mapv(|x| x.mul_real(T::real(10.0))) + r;
+    let b: Array1<T> = random_using(20, &mut rng);
+    let x = a.solve(&b).unwrap();
+
+    let result = bicgstab(&a, &b, rtol, 100);
+    assert!(result.converged);
+    assert_eq!(result.residual_norms.len(), result.iterations + 1);
+    assert_close_l2!(&result.solution, &x, rtol * T::real(1e2));
+
+    let m_inv = a.diag().mapv(|x| T::one() / x).into_diagonal();
+    let result = bicgstab_preconditioned(&a, &m_inv, &b, rtol, 100);
+    assert!(result.converged);
+    assert_close_l2!(&result.solution, &x, rtol * T::real(1e2));
+}
+
+macro_rules! impl_bicgstab {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<bicgstab_ $scalar>]() {
+                test_bicgstab::<$scalar>($rtol);
+            }
+        }
+    };
+}
+
+impl_bicgstab!(f32, 1e-4);
+impl_bicgstab!(f64, 1e-10);
+impl_bicgstab!(c32, 1e-4);
+impl_bicgstab!(c64, 1e-10);
+
+#[test]
+fn bicgstab_matrix_free() {
+    let n = 40;
+    let op = ConvectionDiffusion { n, c: 2.0 };
+    let b = Array1::from_shape_fn(n, |i| (i as f64 * 0.3).cos());
+    let result = bicgstab(&op, &b, 1e-10, 4 * n);
+    assert!(result.converged);
+    let res = &b - &op.apply(&result.solution);
+    assert!(res.norm_l2() <= 1e-10 * b.norm_l2() * 10.0);
+}
+
+#[test]
+fn bicgstab_zero_rhs() {
+    let b = Array1::zeros(5);
+    let result = bicgstab(&laplacian(5), &b, 1e-10, 10);
+    assert!(result.converged);
+    assert_eq!(result.iterations, 0);
+    assert_eq!(result.solution, Array1::zeros(5));
+}
+
+/// Saddle-point system `[H B^H; B 0]` with Hermitian positive definite `H`
+fn kkt<T: Scalar + Lapack>(rng: &mut rand_pcg::Mcg128Xsl64) -> Array2<T> {
+    let h: Array2<T> = random_hpd_using(8, rng);
+    let b: Array2<T> = random_using((3, 8), rng);
+    let bh = b.t().mapv(|x| x.conj());
+    let mut k = Array2::zeros((11, 11));
+    k.slice_mut(s![..8, ..8]).assign(&h);
+    k.slice_mut(s![..8, 8..]).assign(&bh);
+    k.slice_mut(s![8.., ..8]).assign(&b);
+    k
+}
+
+fn test_minres<T: Scalar + Lapack>(rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<T> = kkt(&mut rng);
+    let b: Array1<T> = random_using(11, &mut rng);
+    let result = minres(&a, &b, rtol, 100);
+    assert!(result.converged);
+    assert_eq!(result.residual_norms.len(), result.iterations + 1);
+    let x = a.solve(&b).unwrap();
+    assert_close_l2!(&result.solution, &x, rtol * T::real(1e3));
+    // The residual norm does not increase
+    for w in result.residual_norms.windows(2) {
+        assert!(w[1] <= w[0] * T::real(1.0 + 1e-3));
+    }
+}
+
+macro_rules! impl_minres {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<minres_ $scalar>]() {
+                test_minres::<$scalar>($rtol);
+            }
+        }
+    };
+}
+
+impl_minres!(f32, 1e-4);
+impl_minres!(f64, 1e-10);
+impl_minres!(c32, 1e-4);
+impl_minres!(c64, 1e-10);
+
+#[test]
+fn minres_matrix_free() {
+    let n = 50;
+    let b = Array1::from_shape_fn(n, |i| (i as f64).sin());
+    let result = minres(&Laplacian { n }, &b, 1e-10, 2 * n);
+    assert!(result.converged);
+    let x = laplacian(n).solve(&b).unwrap();
+    assert_close_l2!(&result.solution, &x, 1e-8);
+}
+
+#[test]
+fn minres_indefinite_diagonal() {
+    let a: Array2<f64> = Array2::from_diag(&array![1.0, -2.0, 3.0, -4.0]);
+    let b: Array1<f64> = array![1.0, 1.0, 1.0, 1.0];
+    // CG is not applicable to the indefinite matrix
+    assert!(!cg(&a, &b, 1e-12, 10).converged);
+    let result = minres(&a, &b, 1e-12, 10);
+    assert!(result.converged);
+    assert!(result.iterations <= 4);
+    assert_close_l2!(
+        &result.solution,
+        &array![1.0, -0.5, 1.0 / 3.0, -0.25],
+        1e-10
+    );
+}
+
+fn test_lsqr<T: Scalar + Lapack>(rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<T> = random_using((30, 10), &mut rng);
+    let b: Array1<T> = random_using(30, &mut rng);
+    let x = a.least_squares(&b).unwrap().solution;
+    for result in [
+        lsqr(&a, &b, T::real(0.0), rtol, 100),
+        lsmr(&a, &b, T::real(0.0), rtol, 100),
+    ] {
+        assert!(result.converged);
+        assert_eq!(result.residual_norms.len(), result.iterations + 1);
+        assert_close_l2!(&result.solution, &x, rtol * T::real(1e3));
+    }
+
+    // Damped problem is the ridge regression with `lambda = damp^2`
+    let x = a
+        .least_squares_ridge(&b, &RidgePenalty::Scalar(T::real(0.49)))
+        .unwrap()
+        .solution;
+    for result in [
+        lsqr(&a, &b, T::real(0.7), rtol, 100),
+        lsmr(&a, &b, T::real(0.7), rtol, 100),
+    ] {
+        assert!(result.converged);
+        assert_close_l2!(&result.solution, &x, rtol * T::real(1e3));
+    }
+}
+
+macro_rules! impl_lsqr {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<lsqr_ $scalar>]() {
+                test_lsqr::<$scalar>($rtol);
+            }
+        }
+    };
+}
+
+impl_lsqr!(f32, 1e-4);
+impl_lsqr!(f64, 1e-10);
+impl_lsqr!(c32, 1e-4);
+impl_lsqr!(c64, 1e-10);
+
+#[test]
+fn lsqr_underdetermined() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((4, 7), &mut rng);
+    let b: Array1<f64> = random_using(4, &mut rng);
+    // Minimum norm solution computed by the SVD
+    let x = a.least_squares(&b).unwrap().solution;
+    for result in [lsqr(&a, &b, 0.0, 1e-12, 100), lsmr(&a, &b, 0.0, 1e-12, 100)] {
+        assert!(result.converged);
+        assert_close_l2!(&result.solution, &x, 1e-9);
+    }
+}
+
+#[test]
+fn lsmr_residual_estimate() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((20, 6), &mut rng);
+    let b: Array1<f64> = random_using(20, &mut rng);
+    let damp = 0.3;
+    let result = lsmr(&a, &b, damp, 1e-12, 100);
+    assert!(result.converged);
+    let x = &result.solution;
+    let r = &b - &a.dot(x);
+    let expected = (r.norm_l2().powi(2) + damp * damp * x.norm_l2().powi(2)).sqrt();
+    assert_rclose!(*result.residual_norms.last().unwrap(), expected, 1e-8);
+}
+
+fn test_preconditioned_cg<T: Scalar + Lapack>(rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<T> = random_hpd_using(10, &mut rng);
+    let b: Array1<T> = random_using(10, &mut rng);
+    let x = a.solve(&b).unwrap();
+
+    let jacobi = Jacobi::from_dense(&a).unwrap();
+    let result = cg_preconditioned(&a, &jacobi, &b, rtol, 100);
+    assert!(result.converged);
+    assert_close_l2!(&result.solution, &x, rtol * T::real(1e3));
+
+    let ssor = Ssor::from_dense(&a, T::real(1.2)).unwrap();
+    let result = cg_preconditioned(&a, &ssor, &b, rtol, 100);
+    assert!(result.converged);
+    assert_close_l2!(&result.solution, &x, rtol * T::real(1e3));
+
+    // IC(0) of a dense matrix is the exact Cholesky decomposition
+    for uplo in [UPLO::Upper, UPLO::Lower] {
+        let ic = IncompleteCholesky::from_dense(&a, uplo).unwrap();
+        let result = cg_preconditioned(&a, &ic, &b, rtol, 100);
+        assert!(result.converged);
+        assert!(result.iterations <= 2);
+        assert_close_l2!(&result.solution, &x, rtol * T::real(1e3));
+    }
+}
+
+fn test_preconditioned_nonsymmetric<T: Scalar + Lapack>(rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let r: Array2<T> = random_using((20, 20), &mut rng);
+    let a = Array2::<T>::eye(20).mapv(|x| x.mul_real(T::real(10.0))) + r;
+    let b: Array1<T> = random_using(20, &mut rng);
+    let x = a.solve(&b).unwrap();
+
+    // ILU(0) of a dense matrix is the exact LU decomposition without pivoting
+    let ilu = IncompleteLU::from_dense(&a).unwrap();
+    let result = gmres_preconditioned(&a, &ilu, &b, 10, rtol, 100);
+    assert!(result.converged);
+    assert!(result.iterations <= 2);
+    assert_close_l2!(&result.solution, &x, rtol * T::real(1e2));
+    let result = bicgstab_preconditioned(&a, &ilu, &b, rtol, 100);
+    assert!(result.converged);
+    assert_close_l2!(&result.solution, &x, rtol * T::real(1e2));
+
+    let ssor = Ssor::from_dense(&a, T::real(1.0)).unwrap();
+    let result = gmres_preconditioned(&a, &ssor, &b, 10, rtol, 100);
+    assert!(result.converged);
+    assert_close_l2!(&result.solution, &x, rtol * T::real(1e2));
+}
+
+macro_rules! impl_preconditioner {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<preconditioned_cg_ $scalar>]() {
+                test_preconditioned_cg::<$scalar>($rtol);
+            }
+
+            #[test]
+            fn [<preconditioned_nonsymmetric_ $scalar>]() {
+                test_preconditioned_nonsymmetric::<$scalar>($rtol);
+            }
+        }
+    };
+}
+
+impl_preconditioner!(f32, 1e-4);
+impl_preconditioner!(f64, 1e-10);
+impl_preconditioner!(c32, 1e-4);
+impl_preconditioner!(c64, 1e-10);
+
+/// 2D Laplacian on the `m x m` grid with the bandwidth `m` and zeros inside the band
+fn laplacian_2d(m: usize) -> Array2<f64> {
+    let n = m * m;
+    Array2::from_shape_fn((n, n), |(p, q)| {
+        let (i, j) = (p / m, p % m);
+        let (k, l) = (q / m, q % m);
+        if p == q {
+            4.0
+        } else if (i == k && (j + 1 == l || l + 1 == j)) || (j == l && (i + 1 == k || k + 1 == i)) {
+            -1.0
+        } else {
+            0.0
+        }
+    })
+}
+
+#[test]
+fn preconditioner_banded() {
+    let m = 10;
+    let a = laplacian_2d(m);
+    let b = Array1::from_shape_fn(m * m, |i| (i as f64 * 0.1).sin());
+    let x = a.solve(&b).unwrap();
+    let plain = cg(&a, &b, 1e-10, 500);
+    assert!(plain.converged);
+
+    let ic =
+        IncompleteCholesky::from_banded(&SymmetricBand::from_dense(&a, m, UPLO::Upper)).unwrap();
+    let result = cg_preconditioned(&a, &ic, &b, 1e-10, 500);
+    assert!(result.converged);
+    assert!(result.iterations < plain.iterations);
+    assert_close_l2!(&result.solution, &x, 1e-8);
+
+    let band = BandedMatrix::from_dense(&a, m, m);
+    let ilu = IncompleteLU::from_banded(&band).unwrap();
+    // ILU(0) coincides with IC(0) for the Hermitian matrix
+    assert_close_l2!(&ilu.precondition(&b), &ic.precondition(&b), 1e-12);
+    let result = gmres_preconditioned(&a, &ilu, &b, 30, 1e-10, 500);
+    assert!(result.converged);
+    assert_close_l2!(&result.solution, &x, 1e-8);
+
+    let ssor = Ssor::from_banded(&band, 1.5).unwrap();
+    let result = cg_preconditioned(&a, &ssor, &b, 1e-10, 500);
+    assert!(result.converged);
+    assert!(result.iterations < plain.iterations);
+    assert_close_l2!(&result.solution, &x, 1e-8);
+
+    let jacobi = Jacobi::from_banded(&band).unwrap();
+    assert_close_l2!(&jacobi.precondition(&b), &(&b / 4.0), 1e-12);
+}
+
+#[test]
+fn preconditioner_zero_pivot() {
+    let a: Array2<f64> = array![[0.0, 1.0], [1.0, 0.0]];
+    assert!(Jacobi::from_dense(&a).is_err());
+    assert!(Ssor::from_dense(&a, 1.0).is_err());
+    assert!(IncompleteLU::from_dense(&a).is_err());
+    assert!(IncompleteCholesky::from_dense(&a, UPLO::Lower).is_err());
+    assert!(Jacobi::from_dense(&Array2::<f64>::ones((2, 3))).is_err());
+}
+
+#[test]
+fn lsqr_matrix_free() {
+    // `(n + 1) x n` difference matrix with the boundary rows
+    let n = 20;
+    let op = FnOperator::with_adjoint(
+        (n + 1, n),
+        |x: ArrayView1<f64>| {
+            Array1::from_shape_fn(n + 1, |i| {
+                let left = if i > 0 { x[i - 1] } else { 0.0 };
+                let right = if i < n { x[i] } else { 0.0 };
+                right - left
+            })
+        },
+        |y: ArrayView1<f64>| Array1::from_shape_fn(n, |j| y[j] - y[j + 1]),
+    );
+    let dense = Array2::from_shape_fn((n + 1, n), |(i, j)| {
+        if i == j {
+            1.0
+        } else if i == j + 1 {
+            -1.0
+        } else {
+            0.0
+        }
+    });
+    let b = Array1::from_shape_fn(n + 1, |i| (i as f64 * 0.4).sin());
+    let x = dense.least_squares(&b).unwrap().solution;
+    for result in [
+        lsqr(&op, &b, 0.0, 1e-12, 100),
+        lsmr(&op, &b, 0.0, 1e-12, 100),
+    ] {
+        assert!(result.converged);
+        assert_close_l2!(&result.solution, &x, 1e-9);
+    }
+}
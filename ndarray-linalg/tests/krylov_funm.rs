@@ -0,0 +1,72 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+fn test_krylov_funm_exp<T: Scalar + Lapack>(rtol: T::Real)
+where
+    T::Complex: Lapack,
+{
+    // Full Krylov subspace reproduces exp(A) b
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<T> = random_using((8, 8), &mut rng);
+    let b: Array1<T> = random_using(8, &mut rng);
+    let x = krylov_funm(a.view(), b.clone(), 8, T::real(1e-12), |z| z.exp()).unwrap();
+    let ans = a.expm().unwrap().dot(&b).mapv(|x| x.as_c());
+    assert_close_l2!(&x, &ans, rtol);
+}
+
+macro_rules! test_krylov_funm_exp {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<krylov_funm_exp_ $type>]() {
+                test_krylov_funm_exp::<$type>($rtol);
+            }
+        }
+    };
+}
+
+test_krylov_funm_exp!(f64, 1e-9);
+test_krylov_funm_exp!(c64, 1e-9);
+
+#[test]
+fn krylov_funm_hermite_partial() {
+    // Eigenvalues in [0, 1] and a small subspace are enough for exp
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let n = 60;
+    let eigs: Array1<f64> = (0..n).map(|i| i as f64 / (n - 1) as f64).collect();
+    let q: Array2<f64> = random_unitary_using(n, &mut rng);
+    let a = q.dot(&Array2::from_diag(&eigs)).dot(&q.t());
+    let b: Array1<f64> = random_using(n, &mut rng);
+    let x = krylov_funm(a.view(), b.clone(), 15, 1e-12, |z| z.exp()).unwrap();
+    let ans = q
+        .dot(&Array2::from_diag(&eigs.mapv(f64::exp)))
+        .dot(&q.t())
+        .dot(&b);
+    assert_close_l2!(&x.mapv(|x| x.re), &ans, 1e-9);
+}
+
+#[test]
+fn krylov_funm_sqrt() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hpd_using(10, &mut rng);
+    let b: Array1<f64> = random_using(10, &mut rng);
+    let x = krylov_funm(a.view(), b.clone(), 10, 1e-12, |z| z.sqrt()).unwrap();
+    let ans = a.ssqrt(UPLO::Upper).unwrap().dot(&b);
+    assert_close_l2!(&x.mapv(|x| x.re), &ans, 1e-9);
+}
+
+#[test]
+fn krylov_funm_invariant_subspace() {
+    // The Krylov subspace of `b` is 2-dimensional
+    let a: Array2<f64> = Array2::from_diag(&arr1(&[1.0, 2.0, 3.0, 4.0]));
+    let b = arr1(&[1.0, 1.0, 0.0, 0.0]);
+    let x = krylov_funm(a.view(), b, 4, 1e-12, |z| z * z).unwrap();
+    assert_close_l2!(&x.mapv(|x| x.re), &arr1(&[1.0, 4.0, 0.0, 0.0]), 1e-9);
+}
+
+#[test]
+fn krylov_funm_zero() {
+    let a = Array2::<f64>::eye(3);
+    let x = krylov_funm(a.view(), Array1::zeros(3), 3, 1e-12, |z| z.exp()).unwrap();
+    assert_eq!(x, Array1::zeros(3));
+}
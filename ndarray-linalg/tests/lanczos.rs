@@ -0,0 +1,164 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+fn apply_dense<'a, A: Scalar + Lapack>(a: &'a Array2<A>) -> impl Fn(&[A], &mut [A]) + 'a {
+    move |x, y| {
+        let ax = a.dot(&aview1(x));
+        y.copy_from_slice(ax.as_slice().unwrap());
+    }
+}
+
+#[test]
+fn lanczos_full() {
+    // `n` steps reproduce all eigenvalues
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let n = 8;
+    let a: Array2<c64> = random_hermite_using(n, &mut rng);
+    let start: Array1<c64> = random_using(n, &mut rng);
+    let (d, e) = lanczos(n, apply_dense(&a), n, start.as_slice().unwrap()).unwrap();
+    assert_eq!(d.len(), n);
+    assert_eq!(e.len(), n - 1);
+    let w = (Array1::from(d), Array1::from(e))
+        .eigvalsh_tridiagonal()
+        .unwrap();
+    let ans = a.eigvalsh(UPLO::Upper).unwrap();
+    assert_close_l2!(&w, &ans, 1e-9);
+}
+
+#[test]
+fn lanczos_extreme() {
+    // Eigenvalues are -10, 10, and 98 values in [0, 1]
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let n = 100;
+    let mut eigs: Vec<f64> = (0..n - 2).map(|i| i as f64 / (n - 3) as f64).collect();
+    eigs.push(10.0);
+    eigs.push(-10.0);
+    let q: Array2<f64> = random_unitary_using(n, &mut rng);
+    let a = q.dot(&Array2::from_diag(&arr1(&eigs))).dot(&q.t());
+    let start: Array1<f64> = random_using(n, &mut rng);
+
+    let (d, e) = lanczos(n, apply_dense(&a), 20, start.as_slice().unwrap()).unwrap();
+    assert_eq!(d.len(), 20);
+    let w = (Array1::from(d), Array1::from(e))
+        .eigvalsh_tridiagonal()
+        .unwrap();
+    assert_rclose!(w[0], -10.0, 1e-8);
+    assert_rclose!(w[19], 10.0, 1e-8);
+}
+
+#[test]
+fn lanczos_invariant_subspace() {
+    // The Krylov subspace of the start vector is 2-dimensional
+    let a = Array2::from_diag(&arr1(&[1.0, 2.0, 3.0, 4.0]));
+    let start = [1.0, 1.0, 0.0, 0.0];
+    let (d, e) = lanczos(4, apply_dense(&a), 4, &start).unwrap();
+    assert_eq!(d.len(), 2);
+    assert_eq!(e.len(), 1);
+    let w = (Array1::from(d), Array1::from(e))
+        .eigvalsh_tridiagonal()
+        .unwrap();
+    assert_close_l2!(&w, &arr1(&[1.0, 2.0]), 1e-9);
+}
+
+#[test]
+fn lanczos_length_mismatch() {
+    let a = Array2::<f64>::eye(3);
+    assert!(lanczos(3, apply_dense(&a), 2, &[1.0, 0.0]).is_err());
+}
+
+/// Hermitian matrix with the eigenvalues -10, -5, 5, 10 and `n - 4` values in [0, 1]
+fn separated<A: Scalar + Lapack>(n: usize) -> Array2<A> {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let mut eigs: Vec<A> = (0..n - 4)
+        .map(|i| A::from_real(A::real(i) / A::real(n - 5)))
+        .collect();
+    eigs.extend(
+        [-10.0, -5.0, 5.0, 10.0]
+            .iter()
+            .map(|&x| A::from_real(A::real(x))),
+    );
+    let q: Array2<A> = random_unitary_using(n, &mut rng);
+    q.dot(&Array2::from_diag(&arr1(&eigs)))
+        .dot(&q.t().mapv(|x| x.conj()))
+}
+
+fn test_lanczos_eigh<A: Scalar + Lapack>(reorthogonalization: Reorthogonalization, rtol: A::Real)
+where
+    A::Real: Lapack,
+{
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let n = 100;
+    let a: Array2<A> = separated(n);
+    let start: Array1<A> = random_using(n, &mut rng);
+    for (which, expected) in [
+        (Which::LargestReal, [10.0, 5.0]),
+        (Which::SmallestReal, [-10.0, -5.0]),
+    ] {
+        let result = LanczosEigh::new(2, which)
+            .reorthogonalization(reorthogonalization)
+            .tol(rtol)
+            .solve(&a, &start)
+            .unwrap();
+        assert!(result.converged);
+        assert!(result.iterations < n);
+        for (i, &expected) in expected.iter().enumerate() {
+            let lambda = result.eigenvalues[i];
+            assert_rclose!(lambda, A::real(expected), rtol * A::real(10));
+            // A x = lambda x
+            let x = result.eigenvectors.column(i);
+            let res = a.dot(&x) - x.mapv(|x| x.mul_real(lambda));
+            assert!(res.norm_l2() <= rtol * A::real(10) * lambda.abs());
+        }
+    }
+}
+
+macro_rules! impl_test {
+    ($reorthogonalization:ident, $name:ident) => {
+        impl_test!($reorthogonalization, $name, f32, 1e-4);
+        impl_test!($reorthogonalization, $name, f64, 1e-10);
+        impl_test!($reorthogonalization, $name, c32, 1e-4);
+        impl_test!($reorthogonalization, $name, c64, 1e-10);
+    };
+    ($reorthogonalization:ident, $name:ident, $scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<lanczos_eigh_ $name _ $scalar>]() {
+                test_lanczos_eigh::<$scalar>(Reorthogonalization::$reorthogonalization, $rtol);
+            }
+        }
+    };
+}
+
+impl_test!(Full, full);
+impl_test!(Selective, selective);
+
+#[test]
+fn lanczos_eigh_no_ghost() {
+    // Continue the iteration long after 10 converges.
+    // Without reorthogonalization, a spurious copy of 10 would appear as the second largest.
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let n = 100;
+    let a: Array2<f64> = separated(n);
+    let start: Array1<f64> = random_using(n, &mut rng);
+    let result = LanczosEigh::new(3, Which::LargestReal)
+        .tol(0.0)
+        .max_iter(60)
+        .solve(&a, &start)
+        .unwrap();
+    assert!(!result.converged);
+    assert_eq!(result.iterations, 60);
+    assert_rclose!(result.eigenvalues[0], 10.0, 1e-9);
+    assert_rclose!(result.eigenvalues[1], 5.0, 1e-9);
+    assert!(result.eigenvalues[2] <= 1.0 + 1e-9);
+}
+
+#[test]
+fn lanczos_eigh_invariant_subspace() {
+    let a = Array2::from_diag(&arr1(&[1.0, 2.0, 3.0, 4.0]));
+    let result = LanczosEigh::new(2, Which::LargestReal)
+        .solve(&a, &arr1(&[1.0, 1.0, 0.0, 0.0]))
+        .unwrap();
+    assert!(result.converged);
+    assert_eq!(result.iterations, 2);
+    assert_close_l2!(&result.eigenvalues, &arr1(&[2.0, 1.0]), 1e-9);
+}
@@ -136,3 +136,521 @@ impl_underdetermined!(f32);
 impl_underdetermined!(f64);
 impl_underdetermined!(c32);
 impl_underdetermined!(c64);
+
+/// Normal equations for a well-conditioned tall matrix agree with the SVD-based solution
+fn test_normal_equations<T: Scalar + Lapack>(a: Array2<T>) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let b: Array1<T> = random_using(a.nrows(), &mut rng);
+    let expected = a.least_squares(&b).unwrap().solution;
+    let x = solve_normal_equations(&a, &b).unwrap();
+    assert_close_l2!(&x, &expected, T::real(1.0e-7));
+}
+
+macro_rules! impl_normal_equations {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<least_squares_ $scalar _normal_equations>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((100, 5), &mut rng);
+                test_normal_equations(a)
+            }
+
+            #[test]
+            fn [<least_squares_ $scalar _normal_equations_t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((100, 5).f(), &mut rng);
+                test_normal_equations(a)
+            }
+        }
+    };
+}
+impl_normal_equations!(f64);
+impl_normal_equations!(c64);
+
+#[test]
+fn least_squares_normal_equations_shape_error() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((10, 3), &mut rng);
+    let b: Array1<f64> = random_using(9, &mut rng);
+    match solve_normal_equations(&a, &b) {
+        Err(error::LinalgError::Shape(e)) => assert_eq!(e.kind(), ErrorKind::IncompatibleShape),
+        _ => panic!("IncompatibleShape error is expected"),
+    }
+}
+
+/// The normal equations agree with the SVD based solver on a tall matrix,
+/// and are faster since only an `n x n` matrix is factorized.
+#[test]
+fn least_squares_normal_equations_tall() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((2000, 10), &mut rng);
+    let b: Array1<f64> = random_using(2000, &mut rng);
+
+    let start = std::time::Instant::now();
+    let expected = a.least_squares(&b).unwrap().solution;
+    let t_svd = start.elapsed();
+
+    let start = std::time::Instant::now();
+    let x = solve_normal_equations(&a, &b).unwrap();
+    let t_normal = start.elapsed();
+
+    assert_close_l2!(&x, &expected, 1e-9);
+    println!(
+        "least_squares: {:?}, solve_normal_equations: {:?}, speedup: {:.1}x",
+        t_svd,
+        t_normal,
+        t_svd.as_secs_f64() / t_normal.as_secs_f64()
+    );
+}
+
+/// The solution has `n` entries for any `m x n` matrix,
+/// and the residual is available only when the solution is unique.
+#[test]
+fn least_squares_solution_shape() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    for &(m, n) in &[(5, 3), (3, 3), (3, 5)] {
+        let a: Array2<f64> = random_using((m, n), &mut rng);
+        let b: Array1<f64> = random_using(m, &mut rng);
+        let result = a.least_squares(&b).unwrap();
+        assert_eq!(result.solution.len(), n);
+        assert_eq!(result.residual_sum_of_squares.is_some(), m >= n);
+
+        let bs: Array2<f64> = random_using((m, 2), &mut rng);
+        let result = a.least_squares(&bs).unwrap();
+        assert_eq!(result.solution.dim(), (n, 2));
+        match result.residual_sum_of_squares {
+            Some(res) => {
+                assert!(m >= n);
+                assert_eq!(res.len(), 2);
+            }
+            None => assert!(m < n),
+        }
+    }
+}
+
+/// Ridge regression agrees with the regularized normal equations
+/// `(A^H A + lambda Γ^H Γ) x = A^H b`
+fn test_ridge<T: Scalar + Lapack>(a: Array2<T>, rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let (m, n) = a.dim();
+    let b: Array1<T> = random_using(m, &mut rng);
+    let ah: Array2<T> = conjugate(&a);
+    let aha = ah.dot(&a);
+    let ahb = ah.dot(&b);
+
+    let lambda = T::real(0.5);
+    let result = a
+        .least_squares_ridge(&b, &RidgePenalty::Scalar(lambda))
+        .unwrap();
+    let expected = (&aha + &Array2::from_diag_elem(n, T::from_real(lambda)))
+        .solve(&ahb)
+        .unwrap();
+    assert_eq!(result.rank, n as i32);
+    assert_close_l2!(&result.solution, &expected, rtol);
+    let rss = (&b - &a.dot(&result.solution)).mapv(|x| x.square()).sum();
+    assert_rclose!(result.residual_sum_of_squares.unwrap()[()], rss, rtol);
+
+    let lambdas = Array1::from_shape_fn(n, |j| T::real(j + 1) * T::real(0.1));
+    let result = a
+        .least_squares_ridge(&b, &RidgePenalty::PerColumn(lambdas.clone()))
+        .unwrap();
+    let expected = (&aha + &Array2::from_diag(&lambdas.mapv(T::from_real)))
+        .solve(&ahb)
+        .unwrap();
+    assert_close_l2!(&result.solution, &expected, rtol);
+
+    // First difference operator
+    let mut l = Array2::<T>::zeros((n - 1, n));
+    for i in 0..n - 1 {
+        l[(i, i)] = -T::one();
+        l[(i, i + 1)] = T::one();
+    }
+    let lh: Array2<T> = conjugate(&l);
+    let result = a
+        .least_squares_ridge(&b, &RidgePenalty::Matrix(lambda, l.clone()))
+        .unwrap();
+    let expected = (&aha + &lh.dot(&l).mapv(|x| x.mul_real(lambda)))
+        .solve(&ahb)
+        .unwrap();
+    assert_close_l2!(&result.solution, &expected, rtol);
+}
+
+macro_rules! impl_ridge {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<least_squares_ $scalar _ridge>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((6, 4), &mut rng);
+                test_ridge(a, $rtol)
+            }
+
+            #[test]
+            fn [<least_squares_ $scalar _ridge_t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((6, 4).f(), &mut rng);
+                test_ridge(a, $rtol)
+            }
+
+            #[test]
+            fn [<least_squares_ $scalar _ridge_underdetermined>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((3, 5), &mut rng);
+                test_ridge(a, $rtol)
+            }
+        }
+    };
+}
+
+impl_ridge!(f32, 1e-3);
+impl_ridge!(f64, 1e-9);
+impl_ridge!(c32, 1e-3);
+impl_ridge!(c64, 1e-9);
+
+#[test]
+fn least_squares_ridge_shape_error() {
+    let a: Array2<f64> = Array2::eye(3);
+    let b: Array1<f64> = Array1::ones(3);
+    assert!(a
+        .least_squares_ridge(&Array1::ones(2), &RidgePenalty::Scalar(1.0))
+        .is_err());
+    assert!(a
+        .least_squares_ridge(&b, &RidgePenalty::PerColumn(Array1::ones(2)))
+        .is_err());
+    assert!(a
+        .least_squares_ridge(&b, &RidgePenalty::Matrix(1.0, Array2::eye(2)))
+        .is_err());
+}
+
+/// Weighted least squares agrees with the weighted normal equations `A^H W A x = A^H W b`
+fn test_weighted<T: Scalar + Lapack>(a: Array2<T>, rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let m = a.nrows();
+    let b: Array1<T> = random_using(m, &mut rng);
+    let ah: Array2<T> = conjugate(&a);
+
+    let w = Array1::from_shape_fn(m, |i| T::real(i + 1));
+    let result = a
+        .least_squares_weighted(&b, &LeastSquaresWeights::Diagonal(w.clone()))
+        .unwrap();
+    let wm = Array2::from_diag(&w.mapv(T::from_real));
+    let expected = ah.dot(&wm).dot(&a).solve(&ah.dot(&wm).dot(&b)).unwrap();
+    assert_close_l2!(&result.solution, &expected, rtol);
+    let r = &b - &a.dot(&result.solution);
+    let rss = r.iter().zip(w.iter()).map(|(r, w)| r.square() * *w).sum();
+    assert_rclose!(result.residual_sum_of_squares.unwrap()[()], rss, rtol);
+
+    let wm: Array2<T> = random_hpd_using(m, &mut rng);
+    let result = a
+        .least_squares_weighted(&b, &LeastSquaresWeights::Matrix(wm.clone()))
+        .unwrap();
+    let expected = ah.dot(&wm).dot(&a).solve(&ah.dot(&wm).dot(&b)).unwrap();
+    assert_close_l2!(&result.solution, &expected, rtol);
+    let r = &b - &a.dot(&result.solution);
+    let rh: Array1<T> = r.mapv(|x| x.conj());
+    let rss = rh.dot(&wm.dot(&r)).re();
+    assert_rclose!(result.residual_sum_of_squares.unwrap()[()], rss, rtol);
+}
+
+macro_rules! impl_weighted {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<least_squares_ $scalar _weighted>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((6, 3), &mut rng);
+                test_weighted(a, $rtol)
+            }
+
+            #[test]
+            fn [<least_squares_ $scalar _weighted_t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((6, 3).f(), &mut rng);
+                test_weighted(a, $rtol)
+            }
+        }
+    };
+}
+
+impl_weighted!(f32, 1e-3);
+impl_weighted!(f64, 1e-9);
+impl_weighted!(c32, 1e-3);
+impl_weighted!(c64, 1e-9);
+
+#[test]
+fn least_squares_weighted_shape_error() {
+    let a: Array2<f64> = Array2::eye(3);
+    let b: Array1<f64> = Array1::ones(3);
+    assert!(a
+        .least_squares_weighted(&b, &LeastSquaresWeights::Diagonal(Array1::ones(2)))
+        .is_err());
+    assert!(a
+        .least_squares_weighted(&b, &LeastSquaresWeights::Matrix(Array2::eye(2)))
+        .is_err());
+}
+
+/// The corrected system is consistent and the size of the corrections is the smallest singular value
+fn test_total_least_squares<T: Scalar + Lapack>(a: Array2<T>, rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let (m, n) = a.dim();
+    let b: Array1<T> = random_using(m, &mut rng);
+    let result = total_least_squares(&a, &b).unwrap();
+    let x = &result.solution;
+    let lhs = (&a + &result.correction_a).dot(x);
+    let rhs = &b + &result.correction_b;
+    assert_close_l2!(&lhs, &rhs, rtol);
+    let correction_norm =
+        result.correction_a.norm_l2().powi(2) + result.correction_b.norm_l2().powi(2);
+    assert_rclose!(correction_norm.sqrt(), result.singular_values[n], rtol);
+
+    // No correction is needed for consistent data
+    let x0: Array1<T> = random_using(n, &mut rng);
+    let b = a.dot(&x0);
+    let result = total_least_squares(&a, &b).unwrap();
+    assert_close_l2!(&result.solution, &x0, rtol);
+    assert!(result.correction_b.norm_l2() < rtol);
+}
+
+macro_rules! impl_total_least_squares {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<least_squares_ $scalar _total>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((8, 3), &mut rng);
+                test_total_least_squares(a, $rtol)
+            }
+
+            #[test]
+            fn [<least_squares_ $scalar _total_t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((8, 3).f(), &mut rng);
+                test_total_least_squares(a, $rtol)
+            }
+        }
+    };
+}
+
+impl_total_least_squares!(f32, 1e-3);
+impl_total_least_squares!(f64, 1e-9);
+impl_total_least_squares!(c32, 1e-3);
+impl_total_least_squares!(c64, 1e-9);
+
+#[test]
+fn total_least_squares_shape_error() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((10, 3), &mut rng);
+    let b: Array1<f64> = random_using(9, &mut rng);
+    match total_least_squares(&a, &b) {
+        Err(error::LinalgError::Shape(e)) => assert_eq!(e.kind(), ErrorKind::IncompatibleShape),
+        _ => panic!("IncompatibleShape error is expected"),
+    }
+}
+
+/// The right singular vector of `[A b]` for the smallest singular value has no component for `b`
+#[test]
+fn least_squares_total_nongeneric() {
+    let a: Array2<f64> = array![[0.5], [0.0]];
+    let b: Array1<f64> = array![0.0, 1.0];
+    assert!(total_least_squares(&a, &b).is_err());
+}
+
+/// The solution satisfies the constraints and agrees with the KKT system
+/// `[A^H A, C^H; C, 0] [x; y] = [A^H b; d]`
+fn test_constrained<T: Scalar + Lapack>(a: Array2<T>, rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let (m, n) = a.dim();
+    let p = 2;
+    let b: Array1<T> = random_using(m, &mut rng);
+    let c: Array2<T> = random_using((p, n), &mut rng);
+    let d: Array1<T> = random_using(p, &mut rng);
+    let x = least_squares_constrained(&a, &b, &c, &d).unwrap();
+    assert_close_l2!(&c.dot(&x), &d, rtol);
+
+    let ah: Array2<T> = conjugate(&a);
+    let ch: Array2<T> = conjugate(&c);
+    let mut kkt = Array2::<T>::zeros((n + p, n + p));
+    kkt.slice_mut(s![..n, ..n]).assign(&ah.dot(&a));
+    kkt.slice_mut(s![..n, n..]).assign(&ch);
+    kkt.slice_mut(s![n.., ..n]).assign(&c);
+    let mut rhs = Array1::<T>::zeros(n + p);
+    rhs.slice_mut(s![..n]).assign(&ah.dot(&b));
+    rhs.slice_mut(s![n..]).assign(&d);
+    let expected = kkt.solve(&rhs).unwrap();
+    assert_close_l2!(&x, &expected.slice(s![..n]), rtol);
+}
+
+macro_rules! impl_constrained {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<least_squares_ $scalar _constrained>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((8, 4), &mut rng);
+                test_constrained(a, $rtol)
+            }
+
+            #[test]
+            fn [<least_squares_ $scalar _constrained_t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((8, 4).f(), &mut rng);
+                test_constrained(a, $rtol)
+            }
+
+            #[test]
+            fn [<least_squares_ $scalar _constrained_underdetermined>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((3, 4), &mut rng);
+                test_constrained(a, $rtol)
+            }
+        }
+    };
+}
+
+impl_constrained!(f32, 1e-3);
+impl_constrained!(f64, 1e-9);
+impl_constrained!(c32, 1e-3);
+impl_constrained!(c64, 1e-9);
+
+#[test]
+fn least_squares_constrained_shape_error() {
+    let a: Array2<f64> = Array2::eye(3);
+    let b: Array1<f64> = Array1::ones(3);
+    // More constraints than unknowns
+    let c: Array2<f64> = Array2::ones((4, 3));
+    let d: Array1<f64> = Array1::ones(4);
+    assert!(least_squares_constrained(&a, &b, &c, &d).is_err());
+    // Mismatched number of columns
+    let c: Array2<f64> = Array2::ones((1, 2));
+    let d: Array1<f64> = Array1::ones(1);
+    assert!(least_squares_constrained(&a, &b, &c, &d).is_err());
+}
+
+/// `b = Ax + Wy` holds, and `x` agrees with the least squares solution of `W^{-1} A x = W^{-1} b`
+/// for a nonsingular `W`
+fn test_gauss_markov<T: Scalar + Lapack>(a: Array2<T>, rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let (m, n) = a.dim();
+    let b: Array1<T> = random_using(m, &mut rng);
+    let w: Array2<T> = random_using((m, m), &mut rng);
+    let (x, y) = solve_gauss_markov(&a, &w, &b).unwrap();
+    assert_eq!(x.len(), n);
+    assert_eq!(y.len(), m);
+    assert_close_l2!(&(a.dot(&x) + w.dot(&y)), &b, rtol);
+    let w_inv = w.inv().unwrap();
+    let expected = w_inv
+        .dot(&a)
+        .least_squares(&w_inv.dot(&b))
+        .unwrap()
+        .solution;
+    assert_close_l2!(&x, &expected, rtol);
+
+    // Noise only in a subspace
+    let w: Array2<T> = random_using((m, m - 1), &mut rng);
+    let (x, y) = solve_gauss_markov(&a, &w, &b).unwrap();
+    assert_close_l2!(&(a.dot(&x) + w.dot(&y)), &b, rtol);
+}
+
+macro_rules! impl_gauss_markov {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<least_squares_ $scalar _gauss_markov>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((6, 3), &mut rng);
+                test_gauss_markov(a, $rtol)
+            }
+
+            #[test]
+            fn [<least_squares_ $scalar _gauss_markov_t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((6, 3).f(), &mut rng);
+                test_gauss_markov(a, $rtol)
+            }
+        }
+    };
+}
+
+impl_gauss_markov!(f32, 1e-3);
+impl_gauss_markov!(f64, 1e-9);
+impl_gauss_markov!(c32, 1e-3);
+impl_gauss_markov!(c64, 1e-9);
+
+#[test]
+fn least_squares_gauss_markov_shape_error() {
+    let a: Array2<f64> = Array2::ones((3, 2));
+    let b: Array1<f64> = Array1::ones(3);
+    // Too few noise terms: m > n + p
+    let w: Array2<f64> = Array2::zeros((3, 0));
+    assert!(solve_gauss_markov(&a, &w, &b).is_err());
+    // Mismatched number of rows
+    let w: Array2<f64> = Array2::eye(2);
+    assert!(solve_gauss_markov(&a, &w, &b).is_err());
+}
+
+/// All the drivers give the same minimum norm solution and effective rank
+fn test_drivers<T: Scalar + Lapack>(a: Array2<T>, rank: i32, rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let (m, n) = a.dim();
+    let b: Array1<T> = random_using(m, &mut rng);
+    let bs: Array2<T> = random_using((m, 2), &mut rng);
+    let expected = a.least_squares(&b).unwrap();
+    let expected_nrhs = a.least_squares(&bs).unwrap();
+    for driver in [
+        LeastSquaresDriver::Gelsd,
+        LeastSquaresDriver::Gelsy,
+        LeastSquaresDriver::Gelss,
+    ] {
+        let result = a.least_squares_with_driver(&b, driver).unwrap();
+        assert_eq!(result.rank, rank);
+        assert_close_l2!(&result.solution, &expected.solution, rtol);
+        let result = a.least_squares_with_driver(&bs, driver).unwrap();
+        assert_eq!(result.rank, rank);
+        assert_close_l2!(&result.solution, &expected_nrhs.solution, rtol);
+        match driver {
+            LeastSquaresDriver::Gelsy => assert!(result.singular_values.is_empty()),
+            _ => {
+                assert_eq!(result.singular_values.len(), m.min(n));
+                assert_close_l2!(
+                    &result.singular_values.mapv(T::from_real),
+                    &expected_nrhs.singular_values.mapv(T::from_real),
+                    rtol
+                );
+            }
+        }
+    }
+}
+
+macro_rules! impl_drivers {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<least_squares_ $scalar _drivers_overdetermined>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 3), &mut rng);
+                test_drivers(a, 3, $rtol)
+            }
+
+            #[test]
+            fn [<least_squares_ $scalar _drivers_underdetermined_t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((3, 5).f(), &mut rng);
+                test_drivers(a, 3, $rtol)
+            }
+
+            #[test]
+            fn [<least_squares_ $scalar _drivers_rank_deficient>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let x: Array2<$scalar> = random_using((5, 2), &mut rng);
+                let y: Array2<$scalar> = random_using((2, 4), &mut rng);
+                test_drivers(x.dot(&y), 2, $rtol)
+            }
+        }
+    };
+}
+
+impl_drivers!(f32, 1e-3);
+impl_drivers!(f64, 1e-9);
+impl_drivers!(c32, 1e-3);
+impl_drivers!(c64, 1e-9);
@@ -0,0 +1,70 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn logm_identity() {
+    let a = Array2::<f64>::eye(3);
+    assert_close_l2!(&a.logm().unwrap(), &Array2::zeros((3, 3)), 1e-15);
+}
+
+#[test]
+fn logm_negative_eigenvalue() {
+    // log(-1) = i pi
+    let a: Array2<f64> = array![[-1.0, 0.0], [0.0, 1.0]];
+    let ans = array![
+        [c64::new(0.0, std::f64::consts::PI), c64::new(0.0, 0.0)],
+        [c64::new(0.0, 0.0), c64::new(0.0, 0.0)]
+    ];
+    assert_close_l2!(&a.logm().unwrap(), &ans, 1e-12);
+}
+
+#[test]
+fn logm_singular() {
+    let a: Array2<f64> = array![[1.0, 2.0], [2.0, 4.0]];
+    assert!(a.logm().is_err());
+}
+
+fn test_logm_hpd<T: Scalar + Lapack>(a: &Array2<T>, rtol: T::Real)
+where
+    T::Complex: Lapack,
+{
+    // log(A) = V log(D) V^H for positive definite A = V D V^H
+    let (e, v) = a.eigh(UPLO::Lower).unwrap();
+    let vh = v.t().mapv(|x| x.conj());
+    let ans = v
+        .dot(&Array2::from_diag(&e.mapv(|e| T::from_real(e.ln()))))
+        .dot(&vh);
+    assert_close_l2!(&a.logm().unwrap(), &ans.mapv(|x| x.as_c()), rtol);
+}
+
+#[test]
+fn logm_hpd() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hpd_using(5, &mut rng);
+    test_logm_hpd(&a, 1e-9);
+    let a: Array2<c64> = random_hpd_using(5, &mut rng);
+    test_logm_hpd(&a, 1e-9);
+    let mut a_t = Array2::zeros((5, 5).f());
+    a_t.assign(&a);
+    test_logm_hpd(&a_t, 1e-9);
+}
+
+#[test]
+fn logm_expm() {
+    // log(exp(A)) = A if the imaginary parts of the eigenvalues of A are in (-pi, pi)
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_using((4, 4), &mut rng);
+    let a = a * c64::new(0.5, 0.0);
+    let l = a.expm().unwrap().logm().unwrap();
+    assert_close_l2!(&l, &a, 1e-9);
+}
+
+#[test]
+fn expm_logm() {
+    // exp(log(A)) = A for any nonsingular A
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((5, 5), &mut rng);
+    let a = a * 10.0;
+    let e = a.logm().unwrap().expm().unwrap();
+    assert_close_l2!(&e, &a.mapv(|x| x.as_c()), 1e-9);
+}
@@ -0,0 +1,139 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn conj_t<T: Scalar>(a: &Array2<T>) -> Array2<T> {
+    a.t().mapv(|x| x.conj())
+}
+
+fn test_lyapunov<T: Scalar + Lapack>(a: &Array2<T>, q: &Array2<T>, rtol: T::Real) {
+    let x = solve_lyapunov(a, q).unwrap();
+    assert_close_l2!(
+        &(a.dot(&x) + x.dot(&conj_t(a)) + q),
+        &Array2::zeros(a.dim()),
+        rtol
+    );
+    let x = solve_discrete_lyapunov(a, q).unwrap();
+    assert_close_l2!(&(a.dot(&x).dot(&conj_t(a)) - &x), &q.mapv(|x| -x), rtol);
+}
+
+macro_rules! test_lyapunov {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<lyapunov_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((5, 5), &mut rng);
+                let q: Array2<$type> = random_using((5, 5), &mut rng);
+                test_lyapunov(&a, &q, $rtol);
+            }
+
+            #[test]
+            fn [<lyapunov_ $type _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((5, 5).f(), &mut rng);
+                let q: Array2<$type> = random_using((5, 5).f(), &mut rng);
+                test_lyapunov(&a, &q, $rtol);
+            }
+
+            #[test]
+            fn [<lyapunov_hermite_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((5, 5), &mut rng);
+                let q: Array2<$type> = random_hpd_using(5, &mut rng);
+                test_lyapunov(&a, &q, $rtol);
+                let x = solve_lyapunov(&a, &q).unwrap();
+                assert_eq!(x, conj_t(&x));
+                let x = solve_discrete_lyapunov(&a, &q).unwrap();
+                assert_eq!(x, conj_t(&x));
+            }
+        }
+    };
+}
+
+test_lyapunov!(f64, 1e-9);
+test_lyapunov!(c64, 1e-9);
+
+#[test]
+fn lyapunov_stable() {
+    // Stable `A` and positive definite `Q` give positive definite `X`
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hpd_using(4, &mut rng);
+    let a = a.mapv(|x| -x);
+    let q: Array2<f64> = random_hpd_using(4, &mut rng);
+    let x = solve_lyapunov(&a, &q).unwrap();
+    assert!(x.cholesky(UPLO::Lower).is_ok());
+    let a = Array2::from_diag(&arr1(&[0.5, -0.3, 0.1, 0.9]));
+    let x = solve_discrete_lyapunov(&a, &q).unwrap();
+    assert!(x.cholesky(UPLO::Lower).is_ok());
+}
+
+#[test]
+fn lyapunov_scalar() {
+    // 2 a x + q = 0 and a^2 x - x + q = 0
+    let a = array![[-2.0]];
+    let q = array![[3.0]];
+    assert_close_l2!(&solve_lyapunov(&a, &q).unwrap(), &array![[0.75]], 1e-12);
+    let a = array![[0.5]];
+    assert_close_l2!(
+        &solve_discrete_lyapunov(&a, &q).unwrap(),
+        &array![[4.0]],
+        1e-12
+    );
+}
+
+#[test]
+fn lyapunov_singular() {
+    let a: Array2<f64> = array![[0.0, 1.0], [-1.0, 0.0]];
+    let q = Array2::<f64>::eye(2);
+    assert!(solve_lyapunov(&a, &q).is_err());
+    let a = Array2::<f64>::eye(2);
+    assert!(solve_discrete_lyapunov(&a, &q).is_err());
+}
+
+fn test_gramian<T: Scalar + Lapack>(rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    // stable `A` by shifting the spectrum into the left half plane
+    let a: Array2<T> = random_using((5, 5), &mut rng);
+    let a = a - Array2::<T>::eye(5).mapv(|x| x * T::from_real(T::real(5.0)));
+    let b: Array2<T> = random_using((5, 2), &mut rng);
+    let c: Array2<T> = random_using((3, 5), &mut rng);
+
+    let (w, l) = gramian_controllability(&a, &b, true).unwrap();
+    let res = a.dot(&w) + w.dot(&conj_t(&a)) + b.dot(&conj_t(&b));
+    assert_close_l2!(&res, &Array2::zeros((5, 5)), rtol);
+    let l = l.unwrap();
+    assert_close_l2!(&l.dot(&conj_t(&l)), &w, rtol);
+
+    let (w, l) = gramian_observability(&a, &c, false).unwrap();
+    assert!(l.is_none());
+    let res = conj_t(&a).dot(&w) + w.dot(&a) + conj_t(&c).dot(&c);
+    assert_close_l2!(&res, &Array2::zeros((5, 5)), rtol);
+}
+
+#[test]
+fn gramian_f64() {
+    test_gramian::<f64>(1e-9);
+}
+
+#[test]
+fn gramian_c64() {
+    test_gramian::<c64>(1e-9);
+}
+
+#[test]
+fn gramian_uncontrollable() {
+    // The second state is not affected by the input
+    let a: Array2<f64> = array![[-1.0, 0.0], [0.0, -2.0]];
+    let b: Array2<f64> = array![[1.0], [0.0]];
+    let (w, l) = gramian_controllability(&a, &b, false).unwrap();
+    assert!(l.is_none());
+    assert_close_l2!(&w, &array![[0.5, 0.0], [0.0, 0.0]], 1e-12);
+    assert!(gramian_controllability(&a, &b, true).is_err());
+}
+
+#[test]
+fn gramian_shape_mismatch() {
+    let a = Array2::<f64>::eye(3);
+    assert!(gramian_controllability(&a, &Array2::<f64>::ones((2, 1)), false).is_err());
+    assert!(gramian_observability(&a, &Array2::<f64>::ones((1, 2)), false).is_err());
+}
@@ -0,0 +1,126 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+macro_rules! test_nnls {
+    ($elem:ty, $atol:expr) => {
+        paste::item! {
+            /// Karush-Kuhn-Tucker conditions: `x >= 0`, `w = A^T (b - Ax) <= 0`, and `w_j = 0` if `x_j > 0`
+            #[test]
+            fn [<nnls_kkt_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                for &(m, n) in &[(10, 4), (6, 6), (3, 5)] {
+                    let a: Array2<$elem> = random_using((m, n), &mut rng);
+                    let b: Array1<$elem> = random_using(m, &mut rng);
+                    let result = nnls(&a, &b).unwrap();
+                    assert!(result.converged);
+                    let x = &result.solution;
+                    let w = a.t().dot(&(&b - &a.dot(x)));
+                    for j in 0..n {
+                        assert!(x[j] >= 0.0);
+                        assert!(w[j] <= $atol);
+                        if x[j] > 0.0 {
+                            assert!(w[j].abs() <= $atol);
+                        }
+                    }
+                    let rss = (&b - &a.dot(x)).mapv(|r| r * r).sum();
+                    assert_aclose!(result.residual_sum_of_squares, rss, $atol);
+                }
+            }
+
+            /// The constraints are inactive if the unconstrained solution is positive
+            #[test]
+            fn [<nnls_unconstrained_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_using((8, 3), &mut rng);
+                let x: Array1<$elem> = array![1.0, 2.0, 0.5];
+                let b = a.dot(&x);
+                let result = nnls(&a, &b).unwrap();
+                assert!(result.converged);
+                assert_close_l2!(&result.solution, &x, $atol);
+            }
+
+            /// Karush-Kuhn-Tucker conditions: `w = A^T (b - Ax)` is zero for the variables inside the box,
+            /// not positive at the lower bounds, and not negative at the upper bounds
+            #[test]
+            fn [<bvls_kkt_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let inf = <$elem>::INFINITY;
+                let lower: Array1<$elem> = array![-0.1, -inf, 0.0, -0.2, -inf];
+                let upper: Array1<$elem> = array![0.1, 0.2, inf, 0.2, inf];
+                for _ in 0..5 {
+                    let a: Array2<$elem> = random_using((10, 5), &mut rng);
+                    let b: Array1<$elem> = random_using(10, &mut rng);
+                    let result = bvls(&a, &b, &lower, &upper).unwrap();
+                    assert!(result.converged);
+                    let x = &result.solution;
+                    let w = a.t().dot(&(&b - &a.dot(x)));
+                    for j in 0..5 {
+                        assert!(lower[j] <= x[j] && x[j] <= upper[j]);
+                        if x[j] == lower[j] {
+                            assert!(w[j] <= $atol);
+                        } else if x[j] == upper[j] {
+                            assert!(w[j] >= -$atol);
+                        } else {
+                            assert!(w[j].abs() <= $atol);
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_nnls!(f64, 1e-9);
+test_nnls!(f32, 1e-3);
+
+#[test]
+fn nnls_iteration_limit() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((8, 3), &mut rng);
+    let b = a.dot(&array![1.0, 2.0, 0.5]);
+    let result = nnls_with(&a, &b, 0.0, 1).unwrap();
+    assert!(!result.converged);
+    assert_eq!(result.iterations, 1);
+    assert!(result.solution.iter().all(|&x| x >= 0.0));
+}
+
+#[test]
+fn nnls_shape_error() {
+    let a: Array2<f64> = Array2::eye(3);
+    assert!(nnls(&a, &Array1::ones(2)).is_err());
+}
+
+#[test]
+fn bvls_nonnegative_is_nnls() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((10, 4), &mut rng);
+    let b: Array1<f64> = random_using(10, &mut rng);
+    let lower = Array1::zeros(4);
+    let upper = Array1::from_elem(4, f64::INFINITY);
+    let x = bvls(&a, &b, &lower, &upper).unwrap().solution;
+    assert_close_l2!(&x, &nnls(&a, &b).unwrap().solution, 1e-12);
+}
+
+#[test]
+fn bvls_unbounded_is_least_squares() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((10, 4), &mut rng);
+    let b: Array1<f64> = random_using(10, &mut rng);
+    let lower = Array1::from_elem(4, f64::NEG_INFINITY);
+    let upper = Array1::from_elem(4, f64::INFINITY);
+    let result = bvls(&a, &b, &lower, &upper).unwrap();
+    assert_eq!(result.iterations, 1);
+    assert_close_l2!(
+        &result.solution,
+        &a.least_squares(&b).unwrap().solution,
+        1e-12
+    );
+}
+
+#[should_panic]
+#[test]
+fn bvls_inconsistent_bounds() {
+    let a: Array2<f64> = Array2::eye(2);
+    let b: Array1<f64> = Array1::ones(2);
+    let _ = bvls(&a, &b, &array![0.0, 1.0], &array![1.0, 0.0]);
+}
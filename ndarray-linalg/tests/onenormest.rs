@@ -0,0 +1,72 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+macro_rules! test_onenormest {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<onenormest_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_using((20, 20), &mut rng);
+                let ah: Array2<$elem> = conjugate(&a);
+                let exact = a.opnorm_one().unwrap();
+                for t in [1, 2, 5] {
+                    let est = onenormest_using(
+                        20,
+                        t,
+                        |x: &Array2<$elem>| Ok(a.dot(x)),
+                        |x: &Array2<$elem>| Ok(ah.dot(x)),
+                        &mut rng,
+                    )
+                    .unwrap();
+                    // Always a lower bound, and usually exact for small matrices
+                    assert!(est <= exact * (1.0 + $rtol));
+                    assert!(est >= exact / 3.0);
+                }
+            }
+
+            #[test]
+            fn [<inv_onenormest_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_using((10, 10), &mut rng);
+                let exact = a.inv().unwrap().opnorm_one().unwrap();
+                let est = a.factorize().unwrap().inv_onenormest(2).unwrap();
+                assert!(est <= exact * (1.0 + $rtol));
+                assert!(est >= exact / 3.0);
+            }
+        }
+    };
+}
+
+test_onenormest!(f64, 1e-9);
+test_onenormest!(f32, 1e-4);
+test_onenormest!(c64, 1e-9);
+test_onenormest!(c32, 1e-4);
+
+#[test]
+fn onenormest_exact_for_unit_vector() {
+    // The largest column is found by the first step of the iteration
+    let a = Array2::from_diag(&array![1.0, 5.0, 2.0, 3.0]);
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let est = onenormest_using(
+        4,
+        1,
+        |x: &Array2<f64>| Ok(a.dot(x)),
+        |x: &Array2<f64>| Ok(a.t().dot(x)),
+        &mut rng,
+    )
+    .unwrap();
+    assert_rclose!(est, 5.0, 1e-12);
+}
+
+#[test]
+fn onenormest_empty() {
+    let est = onenormest(
+        0,
+        2,
+        |x: &Array2<f64>| Ok(x.clone()),
+        |x: &Array2<f64>| Ok(x.clone()),
+    )
+    .unwrap();
+    assert_eq!(est, 0.0);
+}
@@ -0,0 +1,121 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// `<A x, y> = <x, A^H y>`
+fn assert_adjoint<T, Op>(op: &Op, rtol: T::Real)
+where
+    T: Scalar + Lapack,
+    Op: AdjointOperator<Elem = T>,
+{
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let (m, n) = op.shape().unwrap();
+    let x: Array1<T> = random_using(n, &mut rng);
+    let y: Array1<T> = random_using(m, &mut rng);
+    let lhs = op.apply(&x).inner(&y);
+    let rhs = x.inner(&op.apply_adjoint(&y));
+    assert_rclose!(lhs.re(), rhs.re(), rtol);
+    assert_rclose!(lhs.im(), rhs.im(), rtol);
+}
+
+fn test_operator<T: Scalar + Lapack>(rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<T> = random_using((5, 3), &mut rng);
+    let b: Array2<T> = random_using((5, 3), &mut rng);
+    let c: Array2<T> = random_using((3, 4), &mut rng);
+    let x: Array1<T> = random_using(4, &mut rng);
+    let alpha: Array1<T> = random_using(1, &mut rng);
+    let alpha = alpha[0];
+
+    assert_eq!(a.shape(), &[5, 3]);
+    assert_eq!(LinearOperator::shape(&a), Some((5, 3)));
+    assert_adjoint(&a, rtol);
+
+    let op = OperatorProduct::new(OperatorSum::new(ScaledOperator::new(alpha, &a), &b), &c);
+    assert_eq!(op.shape(), Some((5, 4)));
+    let expected = (a.mapv(|x| alpha * x) + &b).dot(&c);
+    assert_close_l2!(&op.apply(&x), &expected.dot(&x), rtol);
+    assert_adjoint(&op, rtol);
+
+    let d: Array1<T> = random_using(3, &mut rng);
+    let diag = d.clone().into_diagonal();
+    assert_eq!(diag.shape(), Some((3, 3)));
+    assert_adjoint(&diag, rtol);
+}
+
+fn test_inverse_operator<T: Scalar + Lapack>(rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<T> = random_using((4, 4), &mut rng);
+    let h: Array2<T> = random_hpd_using(4, &mut rng);
+    let b: Array1<T> = random_using(4, &mut rng);
+
+    let lu = InverseOperator::new(a.factorize().unwrap());
+    assert_eq!(lu.shape(), Some((4, 4)));
+    assert_close_l2!(&a.dot(&lu.apply(&b)), &b, rtol);
+    assert_adjoint(&lu, rtol);
+
+    let chol = InverseOperator::new(h.factorizec(UPLO::Lower).unwrap());
+    assert_close_l2!(&h.dot(&chol.apply(&b)), &b, rtol);
+    assert_adjoint(&chol, rtol);
+
+    let bk = InverseOperator::new(h.factorizeh().unwrap());
+    assert_close_l2!(&h.dot(&bk.apply(&b)), &b, rtol);
+    assert_adjoint(&bk, rtol);
+
+    let band = BandedMatrix::from_dense(&a, 3, 3);
+    let lu_band = InverseOperator::new(band.factorize_banded().unwrap());
+    assert_close_l2!(&a.dot(&lu_band.apply(&b)), &b, rtol);
+    assert_adjoint(&lu_band, rtol);
+
+    let band = SymmetricBand::from_dense(&h, 3, UPLO::Upper);
+    let chol_band = InverseOperator::new(band.cholesky_banded().unwrap());
+    assert_close_l2!(&h.dot(&chol_band.apply(&b)), &b, rtol);
+    assert_adjoint(&chol_band, rtol);
+}
+
+macro_rules! impl_test {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<operator_ $scalar>]() {
+                test_operator::<$scalar>($rtol);
+            }
+
+            #[test]
+            fn [<inverse_operator_ $scalar>]() {
+                test_inverse_operator::<$scalar>($rtol);
+            }
+        }
+    };
+}
+
+impl_test!(f32, 1e-4);
+impl_test!(f64, 1e-10);
+impl_test!(c32, 1e-4);
+impl_test!(c64, 1e-10);
+
+#[test]
+fn fn_operator() {
+    // Forward difference on the periodic grid and its adjoint
+    let n = 6;
+    let op = FnOperator::with_adjoint(
+        (n, n),
+        |x: ArrayView1<f64>| Array1::from_shape_fn(n, |i| x[(i + 1) % n] - x[i]),
+        |x: ArrayView1<f64>| Array1::from_shape_fn(n, |i| x[(i + n - 1) % n] - x[i]),
+    );
+    let dense = Array2::from_shape_fn((n, n), |(i, j)| {
+        if j == (i + 1) % n {
+            1.0
+        } else if i == j {
+            -1.0
+        } else {
+            0.0
+        }
+    });
+    let x: Array1<f64> = array![1.0, 4.0, 2.0, 8.0, 5.0, 7.0];
+    assert_close_l2!(&op.apply(&x), &dense.dot(&x), 1e-12);
+    assert_close_l2!(&op.apply_adjoint(&x), &dense.t().dot(&x), 1e-12);
+    assert_adjoint(&op, 1e-12);
+
+    let without_adjoint = FnOperator::new((n, n), |x: ArrayView1<f64>| x.mapv(|x| 2.0 * x));
+    assert_eq!(without_adjoint.apply(&x), &x * 2.0);
+}
@@ -0,0 +1,72 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn packed_pack_unpack() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hermite_using(5, &mut rng);
+    for uplo in [UPLO::Upper, UPLO::Lower] {
+        let p = PackedMatrix::from_dense(&a, uplo);
+        assert_eq!(p.ap.len(), 15);
+        assert_close_l2!(&p.to_dense(), &a, 1e-12);
+    }
+    let p = PackedMatrix::from_dense(&a, UPLO::Lower);
+    assert_eq!(p.ap[1], a[(1, 0)]);
+    assert_eq!(p.ap[5], a[(1, 1)]);
+}
+
+macro_rules! test_packed {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<packed_solve_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let n = 6;
+                let x: Array1<$type> = random_using(n, &mut rng);
+                let a: Array2<$type> = random_hpd_using(n, &mut rng);
+                let b = a.dot(&x);
+                let h: Array2<$type> = random_hermite_using(n, &mut rng);
+                let c = h.dot(&x);
+                for uplo in [UPLO::Upper, UPLO::Lower] {
+                    let p = PackedMatrix::from_dense(&a, uplo);
+                    let f = p.cholesky_packed().unwrap();
+                    assert_close_l2!(&f.solvec(&b).unwrap(), &x, $rtol);
+                    assert_close_l2!(&p.solvec(&b).unwrap(), &x, $rtol);
+
+                    let p = PackedMatrix::from_dense(&h, uplo);
+                    let f = p.factorizeh_packed().unwrap();
+                    assert_close_l2!(&f.solveh(&c).unwrap(), &x, $rtol);
+                    assert_close_l2!(&p.solveh(&c).unwrap(), &x, $rtol);
+                }
+            }
+
+            #[test]
+            fn [<packed_eigh_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let n = 6;
+                let a: Array2<$type> = random_hermite_using(n, &mut rng);
+                let ans = a.eigvalsh(UPLO::Upper).unwrap();
+                for uplo in [UPLO::Upper, UPLO::Lower] {
+                    let p = PackedMatrix::from_dense(&a, uplo);
+                    assert_close_l2!(&p.eigvalsh_packed().unwrap(), &ans, $rtol);
+                    let (w, v) = p.eigh_packed().unwrap();
+                    assert_close_l2!(&w, &ans, $rtol);
+                    let w = w.mapv(<$type>::from_real);
+                    assert_close_l2!(&a.dot(&v), &(&v * &w), $rtol);
+                }
+            }
+        }
+    };
+}
+
+test_packed!(f64, 1e-9);
+test_packed!(f32, 1e-3);
+test_packed!(c64, 1e-9);
+test_packed!(c32, 1e-3);
+
+#[test]
+fn cholesky_packed_not_positive_definite() {
+    let a = arr2(&[[1.0, 2.0], [2.0, 1.0]]);
+    let p = PackedMatrix::from_dense(&a, UPLO::Upper);
+    assert!(p.cholesky_packed().is_err());
+}
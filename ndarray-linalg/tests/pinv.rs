@@ -0,0 +1,93 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Moore-Penrose conditions
+fn test_pinv<T: Scalar + Lapack>(a: &Array2<T>, ap: &Array2<T>, rtol: T::Real) {
+    let (m, n) = a.dim();
+    assert_eq!(ap.dim(), (n, m));
+    assert_close_l2!(&a.dot(ap).dot(a), a, rtol);
+    assert_close_l2!(&ap.dot(a).dot(ap), ap, rtol);
+    let aap = a.dot(ap);
+    let aaph: Array2<T> = conjugate(&aap);
+    assert_close_l2!(&aaph, &aap, rtol);
+    let apa = ap.dot(a);
+    let apah: Array2<T> = conjugate(&apa);
+    assert_close_l2!(&apah, &apa, rtol);
+}
+
+macro_rules! test_pinv {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<pinv_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                for shape in [(3, 3), (5, 3), (3, 5)] {
+                    let a: Array2<$type> = random_using(shape, &mut rng);
+                    test_pinv(&a, &a.pinv().unwrap(), $rtol);
+                }
+                // Same as the inverse for a non-singular matrix
+                let a: Array2<$type> = random_using((4, 4), &mut rng);
+                assert_close_l2!(&a.pinv().unwrap(), &a.inv().unwrap(), $rtol);
+            }
+
+            #[test]
+            fn [<pinv_rank_deficient_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                // Rank 2 matrix of 5 x 4
+                let b: Array2<$type> = random_using((5, 2), &mut rng);
+                let c: Array2<$type> = random_using((2, 4), &mut rng);
+                let a = b.dot(&c);
+                test_pinv(&a, &a.pinv().unwrap(), $rtol);
+            }
+        }
+    };
+}
+
+test_pinv!(f64, 1e-9);
+test_pinv!(f32, 1e-3);
+test_pinv!(c64, 1e-9);
+test_pinv!(c32, 1e-3);
+
+#[test]
+fn pinv_with_tol() {
+    let a: Array2<f64> = Array2::from_diag(&array![2.0, 1.0, 1e-8]);
+    assert_close_l2!(
+        &a.pinv().unwrap(),
+        &Array2::from_diag(&array![0.5, 1.0, 1e8]),
+        1e-12
+    );
+    // The smallest singular value is cut off relative to the largest one
+    assert_close_l2!(
+        &a.pinv_with_tol(1e-6).unwrap(),
+        &Array2::from_diag(&array![0.5, 1.0, 0.0]),
+        1e-12
+    );
+    // Boundary value is regarded as zero
+    assert_close_l2!(
+        &a.pinv_with_tol(0.5).unwrap(),
+        &Array2::from_diag(&array![0.5, 0.0, 0.0]),
+        1e-12
+    );
+}
+
+#[test]
+fn pinv_zero() {
+    let a: Array2<f64> = Array2::zeros((3, 2));
+    assert_eq!(a.pinv().unwrap(), Array2::zeros((2, 3)));
+    let a: Array2<f64> = Array2::zeros((0, 2));
+    assert_eq!(a.pinv().unwrap().dim(), (2, 0));
+}
+
+#[test]
+fn pinv_with_work_reuse() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let mut work = SvdThinWork::<c64>::new(5, 3).unwrap();
+    for _ in 0..3 {
+        let a: Array2<c64> = random_using((5, 3), &mut rng);
+        let ap = pinv_with_work(&a, 1e-12, &mut work).unwrap();
+        assert_close_l2!(&ap, &a.pinv_with_tol(1e-12).unwrap(), 1e-12);
+        assert_eq!(pinv_with_work(&a, 1e-12, &mut work).unwrap(), ap);
+    }
+    let a: Array2<c64> = random_using((3, 5), &mut rng);
+    assert!(pinv_with_work(&a, 1e-12, &mut work).is_err());
+}
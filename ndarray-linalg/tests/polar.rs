@@ -0,0 +1,79 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// `A = U P = P' U` where `U` has orthonormal columns (rows) and `P`, `P'` are Hermitian positive semi-definite
+fn test_polar<T: Scalar + Lapack>(a: &Array2<T>, rtol: T::Real) {
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    let (u, p) = a.polar().unwrap();
+    assert_eq!(u.dim(), (m, n));
+    assert_eq!(p.dim(), (n, n));
+    assert_close_l2!(&u.dot(&p), a, rtol);
+    let uh: Array2<T> = conjugate(&u);
+    if m >= n {
+        assert_close_l2!(&uh.dot(&u), &Array2::eye(k), rtol);
+    } else {
+        assert_close_l2!(&u.dot(&uh), &Array2::eye(k), rtol);
+    }
+    let ph: Array2<T> = conjugate(&p);
+    assert_close_l2!(&ph, &p, rtol);
+
+    let (p_left, u_left) = a.polar_left().unwrap();
+    assert_eq!(p_left.dim(), (m, m));
+    assert_close_l2!(&p_left.dot(&u_left), a, rtol);
+    // The unitary factor is common to both sides
+    assert_close_l2!(&u_left, &u, rtol);
+}
+
+macro_rules! test_polar {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<polar_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                for shape in [(3, 3), (5, 3), (3, 5)] {
+                    let a: Array2<$type> = random_using(shape, &mut rng);
+                    test_polar(&a, $rtol);
+                }
+            }
+
+            #[test]
+            fn [<polar_ $type _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                for shape in [(3, 3), (5, 3), (3, 5)] {
+                    let a: Array2<$type> = random_using(shape.f(), &mut rng);
+                    test_polar(&a, $rtol);
+                }
+            }
+        }
+    };
+}
+
+test_polar!(f64, 1e-9);
+test_polar!(c64, 1e-9);
+
+#[test]
+fn polar_hpd() {
+    // The polar decomposition of a positive definite matrix is `I P` with `P = A`
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hpd_using(4, &mut rng);
+    let (u, p) = a.polar().unwrap();
+    assert_close_l2!(&u, &Array2::eye(4), 1e-9);
+    assert_close_l2!(&p, &a, 1e-9);
+}
+
+#[test]
+fn polar_with_work_reuse() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let mut work = SvdThinWork::<c64>::new(5, 3).unwrap();
+    for _ in 0..3 {
+        let a: Array2<c64> = random_using((5, 3), &mut rng);
+        let (u, p) = polar_with_work(&a, &mut work).unwrap();
+        let (u0, p0) = a.polar().unwrap();
+        assert_close_l2!(&u, &u0, 1e-12);
+        assert_close_l2!(&p, &p0, 1e-12);
+        assert_eq!(polar_with_work(&a, &mut work).unwrap(), (u, p));
+    }
+    let a: Array2<c64> = random_using((3, 5), &mut rng);
+    assert!(polar_with_work(&a, &mut work).is_err());
+}
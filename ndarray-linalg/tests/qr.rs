@@ -1,3 +1,4 @@
+use lax::qr::max_orthogonality_error;
 use ndarray::*;
 use ndarray_linalg::*;
 use std::cmp::min;
@@ -9,6 +10,7 @@ fn test(a: &Array2<f64>, n: usize, m: usize) {
     println!("q = \n{:?}", &q);
     println!("r = \n{:?}", &r);
     assert_close_l2!(&q.t().dot(&q), &Array::eye(min(n, m)), 1e-7);
+    assert!(max_orthogonality_error(q.as_allocated().unwrap(), q.layout().unwrap()) < 1e-12);
     assert_close_l2!(&q.dot(&r), &ans, 1e-7);
     assert_close_l2!(&r.clone().into_triangular(UPLO::Upper), &r, 1e-7);
 }
@@ -20,6 +22,7 @@ fn test_square(a: &Array2<f64>, n: usize, m: usize) {
     println!("q = \n{:?}", &q);
     println!("r = \n{:?}", &r);
     assert_close_l2!(&q.t().dot(&q), &Array::eye(min(n, m)), 1e-7);
+    assert!(max_orthogonality_error(q.as_allocated().unwrap(), q.layout().unwrap()) < 1e-12);
     assert_close_l2!(&q.dot(&r), &ans, 1e-7);
     assert_close_l2!(&r.clone().into_triangular(UPLO::Upper), &r, 1e-7);
 }
@@ -79,3 +82,203 @@ fn qr_4x3_t() {
     let a = random_using((4, 3).f(), &mut rng);
     test(&a, 4, 3);
 }
+
+fn test_householder_vectors<T: Scalar + Lapack>(a: &Array2<T>) {
+    let (n, m) = a.dim();
+    let mut h = a.clone();
+    let l = h.layout().unwrap();
+    let tau = T::householder(l, h.as_allocated_mut().unwrap()).unwrap();
+    let reflectors = householder_vectors(h.as_allocated().unwrap(), &tau, l);
+    assert_eq!(reflectors.len(), min(n, m));
+
+    // Q^H = H_k^H ... H_1^H, where H_i^H = I - conj(tau_i) v_i v_i^H
+    let mut qh: Array2<T> = Array::eye(n);
+    for mut col in qh.axis_iter_mut(Axis(1)) {
+        let mut x = col.to_vec();
+        for (v, tau) in &reflectors {
+            apply_householder(v, tau.conj(), &mut x);
+        }
+        col.assign(&Array::from(x));
+    }
+    let q = qh.t().mapv(|x| x.conj());
+    assert_close_l2!(&qh.dot(&q), &Array::eye(n), T::real(1e-7));
+    let r = qh.dot(a);
+    assert_close_l2!(&r.clone().into_triangular(UPLO::Upper), &r, T::real(1e-7));
+}
+
+#[test]
+fn householder_vectors_4x3() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    test_householder_vectors::<f64>(&random_using((4, 3), &mut rng));
+    test_householder_vectors::<f64>(&random_using((4, 3).f(), &mut rng));
+    test_householder_vectors::<c64>(&random_using((4, 3), &mut rng));
+    test_householder_vectors::<c64>(&random_using((4, 3).f(), &mut rng));
+}
+
+#[test]
+fn householder_vectors_3x4() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    test_householder_vectors::<f64>(&random_using((3, 4), &mut rng));
+    test_householder_vectors::<f64>(&random_using((3, 4).f(), &mut rng));
+    test_householder_vectors::<c64>(&random_using((3, 4), &mut rng));
+    test_householder_vectors::<c64>(&random_using((3, 4).f(), &mut rng));
+}
+
+fn test_qr_pivoted<T: Scalar + Lapack>(a: &Array2<T>) {
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    let QRPivoted { q, r, perm } = qr_pivoted(a, PivotRule::Largest).unwrap();
+    assert_eq!(q.dim(), (m, k));
+    assert_eq!(r.dim(), (k, n));
+    let mut ap = Array2::zeros((m, n));
+    for (j, &p) in perm.iter().enumerate() {
+        ap.column_mut(j).assign(&a.column(p));
+    }
+    assert_close_l2!(&q.dot(&r), &ap, T::real(1e-7));
+    let qh = q.t().mapv(|x| x.conj());
+    assert_close_l2!(&qh.dot(&q), &Array::eye(k), T::real(1e-7));
+    assert_close_l2!(&r.clone().into_triangular(UPLO::Upper), &r, T::real(1e-7));
+    for i in 1..k {
+        assert!(r[(i, i)].abs() <= r[(i - 1, i - 1)].abs() * T::real(1.0 + 1e-7));
+    }
+}
+
+#[test]
+fn qr_pivoted_4x3() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    test_qr_pivoted::<f64>(&random_using((4, 3), &mut rng));
+    test_qr_pivoted::<c64>(&random_using((4, 3), &mut rng));
+}
+
+#[test]
+fn qr_pivoted_3x4() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    test_qr_pivoted::<f64>(&random_using((3, 4), &mut rng));
+    test_qr_pivoted::<c64>(&random_using((3, 4), &mut rng));
+}
+
+#[test]
+fn qr_pivoted_tie() {
+    // The 2nd and 3rd columns tie up to the rounding error
+    let a = arr2(&[[0.5, 0.6, 0.8 + 1e-14], [0.0, 0.8, 0.0], [0.0, 0.0, 0.6]]);
+    assert_eq!(qr_pivoted(&a, PivotRule::Largest).unwrap().perm[0], 2);
+    let QRPivoted { perm, .. } = qr_pivoted(&a, PivotRule::LowestIndex { rtol: 1e-10 }).unwrap();
+    assert_eq!(perm, vec![1, 2, 0]);
+    // Exact ties always pick the lowest index
+    let a = Array2::<f64>::eye(3);
+    assert_eq!(
+        qr_pivoted(&a, PivotRule::Largest).unwrap().perm,
+        vec![0, 1, 2]
+    );
+}
+
+#[test]
+fn qr_pivoted_reduced_column() {
+    // The 1st column needs no reflection, but the 2nd column vanishes after it
+    let a = arr2(&[[10.0, 9.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, 1.0]]);
+    test_qr_pivoted::<f64>(&a);
+    for rule in [PivotRule::Largest, PivotRule::LowestIndex { rtol: 1e-10 }] {
+        let QRPivoted { r, perm, .. } = qr_pivoted(&a, rule).unwrap();
+        assert_eq!(perm, vec![0, 2, 1]);
+        let d = r.diag().mapv(f64::abs);
+        assert_close_l2!(&d, &arr1(&[10.0, 2.0_f64.sqrt(), 0.0]), 1e-12);
+    }
+}
+
+#[test]
+fn qr_pivoted_rank() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    // 5x4 matrix of rank 2
+    let x: Array2<f64> = random_using((5, 2), &mut rng);
+    let y: Array2<f64> = random_using((2, 4), &mut rng);
+    let a = x.dot(&y);
+    let qrp = qr_pivoted(&a, PivotRule::Largest).unwrap();
+    assert_eq!(qrp.rank(1e-10), 2);
+    assert_eq!(qrp.rank(f64::INFINITY), 0);
+}
+
+/// `||Q^H Q - I||_inf` for the columns of `q`
+fn orthogonality_error<T: Lapack, S: Data<Elem = T>>(q: &ArrayBase<S, Ix2>) -> T::Real {
+    max_orthogonality_error(q.as_allocated().unwrap(), q.layout().unwrap())
+}
+
+fn test_lq_ql_rq<T: Scalar + Lapack>(a: &Array2<T>) {
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    let eye = Array2::<T>::eye(k);
+
+    let (l, q) = a.lq().unwrap();
+    assert_eq!((l.dim(), q.dim()), ((m, k), (k, n)));
+    assert_close_l2!(&l.dot(&q), a, T::real(1e-7));
+    assert_close_l2!(&q.dot(&q.t().mapv(|x| x.conj())), &eye, T::real(1e-7));
+    assert!(orthogonality_error(&q.t()) < T::real(1e-12));
+    assert_close_l2!(&l.clone().into_triangular(UPLO::Lower), &l, T::real(1e-7));
+
+    let (q, l) = a.ql().unwrap();
+    assert_eq!((q.dim(), l.dim()), ((m, k), (k, n)));
+    assert_close_l2!(&q.dot(&l), a, T::real(1e-7));
+    assert_close_l2!(&q.t().mapv(|x| x.conj()).dot(&q), &eye, T::real(1e-7));
+    assert!(orthogonality_error(&q) < T::real(1e-12));
+    for ((i, j), x) in l.indexed_iter() {
+        if j > i + n - k {
+            assert_eq!(*x, T::zero());
+        }
+    }
+
+    let (r, q) = a.rq().unwrap();
+    assert_eq!((r.dim(), q.dim()), ((m, k), (k, n)));
+    assert_close_l2!(&r.dot(&q), a, T::real(1e-7));
+    assert_close_l2!(&q.dot(&q.t().mapv(|x| x.conj())), &eye, T::real(1e-7));
+    assert!(orthogonality_error(&q.t()) < T::real(1e-12));
+    for ((i, j), x) in r.indexed_iter() {
+        if i > j + m - k {
+            assert_eq!(*x, T::zero());
+        }
+    }
+}
+
+#[test]
+fn lq_ql_rq_3x5() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    test_lq_ql_rq::<f64>(&random_using((3, 5), &mut rng));
+    test_lq_ql_rq::<f64>(&random_using((3, 5).f(), &mut rng));
+    test_lq_ql_rq::<c64>(&random_using((3, 5), &mut rng));
+}
+
+#[test]
+fn lq_ql_rq_5x3() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    test_lq_ql_rq::<f64>(&random_using((5, 3), &mut rng));
+    test_lq_ql_rq::<f64>(&random_using((5, 3).f(), &mut rng));
+    test_lq_ql_rq::<c64>(&random_using((5, 3), &mut rng));
+}
+
+fn test_factorize_qr<T: Scalar + Lapack>(a: &Array2<T>) {
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let b: Array2<T> = random_using((m, 2), &mut rng);
+    let f = a.factorize_qr().unwrap();
+    let q = f.generate_q().unwrap();
+    let r = f.r();
+    assert_eq!((q.dim(), r.dim()), ((m, k), (k, n)));
+    assert_close_l2!(&q.dot(&r), a, T::real(1e-7));
+    let qh = q.t().mapv(|x| x.conj());
+    let qhb = f.apply_qh(&b).unwrap();
+    assert_close_l2!(
+        &qhb.slice(s![..k, ..]).to_owned(),
+        &qh.dot(&b),
+        T::real(1e-7)
+    );
+    assert_close_l2!(&f.apply_q(&qhb).unwrap(), &b, T::real(1e-7));
+    assert!(f.apply_q(&Array2::<T>::zeros((m + 1, 2))).is_err());
+}
+
+#[test]
+fn factorize_qr() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    test_factorize_qr::<f64>(&random_using((5, 3), &mut rng));
+    test_factorize_qr::<f64>(&random_using((3, 5).f(), &mut rng));
+    test_factorize_qr::<c64>(&random_using((5, 3).f(), &mut rng));
+    test_factorize_qr::<c64>(&random_using((3, 5), &mut rng));
+}
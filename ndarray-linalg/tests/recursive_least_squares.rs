@@ -0,0 +1,84 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_recursive_least_squares<T: Scalar + Lapack>(rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<T> = random_using((8, 3), &mut rng);
+    let b: Array1<T> = random_using(8, &mut rng);
+
+    let mut rls = RecursiveLeastSquares::new(3);
+    for (row, &rhs) in a.rows().into_iter().zip(b.iter()) {
+        rls.add_row(&row, rhs).unwrap();
+    }
+    assert_eq!(rls.nrows(), 8);
+    let expected = a.least_squares(&b).unwrap();
+    assert_close_l2!(&rls.solve().unwrap(), &expected.solution, rtol);
+    assert_rclose!(
+        rls.residual_sum_of_squares(),
+        expected.residual_sum_of_squares.unwrap()[()],
+        rtol
+    );
+    // R^H R = A^H A
+    let r = rls.r();
+    let rh = r.t().mapv(|x| x.conj());
+    let ah = a.t().mapv(|x| x.conj());
+    assert_close_l2!(&rh.dot(r), &ah.dot(&a), rtol);
+
+    // Remove the first two rows
+    rls.remove_row(&a.row(0), b[0]).unwrap();
+    rls.remove_row(&a.row(1), b[1]).unwrap();
+    assert_eq!(rls.nrows(), 6);
+    let a = a.slice(s![2.., ..]).to_owned();
+    let b = b.slice(s![2..]).to_owned();
+    let expected = a.least_squares(&b).unwrap();
+    assert_close_l2!(&rls.solve().unwrap(), &expected.solution, rtol);
+    assert_rclose!(
+        rls.residual_sum_of_squares(),
+        expected.residual_sum_of_squares.unwrap()[()],
+        rtol
+    );
+}
+
+#[test]
+fn recursive_least_squares_f32() {
+    test_recursive_least_squares::<f32>(1e-3);
+}
+
+#[test]
+fn recursive_least_squares_f64() {
+    test_recursive_least_squares::<f64>(1e-9);
+}
+
+#[test]
+fn recursive_least_squares_c32() {
+    test_recursive_least_squares::<c32>(1e-3);
+}
+
+#[test]
+fn recursive_least_squares_c64() {
+    test_recursive_least_squares::<c64>(1e-9);
+}
+
+#[test]
+fn recursive_least_squares_underdetermined() {
+    let mut rls = RecursiveLeastSquares::<f64>::new(2);
+    rls.add_row(&array![1.0, 1.0], 1.0).unwrap();
+    assert!(rls.solve().is_err());
+    // Removing the only row makes `A^H A` singular
+    assert!(rls.remove_row(&array![1.0, 1.0], 1.0).is_err());
+    assert!(rls.add_row(&array![1.0, 1.0, 1.0], 1.0).is_err());
+}
+
+#[test]
+fn recursive_least_squares_remove_unknown_row() {
+    let mut rls = RecursiveLeastSquares::<f64>::new(2);
+    rls.add_row(&array![1.0, 0.0], 1.0).unwrap();
+    rls.add_row(&array![0.0, 1.0], 2.0).unwrap();
+    // `A^H A - x x^H` is indefinite, and the state is unchanged
+    assert!(matches!(
+        rls.remove_row(&array![2.0, 0.0], 1.0),
+        Err(error::LinalgError::NotPositiveDefinite)
+    ));
+    assert_eq!(rls.nrows(), 2);
+    assert_close_l2!(&rls.solve().unwrap(), &array![1.0, 2.0], 1e-12);
+}
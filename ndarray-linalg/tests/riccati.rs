@@ -0,0 +1,98 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn conj_t<T: Scalar>(a: &Array2<T>) -> Array2<T> {
+    a.t().mapv(|x| x.conj())
+}
+
+fn test_care<T: Scalar + Lapack>(a: &Array2<T>, b: &Array2<T>, rtol: T::Real) {
+    let (n, m) = b.dim();
+    let q = Array2::<T>::eye(n);
+    let r = Array2::<T>::eye(m);
+    let x = solve_care(a, b, &q, &r).unwrap();
+    assert_eq!(x, conj_t(&x));
+    let xb = x.dot(b);
+    let res = conj_t(a).dot(&x) + x.dot(a) - xb.dot(&conj_t(&xb)) + &q;
+    assert_close_l2!(&res, &Array2::zeros((n, n)), rtol);
+    // The closed loop `A - B R^{-1} B^H X` is stable
+    let (e, _) = (a - &b.dot(&conj_t(&xb))).eig().unwrap();
+    assert!(e.iter().all(|e| e.re() < T::real(0.0)));
+}
+
+fn test_dare<T: Scalar + Lapack>(a: &Array2<T>, b: &Array2<T>, rtol: T::Real) {
+    let (n, m) = b.dim();
+    let q = Array2::<T>::eye(n);
+    let r = Array2::<T>::eye(m);
+    let x = solve_dare(a, b, &q, &r).unwrap();
+    assert_eq!(x, conj_t(&x));
+    let bh = conj_t(b);
+    let bxa = bh.dot(&x).dot(a);
+    let k = (&r + &bh.dot(&x).dot(b)).inv().unwrap().dot(&bxa);
+    let res = conj_t(a).dot(&x).dot(a) - &x - conj_t(&bxa).dot(&k) + &q;
+    assert_close_l2!(&res, &Array2::zeros((n, n)), rtol);
+    // The closed loop `A - B K` is stable
+    let (e, _) = (a - &b.dot(&k)).eig().unwrap();
+    assert!(e.iter().all(|e| e.abs() < T::real(1.0)));
+}
+
+macro_rules! test_riccati {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<care_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((4, 4), &mut rng);
+                let b: Array2<$type> = random_using((4, 2), &mut rng);
+                test_care(&a, &b, $rtol);
+            }
+
+            #[test]
+            fn [<care_ $type _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((4, 4).f(), &mut rng);
+                let b: Array2<$type> = random_using((4, 2).f(), &mut rng);
+                test_care(&a, &b, $rtol);
+            }
+
+            #[test]
+            fn [<dare_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((4, 4), &mut rng);
+                let b: Array2<$type> = random_using((4, 2), &mut rng);
+                test_dare(&a, &b, $rtol);
+            }
+
+            #[test]
+            fn [<dare_ $type _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((4, 4).f(), &mut rng);
+                let b: Array2<$type> = random_using((4, 2).f(), &mut rng);
+                test_dare(&a, &b, $rtol);
+            }
+        }
+    };
+}
+
+test_riccati!(f64, 1e-9);
+test_riccati!(c64, 1e-9);
+
+#[test]
+fn riccati_scalar() {
+    let one = array![[1.0]];
+    // 2 x - x^2 + 1 = 0
+    let x = solve_care(&one, &one, &one, &one).unwrap();
+    assert_close_l2!(&x, &array![[1.0 + 2.0_f64.sqrt()]], 1e-12);
+    // x^2 = x + 1
+    let x = solve_dare(&one, &one, &one, &one).unwrap();
+    assert_close_l2!(&x, &array![[(1.0 + 5.0_f64.sqrt()) / 2.0]], 1e-12);
+}
+
+#[test]
+fn riccati_shape_mismatch() {
+    let a = Array2::<f64>::eye(3);
+    let b = Array2::<f64>::ones((2, 1));
+    let q = Array2::<f64>::eye(3);
+    let r = Array2::<f64>::eye(1);
+    assert!(solve_care(&a, &b, &q, &r).is_err());
+    assert!(solve_dare(&a, &b, &q, &r).is_err());
+}
@@ -0,0 +1,252 @@
+use lax::qr::max_orthogonality_error;
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn upper_triangular<T: Scalar>(diag: &[T], rng: &mut rand_pcg::Mcg128Xsl64) -> Array2<T> {
+    let n = diag.len();
+    let mut t: Array2<T> = random_using((n, n), rng);
+    for i in 0..n {
+        t[(i, i)] = diag[i];
+        for j in 0..i {
+            t[(i, j)] = T::zero();
+        }
+    }
+    t
+}
+
+macro_rules! test_schur {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<schur_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((5, 5), &mut rng);
+                test_schur(&a, $rtol);
+            }
+
+            #[test]
+            fn [<schur_ $type _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((5, 5).f(), &mut rng);
+                test_schur(&a, $rtol);
+            }
+        }
+    };
+}
+
+/// `A = Z T Z^H` where `Z` is unitary and `T` is (quasi-)upper triangular
+fn test_schur<T: Scalar + Lapack>(a: &Array2<T>, rtol: T::Real) {
+    let n = a.nrows();
+    let (t, z, eigs) = a.schur().unwrap();
+    let zh = z.t().mapv(|x| x.conj());
+    assert_close_l2!(&z.dot(&t).dot(&zh), a, rtol);
+    assert_close_l2!(&zh.dot(&z), &Array2::eye(n), rtol);
+    assert!(max_orthogonality_error(z.as_allocated().unwrap(), z.layout().unwrap()) < rtol);
+    for i in 0..n {
+        for j in 0..i.saturating_sub(1) {
+            assert!(t[(i, j)].abs() < rtol);
+        }
+    }
+    // The eigenvalues are kept in the Schur form
+    let trace: T::Complex = eigs.iter().cloned().sum();
+    assert!((trace - t.diag().iter().map(|x| x.as_c()).sum::<T::Complex>()).abs() < rtol);
+}
+
+test_schur!(f64, 1e-9);
+test_schur!(c64, 1e-9);
+
+#[test]
+fn schur_complex_eigenvalues() {
+    // Rotation matrix has a complex conjugate pair of eigenvalues `c +- i s`
+    let (c, s) = (0.6, 0.8);
+    let a: Array2<f64> = arr2(&[[c, -s], [s, c]]);
+    let (t, z, eigs) = a.schur().unwrap();
+    assert_close_l2!(&z.dot(&t).dot(&z.t()), &a, 1e-12);
+    let mut im: Vec<f64> = eigs.iter().map(|e| e.im).collect();
+    im.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    assert_close_l2!(&arr1(&im), &arr1(&[-s, s]), 1e-12);
+    assert!(eigs.iter().all(|e| (e.re - c).abs() < 1e-12));
+}
+
+/// `A = Q S Z^H` and `B = Q T Z^H` where `S` is (quasi-)upper triangular and `T` is upper triangular
+fn test_qz<T: Scalar + Lapack>(a: &Array2<T>, b: &Array2<T>, rtol: T::Real) {
+    let n = a.nrows();
+    let res = a.qz(b).unwrap();
+    let zh = res.z.t().mapv(|x| x.conj());
+    assert_close_l2!(&res.q.dot(&res.s).dot(&zh), a, rtol);
+    assert_close_l2!(&res.q.dot(&res.t).dot(&zh), b, rtol);
+    for i in 0..n {
+        for j in 0..i {
+            assert!(res.t[(i, j)].abs() < rtol);
+            if j + 1 < i {
+                assert!(res.s[(i, j)].abs() < rtol);
+            }
+        }
+    }
+    // The generalized eigenvalues are the eigenvalues of `B^{-1} A`
+    let sum: T::Complex = (0..n).map(|i| res.alpha[i] / res.beta[i].as_c()).sum();
+    let trace = b.inv().unwrap().dot(a).trace().unwrap();
+    assert!((sum - trace.as_c()).abs() < rtol * (T::real(1.0) + trace.abs()));
+}
+
+macro_rules! test_qz {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<qz_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((5, 5), &mut rng);
+                let b: Array2<$type> = random_using((5, 5), &mut rng);
+                test_qz(&a, &b, $rtol);
+            }
+
+            #[test]
+            fn [<qz_ $type _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((5, 5).f(), &mut rng);
+                // B in C layout while A in F layout
+                let b: Array2<$type> = random_using((5, 5), &mut rng);
+                test_qz(&a, &b, $rtol);
+            }
+        }
+    };
+}
+
+test_qz!(f64, 1e-9);
+test_qz!(c64, 1e-9);
+
+#[test]
+fn qz_singular_b() {
+    // `B` of rank 1 yields an infinite eigenvalue, i.e. `beta = 0`
+    let a: Array2<f64> = arr2(&[[1.0, 2.0], [3.0, 4.0]]);
+    let b: Array2<f64> = arr2(&[[1.0, 0.0], [0.0, 0.0]]);
+    let res = a.qz(&b).unwrap();
+    assert_eq!(res.beta.iter().filter(|b| b.abs() < 1e-12).count(), 1);
+    // The finite eigenvalue solves `det(A - lambda B) = 4 (1 - lambda) - 6 = 0`
+    let lambda: Vec<_> = (0..2)
+        .filter(|&i| res.beta[i].abs() > 1e-12)
+        .map(|i| res.alpha[i].re / res.beta[i])
+        .collect();
+    assert_close_l2!(&arr1(&lambda), &arr1(&[-0.5]), 1e-12);
+}
+
+macro_rules! test_reorder {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<reorder_schur_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let diag: Vec<$type> = (1..=4).map(|i| <$type>::from_real(i as _)).collect();
+                let t0 = upper_triangular(&diag, &mut rng);
+                let mut t = t0.clone();
+                let mut z: Array2<$type> = Array2::eye(4);
+                // move the eigenvalue at the bottom to the top
+                let (ifst, ilst) = reorder_schur(&mut t, Some(&mut z), 3, 0).unwrap();
+                assert_eq!((ifst, ilst), (3, 0));
+                let ans: Vec<$type> = [4, 1, 2, 3]
+                    .iter()
+                    .map(|&i| <$type>::from_real(i as _))
+                    .collect();
+                assert_close_l2!(&t.diag(), &arr1(&ans), $rtol);
+                for i in 0..4 {
+                    for j in 0..i {
+                        assert!(t[(i, j)].abs() < $rtol);
+                    }
+                }
+                // `T0 = Z T Z^H`
+                let zh = z.t().mapv(|x| x.conj());
+                assert_close_l2!(&z.dot(&t).dot(&zh), &t0, $rtol);
+            }
+
+            #[test]
+            fn [<reorder_schur_ $type _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let diag: Vec<$type> = (1..=4).map(|i| <$type>::from_real(i as _)).collect();
+                let t0 = upper_triangular(&diag, &mut rng);
+                let mut t = Array2::zeros((4, 4).f());
+                t.assign(&t0);
+                // Z in C layout while T in F layout
+                let mut z: Array2<$type> = Array2::eye(4);
+                reorder_schur(&mut t.view_mut(), Some(&mut z.view_mut()), 3, 0).unwrap();
+                let zh = z.t().mapv(|x| x.conj());
+                assert_close_l2!(&z.dot(&t).dot(&zh), &t0, $rtol);
+                assert_rclose!(t[(0, 0)], diag[3], $rtol);
+            }
+        }
+    };
+}
+
+test_reorder!(f64, 1e-9);
+test_reorder!(c64, 1e-9);
+test_reorder!(f32, 1e-3);
+test_reorder!(c32, 1e-3);
+
+#[test]
+fn reorder_schur_2x2_block() {
+    // quasi-triangular with the eigenvalues 1, 2, and 3 +- i sqrt(2)
+    // where the 2x2 block is in the standard form, i.e. equal diagonals and `b c < 0`
+    let t0 = arr2(&[
+        [1.0, 0.5, 0.2, -0.3],
+        [0.0, 2.0, 0.4, 0.1],
+        [0.0, 0.0, 3.0, -2.0],
+        [0.0, 0.0, 1.0, 3.0],
+    ]);
+    let mut t = t0.clone();
+    let mut z: Array2<f64> = Array2::eye(4);
+    // `ifst` pointing the second row of the block is adjusted to the first row
+    let (ifst, ilst) = reorder_schur(&mut t, Some(&mut z), 3, 0).unwrap();
+    assert_eq!((ifst, ilst), (2, 0));
+
+    // the block is moved to the top, keeping its eigenvalues
+    let trace = t[(0, 0)] + t[(1, 1)];
+    let det = t[(0, 0)] * t[(1, 1)] - t[(0, 1)] * t[(1, 0)];
+    assert_rclose!(trace, 6.0, 1e-9);
+    assert_rclose!(det, 11.0, 1e-9);
+    assert_rclose!(t[(2, 2)], 1.0, 1e-9);
+    assert_rclose!(t[(3, 3)], 2.0, 1e-9);
+    for (i, j) in [(2, 0), (2, 1), (3, 0), (3, 1), (3, 2)] {
+        assert!(t[(i, j)].abs() < 1e-9);
+    }
+    assert_close_l2!(&z.dot(&t).dot(&z.t()), &t0, 1e-9);
+}
+
+#[test]
+fn reorder_schur_without_vectors() {
+    let mut t = arr2(&[[1.0, 2.0, 3.0], [0.0, 4.0, 5.0], [0.0, 0.0, 6.0]]);
+    reorder_schur(&mut t, None, 0, 2).unwrap();
+    assert_close_l2!(&t.diag(), &arr1(&[4.0, 6.0, 1.0]), 1e-9);
+}
+
+macro_rules! test_reorder_generalized {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<reorder_generalized_schur_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((5, 5), &mut rng);
+                let b: Array2<$type> = random_using((5, 5), &mut rng);
+                let mut res = a.qz(&b).unwrap();
+                let eigs: Vec<_> = (0..5).map(|i| res.alpha[i] / res.beta[i].as_c()).collect();
+                // move the block at the bottom to the top
+                let (_, ilst) = res.reorder(4, 0).unwrap();
+                assert_eq!(ilst, 0);
+                let zh = res.z.t().mapv(|x| x.conj());
+                assert_close_l2!(&res.q.dot(&res.s).dot(&zh), &a, $rtol);
+                assert_close_l2!(&res.q.dot(&res.t).dot(&zh), &b, $rtol);
+                // eigenvalues are permuted in the same way
+                let moved: Vec<_> = (0..5).map(|i| res.alpha[i] / res.beta[i].as_c()).collect();
+                let last = if (eigs[3] - eigs[4].conj()).abs() < $rtol && eigs[4].im.abs() > $rtol {
+                    3
+                } else {
+                    4
+                };
+                let mut ans = eigs[last..].to_vec();
+                ans.extend_from_slice(&eigs[..last]);
+                assert_close_l2!(&arr1(&moved), &arr1(&ans), $rtol);
+            }
+        }
+    };
+}
+
+test_reorder_generalized!(f64, 1e-9);
+test_reorder_generalized!(c64, 1e-9);
@@ -0,0 +1,138 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+fn test_shift_invert<T: Scalar + Lapack>(rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let n = 10;
+    let a: Array2<T> = random_using((n, n), &mut rng);
+    let start: Array1<T> = random_using(n, &mut rng);
+    let (w, _) = a.eig().unwrap();
+    let sigma = T::from_real(T::real(0.1));
+    let closest = w
+        .iter()
+        .min_by(|x, y| {
+            let dx = (**x - sigma.as_c()).abs();
+            let dy = (**y - sigma.as_c()).abs();
+            dx.partial_cmp(&dy).unwrap()
+        })
+        .unwrap();
+
+    let op = ShiftInvert::new(&a, sigma).unwrap();
+    assert_eq!(op.shape(), Some((n, n)));
+    let (lambda, x) = op.eigs(&start, n).unwrap();
+    assert_eq!(lambda.len(), n);
+    assert_rclose!(lambda[0].re(), closest.re(), rtol);
+    assert_rclose!(lambda[0].im(), closest.im(), rtol);
+    // A x = lambda x
+    let ax = a.mapv(|x| x.as_c()).dot(&x.column(0));
+    let lx = x.column(0).mapv(|x| x * lambda[0]);
+    let res: T::Real = Zip::from(&ax)
+        .and(&lx)
+        .fold(T::real(0.0), |acc, &ax, &lx| acc + (ax - lx).square());
+    assert!(res.sqrt() <= rtol * lambda[0].abs());
+}
+
+macro_rules! impl_test {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<shift_invert_ $scalar>]() {
+                test_shift_invert::<$scalar>($rtol);
+            }
+        }
+    };
+}
+
+impl_test!(f32, 1e-3);
+impl_test!(f64, 1e-9);
+impl_test!(c32, 1e-3);
+impl_test!(c64, 1e-9);
+
+#[test]
+fn shift_invert_interior() {
+    // Eigenvalues 1, 2, ..., 100
+    let n = 100;
+    let a: Array2<f64> = Array2::from_diag(&Array1::from_shape_fn(n, |i| (i + 1) as f64));
+    let op = ShiftInvert::new(&a, 50.3).unwrap();
+    let (lambda, _) = op.eigs(&Array1::ones(n), 20).unwrap();
+    assert_rclose!(lambda[0].re, 50.0, 1e-9);
+    assert_rclose!(lambda[1].re, 51.0, 1e-9);
+    assert_rclose!(lambda[2].re, 49.0, 1e-9);
+}
+
+#[test]
+fn shift_invert_nan() {
+    // NaN in the operator is reported as an error
+    let mut a: Array2<f64> = Array2::from_diag(&array![1.0, 2.0, 3.0]);
+    a[(0, 1)] = f64::NAN;
+    let op = ShiftInvert::new(&a, 0.5).unwrap();
+    assert!(op.eigs(&Array1::ones(3), 3).is_err());
+}
+
+#[test]
+fn shift_invert_lanczos() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let n = 50;
+    let a: Array2<f64> = random_hermite_using(n, &mut rng);
+    let w = a.eigvalsh(UPLO::Upper).unwrap();
+    let sigma = 0.5 * (w[20] + w[21]) + 1e-3;
+
+    let op = ShiftInvert::new(&a, sigma).unwrap();
+    let apply = |x: &[f64], y: &mut [f64]| {
+        y.copy_from_slice(op.apply(&aview1(x)).as_slice().unwrap());
+    };
+    let start: Array1<f64> = random_using(n, &mut rng);
+    let (d, e) = lanczos(n, apply, 30, start.as_slice().unwrap()).unwrap();
+    let theta = (Array1::from(d), Array1::from(e))
+        .eigvalsh_tridiagonal()
+        .unwrap();
+    // The largest and smallest `theta` correspond to `w[21]` and `w[20]`
+    assert_rclose!(op.back_transform_real(theta[theta.len() - 1]), w[21], 1e-8);
+    assert_rclose!(op.back_transform_real(theta[0]), w[20], 1e-8);
+}
+
+#[test]
+fn shift_invert_generalized() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let n = 8;
+    let a: Array2<f64> = random_hermite_using(n, &mut rng);
+    let b: Array2<f64> = random_hpd_using(n, &mut rng);
+    let (w, _) = (a.clone(), b.clone()).eigh(UPLO::Upper).unwrap();
+    let sigma = w[3] + 1e-2;
+    let op = ShiftInvert::generalized(&a, &b, sigma).unwrap();
+    let (lambda, x) = op.eigs(&Array1::ones(n), n).unwrap();
+    assert_rclose!(lambda[0].re, w[3], 1e-9);
+    // A x = lambda B x
+    let x = x.column(0).mapv(|x| x.re);
+    assert_close_l2!(&a.dot(&x), &b.dot(&x).mapv(|x| x * lambda[0].re), 1e-9);
+
+    assert!(ShiftInvert::generalized(&a, &Array2::<f64>::eye(n + 1), sigma).is_err());
+}
+
+#[test]
+fn shift_invert_banded() {
+    // 1D Laplacian with the eigenvalues `2 - 2 cos(k pi / (n + 1))`
+    let n = 30;
+    let a: Array2<f64> = Array2::from_shape_fn((n, n), |(i, j)| {
+        if i == j {
+            2.0
+        } else if i + 1 == j || j + 1 == i {
+            -1.0
+        } else {
+            0.0
+        }
+    });
+    let sigma = 1.0;
+    let mut shifted = a.clone();
+    shifted.diag_mut().mapv_inplace(|x| x - sigma);
+    let lu = BandedMatrix::from_dense(&shifted, 1, 1)
+        .factorize_banded()
+        .unwrap();
+    let op = ShiftInvert::from_factorized(lu, sigma, None);
+    let (lambda, _) = op.eigs(&Array1::ones(n), n).unwrap();
+    let exact = (1..=n)
+        .map(|k| 2.0 - 2.0 * (k as f64 * std::f64::consts::PI / (n + 1) as f64).cos())
+        .min_by(|x, y| (x - sigma).abs().partial_cmp(&(y - sigma).abs()).unwrap())
+        .unwrap();
+    assert_rclose!(lambda[0].re, exact, 1e-9);
+}
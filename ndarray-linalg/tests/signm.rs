@@ -0,0 +1,60 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_signm<T: Scalar + Lapack>(a: &Array2<T>, rtol: T::Real) {
+    let n = a.nrows();
+    let s = a.signm().unwrap();
+    // S^2 = I and S commutes with A
+    assert_close_l2!(&s.dot(&s), &Array2::eye(n), rtol);
+    assert_close_l2!(&s.dot(a), &a.dot(&s), rtol);
+}
+
+macro_rules! test_signm {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<signm_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((5, 5), &mut rng);
+                test_signm(&a, $rtol);
+            }
+
+            #[test]
+            fn [<signm_ $type _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((5, 5).f(), &mut rng);
+                test_signm(&a, $rtol);
+            }
+        }
+    };
+}
+
+test_signm!(f64, 1e-9);
+test_signm!(c64, 1e-9);
+
+#[test]
+fn signm_hermite() {
+    // sign(A) = V sign(D) V^H for Hermitian A = V D V^H
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hermite_using(4, &mut rng);
+    let s = a.signm().unwrap();
+    let (e, v) = a.eigh(UPLO::Upper).unwrap();
+    let d = Array2::from_diag(&e.mapv(|e| c64::new(e.signum(), 0.0)));
+    let ans = v.dot(&d).dot(&v.t().mapv(|x| x.conj()));
+    assert_close_l2!(&s, &ans, 1e-9);
+}
+
+#[test]
+fn signm_triangular() {
+    let a: Array2<f64> = array![[2.0, 1.0, 0.5], [0.0, -3.0, 1.0], [0.0, 0.0, 1.0]];
+    let s = a.signm().unwrap();
+    assert_close_l2!(&s.diag().to_owned(), &array![1.0, -1.0, 1.0], 1e-12);
+    test_signm(&a, 1e-12);
+}
+
+#[test]
+fn signm_imaginary_eigenvalues() {
+    // Rotation generator has eigenvalues +-i
+    let a: Array2<f64> = array![[0.0, -1.0], [1.0, 0.0]];
+    assert!(a.signm().is_err());
+}
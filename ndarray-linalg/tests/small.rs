@@ -0,0 +1,88 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn to_array<A: Scalar, const N: usize>(a: &Array2<A>) -> [[A; N]; N] {
+    let mut m = [[A::zero(); N]; N];
+    for i in 0..N {
+        for j in 0..N {
+            m[i][j] = a[(i, j)];
+        }
+    }
+    m
+}
+
+macro_rules! test_small {
+    ($type:ty, $n:expr, $det:ident, $inv:ident, $solve:ident) => {
+        paste::item! {
+            #[test]
+            fn [<small_ $type _ $n x $n>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using(($n, $n), &mut rng);
+                let b: Array1<$type> = random_using($n, &mut rng);
+                let m = to_array::<$type, $n>(&a);
+                let mut v = [b[0]; $n];
+                for i in 0..$n {
+                    v[i] = b[i];
+                }
+
+                let x = $solve(&m, &v).unwrap();
+                let x_lapack = a.factorize().unwrap().solve(&b).unwrap();
+                assert_close_l2!(&arr1(&x), &x_lapack, 1e-9);
+                assert_close_l2!(&solve_small(&a, &b).unwrap(), &x_lapack, 1e-9);
+                // dispatched in `Solve`
+                assert_close_l2!(&a.solve(&b).unwrap(), &x_lapack, 1e-9);
+
+                let inv = $inv(&m).unwrap();
+                let inv_lapack = a.factorize().unwrap().inv().unwrap();
+                for i in 0..$n {
+                    assert_close_l2!(&arr1(&inv[i]), &inv_lapack.row(i), 1e-9);
+                }
+
+                let det = $det(&m);
+                let det_lapack = a.det().unwrap();
+                assert_rclose!(det, det_lapack, 1e-9);
+            }
+        }
+    };
+}
+
+test_small!(f64, 2, det_2x2, inv_2x2, solve_2x2);
+test_small!(f64, 3, det_3x3, inv_3x3, solve_3x3);
+test_small!(f64, 4, det_4x4, inv_4x4, solve_4x4);
+test_small!(c64, 2, det_2x2, inv_2x2, solve_2x2);
+test_small!(c64, 3, det_3x3, inv_3x3, solve_3x3);
+test_small!(c64, 4, det_4x4, inv_4x4, solve_4x4);
+
+#[test]
+fn small_nearly_singular() {
+    let eps = 1e-12;
+    let a = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0 + eps]];
+    assert!(solve_3x3(&a, &[1.0, 2.0, 3.0]).is_none());
+    assert!(inv_3x3(&a).is_none());
+
+    // `solve_small` falls back to LAPACK
+    let a = arr2(&a);
+    let x = arr1(&[1.0, -1.0, 1.0]);
+    let b = a.dot(&x);
+    let y = solve_small(&a, &b).unwrap();
+    assert_close_l2!(&a.dot(&y), &b, 1e-7);
+    // `Solve` also falls back to LAPACK
+    let y = a.solve(&b).unwrap();
+    assert_close_l2!(&a.dot(&y), &b, 1e-7);
+}
+
+#[test]
+fn small_solve_threshold_setting() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((3, 3), &mut rng);
+    let b: Array1<f64> = random_using(3, &mut rng);
+    let x_small = arr1(&solve_3x3(&to_array::<f64, 3>(&a), &[b[0], b[1], b[2]]).unwrap());
+    let x_lapack = a.factorize().unwrap().solve(&b).unwrap();
+
+    assert_eq!(small_solve_threshold(), 4);
+    assert_eq!(a.solve(&b).unwrap(), x_small);
+    set_small_solve_threshold(2);
+    assert_eq!(a.solve(&b).unwrap(), x_lapack);
+    set_small_solve_threshold(4);
+    assert_eq!(a.solve(&b).unwrap(), x_small);
+}
@@ -1,7 +1,7 @@
 use ndarray::prelude::*;
 use ndarray_linalg::{
-    assert_aclose, assert_close_l2, c32, c64, random_hpd_using, random_using, solve::*,
-    OperationNorm, Scalar,
+    assert_aclose, assert_close_l2, c32, c64, random_hpd_using, random_using, solve::*, Diag,
+    IntoTriangular, OperationNorm, Scalar, UPLO,
 };
 
 macro_rules! test_solve {
@@ -280,3 +280,145 @@ fn rcond_identity() {
         rcond_identity!(c32, rows, 1e-3);
     }
 }
+
+#[test]
+fn solve_structured_triangular() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    for &uplo in &[UPLO::Upper, UPLO::Lower] {
+        let a: Array2<f64> = random_using((4, 4), &mut rng);
+        let a = a.into_triangular(uplo) + Array2::<f64>::eye(4) * 4.0;
+        let b: Array1<f64> = random_using(4, &mut rng);
+        let expected = a.solve(&b).unwrap();
+        assert_eq!(
+            detect_structure(&a, 0.0),
+            MatrixStructure::Triangular {
+                uplo,
+                diag: Diag::NonUnit
+            }
+        );
+        let hint = Some(MatrixStructure::Triangular {
+            uplo,
+            diag: Diag::NonUnit,
+        });
+        assert_close_l2!(&a.solve_structured(&b, hint).unwrap(), &expected, 1e-9);
+        assert_close_l2!(&a.solve_structured(&b, None).unwrap(), &expected, 1e-9);
+    }
+}
+
+#[test]
+fn solve_structured_general() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((4, 4), &mut rng);
+    let b: Array1<f64> = random_using(4, &mut rng);
+    assert_eq!(detect_structure(&a, 0.0), MatrixStructure::General);
+    let expected = a.solve(&b).unwrap();
+    assert_close_l2!(&a.solve_structured(&b, None).unwrap(), &expected, 1e-9);
+}
+
+#[test]
+fn woodbury_solve_low_rank_update() {
+    macro_rules! woodbury {
+        ($elem:ty, $rtol:expr) => {
+            let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+            let n = 6;
+            for k in 1..=3 {
+                let a: Array2<$elem> = random_using((n, n), &mut rng);
+                let u: Array2<$elem> = random_using((n, k), &mut rng);
+                let v: Array2<$elem> = random_using((n, k), &mut rng);
+                let b: Array1<$elem> = random_using(n, &mut rng);
+                let f = a.factorize().unwrap();
+                let x = woodbury_solve(&f, &u, &v, &b).unwrap();
+                let updated = &a + &u.dot(&v.t().mapv(|x| x.conj()));
+                let expected = updated.factorize_into().unwrap().solve_into(b).unwrap();
+                assert_close_l2!(&x, &expected, $rtol);
+            }
+        };
+    }
+    woodbury!(f64, 1e-9);
+    woodbury!(f32, 1e-3);
+    woodbury!(c64, 1e-9);
+    woodbury!(c32, 1e-3);
+}
+
+#[test]
+#[should_panic]
+fn woodbury_solve_rhs_length_mismatch() {
+    let a: Array2<f64> = Array2::eye(3);
+    let u: Array2<f64> = Array2::ones((3, 1));
+    let f = a.factorize().unwrap();
+    let _ = woodbury_solve(&f, &u, &u, &Array1::ones(2));
+}
+
+#[test]
+fn solve_auto_dispatch() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let n = 5;
+    let general: Array2<f64> = random_using((n, n), &mut rng);
+    let triangular = general.clone().into_triangular(UPLO::Lower) + Array2::<f64>::eye(n) * 4.0;
+    let hpd: Array2<f64> = random_hpd_using(n, &mut rng);
+    // Hermitian with positive diagonal, but indefinite since its leading 2x2 minor is negative
+    let mut indefinite = hpd.clone();
+    indefinite[(0, 1)] = 2.0 * (hpd[(0, 0)] * hpd[(1, 1)]).sqrt();
+    indefinite[(1, 0)] = indefinite[(0, 1)];
+    let b: Array1<f64> = random_using(n, &mut rng);
+
+    for (a, detected) in [
+        (&general, MatrixStructure::General),
+        (
+            &triangular,
+            MatrixStructure::Triangular {
+                uplo: UPLO::Lower,
+                diag: Diag::NonUnit,
+            },
+        ),
+        (
+            &hpd,
+            MatrixStructure::PositiveDefinite { uplo: UPLO::Upper },
+        ),
+        (
+            &indefinite,
+            MatrixStructure::Hermitian { uplo: UPLO::Upper },
+        ),
+    ] {
+        let expected = a.solve(&b).unwrap();
+        for a in [a.clone(), a.t().as_standard_layout().t().to_owned()] {
+            let mut a_ = a.clone();
+            let mut x = b.clone();
+            assert_eq!(solve_auto(&mut a_, &mut x, None).unwrap(), detected);
+            assert_close_l2!(&x, &expected, 1e-9);
+
+            let mut a_ = a.clone();
+            let mut x = b.clone();
+            assert_eq!(
+                solve_auto(&mut a_, &mut x, Some(detected)).unwrap(),
+                detected
+            );
+            assert_close_l2!(&x, &expected, 1e-9);
+        }
+    }
+}
+
+#[test]
+fn solve_auto_hint() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hpd_using(4, &mut rng);
+    let b: Array1<c64> = random_using(4, &mut rng);
+    let expected = a.solve(&b).unwrap();
+    for hint in [
+        MatrixStructure::General,
+        MatrixStructure::Hermitian { uplo: UPLO::Upper },
+        MatrixStructure::Hermitian { uplo: UPLO::Lower },
+        MatrixStructure::PositiveDefinite { uplo: UPLO::Upper },
+        MatrixStructure::PositiveDefinite { uplo: UPLO::Lower },
+    ] {
+        let mut a = a.clone();
+        let mut x = b.clone();
+        assert_eq!(solve_auto(&mut a, &mut x, Some(hint)).unwrap(), hint);
+        assert_close_l2!(&x, &expected, 1e-9);
+    }
+    assert_eq!(
+        detect_structure(&a, 0.0),
+        MatrixStructure::Hermitian { uplo: UPLO::Upper }
+    );
+    assert_close_l2!(&a.solve_structured(&b, None).unwrap(), &expected, 1e-9);
+}
@@ -0,0 +1,181 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn forward_error(x: &Array1<f64>, x_true: &Array1<f64>) -> f64 {
+    (x - x_true).norm_max() / x_true.norm_max()
+}
+
+#[test]
+fn solve_accurate_random() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((5, 5), &mut rng);
+    let x: Array1<f64> = random_using(5, &mut rng);
+    let b = a.dot(&x);
+    let (y, diag) = solve_accurate(&a, &b).unwrap();
+    assert_close_l2!(&y, &x, 1e-7);
+    assert!(diag.rcond > 0.0 && diag.rcond <= 1.0);
+    assert!(diag.backward_error < 1e-12);
+}
+
+#[test]
+fn solve_accurate_random_t() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_using((5, 5).f(), &mut rng);
+    let x: Array1<c64> = random_using(5, &mut rng);
+    let b = a.dot(&x);
+    let (y, _diag) = solve_accurate(&a, &b).unwrap();
+    assert_close_l2!(&y, &x, 1e-7);
+}
+
+#[test]
+fn solve_accurate_ill_conditioned() {
+    // Hilbert matrix with badly scaled rows and columns
+    let n = 10;
+    let a: Array2<f64> = Array2::from_shape_fn((n, n), |(i, j)| {
+        let scale = 10f64.powi(i as i32 - j as i32);
+        scale / (i + j + 1) as f64
+    });
+    let x: Array1<f64> = Array1::ones(n);
+    let b = a.dot(&x);
+
+    let x_plain = a.solve(&b).unwrap();
+    let (x_accurate, diag) = solve_accurate(&a, &b).unwrap();
+
+    let err_plain = forward_error(&x_plain, &x);
+    let err_accurate = forward_error(&x_accurate, &x);
+    assert!(diag.equilibrated);
+    assert!(err_accurate <= err_plain);
+    assert!(err_accurate <= diag.forward_error);
+}
+
+#[test]
+fn solvec_accurate_random() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hpd_using(5, &mut rng);
+    let x: Array1<c64> = random_using(5, &mut rng);
+    let b = a.dot(&x);
+    for uplo in [UPLO::Upper, UPLO::Lower] {
+        let (y, diag) = solvec_accurate(&a, uplo, &b).unwrap();
+        assert_close_l2!(&y, &x, 1e-7);
+        assert!(diag.rcond > 0.0 && diag.rcond <= 1.0);
+        assert!(diag.backward_error < 1e-12);
+    }
+}
+
+#[test]
+fn solvec_accurate_badly_scaled() {
+    // Diagonal scaling of a well-conditioned positive definite matrix
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let n = 6;
+    let a: Array2<f64> = random_hpd_using(n, &mut rng);
+    let d = Array1::from_shape_fn(n, |i| 10f64.powi(2 * i as i32 - 5));
+    let a = Array2::from_shape_fn((n, n), |(i, j)| d[i] * a[(i, j)] * d[j]);
+    let x: Array1<f64> = Array1::ones(n);
+    let b = a.dot(&x);
+    let (y, diag) = solvec_accurate(&a.reversed_axes(), UPLO::Lower, &b).unwrap();
+    assert!(diag.equilibrated);
+    assert!(forward_error(&y, &x) <= diag.forward_error);
+}
+
+#[test]
+fn solvec_accurate_not_positive_definite() {
+    let a = array![[1.0, 2.0], [2.0, 1.0]];
+    assert!(solvec_accurate(&a, UPLO::Upper, &array![1.0, 1.0]).is_err());
+}
+
+#[test]
+fn solve_refined_ill_conditioned() {
+    // Hilbert matrix
+    let n = 8;
+    let a: Array2<f64> = Array2::from_shape_fn((n, n), |(i, j)| 1.0 / (i + j + 1) as f64);
+    let x: Array1<f64> = Array1::ones(n);
+    let b = a.dot(&x);
+    let f = a.factorize().unwrap();
+    let (x_refined, err) = f.solve_refined(&a, &b).unwrap();
+    assert!(err.backward_error < 1e-14);
+    assert!(forward_error(&x_refined, &x) <= err.forward_error);
+}
+
+macro_rules! test_solve_refined {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<solve_refined_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_using((5, 5), &mut rng);
+                let x: Array1<$elem> = random_using(5, &mut rng);
+                let b = a.dot(&x);
+                // The original matrix may be in the different memory order from the factorization
+                let mut a_f = Array2::zeros((5, 5).f());
+                a_f.assign(&a);
+                for (a, a_orig) in [(&a, &a_f), (&a_f, &a)] {
+                    let (y, err) = a.factorize().unwrap().solve_refined(a_orig, &b).unwrap();
+                    assert_close_l2!(&y, &x, $rtol);
+                    assert!(err.backward_error < $rtol);
+                }
+            }
+
+            #[test]
+            fn [<solvec_refined_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hpd_using(5, &mut rng);
+                let x: Array1<$elem> = random_using(5, &mut rng);
+                let b = a.dot(&x);
+                let mut a_f = Array2::zeros((5, 5).f());
+                a_f.assign(&a);
+                for a in [&a, &a_f] {
+                    for uplo in [UPLO::Upper, UPLO::Lower] {
+                        let (y, err) = a.factorizec(uplo).unwrap().solve_refined(a, &b).unwrap();
+                        assert_close_l2!(&y, &x, $rtol);
+                        assert!(err.backward_error < $rtol);
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_solve_refined!(f64, 1e-7);
+test_solve_refined!(f32, 1e-3);
+test_solve_refined!(c64, 1e-7);
+test_solve_refined!(c32, 1e-3);
+
+#[test]
+fn solve_refined_shape_mismatch() {
+    let a = array![[4.0, 1.0], [1.0, 3.0]];
+    let f = a.factorize().unwrap();
+    assert!(f.solve_refined(&a, &array![1.0, 2.0, 3.0]).is_err());
+    assert!(f.solve_refined(&array![[1.0]], &array![1.0, 2.0]).is_err());
+}
+
+#[test]
+fn solve_mixed_precision_random() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((10, 10), &mut rng);
+    let x: Array1<f64> = random_using(10, &mut rng);
+    let b = a.dot(&x);
+    let (y, iter) = solve_mixed_precision(&a, &b).unwrap();
+    assert_close_l2!(&y, &x, 1e-9);
+    assert!(iter.is_some());
+}
+
+#[test]
+fn solve_mixed_precision_random_c64() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_using((10, 10).f(), &mut rng);
+    let x: Array1<c64> = random_using(10, &mut rng);
+    let b = a.dot(&x);
+    let (y, _iter) = solve_mixed_precision(&a, &b).unwrap();
+    assert_close_l2!(&y, &x, 1e-9);
+}
+
+#[test]
+fn solve_mixed_precision_ill_conditioned() {
+    // Hilbert matrix is too ill-conditioned for the single precision factorization
+    let n = 10;
+    let a: Array2<f64> = Array2::from_shape_fn((n, n), |(i, j)| 1.0 / (i + j + 1) as f64);
+    let b = a.dot(&Array1::ones(n));
+    let (y, iter) = solve_mixed_precision(&a, &b).unwrap();
+    assert_eq!(iter, None);
+    assert_close_l2!(&a.dot(&y), &b, 1e-9);
+}
@@ -68,3 +68,24 @@ fn solveh_random_t() {
     let y = f.solveh_into(b).unwrap();
     assert_close_l2!(&x, &y, 1e-7);
 }
+
+#[test]
+fn factorizeh_inertia() {
+    macro_rules! factorizeh_inertia {
+        ($elem:ty, $rows:expr) => {
+            let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+            let a: Array2<$elem> = random_hermite_using($rows, &mut rng);
+            let e = a.eigvalsh(UPLO::Upper).unwrap();
+            let pos = e.iter().filter(|&&x| x > 0.0).count();
+            let neg = e.iter().filter(|&&x| x < 0.0).count();
+            assert_eq!(a.factorizeh().unwrap().inertia(), (pos, neg, 0));
+            // The transpose of a Hermitian matrix has the same eigenvalues
+            let a_t = a.reversed_axes();
+            assert_eq!(a_t.factorizeh().unwrap().inertia(), (pos, neg, 0));
+        };
+    }
+    for rows in 1..8 {
+        factorizeh_inertia!(f64, rows);
+        factorizeh_inertia!(c64, rows);
+    }
+}
@@ -0,0 +1,75 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_sqrtm<T: Scalar + Lapack>(a: &Array2<T>, rtol: T::Real)
+where
+    T::Complex: Lapack,
+{
+    let s = a.sqrtm().unwrap();
+    assert_close_l2!(&s.dot(&s), &a.mapv(|x| x.as_c()), rtol);
+    // Principal square root has eigenvalues in the right half plane
+    let (e, _) = s.eig().unwrap();
+    assert!(e.iter().all(|e| e.re() > -rtol));
+}
+
+macro_rules! test_sqrtm {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<sqrtm_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((5, 5), &mut rng);
+                test_sqrtm(&a, $rtol);
+            }
+
+            #[test]
+            fn [<sqrtm_ $type _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((5, 5).f(), &mut rng);
+                test_sqrtm(&a, $rtol);
+            }
+
+            #[test]
+            fn [<sqrtm_hermite_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_hermite_using(5, &mut rng);
+                test_sqrtm(&a, $rtol);
+            }
+        }
+    };
+}
+
+test_sqrtm!(f64, 1e-9);
+test_sqrtm!(c64, 1e-9);
+
+#[test]
+fn sqrtm_hpd() {
+    // Coincides with the real square root by `ssqrt`
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hpd_using(4, &mut rng);
+    let s = a.sqrtm().unwrap();
+    let ans = a.ssqrt(UPLO::Lower).unwrap();
+    assert_close_l2!(&s, &ans.mapv(|x| x.as_c()), 1e-9);
+}
+
+#[test]
+fn sqrtm_negative_eigenvalue() {
+    let a: Array2<f64> = array![[-4.0, 1.0], [0.0, 9.0]];
+    let s = a.sqrtm().unwrap();
+    assert_close_l2!(&s.dot(&s), &a.mapv(|x| x.as_c()), 1e-12);
+    assert_close_l2!(
+        &s.diag().to_owned(),
+        &array![c64::new(0.0, 2.0), c64::new(3.0, 0.0)],
+        1e-12
+    );
+}
+
+#[test]
+fn sqrtm_singular() {
+    // Zero eigenvalues are allowed unless they form a Jordan block
+    let a: Array2<f64> = array![[0.0, 0.0], [0.0, 4.0]];
+    let s = a.sqrtm().unwrap();
+    assert_close_l2!(&s.dot(&s), &a.mapv(|x| x.as_c()), 1e-12);
+    let a: Array2<f64> = array![[0.0, 1.0], [0.0, 0.0]];
+    assert!(a.sqrtm().is_err());
+}
@@ -0,0 +1,156 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn assert_orthonormal<T: Scalar + Lapack>(q: &Array2<T>, rtol: T::Real) {
+    let qh: Array2<T> = conjugate(q);
+    assert_close_l2!(&qh.dot(q), &Array2::eye(q.ncols()), rtol);
+}
+
+macro_rules! test_subspace {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<subspace_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let tol = 1e-4;
+                // Rank 2 matrices
+                for (m, n) in [(5, 4), (3, 6), (4, 4)] {
+                    let b: Array2<$type> = random_using((m, 2), &mut rng);
+                    let c: Array2<$type> = random_using((2, n), &mut rng);
+                    let a = b.dot(&c);
+
+                    let (ns, rank) = a.null_space(tol).unwrap();
+                    assert_eq!(rank, 2);
+                    assert_eq!(ns.dim(), (n, n - 2));
+                    assert_orthonormal(&ns, $rtol);
+                    assert!(a.dot(&ns).iter().all(|x| x.abs() < $rtol));
+
+                    for (cs, rank) in [a.column_space(tol).unwrap(), a.column_space_qr(tol).unwrap()] {
+                        assert_eq!(rank, 2);
+                        assert_eq!(cs.dim(), (m, 2));
+                        assert_orthonormal(&cs, $rtol);
+                        // Columns of `A` are in the column space
+                        let csh: Array2<$type> = conjugate(&cs);
+                        assert_close_l2!(&cs.dot(&csh.dot(&a)), &a, $rtol);
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_subspace!(f64, 1e-9);
+test_subspace!(f32, 1e-3);
+test_subspace!(c64, 1e-9);
+test_subspace!(c32, 1e-3);
+
+#[test]
+fn subspace_full_rank() {
+    let a: Array2<f64> = Array2::eye(3);
+    let (ns, rank) = a.null_space(1e-10).unwrap();
+    assert_eq!(rank, 3);
+    assert_eq!(ns.dim(), (3, 0));
+    let (cs, _) = a.column_space(1e-10).unwrap();
+    assert_eq!(cs.dim(), (3, 3));
+}
+
+#[test]
+fn subspace_zero() {
+    let a: Array2<f64> = Array2::zeros((2, 3));
+    let (ns, rank) = a.null_space(1e-10).unwrap();
+    assert_eq!(rank, 0);
+    assert_eq!(ns.dim(), (3, 3));
+    let (cs, rank) = a.column_space_qr(1e-10).unwrap();
+    assert_eq!(rank, 0);
+    assert_eq!(cs.dim(), (2, 0));
+}
+
+#[test]
+fn column_space_qr_reduced_column() {
+    // The 2nd column vanishes after the 1st step without reflection
+    let a: Array2<f64> = array![[10.0, 9.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, 1.0]];
+    let (cs, rank) = a.column_space_qr(1e-10).unwrap();
+    assert_eq!(rank, 2);
+    // Projector onto span(e1, e2 + e3)
+    let p = array![[1.0, 0.0, 0.0], [0.0, 0.5, 0.5], [0.0, 0.5, 0.5]];
+    assert_close_l2!(&cs.dot(&cs.t()), &p, 1e-12);
+}
+
+macro_rules! test_rank {
+    ($type:ty) => {
+        paste::item! {
+            #[test]
+            fn [<rank_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                for (m, n, r) in [(5, 4, 2), (3, 6, 3), (4, 4, 4), (6, 6, 1)] {
+                    let b: Array2<$type> = random_using((m, r), &mut rng);
+                    let c: Array2<$type> = random_using((r, n), &mut rng);
+                    let a = b.dot(&c);
+                    assert_eq!(a.rank().unwrap(), r);
+                }
+            }
+        }
+    };
+}
+
+test_rank!(f64);
+test_rank!(f32);
+test_rank!(c64);
+test_rank!(c32);
+
+#[test]
+fn rank_qr() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    for (m, n, r) in [(5, 4, 2), (3, 6, 3), (4, 4, 4)] {
+        let b: Array2<f64> = random_using((m, r), &mut rng);
+        let c: Array2<f64> = random_using((r, n), &mut rng);
+        assert_eq!(b.dot(&c).rank_qr().unwrap(), r);
+    }
+}
+
+#[test]
+fn rank_qr_reduced_column() {
+    // The 2nd column is exactly zero after the 1st step without reflection,
+    // and must not be chosen as the 2nd pivot instead of the 3rd column
+    let a: Array2<f64> = array![[10.0, 9.0, 0.0], [0.0, 0.0, 1.0]];
+    assert_eq!(a.rank_qr().unwrap(), 2);
+}
+
+#[test]
+fn rank_with_tol() {
+    let a: Array2<f64> = Array2::from_diag(&array![3.0, 1.0, 1e-3, 0.0]);
+    assert_eq!(a.rank().unwrap(), 3);
+    assert_eq!(a.rank_with_tol(1e-2).unwrap(), 2);
+    // Singular value equal to the tolerance is regarded as zero
+    assert_eq!(a.rank_with_tol(1.0).unwrap(), 1);
+    assert_eq!(Array2::<f64>::zeros((3, 2)).rank().unwrap(), 0);
+    assert_eq!(Array2::<f64>::zeros((0, 2)).rank().unwrap(), 0);
+}
+
+macro_rules! test_orth {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<orth_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                // Full column rank, rank deficient, and wide matrices
+                let full: Array2<$type> = random_using((6, 4), &mut rng);
+                let b: Array2<$type> = random_using((6, 2), &mut rng);
+                let c: Array2<$type> = random_using((2, 4), &mut rng);
+                let wide: Array2<$type> = random_using((3, 5), &mut rng);
+                for (a, rank) in [(full, 4), (b.dot(&c), 2), (wide, 3)] {
+                    let q = orth(&a).unwrap();
+                    assert_eq!(q.dim(), (a.nrows(), rank));
+                    assert_orthonormal(&q, $rtol);
+                    let qh: Array2<$type> = conjugate(&q);
+                    assert_close_l2!(&q.dot(&qh.dot(&a)), &a, $rtol);
+                }
+            }
+        }
+    };
+}
+
+test_orth!(f64, 1e-9);
+test_orth!(f32, 1e-3);
+test_orth!(c64, 1e-9);
+test_orth!(c32, 1e-3);
@@ -19,6 +19,96 @@ fn test<T: Scalar + Lapack>(a: &Array2<T>) {
     assert_close_l2!(&u.dot(&sm).dot(&vt), &answer, T::real(1e-7));
 }
 
+/// Reconstruct `A = U Σ V^T` where `Σ` is a rectangular `n x m` diagonal matrix
+fn test_reconstruct<T: Scalar + Lapack>(a: &Array2<T>) {
+    let (n, m) = a.dim();
+    let k = min(n, m);
+    let (u, s, vt): (_, Array1<_>, _) = a.svd(true, true).unwrap();
+    let u: Array2<_> = u.unwrap();
+    let vt: Array2<_> = vt.unwrap();
+    assert_eq!(u.dim(), (n, n));
+    assert_eq!(s.dim(), k);
+    assert_eq!(vt.dim(), (m, m));
+    let mut sm = Array::<T, _>::zeros((n, m));
+    for i in 0..k {
+        sm[(i, i)] = T::from_real(s[i]);
+    }
+    assert_close_l2!(&u.dot(&sm).dot(&vt), a, T::real(1e-12));
+}
+
+/// Reconstruct `A = U Σ V^T` by the thin SVD where `Σ` is a square `k x k` diagonal matrix
+fn test_thin<T: Scalar + Lapack>(a: &Array2<T>) {
+    let (n, m) = a.dim();
+    let k = min(n, m);
+    let (u, s, vt): (_, Array1<_>, _) = a.svd_thin(true, true).unwrap();
+    let u: Array2<_> = u.unwrap();
+    let vt: Array2<_> = vt.unwrap();
+    assert_eq!(u.dim(), (n, k));
+    assert_eq!(s.dim(), k);
+    assert_eq!(vt.dim(), (k, m));
+    let sm = Array2::from_diag(&s.mapv(T::from_real));
+    assert_close_l2!(&u.dot(&sm).dot(&vt), a, T::real(1e-12));
+
+    let (u, _, vt) = a.svd_thin(false, true).unwrap();
+    assert!(u.is_none());
+    assert_eq!(vt.unwrap().dim(), (k, m));
+}
+
+/// Same as [test_thin] but by the one-sided Jacobi method
+fn test_jacobi<T: Scalar + Lapack>(a: &Array2<T>) {
+    let (n, m) = a.dim();
+    let k = min(n, m);
+    let (u, s, vt): (_, Array1<_>, _) = a.svd_jacobi(true, true).unwrap();
+    let u: Array2<_> = u.unwrap();
+    let vt: Array2<_> = vt.unwrap();
+    assert_eq!(u.dim(), (n, k));
+    assert_eq!(s.dim(), k);
+    assert_eq!(vt.dim(), (k, m));
+    let sm = Array2::from_diag(&s.mapv(T::from_real));
+    assert_close_l2!(&u.dot(&sm).dot(&vt), a, T::real(1e-12));
+
+    let (_, s_ref, _) = a.svd(false, false).unwrap();
+    assert_close_l2!(
+        &s.mapv(T::from_real),
+        &s_ref.mapv(T::from_real),
+        T::real(1e-12)
+    );
+}
+
+/// Compute the second and third largest singular values and vectors by `*gesvdx`
+fn test_select<T: Scalar + Lapack>(a: &Array2<T>) {
+    let (n, m) = a.dim();
+    let (_, s_ref, _) = a.svd(false, false).unwrap();
+    let (u, s, vt) = a
+        .svd_select_with_vectors(true, true, SingularRange::Indices(1..3))
+        .unwrap();
+    let u: Array2<_> = u.unwrap();
+    let vt: Array2<_> = vt.unwrap();
+    assert_eq!(u.dim(), (n, 2));
+    assert_eq!(vt.dim(), (2, m));
+    assert_close_l2!(
+        &s.mapv(T::from_real),
+        &s_ref.slice(s![1..3]).mapv(T::from_real),
+        T::real(1e-12)
+    );
+    // `A v_i = s_i u_i`
+    let v = vt.t().mapv(|x| x.conj());
+    let sm = Array2::from_diag(&s.mapv(T::from_real));
+    assert_close_l2!(&a.dot(&v), &u.dot(&sm), T::real(1e-12));
+
+    // The interval contains only `s_0` and `s_1`
+    let range = SingularRange::Values {
+        lower: (s_ref[1] + s_ref[2]) / T::real(2.0),
+        upper: s_ref[0] * T::real(2.0),
+    };
+    let s = a.svd_select(range).unwrap();
+    assert_close_l2!(
+        &s.mapv(T::from_real),
+        &s_ref.slice(s![..2]).mapv(T::from_real),
+        T::real(1e-12)
+    );
+}
+
 fn test_no_vt<T: Scalar + Lapack>(a: &Array2<T>) {
     let (n, _m) = a.dim();
     println!("a = \n{:?}", a);
@@ -80,6 +170,14 @@ test_svd_impl!(f64, test, 3, 4);
 test_svd_impl!(f64, test_no_vt, 3, 4);
 test_svd_impl!(f64, test_no_u, 3, 4);
 test_svd_impl!(f64, test_diag_only, 3, 4);
+test_svd_impl!(f64, test_reconstruct, 5, 3);
+test_svd_impl!(f64, test_reconstruct, 3, 5);
+test_svd_impl!(f64, test_thin, 7, 3);
+test_svd_impl!(f64, test_thin, 3, 7);
+test_svd_impl!(f64, test_jacobi, 7, 3);
+test_svd_impl!(f64, test_jacobi, 3, 7);
+test_svd_impl!(f64, test_select, 7, 3);
+test_svd_impl!(f64, test_select, 3, 7);
 test_svd_impl!(c64, test, 3, 3);
 test_svd_impl!(c64, test_no_vt, 3, 3);
 test_svd_impl!(c64, test_no_u, 3, 3);
@@ -92,3 +190,95 @@ test_svd_impl!(c64, test, 3, 4);
 test_svd_impl!(c64, test_no_vt, 3, 4);
 test_svd_impl!(c64, test_no_u, 3, 4);
 test_svd_impl!(c64, test_diag_only, 3, 4);
+test_svd_impl!(c64, test_reconstruct, 5, 3);
+test_svd_impl!(c64, test_reconstruct, 3, 5);
+test_svd_impl!(c64, test_thin, 7, 3);
+test_svd_impl!(c64, test_thin, 3, 7);
+test_svd_impl!(c64, test_jacobi, 7, 3);
+test_svd_impl!(c64, test_jacobi, 3, 7);
+test_svd_impl!(c64, test_select, 7, 3);
+test_svd_impl!(c64, test_select, 3, 7);
+
+#[test]
+fn svd_jacobi_graded() {
+    // `A = Q D` with orthogonal `Q` has exactly the singular values `D`,
+    // and the Jacobi method keeps the relative accuracy of the smallest one
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let q: Array2<f64> = random_unitary_using(4, &mut rng);
+    let d = arr1(&[1.0, 1e-5, 1e-10, 1e-15]);
+    let a = q.dot(&Array2::from_diag(&d));
+    let (_, s, _) = a.svd_jacobi(false, false).unwrap();
+    for (s, d) in s.iter().zip(d.iter()) {
+        assert!((s - d).abs() <= 1e-12 * d, "s = {}, d = {}", s, d);
+    }
+}
+
+#[test]
+fn principal_angles_same_subspace() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_using((5, 2), &mut rng);
+    let (q1, _): (Array2<c64>, Array2<c64>) = a.qr().unwrap();
+    // Different orthonormal basis of the same subspace
+    let r: Array2<c64> = random_unitary_using(2, &mut rng);
+    let q2 = q1.dot(&r);
+    let angles = principal_angles(&q1, &q2).unwrap();
+    assert_eq!(angles.len(), 2);
+    assert_close_max!(&angles, &Array1::zeros(2), 1e-6);
+}
+
+#[test]
+fn principal_angles_orthogonal_subspace() {
+    let e: Array2<f64> = Array::eye(4);
+    let q1 = e.slice(s![.., 0..2]);
+    let q2 = e.slice(s![.., 2..4]);
+    let angles = principal_angles(&q1, &q2).unwrap();
+    assert_close_max!(
+        &angles,
+        &Array1::from_elem(2, std::f64::consts::FRAC_PI_2),
+        1e-12
+    );
+}
+
+#[test]
+fn rank_at_threshold() {
+    // The smallest non-zero singular value is placed exactly at the threshold
+    let a: Array2<f64> = Array::from_diag(&arr1(&[4.0, 2.0, 0.5, 0.0]));
+    let (_, s, _) = a.svd(false, false).unwrap();
+    let s = s.to_vec();
+    assert_eq!(rank_from_singular_values(&s, 0.5, false), 2);
+    assert_eq!(rank_from_singular_values(&s, 0.5, true), 3);
+    assert_eq!(rank_from_singular_values(&s, 0.0, false), 3);
+}
+
+#[test]
+fn svd_drivers_agree() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((120, 110), &mut rng);
+    let l = a.layout().unwrap();
+    assert_eq!(SvdDriver::select(l, true, true), SvdDriver::Gesdd);
+    assert_eq!(SvdDriver::select(l, false, true), SvdDriver::Gesvd);
+
+    let s_gesvd = svd_with_driver(
+        SvdDriver::Gesvd,
+        l,
+        true,
+        false,
+        &mut a.clone().into_raw_vec(),
+    )
+    .unwrap()
+    .s;
+    let res = svd_with_driver(
+        SvdDriver::Gesdd,
+        l,
+        true,
+        false,
+        &mut a.clone().into_raw_vec(),
+    )
+    .unwrap();
+    assert!(res.u.is_some());
+    assert!(res.vt.is_none());
+    assert_close_l2!(&arr1(&res.s), &arr1(&s_gesvd), 1e-7);
+
+    let s_auto = svd_auto(l, true, true, &mut a.into_raw_vec()).unwrap().s;
+    assert_close_l2!(&arr1(&s_auto), &arr1(&s_gesvd), 1e-7);
+}
@@ -0,0 +1,70 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// `m x n` matrix with the given singular values
+fn with_singular_values<T: Scalar + Lapack>(
+    m: usize,
+    n: usize,
+    s: &[T::Real],
+    rng: &mut rand_pcg::Mcg128Xsl64,
+) -> Array2<T> {
+    let u: Array2<T> = random_unitary_using(m, rng);
+    let v: Array2<T> = random_unitary_using(n, rng);
+    let mut sigma = Array2::zeros((m, n));
+    for (i, s) in s.iter().enumerate() {
+        sigma[(i, i)] = T::from_real(*s);
+    }
+    u.dot(&sigma).dot(&v)
+}
+
+macro_rules! test_svd_partial {
+    ($type:ty, $m:expr, $n:expr, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<svd_partial_ $type _ $m x $n>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let s: Vec<_> = (0..std::cmp::min($m, $n) as i32).map(|i| (10.0 * 0.9_f64.powi(i)) as _).collect();
+                let a: Array2<$type> = with_singular_values($m, $n, &s, &mut rng);
+                let k = 5;
+                let res = svd_partial_using(&a, k, $rtol, 100, &mut rng).unwrap();
+                assert_eq!(res.u.dim(), ($m, k));
+                assert_eq!(res.vt.dim(), (k, $n));
+                assert_close_l2!(&res.s, &arr1(&s[..k]), $rtol);
+                // `A v_i = s_i u_i` and `A^H u_i = s_i v_i`
+                let v = res.vt.t().mapv(|x| x.conj());
+                let s_diag = Array2::from_diag(&res.s.mapv(<$type>::from_real));
+                assert_close_l2!(&a.dot(&v), &res.u.dot(&s_diag), $rtol);
+                let ah = a.t().mapv(|x| x.conj());
+                assert_close_l2!(&ah.dot(&res.u), &v.dot(&s_diag), $rtol);
+                assert!(res.residuals.iter().all(|&r| r <= $rtol * s[0]));
+            }
+        }
+    };
+}
+
+test_svd_partial!(f64, 200, 120, 1e-9);
+test_svd_partial!(f64, 120, 200, 1e-9);
+test_svd_partial!(c64, 200, 120, 1e-9);
+test_svd_partial!(c64, 120, 200, 1e-9);
+
+#[test]
+fn svd_partial_all() {
+    // The Krylov subspace covers the whole space if `k = min(m, n)`
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_using((30, 20), &mut rng);
+    let res = svd_partial_using(&a, 20, 1e-10, 10, &mut rng).unwrap();
+    let (_, s, _) = a.svd(false, false).unwrap();
+    assert_close_l2!(&res.s, &s, 1e-10);
+}
+
+#[test]
+fn svd_partial_max_restarts() {
+    // Residuals are reported even if the iteration does not converge
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let s: Vec<f64> = (0..200).map(|i| 1.0 / (i as f64 + 1.0).sqrt()).collect();
+    let a: Array2<f64> = with_singular_values(300, 200, &s, &mut rng);
+    let res = svd_partial_using(&a, 3, 1e-14, 0, &mut rng).unwrap();
+    assert_eq!(res.restarts, 0);
+    assert_eq!(res.residuals.len(), 3);
+    assert!(res.residuals.iter().any(|&r| r > 1e-14));
+}
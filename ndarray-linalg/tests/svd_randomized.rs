@@ -0,0 +1,82 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// `m x n` matrix with the given singular values
+fn with_singular_values<T: Scalar + Lapack>(
+    m: usize,
+    n: usize,
+    s: &[T::Real],
+    rng: &mut rand_pcg::Mcg128Xsl64,
+) -> Array2<T> {
+    let u: Array2<T> = random_unitary_using(m, rng);
+    let v: Array2<T> = random_unitary_using(n, rng);
+    let mut sigma = Array2::zeros((m, n));
+    for (i, s) in s.iter().enumerate() {
+        sigma[(i, i)] = T::from_real(*s);
+    }
+    u.dot(&sigma).dot(&v)
+}
+
+macro_rules! test_svd_randomized {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<svd_randomized_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let s: Vec<_> = (0..40).map(|i| 0.5_f64.powi(i) as _).collect();
+                let a: Array2<$type> = with_singular_values(60, 40, &s, &mut rng);
+                let k = 5;
+                let res = svd_randomized_using(&a, k, 10, PowerIteration::Fixed(2), &mut rng).unwrap();
+                assert_eq!(res.iterations, 2);
+                assert_eq!(res.u.dim(), (60, k));
+                assert_eq!(res.vt.dim(), (k, 40));
+                assert_close_l2!(&res.s, &arr1(&s[..k]), $rtol);
+                // `U^H A V = S`
+                let uh = res.u.t().mapv(|x| x.conj());
+                let vh = res.vt.t().mapv(|x| x.conj());
+                let s_diag = Array2::from_diag(&res.s.mapv(<$type>::from_real));
+                assert_close_l2!(&uh.dot(&a).dot(&vh), &s_diag, $rtol);
+            }
+        }
+    };
+}
+
+test_svd_randomized!(f64, 1e-7);
+test_svd_randomized!(c64, 1e-7);
+
+#[test]
+fn svd_randomized_adaptive() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let (m, n, k) = (100, 60, 5);
+    let power = PowerIteration::Adaptive {
+        tol: 1e-6,
+        max_iters: 30,
+    };
+
+    // fast decay converges in a few iterations
+    let s: Vec<f64> = (0..n).map(|i| 0.1_f64.powi(i as i32)).collect();
+    let a: Array2<f64> = with_singular_values(m, n, &s, &mut rng);
+    let fast = svd_randomized_using(&a, k, 5, power, &mut rng).unwrap();
+    assert!(fast.iterations <= 3);
+    assert_close_l2!(&fast.s, &arr1(&s[..k]), 1e-6);
+
+    // slow decay needs more iterations
+    let s: Vec<f64> = (0..n).map(|i| 1.0 / (i as f64 + 1.0).sqrt()).collect();
+    let a: Array2<f64> = with_singular_values(m, n, &s, &mut rng);
+    let slow = svd_randomized_using(&a, k, 5, power, &mut rng).unwrap();
+    assert!(slow.iterations > fast.iterations);
+    assert!(slow.iterations <= 30);
+}
+
+#[test]
+fn svd_randomized_low_rank() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let (m, n, k) = (1000, 80, 4);
+    let s = [8.0, 4.0, 2.0, 1.0];
+    let a: Array2<f64> = with_singular_values(m, n, &s, &mut rng);
+    let res = svd_randomized(&a, k, 5, 1).unwrap();
+    assert_eq!(res.iterations, 1);
+    assert_close_l2!(&res.s, &arr1(&s), 1e-9);
+    let sigma = Array2::from_diag(&res.s);
+    assert_close_l2!(&res.u.dot(&sigma).dot(&res.vt), &a, 1e-9);
+}
@@ -0,0 +1,71 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_sylvester<T: Scalar + Lapack>(a: &Array2<T>, b: &Array2<T>, c: &Array2<T>, rtol: T::Real) {
+    let x = solve_sylvester(a, b, c).unwrap();
+    assert_close_l2!(&(a.dot(&x) + x.dot(b)), c, rtol);
+}
+
+macro_rules! test_sylvester {
+    ($type:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<sylvester_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((4, 4), &mut rng);
+                let b: Array2<$type> = random_using((3, 3), &mut rng);
+                let c: Array2<$type> = random_using((4, 3), &mut rng);
+                test_sylvester(&a, &b, &c, $rtol);
+            }
+
+            #[test]
+            fn [<sylvester_ $type _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((4, 4).f(), &mut rng);
+                let b: Array2<$type> = random_using((3, 3).f(), &mut rng);
+                let c: Array2<$type> = random_using((4, 3).f(), &mut rng);
+                test_sylvester(&a, &b, &c, $rtol);
+            }
+
+            #[test]
+            fn [<sylvester_mixed_layout_ $type>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$type> = random_using((4, 4), &mut rng);
+                let b: Array2<$type> = random_using((3, 3).f(), &mut rng);
+                let c: Array2<$type> = random_using((4, 3), &mut rng);
+                test_sylvester(&a, &b, &c, $rtol);
+            }
+        }
+    };
+}
+
+test_sylvester!(f64, 1e-9);
+test_sylvester!(c64, 1e-9);
+
+#[test]
+fn sylvester_diagonal() {
+    // x_ij = c_ij / (a_i + b_j)
+    let a = Array2::from_diag(&arr1(&[1.0, 2.0]));
+    let b = Array2::from_diag(&arr1(&[3.0, 4.0, 5.0]));
+    let c = Array2::<f64>::ones((2, 3));
+    let x = solve_sylvester(&a, &b, &c).unwrap();
+    let ans = array![[1. / 4., 1. / 5., 1. / 6.], [1. / 5., 1. / 6., 1. / 7.]];
+    assert_close_l2!(&x, &ans, 1e-12);
+}
+
+#[test]
+fn sylvester_singular() {
+    // A and -B share the eigenvalue 1
+    let a = Array2::from_diag(&arr1(&[1.0, 2.0]));
+    let b = Array2::from_diag(&arr1(&[-1.0, 3.0]));
+    let c = Array2::<f64>::ones((2, 2));
+    assert!(solve_sylvester(&a, &b, &c).is_err());
+}
+
+#[test]
+fn sylvester_shape_mismatch() {
+    let a = Array2::<f64>::eye(2);
+    let b = Array2::<f64>::eye(3);
+    let c = Array2::<f64>::ones((3, 2));
+    assert!(solve_sylvester(&a, &b, &c).is_err());
+}
@@ -0,0 +1,53 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_toeplitz<T: Scalar + Lapack>(rtol: T::Real) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let n = 8;
+    let mut c: Array1<T> = random_using(n, &mut rng);
+    let mut r: Array1<T> = random_using(n, &mut rng);
+    // Diagonally dominant so that all the leading submatrices are non-singular
+    c[0] = T::from_real(T::real(n));
+    r[0] = c[0];
+    let t = ToeplitzMatrix::new(c, r);
+    let a = t.to_dense();
+    let b: Array1<T> = random_using(n, &mut rng);
+    assert_close_l2!(&t.solve(&b).unwrap(), &a.solve(&b).unwrap(), rtol);
+    assert_close_l2!(&t.solve_t(&b).unwrap(), &a.solve_t(&b).unwrap(), rtol);
+    assert_close_l2!(&t.solve_h(&b).unwrap(), &a.solve_h(&b).unwrap(), rtol);
+}
+
+#[test]
+fn toeplitz_solve() {
+    test_toeplitz::<f64>(1e-9);
+    test_toeplitz::<f32>(1e-4);
+    test_toeplitz::<c64>(1e-9);
+    test_toeplitz::<c32>(1e-4);
+}
+
+#[test]
+fn toeplitz_hermitian() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let c: Array1<c64> = random_using(5, &mut rng);
+    let a = ToeplitzMatrix::hermitian(c).to_dense();
+    assert_close_l2!(&a, &a.t().mapv(|x| x.conj()), 1e-12);
+}
+
+#[test]
+fn toeplitz_singular_leading_minor() {
+    // Non-singular, but the leading 1x1 submatrix is zero
+    let t = ToeplitzMatrix::new(array![0.0, 1.0], array![0.0, 1.0]);
+    assert!(t.solve(&array![1.0, 1.0]).is_err());
+}
+
+#[test]
+fn yule_walker() {
+    // Autocorrelation of the AR(2) process x_t = 0.5 x_{t-1} - 0.3 x_{t-2} + e_t
+    let (a1, a2) = (0.5, -0.3);
+    let mut acf = vec![1.0, a1 / (1.0 - a2)];
+    for k in 2..6 {
+        acf.push(a1 * acf[k - 1] + a2 * acf[k - 2]);
+    }
+    let a = solve_yule_walker(&Array1::from(acf)).unwrap();
+    assert_close_l2!(&a, &array![a1, a2, 0.0, 0.0, 0.0], 1e-12);
+}
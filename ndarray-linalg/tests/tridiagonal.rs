@@ -175,6 +175,30 @@ fn extract_tridiagonal_solve_random() {
     assert_close_l2!(&y1, &y2, 1e-7);
 }
 
+#[test]
+fn factorize_tridiagonal_reuse() {
+    // factorize once by `*gttrf`, and solve for two right hand sides by `*gttrs`
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let n = 6;
+    let mut a: Array2<c64> = random_using((n, n), &mut rng);
+    for ((i, j), a) in a.indexed_iter_mut() {
+        if i.abs_diff(j) > 1 {
+            *a = c64::new(0.0, 0.0);
+        }
+    }
+    let tridiag = a.extract_tridiagonal().unwrap();
+    let f = tridiag.factorize_tridiagonal().unwrap();
+    for _ in 0..2 {
+        let x: Array1<c64> = random_using(n, &mut rng);
+        let b = a.dot(&x);
+        let y = f.solve_tridiagonal(&b).unwrap();
+        // compare with the one-shot solver which factorizes every time
+        let y_fresh = tridiag.solve_tridiagonal(&b).unwrap();
+        assert_close_l2!(&y, &x, 1e-7);
+        assert_close_l2!(&y, &y_fresh, 1e-7);
+    }
+}
+
 #[test]
 fn det_tridiagonal_f64() {
     let a: Array2<f64> = arr2(&[[10.0, -9.0, 0.0], [7.0, -12.0, 11.0], [0.0, 10.0, 3.0]]);
@@ -265,3 +289,98 @@ fn rcond_tridiagonal_identity() {
         rcond_identity!(c32, rows, 1e-3);
     }
 }
+
+#[test]
+fn eigh_tridiagonal_laplacian() {
+    // 1D Laplacian has eigenvalues 2 - 2cos(k pi / (n + 1)), k = 1, ..., n
+    let n = 10;
+    let d: Array1<f64> = Array1::from_elem(n, 2.0);
+    let e: Array1<f64> = Array1::from_elem(n - 1, -1.0);
+    let ans = Array1::from_shape_fn(n, |k| {
+        2.0 - 2.0 * ((k + 1) as f64 * std::f64::consts::PI / (n + 1) as f64).cos()
+    });
+
+    let w = (d.clone(), e.clone()).eigvalsh_tridiagonal().unwrap();
+    assert_close_l2!(&w, &ans, 1e-7);
+
+    let (w, v) = (d.clone(), e.clone()).eigh_tridiagonal().unwrap();
+    assert_close_l2!(&w, &ans, 1e-7);
+    let mut t = Array2::from_diag(&d);
+    for i in 0..n - 1 {
+        t[(i, i + 1)] = e[i];
+        t[(i + 1, i)] = e[i];
+    }
+    assert_close_l2!(&t.dot(&v), &(&v * &w), 1e-7);
+    assert_close_l2!(&v.t().dot(&v), &Array2::eye(n), 1e-7);
+}
+
+#[should_panic]
+#[test]
+fn eigh_tridiagonal_length_mismatch() {
+    let d: Array1<f64> = Array1::ones(3);
+    let e: Array1<f64> = Array1::ones(3);
+    let _ = (d, e).eigvalsh_tridiagonal();
+}
+
+#[test]
+fn eigh_tridiagonal_select() {
+    let n = 10;
+    let d: Array1<f64> = Array1::from_elem(n, 2.0);
+    let e: Array1<f64> = Array1::from_elem(n - 1, -1.0);
+    let (ans, v_ans) = (d.clone(), e.clone()).eigh_tridiagonal().unwrap();
+
+    let (w, v) = (d.clone(), e.clone())
+        .eigh_tridiagonal_select(false, EighRange::All)
+        .unwrap();
+    assert!(v.is_none());
+    assert_close_l2!(&w, &ans, 1e-9);
+
+    let (w, v) = (d.clone(), e.clone())
+        .eigh_tridiagonal_select(true, EighRange::Indices(2..5))
+        .unwrap();
+    assert_close_l2!(&w, &ans.slice(s![2..5]).to_owned(), 1e-9);
+    let v = v.unwrap();
+    assert_eq!(v.dim(), (n, 3));
+    for k in 0..3 {
+        // Eigenvectors are unique up to sign
+        let overlap = v.column(k).dot(&v_ans.column(k + 2));
+        assert!((overlap.abs() - 1.0).abs() < 1e-9);
+    }
+
+    let (w, _) = (d, e)
+        .eigh_tridiagonal_select(
+            false,
+            EighRange::Values {
+                lower: 1.0,
+                upper: 3.0,
+            },
+        )
+        .unwrap();
+    let expected: Vec<f64> = ans
+        .iter()
+        .cloned()
+        .filter(|&x| x > 1.0 && x <= 3.0)
+        .collect();
+    assert_close_l2!(&w, &Array1::from(expected), 1e-9);
+}
+
+#[test]
+fn eigh_tridiagonal_struct() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let d: Array1<f64> = random_using(6, &mut rng);
+    let e: Array1<f64> = random_using(5, &mut rng);
+    let mut a = Array2::from_diag(&d);
+    for i in 0..5 {
+        a[(i, i + 1)] = e[i];
+        a[(i + 1, i)] = e[i];
+    }
+    let t = a.extract_tridiagonal().unwrap();
+    let ans = a.eigvalsh(UPLO::Upper).unwrap();
+    assert_close_l2!(&t.eigvalsh_tridiagonal().unwrap(), &ans, 1e-9);
+    let (w, v) = t
+        .eigh_tridiagonal_select(true, EighRange::Indices(5..6))
+        .unwrap();
+    assert_close_l2!(&w, &ans.slice(s![5..]).to_owned(), 1e-9);
+    let v = v.unwrap();
+    assert_close_l2!(&a.dot(&v), &(&v * &w), 1e-9);
+}